@@ -1,8 +1,10 @@
 use call_args::CallArgs;
+use errors::account_validation_error::AccountValidationError;
 use model::{
-    ApiList, Account, AccountRejectReason, BankAccount, Card, Currency, Delete, NewLegalEntity,
-    NewBankAccount, NewCard, TosAcceptance, TransferSchedule
- };
+    Account, AccountId, AccountRejectReason, ApiList, BankAccount, Card, CardId, Country, Currency,
+    DeclineChargeOn, Delete, NewBankAccount, NewCard, NewLegalEntity, TokenId, TosAcceptance,
+    TransferSchedule
+};
 use std::collections::BTreeMap;
 use super::ApiCall;
 use {Result, StripeClient};
@@ -10,11 +12,11 @@ use {Result, StripeClient};
 #[derive(Debug)]
 pub struct RetrieveAccountCall<'a> {
     client: &'a StripeClient,
-    account_id: Option<String>
+    account_id: Option<AccountId>
 }
 
 impl<'a> RetrieveAccountCall<'a> {
-    pub fn new(client: &'a StripeClient, account_id: Option<String>) -> RetrieveAccountCall<'a> {
+    pub fn new(client: &'a StripeClient, account_id: Option<AccountId>) -> RetrieveAccountCall<'a> {
         RetrieveAccountCall {
             client: client,
             account_id: account_id
@@ -23,10 +25,14 @@ impl<'a> RetrieveAccountCall<'a> {
 }
 
 impl<'a> ApiCall<Account> for RetrieveAccountCall<'a> {
-    fn call(self) -> Result<Account> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveAccountCall"
+    }
+
+    fn execute(self) -> Result<Account> {
         let endpoint = self.account_id
             .map(|id| format!("/accounts/{}", id))
-            .unwrap_or("/accounts".to_string());
+            .unwrap_or("/account".to_string());
         self.client.get(endpoint, &())
     }
 }
@@ -60,7 +66,7 @@ impl<'a> CreateAccountCall<'a> {
         self
     }
 
-    pub fn country(mut self, country: String) -> Self {
+    pub fn country(mut self, country: Country) -> Self {
         self.args.add_arg("country", country);
         self
     }
@@ -71,10 +77,7 @@ impl<'a> CreateAccountCall<'a> {
     }
 
     pub fn decline_charge_on(mut self, avs_failure: bool, cvc_failure: bool) -> Self {
-        self.args.add_object("decline_charge_on", (
-            ("avs_failure", avs_failure.to_string()),
-            ("cvc_failure", cvc_failure.to_string())
-        ));
+        self.args.add_object("decline_charge_on", DeclineChargeOn::new(avs_failure, cvc_failure));
         self
     }
 
@@ -148,14 +151,24 @@ impl<'a> CreateAccountCall<'a> {
         self
     }
 
-    pub fn transfer_schedule(mut self, transfer_schedule: TransferSchedule) -> Self {
+    /// Refuses a schedule that fails `TransferSchedule::validate` rather
+    /// than leaving Stripe to reject the call.
+    pub fn transfer_schedule(
+        mut self,
+        transfer_schedule: TransferSchedule
+    ) -> ::std::result::Result<Self, AccountValidationError> {
+        transfer_schedule.validate()?;
         self.args.add_object("transfer_schedule", transfer_schedule);
-        self
+        Ok(self)
     }
 }
 
 impl<'a> ApiCall<Account> for CreateAccountCall<'a> {
-    fn call(self) -> Result<Account> {
+    fn call_type(&self) -> &'static str {
+        "CreateAccountCall"
+    }
+
+    fn execute(self) -> Result<Account> {
         self.client.post("/accounts", &self.args)
     }
 }
@@ -163,12 +176,12 @@ impl<'a> ApiCall<Account> for CreateAccountCall<'a> {
 #[derive(Debug)]
 pub struct UpdateAccountCall<'a> {
     client: &'a StripeClient,
-    account_id: String,
+    account_id: AccountId,
     args: CallArgs
 }
 
 impl<'a> UpdateAccountCall<'a> {
-    pub fn new(client: &'a StripeClient, account_id: String) -> UpdateAccountCall<'a> {
+    pub fn new(client: &'a StripeClient, account_id: AccountId) -> UpdateAccountCall<'a> {
         UpdateAccountCall {
             client: client,
             account_id: account_id,
@@ -197,10 +210,7 @@ impl<'a> UpdateAccountCall<'a> {
     }
 
     pub fn decline_charge_on(mut self, avs_failure: bool, cvc_failure: bool) -> Self {
-        self.args.add_object("decline_charge_on", (
-            ("avs_failure", avs_failure.to_string()),
-            ("cvc_failure", cvc_failure.to_string())
-        ));
+        self.args.add_object("decline_charge_on", DeclineChargeOn::new(avs_failure, cvc_failure));
         self
     }
 
@@ -269,14 +279,24 @@ impl<'a> UpdateAccountCall<'a> {
         self
     }
 
-    pub fn transfer_schedule(mut self, transfer_schedule: TransferSchedule) -> Self {
+    /// Refuses a schedule that fails `TransferSchedule::validate` rather
+    /// than leaving Stripe to reject the call.
+    pub fn transfer_schedule(
+        mut self,
+        transfer_schedule: TransferSchedule
+    ) -> ::std::result::Result<Self, AccountValidationError> {
+        transfer_schedule.validate()?;
         self.args.add_object("transfer_schedule", transfer_schedule);
-        self
+        Ok(self)
     }
 }
 
 impl<'a> ApiCall<Account> for UpdateAccountCall<'a> {
-    fn call(self) -> Result<Account> {
+    fn call_type(&self) -> &'static str {
+        "UpdateAccountCall"
+    }
+
+    fn execute(self) -> Result<Account> {
         self.client.post(format!("/accounts/{}", self.account_id), &self.args)
     }
 }
@@ -284,11 +304,11 @@ impl<'a> ApiCall<Account> for UpdateAccountCall<'a> {
 #[derive(Debug)]
 pub struct DeleteAccountCall<'a> {
     client: &'a StripeClient,
-    account_id: String
+    account_id: AccountId
 }
 
 impl<'a> DeleteAccountCall<'a> {
-    pub fn new(client: &'a StripeClient, account_id: String) -> DeleteAccountCall<'a> {
+    pub fn new(client: &'a StripeClient, account_id: AccountId) -> DeleteAccountCall<'a> {
         DeleteAccountCall {
             client: client,
             account_id: account_id
@@ -297,7 +317,11 @@ impl<'a> DeleteAccountCall<'a> {
 }
 
 impl<'a> ApiCall<Delete> for DeleteAccountCall<'a> {
-    fn call(self) -> Result<Delete> {
+    fn call_type(&self) -> &'static str {
+        "DeleteAccountCall"
+    }
+
+    fn execute(self) -> Result<Delete> {
         self.client.delete(format!("/accounts/{}", self.account_id))
     }
 }
@@ -305,14 +329,14 @@ impl<'a> ApiCall<Delete> for DeleteAccountCall<'a> {
 #[derive(Debug)]
 pub struct RejectAccountCall<'a> {
     client: &'a StripeClient,
-    account_id: String,
+    account_id: AccountId,
     reason: AccountRejectReason
 }
 
 impl<'a> RejectAccountCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        account_id: String,
+        account_id: AccountId,
         reason: AccountRejectReason
     ) -> RejectAccountCall<'a> {
         RejectAccountCall {
@@ -324,7 +348,11 @@ impl<'a> RejectAccountCall<'a> {
 }
 
 impl<'a> ApiCall<Account> for RejectAccountCall<'a> {
-    fn call(self) -> Result<Account> {
+    fn call_type(&self) -> &'static str {
+        "RejectAccountCall"
+    }
+
+    fn execute(self) -> Result<Account> {
         self.client.post(
             format!("/accounts/{}/reject", self.account_id),
             &("reason", self.reason.to_string())
@@ -363,7 +391,11 @@ impl<'a> ListAccountsCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<Account>> for ListAccountsCall<'a> {
-    fn call(self) -> Result<ApiList<Account>> {
+    fn call_type(&self) -> &'static str {
+        "ListAccountsCall"
+    }
+
+    fn execute(self) -> Result<ApiList<Account>> {
         self.client.get("/accounts", &self.args)
     }
 }
@@ -371,12 +403,12 @@ impl<'a> ApiCall<ApiList<Account>> for ListAccountsCall<'a> {
 #[derive(Debug)]
 pub struct AccountCreateBankAccountCall<'a> {
     client: &'a StripeClient,
-    account_id: String,
+    account_id: AccountId,
     args: CallArgs
 }
 
 impl<'a> AccountCreateBankAccountCall<'a> {
-    pub fn new(client: &'a StripeClient, account_id: String) -> AccountCreateBankAccountCall<'a> {
+    pub fn new(client: &'a StripeClient, account_id: AccountId) -> AccountCreateBankAccountCall<'a> {
         AccountCreateBankAccountCall {
             client: client,
             account_id: account_id,
@@ -384,7 +416,7 @@ impl<'a> AccountCreateBankAccountCall<'a> {
         }
     }
 
-    pub fn bank_account_token(mut self, bank_account_token: String) -> Self {
+    pub fn bank_account_token(mut self, bank_account_token: TokenId) -> Self {
         self.args.add_arg("external_account", bank_account_token);
         self
     }
@@ -406,7 +438,11 @@ impl<'a> AccountCreateBankAccountCall<'a> {
 }
 
 impl<'a> ApiCall<BankAccount> for AccountCreateBankAccountCall<'a> {
-    fn call(self) -> Result<BankAccount> {
+    fn call_type(&self) -> &'static str {
+        "AccountCreateBankAccountCall"
+    }
+
+    fn execute(self) -> Result<BankAccount> {
         self.client.post(format!("/accounts/{}/external_accounts", self.account_id), &self.args)
     }
 }
@@ -414,14 +450,14 @@ impl<'a> ApiCall<BankAccount> for AccountCreateBankAccountCall<'a> {
 #[derive(Debug)]
 pub struct AccountRetrieveBankAccountCall<'a> {
     client: &'a StripeClient,
-    account_id: String,
+    account_id: AccountId,
     external_account_id: String
 }
 
 impl<'a> AccountRetrieveBankAccountCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        account_id: String,
+        account_id: AccountId,
         external_account_id: String
     ) -> AccountRetrieveBankAccountCall<'a> {
         AccountRetrieveBankAccountCall {
@@ -433,7 +469,11 @@ impl<'a> AccountRetrieveBankAccountCall<'a> {
 }
 
 impl<'a> ApiCall<BankAccount> for AccountRetrieveBankAccountCall<'a> {
-    fn call(self) -> Result<BankAccount> {
+    fn call_type(&self) -> &'static str {
+        "AccountRetrieveBankAccountCall"
+    }
+
+    fn execute(self) -> Result<BankAccount> {
         self.client.get(
             format!("/accounts/{}/external_accounts/{}", self.account_id, self.external_account_id),
             &()
@@ -444,7 +484,7 @@ impl<'a> ApiCall<BankAccount> for AccountRetrieveBankAccountCall<'a> {
 #[derive(Debug)]
 pub struct AccountUpdateBankAccountCall<'a> {
     client: &'a StripeClient,
-    account_id: String,
+    account_id: AccountId,
     external_account_id: String,
     args: CallArgs
 }
@@ -452,7 +492,7 @@ pub struct AccountUpdateBankAccountCall<'a> {
 impl<'a> AccountUpdateBankAccountCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        account_id: String,
+        account_id: AccountId,
         external_account_id: String
     ) -> AccountUpdateBankAccountCall<'a> {
         AccountUpdateBankAccountCall {
@@ -475,7 +515,11 @@ impl<'a> AccountUpdateBankAccountCall<'a> {
 }
 
 impl<'a> ApiCall<BankAccount> for AccountUpdateBankAccountCall<'a> {
-    fn call(self) -> Result<BankAccount> {
+    fn call_type(&self) -> &'static str {
+        "AccountUpdateBankAccountCall"
+    }
+
+    fn execute(self) -> Result<BankAccount> {
         self.client.post(
             format!("/accounts/{}/external_accounts/{}", self.account_id, self.external_account_id),
             &self.args
@@ -486,14 +530,14 @@ impl<'a> ApiCall<BankAccount> for AccountUpdateBankAccountCall<'a> {
 #[derive(Debug)]
 pub struct AccountDeleteBankAccountCall<'a> {
     client: &'a StripeClient,
-    account_id: String,
+    account_id: AccountId,
     external_account_id: String
 }
 
 impl<'a> AccountDeleteBankAccountCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        account_id: String,
+        account_id: AccountId,
         external_account_id: String
     ) -> AccountDeleteBankAccountCall<'a> {
         AccountDeleteBankAccountCall {
@@ -505,7 +549,11 @@ impl<'a> AccountDeleteBankAccountCall<'a> {
 }
 
 impl<'a> ApiCall<Delete> for AccountDeleteBankAccountCall<'a> {
-    fn call(self) -> Result<Delete> {
+    fn call_type(&self) -> &'static str {
+        "AccountDeleteBankAccountCall"
+    }
+
+    fn execute(self) -> Result<Delete> {
         self.client.delete(
             format!("/accounts/{}/external_accounts/{}",
             self.account_id,
@@ -517,12 +565,12 @@ impl<'a> ApiCall<Delete> for AccountDeleteBankAccountCall<'a> {
 #[derive(Debug)]
 pub struct AccountListBankAccountsCall<'a> {
     client: &'a StripeClient,
-    account_id: String,
+    account_id: AccountId,
     args: CallArgs
 }
 
 impl<'a> AccountListBankAccountsCall<'a> {
-    pub fn new(client: &'a StripeClient, account_id: String) -> AccountListBankAccountsCall<'a> {
+    pub fn new(client: &'a StripeClient, account_id: AccountId) -> AccountListBankAccountsCall<'a> {
         AccountListBankAccountsCall {
             client: client,
             account_id: account_id,
@@ -550,7 +598,11 @@ impl<'a> AccountListBankAccountsCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<BankAccount>> for AccountListBankAccountsCall<'a> {
-    fn call(self) -> Result<ApiList<BankAccount>> {
+    fn call_type(&self) -> &'static str {
+        "AccountListBankAccountsCall"
+    }
+
+    fn execute(self) -> Result<ApiList<BankAccount>> {
         self.client.get(format!("/accounts/{}/external_accounts", self.account_id), &self.args)
     }
 }
@@ -558,12 +610,12 @@ impl<'a> ApiCall<ApiList<BankAccount>> for AccountListBankAccountsCall<'a> {
 #[derive(Debug)]
 pub struct AccountCreateCardCall<'a> {
     client: &'a StripeClient,
-    account_id: String,
+    account_id: AccountId,
     args: CallArgs
 }
 
 impl<'a> AccountCreateCardCall<'a> {
-    pub fn new(client: &'a StripeClient, account_id: String) -> AccountCreateCardCall<'a> {
+    pub fn new(client: &'a StripeClient, account_id: AccountId) -> AccountCreateCardCall<'a> {
         AccountCreateCardCall {
             client: client,
             account_id: account_id,
@@ -571,7 +623,7 @@ impl<'a> AccountCreateCardCall<'a> {
         }
     }
 
-    pub fn card_token(mut self, card_token: String) -> Self {
+    pub fn card_token(mut self, card_token: TokenId) -> Self {
         self.args.add_arg("external_account", card_token);
         self
     }
@@ -593,7 +645,11 @@ impl<'a> AccountCreateCardCall<'a> {
 }
 
 impl<'a> ApiCall<Card> for AccountCreateCardCall<'a> {
-    fn call(self) -> Result<Card> {
+    fn call_type(&self) -> &'static str {
+        "AccountCreateCardCall"
+    }
+
+    fn execute(self) -> Result<Card> {
         self.client.post(format!("/accounts/{}/external_accounts", self.account_id), &self.args)
     }
 }
@@ -601,15 +657,15 @@ impl<'a> ApiCall<Card> for AccountCreateCardCall<'a> {
 #[derive(Debug)]
 pub struct AccountRetrieveCardCall<'a> {
     client: &'a StripeClient,
-    account_id: String,
-    card_id: String
+    account_id: AccountId,
+    card_id: CardId
 }
 
 impl<'a> AccountRetrieveCardCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        account_id: String,
-        card_id: String
+        account_id: AccountId,
+        card_id: CardId
     ) -> AccountRetrieveCardCall<'a> {
         AccountRetrieveCardCall {
             client: client,
@@ -620,7 +676,11 @@ impl<'a> AccountRetrieveCardCall<'a> {
 }
 
 impl<'a> ApiCall<Card> for AccountRetrieveCardCall<'a> {
-    fn call(self) -> Result<Card> {
+    fn call_type(&self) -> &'static str {
+        "AccountRetrieveCardCall"
+    }
+
+    fn execute(self) -> Result<Card> {
         self.client.get(
             format!("/accounts/{}/external_accounts/{}", self.account_id, self.card_id),
             &()
@@ -631,16 +691,16 @@ impl<'a> ApiCall<Card> for AccountRetrieveCardCall<'a> {
 #[derive(Debug)]
 pub struct AccountUpdateCardCall<'a> {
     client: &'a StripeClient,
-    account_id: String,
-    card_id: String,
+    account_id: AccountId,
+    card_id: CardId,
     args: CallArgs
 }
 
 impl<'a> AccountUpdateCardCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        account_id: String,
-        card_id: String
+        account_id: AccountId,
+        card_id: CardId
     ) -> AccountUpdateCardCall<'a> {
         AccountUpdateCardCall {
             client: client,
@@ -707,7 +767,11 @@ impl<'a> AccountUpdateCardCall<'a> {
 }
 
 impl<'a> ApiCall<Card> for AccountUpdateCardCall<'a> {
-    fn call(self) -> Result<Card> {
+    fn call_type(&self) -> &'static str {
+        "AccountUpdateCardCall"
+    }
+
+    fn execute(self) -> Result<Card> {
         self.client.post(
             format!("/accounts/{}/external_accounts/{}", self.account_id, self.card_id),
             &self.args
@@ -718,15 +782,15 @@ impl<'a> ApiCall<Card> for AccountUpdateCardCall<'a> {
 #[derive(Debug)]
 pub struct AccountDeleteCardCall<'a> {
     client: &'a StripeClient,
-    account_id: String,
-    card_id: String
+    account_id: AccountId,
+    card_id: CardId
 }
 
 impl<'a> AccountDeleteCardCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        account_id: String,
-        card_id: String
+        account_id: AccountId,
+        card_id: CardId
     ) -> AccountDeleteCardCall<'a> {
         AccountDeleteCardCall {
             client: client,
@@ -737,7 +801,11 @@ impl<'a> AccountDeleteCardCall<'a> {
 }
 
 impl<'a> ApiCall<Delete> for AccountDeleteCardCall<'a> {
-    fn call(self) -> Result<Delete> {
+    fn call_type(&self) -> &'static str {
+        "AccountDeleteCardCall"
+    }
+
+    fn execute(self) -> Result<Delete> {
         self.client.delete(format!(
             "/accounts/{}/external_accounts/{}",
             self.account_id,
@@ -749,12 +817,12 @@ impl<'a> ApiCall<Delete> for AccountDeleteCardCall<'a> {
 #[derive(Debug)]
 pub struct AccountListCardsCall<'a> {
     client: &'a StripeClient,
-    account_id: String,
+    account_id: AccountId,
     args: CallArgs
 }
 
 impl<'a> AccountListCardsCall<'a> {
-    pub fn new(client: &'a StripeClient, account_id: String) -> AccountListCardsCall<'a> {
+    pub fn new(client: &'a StripeClient, account_id: AccountId) -> AccountListCardsCall<'a> {
         AccountListCardsCall {
             client: client,
             account_id: account_id,
@@ -782,7 +850,11 @@ impl<'a> AccountListCardsCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<Card>> for AccountListCardsCall<'a> {
-    fn call(self) -> Result<ApiList<Card>> {
+    fn call_type(&self) -> &'static str {
+        "AccountListCardsCall"
+    }
+
+    fn execute(self) -> Result<ApiList<Card>> {
         self.client.get(format!("/accounts/{}/external_accounts", self.account_id), &self.args)
     }
 }
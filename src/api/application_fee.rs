@@ -1,5 +1,5 @@
 use call_args::CallArgs;
-use model::{ApiList, ApplicationFee};
+use model::{ApiList, ApplicationFee, ApplicationFeeId, ChargeId};
 use super::ApiCall;
 use time_constraint::TimeConstraint;
 use {Result, StripeClient};
@@ -7,11 +7,11 @@ use {Result, StripeClient};
 #[derive(Debug)]
 pub struct RetrieveApplicationFeeCall<'a> {
     client: &'a StripeClient,
-    fee_id: String
+    fee_id: ApplicationFeeId
 }
 
 impl<'a> RetrieveApplicationFeeCall<'a> {
-    pub fn new(client: &'a StripeClient, fee_id: String) -> RetrieveApplicationFeeCall<'a> {
+    pub fn new(client: &'a StripeClient, fee_id: ApplicationFeeId) -> RetrieveApplicationFeeCall<'a> {
         RetrieveApplicationFeeCall {
             client: client,
             fee_id: fee_id
@@ -20,7 +20,11 @@ impl<'a> RetrieveApplicationFeeCall<'a> {
 }
 
 impl<'a> ApiCall<ApplicationFee> for RetrieveApplicationFeeCall<'a> {
-    fn call(self) -> Result<ApplicationFee> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveApplicationFeeCall"
+    }
+
+    fn execute(self) -> Result<ApplicationFee> {
         self.client.get(format!("/application_fees/{}", self.fee_id), &())
     }
 }
@@ -39,7 +43,7 @@ impl<'a> ListApplicationFeesCall<'a> {
         }
     }
 
-    pub fn charge(mut self, charge: String) -> Self {
+    pub fn charge(mut self, charge: ChargeId) -> Self {
         self.args.add_arg("charge", charge);
         self
     }
@@ -71,7 +75,11 @@ impl<'a> ListApplicationFeesCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<ApplicationFee>> for ListApplicationFeesCall<'a> {
-    fn call(self) -> Result<ApiList<ApplicationFee>> {
+    fn call_type(&self) -> &'static str {
+        "ListApplicationFeesCall"
+    }
+
+    fn execute(self) -> Result<ApiList<ApplicationFee>> {
         self.client.get("/application_fees", &self.args)
     }
 }
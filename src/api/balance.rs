@@ -1,5 +1,8 @@
 use call_args::CallArgs;
-use model::{ApiList, Balance, BalanceTransaction, Currency, TransactionType};
+use model::{
+    ApiList, Balance, BalanceTransaction, BalanceTransactionId, Currency, TransactionType,
+    TransferId
+};
 use {Result, StripeClient};
 use super::ApiCall;
 use time_constraint::TimeConstraint;
@@ -18,7 +21,11 @@ impl<'a> RetrieveBalanceCall<'a> {
 }
 
 impl<'a> ApiCall<Balance> for RetrieveBalanceCall<'a> {
-    fn call(self) -> Result<Balance> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveBalanceCall"
+    }
+
+    fn execute(self) -> Result<Balance> {
         self.client.get("/balance", &())
     }
 }
@@ -26,13 +33,13 @@ impl<'a> ApiCall<Balance> for RetrieveBalanceCall<'a> {
 #[derive(Debug)]
 pub struct RetrieveBalanceTransactionCall<'a> {
     client: &'a StripeClient,
-    balance_transaction_id: String
+    balance_transaction_id: BalanceTransactionId
 }
 
 impl<'a> RetrieveBalanceTransactionCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        balance_transaction_id: String
+        balance_transaction_id: BalanceTransactionId
     ) -> RetrieveBalanceTransactionCall<'a> {
         RetrieveBalanceTransactionCall {
             client: client,
@@ -42,7 +49,11 @@ impl<'a> RetrieveBalanceTransactionCall<'a> {
 }
 
 impl<'a> ApiCall<BalanceTransaction> for RetrieveBalanceTransactionCall<'a> {
-    fn call(self) -> Result<BalanceTransaction> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveBalanceTransactionCall"
+    }
+
+    fn execute(self) -> Result<BalanceTransaction> {
         self.client.get(&format!("/balance/history/{}", self.balance_transaction_id), &())
     }
 }
@@ -96,19 +107,23 @@ impl<'a> ListBalanceHistoryCall<'a> {
         self
     }
 
-    pub fn transfer(mut self, transfer: String) -> Self {
+    pub fn transfer(mut self, transfer: TransferId) -> Self {
         self.args.add_arg("transfer", transfer);
         self
     }
 
     pub fn transaction_type(mut self, transaction_type: TransactionType) -> Self {
-        self.args.add_arg("transaction_type", transaction_type);
+        self.args.add_arg("type", transaction_type);
         self
     }
 }
 
 impl<'a> ApiCall<ApiList<BalanceTransaction>> for ListBalanceHistoryCall<'a> {
-    fn call(self) -> Result<ApiList<BalanceTransaction>> {
+    fn call_type(&self) -> &'static str {
+        "ListBalanceHistoryCall"
+    }
+
+    fn execute(self) -> Result<ApiList<BalanceTransaction>> {
         self.client.get("/balance/history", &self.args)
     }
 }
@@ -1,5 +1,5 @@
 use call_args::CallArgs;
-use model::{ApiList, BitcoinReceiver, Currency};
+use model::{ApiList, BitcoinReceiver, BitcoinReceiverId, Currency};
 use {Result, StripeClient};
 use std::collections::BTreeMap;
 use super::ApiCall;
@@ -44,7 +44,11 @@ impl<'a> CreateBitcoinReceiverCall<'a> {
 }
 
 impl<'a> ApiCall<BitcoinReceiver> for CreateBitcoinReceiverCall<'a> {
-    fn call(self) -> Result<BitcoinReceiver> {
+    fn call_type(&self) -> &'static str {
+        "CreateBitcoinReceiverCall"
+    }
+
+    fn execute(self) -> Result<BitcoinReceiver> {
         self.client.post("/bitcoin/receivers", &self.args)
     }
 }
@@ -52,11 +56,11 @@ impl<'a> ApiCall<BitcoinReceiver> for CreateBitcoinReceiverCall<'a> {
 #[derive(Debug)]
 pub struct RetrieveBitcoinReceiverCall<'a> {
     client: &'a StripeClient,
-    receiver_id: String
+    receiver_id: BitcoinReceiverId
 }
 
 impl<'a> RetrieveBitcoinReceiverCall<'a> {
-    pub fn new(client: &'a StripeClient, receiver_id: String) -> RetrieveBitcoinReceiverCall<'a> {
+    pub fn new(client: &'a StripeClient, receiver_id: BitcoinReceiverId) -> RetrieveBitcoinReceiverCall<'a> {
         RetrieveBitcoinReceiverCall {
             client: client,
             receiver_id: receiver_id
@@ -65,7 +69,11 @@ impl<'a> RetrieveBitcoinReceiverCall<'a> {
 }
 
 impl<'a> ApiCall<BitcoinReceiver> for RetrieveBitcoinReceiverCall<'a> {
-    fn call(self) -> Result<BitcoinReceiver> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveBitcoinReceiverCall"
+    }
+
+    fn execute(self) -> Result<BitcoinReceiver> {
         self.client.get(format!("/bitcoin/receivers/{}", self.receiver_id), &())
     }
 }
@@ -118,7 +126,11 @@ impl<'a> ListBitcoinReceiversCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<BitcoinReceiver>> for ListBitcoinReceiversCall<'a> {
-    fn call(self) -> Result<ApiList<BitcoinReceiver>> {
+    fn call_type(&self) -> &'static str {
+        "ListBitcoinReceiversCall"
+    }
+
+    fn execute(self) -> Result<ApiList<BitcoinReceiver>> {
         self.client.get("/bitcoin/receivers", &self.args)
     }
 }
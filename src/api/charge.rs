@@ -1,7 +1,7 @@
 use call_args::CallArgs;
 use hyper::header::Headers;
 use idempotency_header::IdempotencyKey;
-use model::{ApiList, Charge, Currency, Shipping, SourceType};
+use model::{AccountId, ApiList, Charge, ChargeId, Currency, CustomerId, Shipping, SourceType};
 use std::collections::BTreeMap;
 use time_constraint::TimeConstraint;
 use super::ApiCall;
@@ -50,7 +50,7 @@ impl<'a> CreateChargeCall<'a> {
     }
 
 
-    pub fn destination(mut self, destination: String) -> Self {
+    pub fn destination(mut self, destination: AccountId) -> Self {
         self.args.add_arg("destination", destination);
         self
     }
@@ -75,7 +75,7 @@ impl<'a> CreateChargeCall<'a> {
         self
     }
 
-    pub fn customer(mut self, customer: String) -> Self {
+    pub fn customer(mut self, customer: CustomerId) -> Self {
         self.args.add_arg("customer", customer);
         self
     }
@@ -97,7 +97,11 @@ impl<'a> CreateChargeCall<'a> {
 }
 
 impl<'a> ApiCall<Charge> for CreateChargeCall<'a> {
-    fn call(self) -> Result<Charge> {
+    fn call_type(&self) -> &'static str {
+        "CreateChargeCall"
+    }
+
+    fn execute(self) -> Result<Charge> {
         let endpoint = "/charges";
 
         if let Some(idempotency_key) = self.idempotency_key {
@@ -114,12 +118,12 @@ impl<'a> ApiCall<Charge> for CreateChargeCall<'a> {
 #[derive(Debug)]
 pub struct RetrieveChargeCall<'a> {
     client: &'a StripeClient,
-    charge_id: String,
+    charge_id: ChargeId,
     args: CallArgs
 }
 
 impl<'a> RetrieveChargeCall<'a> {
-    pub fn new(client: &'a StripeClient, charge_id: String) -> RetrieveChargeCall<'a> {
+    pub fn new(client: &'a StripeClient, charge_id: ChargeId) -> RetrieveChargeCall<'a> {
         RetrieveChargeCall {
             client: client,
             charge_id: charge_id,
@@ -135,7 +139,11 @@ impl<'a> RetrieveChargeCall<'a> {
 }
 
 impl<'a> ApiCall<Charge> for RetrieveChargeCall<'a> {
-    fn call(self) -> Result<Charge> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveChargeCall"
+    }
+
+    fn execute(self) -> Result<Charge> {
         self.client.get(&format!("/charges/{}", self.charge_id), &self.args)
     }
 }
@@ -143,12 +151,12 @@ impl<'a> ApiCall<Charge> for RetrieveChargeCall<'a> {
 #[derive(Debug)]
 pub struct UpdateChargeCall<'a> {
     client: &'a StripeClient,
-    charge_id: String,
+    charge_id: ChargeId,
     args: CallArgs,
 }
 
 impl<'a> UpdateChargeCall<'a> {
-    pub fn new(client: &'a StripeClient, charge_id: String) -> UpdateChargeCall<'a> {
+    pub fn new(client: &'a StripeClient, charge_id: ChargeId) -> UpdateChargeCall<'a> {
         UpdateChargeCall {
             client: client,
             charge_id: charge_id,
@@ -188,7 +196,11 @@ impl<'a> UpdateChargeCall<'a> {
 }
 
 impl<'a> ApiCall<Charge> for UpdateChargeCall<'a> {
-    fn call(self) -> Result<Charge> {
+    fn call_type(&self) -> &'static str {
+        "UpdateChargeCall"
+    }
+
+    fn execute(self) -> Result<Charge> {
         self.client.post(format!("/charges/{}", self.charge_id), &self.args)
     }
 }
@@ -196,12 +208,12 @@ impl<'a> ApiCall<Charge> for UpdateChargeCall<'a> {
 #[derive(Debug)]
 pub struct CaptureChargeCall<'a> {
     client: &'a StripeClient,
-    charge_id: String,
+    charge_id: ChargeId,
     args: CallArgs,
 }
 
 impl<'a> CaptureChargeCall<'a> {
-    pub fn new(client: &'a StripeClient, charge_id: String) -> CaptureChargeCall<'a> {
+    pub fn new(client: &'a StripeClient, charge_id: ChargeId) -> CaptureChargeCall<'a> {
         CaptureChargeCall {
             client: client,
             charge_id: charge_id,
@@ -236,7 +248,11 @@ impl<'a> CaptureChargeCall<'a> {
 }
 
 impl<'a> ApiCall<Charge> for CaptureChargeCall<'a> {
-    fn call(self) -> Result<Charge> {
+    fn call_type(&self) -> &'static str {
+        "CaptureChargeCall"
+    }
+
+    fn execute(self) -> Result<Charge> {
         self.client.post(format!("/charges/{}/capture", self.charge_id), &self.args)
     }
 }
@@ -260,7 +276,7 @@ impl<'a> ListChargesCall<'a> {
         self
     }
 
-    pub fn customer(mut self, customer: String) -> Self {
+    pub fn customer(mut self, customer: CustomerId) -> Self {
         self.args.add_arg("customer", customer);
         self
     }
@@ -292,7 +308,11 @@ impl<'a> ListChargesCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<Charge>> for ListChargesCall<'a> {
-    fn call(self) -> Result<ApiList<Charge>> {
+    fn call_type(&self) -> &'static str {
+        "ListChargesCall"
+    }
+
+    fn execute(self) -> Result<ApiList<Charge>> {
         self.client.get("/charges", &self.args)
     }
 }
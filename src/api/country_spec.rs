@@ -34,8 +34,12 @@ impl<'a> ListCountrySpecCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<CountrySpec>> for ListCountrySpecCall<'a> {
-    fn call(self) -> Result<ApiList<CountrySpec>> {
-        self.client.get("country_specs", &self.args)
+    fn call_type(&self) -> &'static str {
+        "ListCountrySpecCall"
+    }
+
+    fn execute(self) -> Result<ApiList<CountrySpec>> {
+        self.client.get("/country_specs", &self.args)
     }
 }
 
@@ -55,7 +59,11 @@ impl<'a> RetrieveCountrySpecCall<'a> {
 }
 
 impl<'a> ApiCall<CountrySpec> for RetrieveCountrySpecCall<'a> {
-    fn call(self) -> Result<CountrySpec> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveCountrySpecCall"
+    }
+
+    fn execute(self) -> Result<CountrySpec> {
         self.client.get(format!("/country_specs/{}", self.iso_code), &())
     }
 }
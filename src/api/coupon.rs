@@ -1,5 +1,5 @@
 use call_args::CallArgs;
-use model::{ApiList, Coupon, CouponDuration, Currency, Delete};
+use model::{Amount, ApiList, Coupon, CouponDuration, CouponId, Currency, Delete};
 use std::collections::BTreeMap;
 use super::ApiCall;
 use {Result, StripeClient, TimeConstraint};
@@ -20,7 +20,7 @@ impl<'a> CreateCouponCall<'a> {
         }
     }
 
-    pub fn id(mut self, id: String) -> Self {
+    pub fn id(mut self, id: CouponId) -> Self {
         self.args.add_arg("id", id);
         self
     }
@@ -30,13 +30,20 @@ impl<'a> CreateCouponCall<'a> {
         self
     }
 
+    /// Like `amount_off`, with the currency taken from `amount_off` too.
+    pub fn amount_off_for(mut self, amount_off: Amount) -> Self {
+        self.args.add_arg("amount_off", amount_off.amount);
+        self.args.add_arg("currency", amount_off.currency);
+        self
+    }
+
     pub fn currency(mut self, currency: Currency) -> Self {
         self.args.add_arg("currency", currency);
         self
     }
 
-    pub fn duration_in_month(mut self, duration_in_month: i64) -> Self {
-        self.args.add_arg("duration_in_month", duration_in_month);
+    pub fn duration_in_months(mut self, duration_in_months: i64) -> Self {
+        self.args.add_arg("duration_in_months", duration_in_months);
         self
     }
 
@@ -62,7 +69,11 @@ impl<'a> CreateCouponCall<'a> {
 }
 
 impl<'a> ApiCall<Coupon> for CreateCouponCall<'a> {
-    fn call(self) -> Result<Coupon> {
+    fn call_type(&self) -> &'static str {
+        "CreateCouponCall"
+    }
+
+    fn execute(self) -> Result<Coupon> {
         self.client.post("/coupons", &self.args)
     }
 }
@@ -70,11 +81,11 @@ impl<'a> ApiCall<Coupon> for CreateCouponCall<'a> {
 #[derive(Debug)]
 pub struct RetrieveCouponCall<'a> {
     client: &'a StripeClient,
-    coupon_id: String
+    coupon_id: CouponId
 }
 
 impl<'a> RetrieveCouponCall<'a> {
-    pub fn new(client: &'a StripeClient, coupon_id: String) -> RetrieveCouponCall<'a> {
+    pub fn new(client: &'a StripeClient, coupon_id: CouponId) -> RetrieveCouponCall<'a> {
         RetrieveCouponCall {
             client: client,
             coupon_id: coupon_id
@@ -83,7 +94,11 @@ impl<'a> RetrieveCouponCall<'a> {
 }
 
 impl<'a> ApiCall<Coupon> for RetrieveCouponCall<'a> {
-    fn call(self) -> Result<Coupon> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveCouponCall"
+    }
+
+    fn execute(self) -> Result<Coupon> {
         self.client.get(format!("/coupons/{}", self.coupon_id), &())
     }
 }
@@ -91,12 +106,12 @@ impl<'a> ApiCall<Coupon> for RetrieveCouponCall<'a> {
 #[derive(Debug)]
 pub struct UpdateCouponCall<'a> {
     client: &'a StripeClient,
-    coupon_id: String,
+    coupon_id: CouponId,
     args: CallArgs
 }
 
 impl<'a> UpdateCouponCall<'a> {
-    pub fn new(client: &'a StripeClient, coupon_id: String) -> UpdateCouponCall<'a> {
+    pub fn new(client: &'a StripeClient, coupon_id: CouponId) -> UpdateCouponCall<'a> {
         UpdateCouponCall {
             client: client,
             coupon_id: coupon_id,
@@ -111,7 +126,11 @@ impl<'a> UpdateCouponCall<'a> {
 }
 
 impl<'a> ApiCall<Coupon> for UpdateCouponCall<'a> {
-    fn call(self) -> Result<Coupon> {
+    fn call_type(&self) -> &'static str {
+        "UpdateCouponCall"
+    }
+
+    fn execute(self) -> Result<Coupon> {
         self.client.post(format!("/coupons/{}", self.coupon_id), &self.args)
     }
 }
@@ -119,11 +138,11 @@ impl<'a> ApiCall<Coupon> for UpdateCouponCall<'a> {
 #[derive(Debug)]
 pub struct DeleteCouponCall<'a> {
     client: &'a StripeClient,
-    coupon_id: String
+    coupon_id: CouponId
 }
 
 impl<'a> DeleteCouponCall<'a> {
-    pub fn new(client: &'a StripeClient, coupon_id: String) -> DeleteCouponCall<'a> {
+    pub fn new(client: &'a StripeClient, coupon_id: CouponId) -> DeleteCouponCall<'a> {
         DeleteCouponCall {
             client: client,
             coupon_id: coupon_id
@@ -132,7 +151,11 @@ impl<'a> DeleteCouponCall<'a> {
 }
 
 impl<'a> ApiCall<Delete> for DeleteCouponCall<'a> {
-    fn call(self) -> Result<Delete> {
+    fn call_type(&self) -> &'static str {
+        "DeleteCouponCall"
+    }
+
+    fn execute(self) -> Result<Delete> {
         self.client.delete(format!("/coupons/{}", self.coupon_id))
     }
 }
@@ -178,7 +201,11 @@ impl<'a> ListCouponsCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<Coupon>> for ListCouponsCall<'a> {
-    fn call(self) -> Result<ApiList<Coupon>> {
+    fn call_type(&self) -> &'static str {
+        "ListCouponsCall"
+    }
+
+    fn execute(self) -> Result<ApiList<Coupon>> {
         self.client.get("/coupons", &self.args)
     }
 }
@@ -1,7 +1,7 @@
 use call_args::CallArgs;
 use model::{
-    AccountHolderType, ApiList, BankAccount, Card, Customer, Delete, NewBankAccount, NewCard,
-    Shipping
+    AccountHolderType, ApiList, BankAccount, BankAccountId, Card, CardId, CouponId, Customer,
+    CustomerId, Delete, NewBankAccount, NewCard, PlanId, Shipping, SourceId, TokenId
 };
 use std::collections::BTreeMap;
 use super::ApiCall;
@@ -32,7 +32,7 @@ impl<'a> CreateCustomerCall<'a> {
         self
     }
 
-    pub fn coupon(mut self, coupon: String) -> Self {
+    pub fn coupon(mut self, coupon: CouponId) -> Self {
         self.args.add_arg("coupon", coupon);
         self
     }
@@ -52,7 +52,7 @@ impl<'a> CreateCustomerCall<'a> {
         self
     }
 
-    pub fn plan(mut self, plan: String) -> Self {
+    pub fn plan(mut self, plan: PlanId) -> Self {
         self.args.add_arg("plan", plan);
         self
     }
@@ -67,7 +67,7 @@ impl<'a> CreateCustomerCall<'a> {
         self
     }
 
-    pub fn source_token(mut self, source: String) -> Self {
+    pub fn source_token(mut self, source: TokenId) -> Self {
         self.args.add_arg("source", source);
         self
     }
@@ -89,7 +89,11 @@ impl<'a> CreateCustomerCall<'a> {
 }
 
 impl<'a> ApiCall<Customer> for CreateCustomerCall<'a> {
-    fn call(self) -> Result<Customer> {
+    fn call_type(&self) -> &'static str {
+        "CreateCustomerCall"
+    }
+
+    fn execute(self) -> Result<Customer> {
         self.client.post("/customers", &self.args)
     }
 }
@@ -97,11 +101,11 @@ impl<'a> ApiCall<Customer> for CreateCustomerCall<'a> {
 #[derive(Debug)]
 pub struct RetrieveCustomerCall<'a> {
     client: &'a StripeClient,
-    customer_id: String
+    customer_id: CustomerId
 }
 
 impl<'a> RetrieveCustomerCall<'a> {
-    pub fn new(client: &'a StripeClient, customer_id: String) -> RetrieveCustomerCall<'a> {
+    pub fn new(client: &'a StripeClient, customer_id: CustomerId) -> RetrieveCustomerCall<'a> {
         RetrieveCustomerCall {
             client: client,
             customer_id: customer_id
@@ -110,7 +114,11 @@ impl<'a> RetrieveCustomerCall<'a> {
 }
 
 impl<'a> ApiCall<Customer> for RetrieveCustomerCall<'a> {
-    fn call(self) -> Result<Customer> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveCustomerCall"
+    }
+
+    fn execute(self) -> Result<Customer> {
         self.client.get(format!("/customers/{}", self.customer_id), &())
     }
 }
@@ -118,12 +126,12 @@ impl<'a> ApiCall<Customer> for RetrieveCustomerCall<'a> {
 #[derive(Debug)]
 pub struct UpdateCustomerCall<'a> {
     client: &'a StripeClient,
-    customer_id: String,
+    customer_id: CustomerId,
     args: CallArgs
 }
 
 impl<'a> UpdateCustomerCall<'a> {
-    pub fn new(client: &'a StripeClient, customer_id: String) -> UpdateCustomerCall<'a> {
+    pub fn new(client: &'a StripeClient, customer_id: CustomerId) -> UpdateCustomerCall<'a> {
         UpdateCustomerCall {
             client: client,
             customer_id: customer_id,
@@ -141,12 +149,12 @@ impl<'a> UpdateCustomerCall<'a> {
         self
     }
 
-    pub fn coupon(mut self, coupon: String) -> Self {
+    pub fn coupon(mut self, coupon: CouponId) -> Self {
         self.args.add_arg("coupon", coupon);
         self
     }
 
-    pub fn default_source(mut self, default_source: String) -> Self {
+    pub fn default_source(mut self, default_source: SourceId) -> Self {
         self.args.add_arg("default_source", default_source);
         self
     }
@@ -171,7 +179,7 @@ impl<'a> UpdateCustomerCall<'a> {
         self
     }
 
-    pub fn source_token(mut self, source_token: String) -> Self {
+    pub fn source_token(mut self, source_token: TokenId) -> Self {
         self.args.add_arg("source", source_token);
         self
     }
@@ -183,7 +191,11 @@ impl<'a> UpdateCustomerCall<'a> {
 }
 
 impl<'a> ApiCall<Customer> for UpdateCustomerCall<'a> {
-    fn call(self) -> Result<Customer> {
+    fn call_type(&self) -> &'static str {
+        "UpdateCustomerCall"
+    }
+
+    fn execute(self) -> Result<Customer> {
         self.client.post(format!("/customers/{}", self.customer_id), &self.args)
     }
 }
@@ -191,11 +203,11 @@ impl<'a> ApiCall<Customer> for UpdateCustomerCall<'a> {
 #[derive(Debug)]
 pub struct DeleteCustomerCall<'a> {
     client: &'a StripeClient,
-    customer_id: String
+    customer_id: CustomerId
 }
 
 impl<'a> DeleteCustomerCall<'a> {
-    pub fn new(client: &'a StripeClient, customer_id: String) -> DeleteCustomerCall<'a> {
+    pub fn new(client: &'a StripeClient, customer_id: CustomerId) -> DeleteCustomerCall<'a> {
         DeleteCustomerCall {
             client: client,
             customer_id: customer_id
@@ -204,7 +216,11 @@ impl<'a> DeleteCustomerCall<'a> {
 }
 
 impl<'a> ApiCall<Delete> for DeleteCustomerCall<'a> {
-    fn call(self) -> Result<Delete> {
+    fn call_type(&self) -> &'static str {
+        "DeleteCustomerCall"
+    }
+
+    fn execute(self) -> Result<Delete> {
         self.client.delete(format!("/customers/{}", self.customer_id))
     }
 }
@@ -245,7 +261,11 @@ impl<'a> ListCustomersCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<Customer>> for ListCustomersCall<'a> {
-    fn call(self) -> Result<ApiList<Customer>> {
+    fn call_type(&self) -> &'static str {
+        "ListCustomersCall"
+    }
+
+    fn execute(self) -> Result<ApiList<Customer>> {
         self.client.get("/customers", &self.args)
     }
 }
@@ -253,14 +273,14 @@ impl<'a> ApiCall<ApiList<Customer>> for ListCustomersCall<'a> {
 #[derive(Debug)]
 pub struct CustomerCreateBankAccountCall<'a> {
     client: &'a StripeClient,
-    customer_id: String,
+    customer_id: CustomerId,
     args: CallArgs
 }
 
 impl<'a> CustomerCreateBankAccountCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        customer_id: String
+        customer_id: CustomerId
     ) -> CustomerCreateBankAccountCall<'a> {
         CustomerCreateBankAccountCall {
             client: client,
@@ -269,7 +289,7 @@ impl<'a> CustomerCreateBankAccountCall<'a> {
         }
     }
 
-    pub fn bank_account_token(mut self, bank_account_token: String) -> Self {
+    pub fn bank_account_token(mut self, bank_account_token: TokenId) -> Self {
         self.args.add_arg("source", bank_account_token);
         self
     }
@@ -291,7 +311,11 @@ impl<'a> CustomerCreateBankAccountCall<'a> {
 }
 
 impl<'a> ApiCall<BankAccount> for CustomerCreateBankAccountCall<'a> {
-    fn call(self) -> Result<BankAccount> {
+    fn call_type(&self) -> &'static str {
+        "CustomerCreateBankAccountCall"
+    }
+
+    fn execute(self) -> Result<BankAccount> {
         self.client.post(format!("/customers/{}/sources", self.customer_id), &self.args)
     }
 }
@@ -299,15 +323,15 @@ impl<'a> ApiCall<BankAccount> for CustomerCreateBankAccountCall<'a> {
 #[derive(Debug)]
 pub struct CustomerRetrieveBankAccountCall<'a> {
     client: &'a StripeClient,
-    customer_id: String,
-    bank_account_id: String
+    customer_id: CustomerId,
+    bank_account_id: BankAccountId
 }
 
 impl<'a> CustomerRetrieveBankAccountCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        customer_id: String,
-        bank_account_id: String
+        customer_id: CustomerId,
+        bank_account_id: BankAccountId
     ) -> CustomerRetrieveBankAccountCall<'a> {
         CustomerRetrieveBankAccountCall {
             client: client,
@@ -318,7 +342,11 @@ impl<'a> CustomerRetrieveBankAccountCall<'a> {
 }
 
 impl<'a> ApiCall<BankAccount> for CustomerRetrieveBankAccountCall<'a> {
-    fn call(self) -> Result<BankAccount> {
+    fn call_type(&self) -> &'static str {
+        "CustomerRetrieveBankAccountCall"
+    }
+
+    fn execute(self) -> Result<BankAccount> {
         self.client.get(
             format!("/customers/{}/sources/{}", self.customer_id, self.bank_account_id),
             &()
@@ -329,16 +357,16 @@ impl<'a> ApiCall<BankAccount> for CustomerRetrieveBankAccountCall<'a> {
 #[derive(Debug)]
 pub struct CustomerUpdateBankAccountCall<'a> {
     client: &'a StripeClient,
-    customer_id: String,
-    bank_account_id: String,
+    customer_id: CustomerId,
+    bank_account_id: BankAccountId,
     args: CallArgs
 }
 
 impl<'a> CustomerUpdateBankAccountCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        customer_id: String,
-        bank_account_id: String
+        customer_id: CustomerId,
+        bank_account_id: BankAccountId
     ) -> CustomerUpdateBankAccountCall<'a> {
         CustomerUpdateBankAccountCall {
             client: client,
@@ -365,7 +393,11 @@ impl<'a> CustomerUpdateBankAccountCall<'a> {
 }
 
 impl<'a> ApiCall<BankAccount> for CustomerUpdateBankAccountCall<'a> {
-    fn call(self) -> Result<BankAccount> {
+    fn call_type(&self) -> &'static str {
+        "CustomerUpdateBankAccountCall"
+    }
+
+    fn execute(self) -> Result<BankAccount> {
         self.client.post(
             format!("/customers/{}/sources/{}", self.customer_id, self.bank_account_id),
             &self.args
@@ -376,15 +408,15 @@ impl<'a> ApiCall<BankAccount> for CustomerUpdateBankAccountCall<'a> {
 #[derive(Debug)]
 pub struct CustomerDeleteBankAccountCall<'a> {
     client: &'a StripeClient,
-    customer_id: String,
-    bank_account_id: String
+    customer_id: CustomerId,
+    bank_account_id: BankAccountId
 }
 
 impl<'a> CustomerDeleteBankAccountCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        customer_id: String,
-        bank_account_id: String
+        customer_id: CustomerId,
+        bank_account_id: BankAccountId
     ) -> CustomerDeleteBankAccountCall<'a> {
         CustomerDeleteBankAccountCall {
             client: client,
@@ -395,7 +427,11 @@ impl<'a> CustomerDeleteBankAccountCall<'a> {
 }
 
 impl<'a> ApiCall<Delete> for CustomerDeleteBankAccountCall<'a> {
-    fn call(self) -> Result<Delete> {
+    fn call_type(&self) -> &'static str {
+        "CustomerDeleteBankAccountCall"
+    }
+
+    fn execute(self) -> Result<Delete> {
         self.client.delete(format!(
             "/customers/{}/sources/{}",
             self.customer_id,
@@ -407,12 +443,12 @@ impl<'a> ApiCall<Delete> for CustomerDeleteBankAccountCall<'a> {
 #[derive(Debug)]
 pub struct CustomerListBankAccountsCall<'a> {
     client: &'a StripeClient,
-    customer_id: String,
+    customer_id: CustomerId,
     args: CallArgs
 }
 
 impl<'a> CustomerListBankAccountsCall<'a> {
-    pub fn new(client: &'a StripeClient, customer_id: String) -> CustomerListBankAccountsCall<'a> {
+    pub fn new(client: &'a StripeClient, customer_id: CustomerId) -> CustomerListBankAccountsCall<'a> {
         CustomerListBankAccountsCall {
             client: client,
             customer_id: customer_id,
@@ -440,7 +476,11 @@ impl<'a> CustomerListBankAccountsCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<BankAccount>> for CustomerListBankAccountsCall<'a> {
-    fn call(self) -> Result<ApiList<BankAccount>> {
+    fn call_type(&self) -> &'static str {
+        "CustomerListBankAccountsCall"
+    }
+
+    fn execute(self) -> Result<ApiList<BankAccount>> {
         self.client.get(format!("/customers/{}/sources", self.customer_id), &self.args)
     }
 }
@@ -448,12 +488,12 @@ impl<'a> ApiCall<ApiList<BankAccount>> for CustomerListBankAccountsCall<'a> {
 #[derive(Debug)]
 pub struct CustomerCreateCardCall<'a> {
     client: &'a StripeClient,
-    customer_id: String,
+    customer_id: CustomerId,
     args: CallArgs
 }
 
 impl<'a> CustomerCreateCardCall<'a> {
-    pub fn new(client: &'a StripeClient, customer_id: String) -> CustomerCreateCardCall<'a> {
+    pub fn new(client: &'a StripeClient, customer_id: CustomerId) -> CustomerCreateCardCall<'a> {
         CustomerCreateCardCall {
             client: client,
             customer_id: customer_id,
@@ -461,7 +501,7 @@ impl<'a> CustomerCreateCardCall<'a> {
         }
     }
 
-    pub fn card_token(mut self, card_token: String) -> Self {
+    pub fn card_token(mut self, card_token: TokenId) -> Self {
         self.args.add_arg("source", card_token);
         self
     }
@@ -483,7 +523,11 @@ impl<'a> CustomerCreateCardCall<'a> {
 }
 
 impl<'a> ApiCall<Card> for CustomerCreateCardCall<'a> {
-    fn call(self) -> Result<Card> {
+    fn call_type(&self) -> &'static str {
+        "CustomerCreateCardCall"
+    }
+
+    fn execute(self) -> Result<Card> {
         self.client.post(format!("/customers/{}/sources", self.customer_id), &self.args)
     }
 }
@@ -491,15 +535,15 @@ impl<'a> ApiCall<Card> for CustomerCreateCardCall<'a> {
 #[derive(Debug)]
 pub struct CustomerRetrieveCardCall<'a> {
     client: &'a StripeClient,
-    customer_id: String,
-    card_id: String
+    customer_id: CustomerId,
+    card_id: CardId
 }
 
 impl<'a> CustomerRetrieveCardCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        customer_id: String,
-        card_id: String
+        customer_id: CustomerId,
+        card_id: CardId
     ) -> CustomerRetrieveCardCall<'a> {
         CustomerRetrieveCardCall {
             client: client,
@@ -510,7 +554,11 @@ impl<'a> CustomerRetrieveCardCall<'a> {
 }
 
 impl<'a> ApiCall<Card> for CustomerRetrieveCardCall<'a> {
-    fn call(self) -> Result<Card> {
+    fn call_type(&self) -> &'static str {
+        "CustomerRetrieveCardCall"
+    }
+
+    fn execute(self) -> Result<Card> {
         self.client.get(format!("/customers/{}/sources/{}", self.customer_id, self.card_id), &())
     }
 }
@@ -518,16 +566,16 @@ impl<'a> ApiCall<Card> for CustomerRetrieveCardCall<'a> {
 #[derive(Debug)]
 pub struct CustomerUpdateCardCall<'a> {
     client: &'a StripeClient,
-    customer_id: String,
-    card_id: String,
+    customer_id: CustomerId,
+    card_id: CardId,
     args: CallArgs
 }
 
 impl<'a> CustomerUpdateCardCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        customer_id: String,
-        card_id: String
+        customer_id: CustomerId,
+        card_id: CardId
     ) -> CustomerUpdateCardCall<'a> {
         CustomerUpdateCardCall {
             client: client,
@@ -594,7 +642,11 @@ impl<'a> CustomerUpdateCardCall<'a> {
 }
 
 impl<'a> ApiCall<Card> for CustomerUpdateCardCall<'a> {
-    fn call(self) -> Result<Card> {
+    fn call_type(&self) -> &'static str {
+        "CustomerUpdateCardCall"
+    }
+
+    fn execute(self) -> Result<Card> {
         self.client.post(
             format!("/customers/{}/sources/{}", self.customer_id, self.card_id),
             &self.args
@@ -605,15 +657,15 @@ impl<'a> ApiCall<Card> for CustomerUpdateCardCall<'a> {
 #[derive(Debug)]
 pub struct CustomerDeleteCardCall<'a> {
     client: &'a StripeClient,
-    customer_id: String,
-    card_id: String
+    customer_id: CustomerId,
+    card_id: CardId
 }
 
 impl<'a> CustomerDeleteCardCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        customer_id: String,
-        card_id: String
+        customer_id: CustomerId,
+        card_id: CardId
     ) -> CustomerDeleteCardCall<'a> {
         CustomerDeleteCardCall {
             client: client,
@@ -624,7 +676,11 @@ impl<'a> CustomerDeleteCardCall<'a> {
 }
 
 impl<'a> ApiCall<Delete> for CustomerDeleteCardCall<'a> {
-    fn call(self) -> Result<Delete> {
+    fn call_type(&self) -> &'static str {
+        "CustomerDeleteCardCall"
+    }
+
+    fn execute(self) -> Result<Delete> {
         self.client.delete(format!("/customers/{}/sources/{}", self.customer_id, self.card_id))
     }
 }
@@ -632,12 +688,12 @@ impl<'a> ApiCall<Delete> for CustomerDeleteCardCall<'a> {
 #[derive(Debug)]
 pub struct CustomerListCardsCall<'a> {
     client: &'a StripeClient,
-    customer_id: String,
+    customer_id: CustomerId,
     args: CallArgs
 }
 
 impl<'a> CustomerListCardsCall<'a> {
-    pub fn new(client: &'a StripeClient, customer_id: String) -> CustomerListCardsCall<'a> {
+    pub fn new(client: &'a StripeClient, customer_id: CustomerId) -> CustomerListCardsCall<'a> {
         CustomerListCardsCall {
             client: client,
             customer_id: customer_id,
@@ -665,7 +721,11 @@ impl<'a> CustomerListCardsCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<Card>> for CustomerListCardsCall<'a> {
-    fn call(self) -> Result<ApiList<Card>> {
+    fn call_type(&self) -> &'static str {
+        "CustomerListCardsCall"
+    }
+
+    fn execute(self) -> Result<ApiList<Card>> {
         self.client.get(format!("/customers/{}/sources", self.customer_id), &self.args)
     }
 }
@@ -1,15 +1,15 @@
-use model::Delete;
+use model::{CustomerId, Delete, SubscriptionId};
 use super::ApiCall;
 use {Result, StripeClient};
 
 #[derive(Debug)]
 pub struct DeleteCustomerDiscountCall<'a> {
     client: &'a StripeClient,
-    customer_id: String
+    customer_id: CustomerId
 }
 
 impl<'a> DeleteCustomerDiscountCall<'a> {
-    pub fn new(client: &'a StripeClient, customer_id: String) -> DeleteCustomerDiscountCall<'a> {
+    pub fn new(client: &'a StripeClient, customer_id: CustomerId) -> DeleteCustomerDiscountCall<'a> {
         DeleteCustomerDiscountCall {
             client: client,
             customer_id: customer_id
@@ -18,7 +18,11 @@ impl<'a> DeleteCustomerDiscountCall<'a> {
 }
 
 impl<'a> ApiCall<Delete> for DeleteCustomerDiscountCall<'a> {
-    fn call(self) -> Result<Delete> {
+    fn call_type(&self) -> &'static str {
+        "DeleteCustomerDiscountCall"
+    }
+
+    fn execute(self) -> Result<Delete> {
         self.client.delete(format!("/customers/{}/discount", self.customer_id))
     }
 }
@@ -26,15 +30,15 @@ impl<'a> ApiCall<Delete> for DeleteCustomerDiscountCall<'a> {
 #[derive(Debug)]
 pub struct DeleteSubscriptionDiscountCall<'a> {
     client: &'a StripeClient,
-    customer_id: String,
-    subscription_id: String
+    customer_id: CustomerId,
+    subscription_id: SubscriptionId
 }
 
 impl<'a> DeleteSubscriptionDiscountCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        customer_id: String,
-        subscription_id: String
+        customer_id: CustomerId,
+        subscription_id: SubscriptionId
     ) -> DeleteSubscriptionDiscountCall<'a> {
         DeleteSubscriptionDiscountCall {
             client: client,
@@ -45,7 +49,11 @@ impl<'a> DeleteSubscriptionDiscountCall<'a> {
 }
 
 impl<'a> ApiCall<Delete> for DeleteSubscriptionDiscountCall<'a> {
-    fn call(self) -> Result<Delete> {
+    fn call_type(&self) -> &'static str {
+        "DeleteSubscriptionDiscountCall"
+    }
+
+    fn execute(self) -> Result<Delete> {
         self.client.delete(format!(
             "/customers/{}/subscriptions/{}/discount",
             self.customer_id,
@@ -1,5 +1,5 @@
 use call_args::CallArgs;
-use model::{ApiList, Dispute};
+use model::{ApiList, Dispute, DisputeId};
 use std::collections::BTreeMap;
 use super::ApiCall;
 use time_constraint::TimeConstraint;
@@ -8,11 +8,11 @@ use {Result, StripeClient};
 #[derive(Debug)]
 pub struct RetrieveDisputeCall<'a> {
     client: &'a StripeClient,
-    dispute_id: String
+    dispute_id: DisputeId
 }
 
 impl<'a> RetrieveDisputeCall<'a> {
-    pub fn new(client: &'a StripeClient, dispute_id: String) -> RetrieveDisputeCall<'a> {
+    pub fn new(client: &'a StripeClient, dispute_id: DisputeId) -> RetrieveDisputeCall<'a> {
         RetrieveDisputeCall {
             client: client,
             dispute_id: dispute_id
@@ -21,7 +21,11 @@ impl<'a> RetrieveDisputeCall<'a> {
 }
 
 impl<'a> ApiCall<Dispute> for RetrieveDisputeCall<'a> {
-    fn call(self) -> Result<Dispute> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveDisputeCall"
+    }
+
+    fn execute(self) -> Result<Dispute> {
         self.client.get(format!("/disputes/{}", self.dispute_id), &())
     }
 }
@@ -29,12 +33,12 @@ impl<'a> ApiCall<Dispute> for RetrieveDisputeCall<'a> {
 #[derive(Debug)]
 pub struct UpdateDisputeCall<'a> {
     client: &'a StripeClient,
-    dispute_id: String,
+    dispute_id: DisputeId,
     args: CallArgs,
 }
 
 impl<'a> UpdateDisputeCall<'a> {
-    pub fn new(client: &'a StripeClient, dispute_id: String) -> UpdateDisputeCall<'a> {
+    pub fn new(client: &'a StripeClient, dispute_id: DisputeId) -> UpdateDisputeCall<'a> {
         UpdateDisputeCall {
             client: client,
             dispute_id: dispute_id,
@@ -54,7 +58,11 @@ impl<'a> UpdateDisputeCall<'a> {
 }
 
 impl<'a> ApiCall<Dispute> for UpdateDisputeCall<'a> {
-    fn call(self) -> Result<Dispute> {
+    fn call_type(&self) -> &'static str {
+        "UpdateDisputeCall"
+    }
+
+    fn execute(self) -> Result<Dispute> {
         self.client.post(format!("/disputes/{}", self.dispute_id), &self.args)
     }
 }
@@ -62,11 +70,11 @@ impl<'a> ApiCall<Dispute> for UpdateDisputeCall<'a> {
 #[derive(Debug)]
 pub struct CloseDisputeCall<'a> {
     client: &'a StripeClient,
-    dispute_id: String
+    dispute_id: DisputeId
 }
 
 impl<'a> CloseDisputeCall<'a> {
-    pub fn new(client: &'a StripeClient, dispute_id: String) -> CloseDisputeCall<'a> {
+    pub fn new(client: &'a StripeClient, dispute_id: DisputeId) -> CloseDisputeCall<'a> {
         CloseDisputeCall {
             client: client,
             dispute_id: dispute_id
@@ -75,7 +83,11 @@ impl<'a> CloseDisputeCall<'a> {
 }
 
 impl<'a> ApiCall<Dispute> for CloseDisputeCall<'a> {
-    fn call(self) -> Result<Dispute> {
+    fn call_type(&self) -> &'static str {
+        "CloseDisputeCall"
+    }
+
+    fn execute(self) -> Result<Dispute> {
         self.client.post(format!("/disputes/{}/close", self.dispute_id), &())
     }
 }
@@ -116,7 +128,11 @@ impl<'a> ListDisputesCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<Dispute>> for ListDisputesCall<'a> {
-    fn call(self) -> Result<ApiList<Dispute>> {
+    fn call_type(&self) -> &'static str {
+        "ListDisputesCall"
+    }
+
+    fn execute(self) -> Result<ApiList<Dispute>> {
         self.client.get("/disputes", &self.args)
     }
 }
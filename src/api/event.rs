@@ -1,5 +1,5 @@
 use call_args::CallArgs;
-use model::{ApiList, Event};
+use model::{ApiList, Event, EventId};
 use super::ApiCall;
 use time_constraint::TimeConstraint;
 use {Result, StripeClient};
@@ -7,11 +7,11 @@ use {Result, StripeClient};
 #[derive(Debug)]
 pub struct RetrieveEventCall<'a> {
     client: &'a StripeClient,
-    event_id: String
+    event_id: EventId
 }
 
 impl<'a> RetrieveEventCall<'a> {
-    pub fn new(client: &'a StripeClient, event_id: String) -> RetrieveEventCall<'a> {
+    pub fn new(client: &'a StripeClient, event_id: EventId) -> RetrieveEventCall<'a> {
         RetrieveEventCall {
             client: client,
             event_id: event_id
@@ -20,7 +20,11 @@ impl<'a> RetrieveEventCall<'a> {
 }
 
 impl<'a> ApiCall<Event> for RetrieveEventCall<'a> {
-    fn call(self) -> Result<Event> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveEventCall"
+    }
+
+    fn execute(self) -> Result<Event> {
         self.client.get(&format!("/events/{}", self.event_id), &())
     }
 }
@@ -66,7 +70,11 @@ impl<'a> ListEventCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<Event>> for ListEventCall<'a> {
-    fn call(self) -> Result<ApiList<Event>> {
+    fn call_type(&self) -> &'static str {
+        "ListEventCall"
+    }
+
+    fn execute(self) -> Result<ApiList<Event>> {
         self.client.get("/events", &self.args)
     }
 }
@@ -1,5 +1,5 @@
 use call_args::CallArgs;
-use model::{ApiList, FeeRefund};
+use model::{ApiList, ApplicationFeeId, FeeRefund, FeeRefundId};
 use std::collections::BTreeMap;
 use super::ApiCall;
 use {Result, StripeClient};
@@ -7,12 +7,12 @@ use {Result, StripeClient};
 #[derive(Debug)]
 pub struct CreateFeeRefundCall<'a> {
     client: &'a StripeClient,
-    application_fee_id: String,
+    application_fee_id: ApplicationFeeId,
     args: CallArgs
 }
 
 impl<'a> CreateFeeRefundCall<'a> {
-    pub fn new(client: &'a StripeClient, application_fee_id: String) -> CreateFeeRefundCall<'a> {
+    pub fn new(client: &'a StripeClient, application_fee_id: ApplicationFeeId) -> CreateFeeRefundCall<'a> {
         CreateFeeRefundCall {
             client: client,
             application_fee_id: application_fee_id,
@@ -32,7 +32,11 @@ impl<'a> CreateFeeRefundCall<'a> {
 }
 
 impl<'a> ApiCall<FeeRefund> for CreateFeeRefundCall<'a> {
-    fn call(self) -> Result<FeeRefund> {
+    fn call_type(&self) -> &'static str {
+        "CreateFeeRefundCall"
+    }
+
+    fn execute(self) -> Result<FeeRefund> {
         self.client.post(
             format!("/application_fees/{}/refunds", self.application_fee_id),
             &self.args
@@ -43,15 +47,15 @@ impl<'a> ApiCall<FeeRefund> for CreateFeeRefundCall<'a> {
 #[derive(Debug)]
 pub struct RetrieveFeeRefundCall<'a> {
     client: &'a StripeClient,
-    fee_id: String,
-    refund_id: String
+    fee_id: ApplicationFeeId,
+    refund_id: FeeRefundId
 }
 
 impl<'a> RetrieveFeeRefundCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        fee_id: String,
-        refund_id: String
+        fee_id: ApplicationFeeId,
+        refund_id: FeeRefundId
     ) -> RetrieveFeeRefundCall<'a> {
         RetrieveFeeRefundCall {
             client: client,
@@ -62,7 +66,11 @@ impl<'a> RetrieveFeeRefundCall<'a> {
 }
 
 impl<'a> ApiCall<FeeRefund> for RetrieveFeeRefundCall<'a> {
-    fn call(self) -> Result<FeeRefund> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveFeeRefundCall"
+    }
+
+    fn execute(self) -> Result<FeeRefund> {
         self.client.get(
             format!("/application_fees/{}/refunds/{}", self.fee_id, self.refund_id),
             &()
@@ -73,16 +81,16 @@ impl<'a> ApiCall<FeeRefund> for RetrieveFeeRefundCall<'a> {
 #[derive(Debug)]
 pub struct UpdateFeeRefundCall<'a> {
     client: &'a StripeClient,
-    fee_id: String,
-    refund_id: String,
+    fee_id: ApplicationFeeId,
+    refund_id: FeeRefundId,
     args: CallArgs
 }
 
 impl<'a> UpdateFeeRefundCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        fee_id: String,
-        refund_id: String
+        fee_id: ApplicationFeeId,
+        refund_id: FeeRefundId
     ) -> UpdateFeeRefundCall<'a> {
         UpdateFeeRefundCall {
             client: client,
@@ -99,7 +107,11 @@ impl<'a> UpdateFeeRefundCall<'a> {
 }
 
 impl<'a> ApiCall<FeeRefund> for UpdateFeeRefundCall<'a> {
-    fn call(self) -> Result<FeeRefund> {
+    fn call_type(&self) -> &'static str {
+        "UpdateFeeRefundCall"
+    }
+
+    fn execute(self) -> Result<FeeRefund> {
         self.client.post(
             format!("/application_fees/{}/refunds/{}", self.fee_id, self.refund_id),
             &self.args
@@ -110,12 +122,12 @@ impl<'a> ApiCall<FeeRefund> for UpdateFeeRefundCall<'a> {
 #[derive(Debug)]
 pub struct ListFeeRefundsCall<'a> {
     client: &'a StripeClient,
-    fee_id: String,
+    fee_id: ApplicationFeeId,
     args: CallArgs
 }
 
 impl<'a> ListFeeRefundsCall<'a> {
-    pub fn new(client: &'a StripeClient, fee_id: String) -> ListFeeRefundsCall<'a> {
+    pub fn new(client: &'a StripeClient, fee_id: ApplicationFeeId) -> ListFeeRefundsCall<'a> {
         ListFeeRefundsCall {
             client: client,
             fee_id: fee_id,
@@ -140,7 +152,11 @@ impl<'a> ListFeeRefundsCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<FeeRefund>> for ListFeeRefundsCall<'a> {
-    fn call(self) -> Result<ApiList<FeeRefund>> {
+    fn call_type(&self) -> &'static str {
+        "ListFeeRefundsCall"
+    }
+
+    fn execute(self) -> Result<ApiList<FeeRefund>> {
         self.client.get(format!("/application_fees/{}/refunds", self.fee_id), &self.args)
     }
 }
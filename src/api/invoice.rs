@@ -1,5 +1,7 @@
 use call_args::CallArgs;
-use model::{ApiList, Invoice, InvoiceLineItem};
+use model::{
+    ApiList, CouponId, CustomerId, Invoice, InvoiceId, InvoiceLineItem, PlanId, SubscriptionId
+};
 use std::collections::BTreeMap;
 use super::ApiCall;
 use {Result, StripeClient, TimeConstraint};
@@ -11,10 +13,10 @@ pub struct CreateInvoiceCall<'a> {
 }
 
 impl<'a> CreateInvoiceCall<'a> {
-    pub fn new(client: &'a StripeClient, customer: String) -> CreateInvoiceCall<'a> {
+    pub fn new(client: &'a StripeClient, customer: CustomerId) -> CreateInvoiceCall<'a> {
         CreateInvoiceCall {
             client: client,
-            args: CallArgs::from(("customer", customer))
+            args: CallArgs::from(("customer", customer.to_string()))
         }
     }
 
@@ -38,7 +40,7 @@ impl<'a> CreateInvoiceCall<'a> {
         self
     }
 
-    pub fn subscription(mut self, subscription: String) -> Self {
+    pub fn subscription(mut self, subscription: SubscriptionId) -> Self {
         self.args.add_arg("subscription", subscription);
         self
     }
@@ -50,7 +52,11 @@ impl<'a> CreateInvoiceCall<'a> {
 }
 
 impl<'a> ApiCall<Invoice> for CreateInvoiceCall<'a> {
-    fn call(self) -> Result<Invoice> {
+    fn call_type(&self) -> &'static str {
+        "CreateInvoiceCall"
+    }
+
+    fn execute(self) -> Result<Invoice> {
         self.client.post("/invoices", &self.args)
     }
 }
@@ -58,11 +64,11 @@ impl<'a> ApiCall<Invoice> for CreateInvoiceCall<'a> {
 #[derive(Debug)]
 pub struct RetrieveInvoiceCall<'a> {
     client: &'a StripeClient,
-    invoice_id: String
+    invoice_id: InvoiceId
 }
 
 impl<'a> RetrieveInvoiceCall<'a> {
-    pub fn new(client: &'a StripeClient, invoice_id: String) -> RetrieveInvoiceCall<'a> {
+    pub fn new(client: &'a StripeClient, invoice_id: InvoiceId) -> RetrieveInvoiceCall<'a> {
         RetrieveInvoiceCall {
             client: client,
             invoice_id: invoice_id
@@ -71,20 +77,24 @@ impl<'a> RetrieveInvoiceCall<'a> {
 }
 
 impl<'a> ApiCall<Invoice> for RetrieveInvoiceCall<'a> {
-    fn call(self) -> Result<Invoice> {
-        self.client.get(format!("/incoices/{}", self.invoice_id), &())
+    fn call_type(&self) -> &'static str {
+        "RetrieveInvoiceCall"
+    }
+
+    fn execute(self) -> Result<Invoice> {
+        self.client.get(format!("/invoices/{}", self.invoice_id), &())
     }
 }
 
 #[derive(Debug)]
 pub struct RetrieveInvoiceLinesCall<'a> {
     client: &'a StripeClient,
-    invoice_id: String,
+    invoice_id: InvoiceId,
     args: CallArgs
 }
 
 impl<'a> RetrieveInvoiceLinesCall<'a> {
-    pub fn new(client: &'a StripeClient, invoice_id: String) -> RetrieveInvoiceLinesCall<'a> {
+    pub fn new(client: &'a StripeClient, invoice_id: InvoiceId) -> RetrieveInvoiceLinesCall<'a> {
         RetrieveInvoiceLinesCall {
             client: client,
             invoice_id: invoice_id,
@@ -92,12 +102,12 @@ impl<'a> RetrieveInvoiceLinesCall<'a> {
         }
     }
 
-    pub fn coupon(mut self, coupon: String) -> Self {
+    pub fn coupon(mut self, coupon: CouponId) -> Self {
         self.args.add_arg("coupon", coupon);
         self
     }
 
-    pub fn customer(mut self, customer: String) -> Self {
+    pub fn customer(mut self, customer: CustomerId) -> Self {
         self.args.add_arg("customer", customer);
         self
     }
@@ -117,12 +127,12 @@ impl<'a> RetrieveInvoiceLinesCall<'a> {
         self
     }
 
-    pub fn subscription(mut self, subscription: String) -> Self {
+    pub fn subscription(mut self, subscription: SubscriptionId) -> Self {
         self.args.add_arg("subscription", subscription);
         self
     }
 
-    pub fn subscription_plan(mut self, subscription_plan: String) -> Self {
+    pub fn subscription_plan(mut self, subscription_plan: PlanId) -> Self {
         self.args.add_arg("subscription_plan", subscription_plan);
         self
     }
@@ -149,7 +159,11 @@ impl<'a> RetrieveInvoiceLinesCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<InvoiceLineItem>> for RetrieveInvoiceLinesCall<'a> {
-    fn call(self) -> Result<ApiList<InvoiceLineItem>> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveInvoiceLinesCall"
+    }
+
+    fn execute(self) -> Result<ApiList<InvoiceLineItem>> {
         self.client.get(format!("/invoices/{}/lines", self.invoice_id), &self.args)
     }
 }
@@ -161,24 +175,24 @@ pub struct RetrieveUpcomingInvoiceCall<'a> {
 }
 
 impl<'a> RetrieveUpcomingInvoiceCall<'a> {
-    pub fn new(client: &'a StripeClient, customer_id: String) -> RetrieveUpcomingInvoiceCall<'a> {
+    pub fn new(client: &'a StripeClient, customer_id: CustomerId) -> RetrieveUpcomingInvoiceCall<'a> {
         RetrieveUpcomingInvoiceCall {
             client: client,
-            args: CallArgs::from(("customer", customer_id))
+            args: CallArgs::from(("customer", customer_id.to_string()))
         }
     }
 
-    pub fn coupon(mut self, coupon: String) -> Self {
+    pub fn coupon(mut self, coupon: CouponId) -> Self {
         self.args.add_arg("coupon", coupon);
         self
     }
 
-    pub fn subscription(mut self, subscription: String) -> Self {
+    pub fn subscription(mut self, subscription: SubscriptionId) -> Self {
         self.args.add_arg("subscription", subscription);
         self
     }
 
-    pub fn subscription_plan(mut self, subscription_plan: String) -> Self {
+    pub fn subscription_plan(mut self, subscription_plan: PlanId) -> Self {
         self.args.add_arg("subscription_plan", subscription_plan);
         self
     }
@@ -205,7 +219,11 @@ impl<'a> RetrieveUpcomingInvoiceCall<'a> {
 }
 
 impl<'a> ApiCall<Invoice> for RetrieveUpcomingInvoiceCall<'a> {
-    fn call(self) -> Result<Invoice> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveUpcomingInvoiceCall"
+    }
+
+    fn execute(self) -> Result<Invoice> {
         self.client.get("/invoices/upcoming", &self.args)
     }
 }
@@ -213,12 +231,12 @@ impl<'a> ApiCall<Invoice> for RetrieveUpcomingInvoiceCall<'a> {
 #[derive(Debug)]
 pub struct UpdateInvoiceCall<'a> {
     client: &'a StripeClient,
-    invoice_id: String,
+    invoice_id: InvoiceId,
     args: CallArgs
 }
 
 impl<'a> UpdateInvoiceCall<'a> {
-    pub fn new(client: &'a StripeClient, invoice_id: String) -> UpdateInvoiceCall<'a> {
+    pub fn new(client: &'a StripeClient, invoice_id: InvoiceId) -> UpdateInvoiceCall<'a> {
         UpdateInvoiceCall {
             client: client,
             invoice_id: invoice_id,
@@ -263,7 +281,11 @@ impl<'a> UpdateInvoiceCall<'a> {
 }
 
 impl<'a> ApiCall<Invoice> for UpdateInvoiceCall<'a> {
-    fn call(self) -> Result<Invoice> {
+    fn call_type(&self) -> &'static str {
+        "UpdateInvoiceCall"
+    }
+
+    fn execute(self) -> Result<Invoice> {
         self.client.post(format!("/invoices/{}", self.invoice_id), &self.args)
     }
 }
@@ -271,11 +293,11 @@ impl<'a> ApiCall<Invoice> for UpdateInvoiceCall<'a> {
 #[derive(Debug)]
 pub struct PayInvoiceCall<'a> {
     client: &'a StripeClient,
-    invoice_id: String
+    invoice_id: InvoiceId
 }
 
 impl<'a> PayInvoiceCall<'a> {
-    pub fn new(client: &'a StripeClient, invoice_id: String) -> PayInvoiceCall<'a> {
+    pub fn new(client: &'a StripeClient, invoice_id: InvoiceId) -> PayInvoiceCall<'a> {
         PayInvoiceCall {
             client: client,
             invoice_id: invoice_id
@@ -284,7 +306,11 @@ impl<'a> PayInvoiceCall<'a> {
 }
 
 impl<'a> ApiCall<Invoice> for PayInvoiceCall<'a> {
-    fn call(self) -> Result<Invoice> {
+    fn call_type(&self) -> &'static str {
+        "PayInvoiceCall"
+    }
+
+    fn execute(self) -> Result<Invoice> {
         self.client.post(format!("/invoices/{}/pay", self.invoice_id), &())
     }
 }
@@ -303,7 +329,7 @@ impl<'a> ListInvoicesCall<'a> {
         }
     }
 
-    pub fn customer(mut self, customer: String) -> Self {
+    pub fn customer(mut self, customer: CustomerId) -> Self {
         self.args.add_arg("customer", customer);
         self
     }
@@ -335,7 +361,11 @@ impl<'a> ListInvoicesCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<Invoice>> for ListInvoicesCall<'a> {
-    fn call(self) -> Result<ApiList<Invoice>> {
+    fn call_type(&self) -> &'static str {
+        "ListInvoicesCall"
+    }
+
+    fn execute(self) -> Result<ApiList<Invoice>> {
         self.client.get("/invoices", &self.args)
     }
 }
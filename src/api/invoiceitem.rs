@@ -1,5 +1,7 @@
 use call_args::CallArgs;
-use model::{ApiList, Currency, Delete, Invoiceitem};
+use model::{
+    ApiList, Currency, CustomerId, Delete, InvoiceId, InvoiceItemId, Invoiceitem, SubscriptionId
+};
 use std::collections::BTreeMap;
 use super::ApiCall;
 use {Result, StripeClient, TimeConstraint};
@@ -16,14 +18,14 @@ impl<'a> CreateInvoiceitemCall<'a> {
         client: &'a StripeClient,
         amount: i64,
         currency: Currency,
-        customer: String
+        customer: CustomerId
     ) -> CreateInvoiceitemCall<'a> {
         CreateInvoiceitemCall {
             client: client,
             args: CallArgs::from((
                 ("amount", amount.to_string()),
                 ("currency", currency.to_string()),
-                ("customer", customer)
+                ("customer", customer.to_string())
             ))
         }
     }
@@ -38,7 +40,7 @@ impl<'a> CreateInvoiceitemCall<'a> {
         self
     }
 
-    pub fn invoice(mut self, invoice: String) -> Self {
+    pub fn invoice(mut self, invoice: InvoiceId) -> Self {
         self.args.add_arg("invoice", invoice);
         self
     }
@@ -48,14 +50,18 @@ impl<'a> CreateInvoiceitemCall<'a> {
         self
     }
 
-    pub fn subscription(mut self, subscription: String) -> Self {
+    pub fn subscription(mut self, subscription: SubscriptionId) -> Self {
         self.args.add_arg("subscription", subscription);
         self
     }
 }
 
 impl<'a> ApiCall<Invoiceitem> for CreateInvoiceitemCall<'a> {
-    fn call(self) -> Result<Invoiceitem> {
+    fn call_type(&self) -> &'static str {
+        "CreateInvoiceitemCall"
+    }
+
+    fn execute(self) -> Result<Invoiceitem> {
         self.client.post("/invoiceitems", &self.args)
     }
 }
@@ -63,11 +69,11 @@ impl<'a> ApiCall<Invoiceitem> for CreateInvoiceitemCall<'a> {
 #[derive(Debug)]
 pub struct RetrieveInvoiceitemCall<'a> {
     client: &'a StripeClient,
-    invoiceitem_id: String
+    invoiceitem_id: InvoiceItemId
 }
 
 impl<'a> RetrieveInvoiceitemCall<'a> {
-    pub fn new(client: &'a StripeClient, invoiceitem_id: String) -> RetrieveInvoiceitemCall<'a> {
+    pub fn new(client: &'a StripeClient, invoiceitem_id: InvoiceItemId) -> RetrieveInvoiceitemCall<'a> {
         RetrieveInvoiceitemCall {
             client: client,
             invoiceitem_id: invoiceitem_id
@@ -76,7 +82,11 @@ impl<'a> RetrieveInvoiceitemCall<'a> {
 }
 
 impl<'a> ApiCall<Invoiceitem> for RetrieveInvoiceitemCall<'a> {
-    fn call(self) -> Result<Invoiceitem> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveInvoiceitemCall"
+    }
+
+    fn execute(self) -> Result<Invoiceitem> {
         self.client.get(format!("/invoiceitems/{}", self.invoiceitem_id), &())
     }
 }
@@ -84,12 +94,12 @@ impl<'a> ApiCall<Invoiceitem> for RetrieveInvoiceitemCall<'a> {
 #[derive(Debug)]
 pub struct UpdateInvoiceitemCall<'a> {
     client: &'a StripeClient,
-    invoiceitem_id: String,
+    invoiceitem_id: InvoiceItemId,
     args: CallArgs
 }
 
 impl<'a> UpdateInvoiceitemCall<'a> {
-    pub fn new(client: &'a StripeClient, invoiceitem_id: String) -> UpdateInvoiceitemCall<'a> {
+    pub fn new(client: &'a StripeClient, invoiceitem_id: InvoiceItemId) -> UpdateInvoiceitemCall<'a> {
         UpdateInvoiceitemCall {
             client: client,
             invoiceitem_id: invoiceitem_id,
@@ -119,7 +129,11 @@ impl<'a> UpdateInvoiceitemCall<'a> {
 }
 
 impl<'a> ApiCall<Invoiceitem> for UpdateInvoiceitemCall<'a> {
-    fn call(self) -> Result<Invoiceitem> {
+    fn call_type(&self) -> &'static str {
+        "UpdateInvoiceitemCall"
+    }
+
+    fn execute(self) -> Result<Invoiceitem> {
         self.client.post(format!("/invoiceitems/{}", self.invoiceitem_id), &self.args)
     }
 }
@@ -127,11 +141,11 @@ impl<'a> ApiCall<Invoiceitem> for UpdateInvoiceitemCall<'a> {
 #[derive(Debug)]
 pub struct DeleteInvoiceitemCall<'a> {
     client: &'a StripeClient,
-    invoiceitem_id: String
+    invoiceitem_id: InvoiceItemId
 }
 
 impl<'a> DeleteInvoiceitemCall<'a> {
-    pub fn new(client: &'a StripeClient, invoiceitem_id: String) -> DeleteInvoiceitemCall<'a> {
+    pub fn new(client: &'a StripeClient, invoiceitem_id: InvoiceItemId) -> DeleteInvoiceitemCall<'a> {
         DeleteInvoiceitemCall {
             client: client,
             invoiceitem_id: invoiceitem_id
@@ -140,7 +154,11 @@ impl<'a> DeleteInvoiceitemCall<'a> {
 }
 
 impl<'a> ApiCall<Delete> for DeleteInvoiceitemCall<'a> {
-    fn call(self) -> Result<Delete> {
+    fn call_type(&self) -> &'static str {
+        "DeleteInvoiceitemCall"
+    }
+
+    fn execute(self) -> Result<Delete> {
         self.client.delete(format!("/invoiceitems/{}", self.invoiceitem_id))
     }
 }
@@ -169,7 +187,7 @@ impl<'a> ListInvoiceitemsCall<'a> {
         self
     }
 
-    pub fn customer(mut self, customer: String) -> Self {
+    pub fn customer(mut self, customer: CustomerId) -> Self {
         self.args.add_arg("customer", customer);
         self
     }
@@ -191,7 +209,11 @@ impl<'a> ListInvoiceitemsCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<Invoiceitem>> for ListInvoiceitemsCall<'a> {
-    fn call(self) -> Result<ApiList<Invoiceitem>> {
+    fn call_type(&self) -> &'static str {
+        "ListInvoiceitemsCall"
+    }
+
+    fn execute(self) -> Result<ApiList<Invoiceitem>> {
         self.client.get("/invoiceitems", &self.args)
     }
 }
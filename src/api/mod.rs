@@ -46,8 +46,35 @@ pub use self::token::*;
 pub use self::transfer::*;
 pub use self::transfer_reversal::*;
 
+use call_context;
+use errors::error::Error;
+use prepared_request::PreparedRequest;
+use std::io;
 use Result;
 
 pub trait ApiCall<T> {
-    fn call(self) -> Result<T>;
+    /// Sends the request, tagged with the call type (e.g. `CreateChargeCall`)
+    /// for middleware and instrumentation.
+    fn call(self) -> Result<T> where Self: Sized {
+        let call_type = self.call_type();
+        call_context::with_call_type(call_type, || self.execute())
+    }
+
+    /// Builds the request this call would send, after middleware, without
+    /// sending it.
+    fn prepare(self) -> Result<PreparedRequest> where Self: Sized {
+        match call_context::preparing(|| self.call()) {
+            Err(Error::DryRun(prepared)) => Ok(prepared),
+            Err(err)                     => Err(err),
+            Ok(_)                        => Err(Error::IoError(io::Error::new(
+                io::ErrorKind::Other,
+                "the call returned without building a request to prepare"
+            )))
+        }
+    }
+
+    /// The name of the call, e.g. `CreateChargeCall`.
+    fn call_type(&self) -> &'static str;
+
+    fn execute(self) -> Result<T>;
 }
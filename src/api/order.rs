@@ -1,7 +1,11 @@
 use call_args::CallArgs;
-use model::{ApiList, Currency, NewCard, Order, OrderItem, OrderStatus, Shipping};
+use model::{
+    ApiList, CouponId, Currency, CustomerId, NewCard, Order, OrderId, OrderItem, OrderStatus,
+    Shipping
+};
 use std::collections::BTreeMap;
 use super::ApiCall;
+use url_encodable::UrlEncodable;
 use {Result, StripeClient, TimeConstraint};
 
 #[derive(Debug)]
@@ -18,12 +22,12 @@ impl<'a> CreateOrderCall<'a> {
         }
     }
 
-    pub fn coupon(mut self, coupon: String) -> Self {
+    pub fn coupon(mut self, coupon: CouponId) -> Self {
         self.args.add_arg("coupon", coupon);
         self
     }
 
-    pub fn customer(mut self, customer: String) -> Self {
+    pub fn customer(mut self, customer: CustomerId) -> Self {
         self.args.add_arg("customer", customer);
         self
     }
@@ -34,7 +38,7 @@ impl<'a> CreateOrderCall<'a> {
     }
 
     pub fn items(mut self, items: Vec<OrderItem>) -> Self {
-        self.args.add_object("items", items);
+        self.args.add(UrlEncodable::structured_list("items", &items));
         self
     }
 
@@ -50,7 +54,11 @@ impl<'a> CreateOrderCall<'a> {
 }
 
 impl<'a> ApiCall<Order> for CreateOrderCall<'a> {
-    fn call(self) -> Result<Order> {
+    fn call_type(&self) -> &'static str {
+        "CreateOrderCall"
+    }
+
+    fn execute(self) -> Result<Order> {
         self.client.post("/orders", &self.args)
     }
 }
@@ -58,11 +66,11 @@ impl<'a> ApiCall<Order> for CreateOrderCall<'a> {
 #[derive(Debug)]
 pub struct RetrieveOrderCall<'a> {
     client: &'a StripeClient,
-    order_id: String
+    order_id: OrderId
 }
 
 impl<'a> RetrieveOrderCall<'a> {
-    pub fn new(client: &'a StripeClient, order_id: String) -> RetrieveOrderCall<'a> {
+    pub fn new(client: &'a StripeClient, order_id: OrderId) -> RetrieveOrderCall<'a> {
         RetrieveOrderCall {
             client: client,
             order_id: order_id
@@ -71,7 +79,11 @@ impl<'a> RetrieveOrderCall<'a> {
 }
 
 impl<'a> ApiCall<Order> for RetrieveOrderCall<'a> {
-    fn call(self) -> Result<Order> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveOrderCall"
+    }
+
+    fn execute(self) -> Result<Order> {
         self.client.get(format!("/orders/{}", self.order_id), &())
     }
 }
@@ -79,12 +91,12 @@ impl<'a> ApiCall<Order> for RetrieveOrderCall<'a> {
 #[derive(Debug)]
 pub struct UpdateOrderCall<'a> {
     client: &'a StripeClient,
-    order_id: String,
+    order_id: OrderId,
     args: CallArgs
 }
 
 impl<'a> UpdateOrderCall<'a> {
-    pub fn new(client: &'a StripeClient, order_id: String) -> UpdateOrderCall<'a> {
+    pub fn new(client: &'a StripeClient, order_id: OrderId) -> UpdateOrderCall<'a> {
         UpdateOrderCall {
             client: client,
             order_id: order_id,
@@ -92,7 +104,7 @@ impl<'a> UpdateOrderCall<'a> {
         }
     }
 
-    pub fn coupon(mut self, coupon: String) -> Self {
+    pub fn coupon(mut self, coupon: CouponId) -> Self {
         self.args.add_arg("coupon", coupon);
         self
     }
@@ -114,7 +126,11 @@ impl<'a> UpdateOrderCall<'a> {
 }
 
 impl<'a> ApiCall<Order> for UpdateOrderCall<'a> {
-    fn call(self) -> Result<Order> {
+    fn call_type(&self) -> &'static str {
+        "UpdateOrderCall"
+    }
+
+    fn execute(self) -> Result<Order> {
         self.client.post(format!("/orders/{}", self.order_id), &self.args)
     }
 }
@@ -122,12 +138,12 @@ impl<'a> ApiCall<Order> for UpdateOrderCall<'a> {
 #[derive(Debug)]
 pub struct PayOrderCall<'a> {
     client: &'a StripeClient,
-    order_id: String,
+    order_id: OrderId,
     args: CallArgs
 }
 
 impl<'a> PayOrderCall<'a> {
-    pub fn new(client: &'a StripeClient, order_id: String) -> PayOrderCall<'a> {
+    pub fn new(client: &'a StripeClient, order_id: OrderId) -> PayOrderCall<'a> {
         PayOrderCall {
             client: client,
             order_id: order_id,
@@ -135,7 +151,7 @@ impl<'a> PayOrderCall<'a> {
         }
     }
 
-    pub fn customer(mut self, customer: String) -> Self {
+    pub fn customer(mut self, customer: CustomerId) -> Self {
         self.args.add_arg("customer", customer);
         self
     }
@@ -167,7 +183,11 @@ impl<'a> PayOrderCall<'a> {
 }
 
 impl<'a> ApiCall<Order> for PayOrderCall<'a> {
-    fn call(self) -> Result<Order> {
+    fn call_type(&self) -> &'static str {
+        "PayOrderCall"
+    }
+
+    fn execute(self) -> Result<Order> {
         self.client.post(format!("/orders/{}/pay", self.order_id), &self.args)
     }
 }
@@ -196,7 +216,7 @@ impl<'a> ListOrdersCall<'a> {
         self
     }
 
-    pub fn customer(mut self, customer: String) -> Self {
+    pub fn customer(mut self, customer: CustomerId) -> Self {
         self.args.add_arg("customer", customer);
         self
     }
@@ -273,7 +293,11 @@ impl<'a> ListOrdersCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<Order>> for ListOrdersCall<'a> {
-    fn call(self) -> Result<ApiList<Order>> {
+    fn call_type(&self) -> &'static str {
+        "ListOrdersCall"
+    }
+
+    fn execute(self) -> Result<ApiList<Order>> {
         self.client.get("/orders", &self.args)
     }
 }
@@ -1,5 +1,5 @@
 use call_args::CallArgs;
-use model::{ApiList, Currency, Delete, Interval, Plan};
+use model::{ApiList, Currency, Delete, Interval, Plan, PlanId};
 use std::collections::BTreeMap;
 use super::ApiCall;
 use {Result, StripeClient, TimeConstraint};
@@ -13,7 +13,7 @@ pub struct CreatePlanCall<'a> {
 impl<'a> CreatePlanCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        id: String,
+        id: PlanId,
         amount: i64,
         currency: Currency,
         interval: Interval,
@@ -22,7 +22,7 @@ impl<'a> CreatePlanCall<'a> {
         CreatePlanCall {
             client: client,
             args: CallArgs::from((
-                ("id", id),
+                ("id", id.to_string()),
                 ("amount", amount.to_string()),
                 ("currency", currency.to_string()),
                 ("interval", interval.to_string()),
@@ -53,7 +53,11 @@ impl<'a> CreatePlanCall<'a> {
 }
 
 impl<'a> ApiCall<Plan> for CreatePlanCall<'a> {
-    fn call(self) -> Result<Plan> {
+    fn call_type(&self) -> &'static str {
+        "CreatePlanCall"
+    }
+
+    fn execute(self) -> Result<Plan> {
         self.client.post("/plans", &self.args)
     }
 }
@@ -61,11 +65,11 @@ impl<'a> ApiCall<Plan> for CreatePlanCall<'a> {
 #[derive(Debug)]
 pub struct RetrievePlanCall<'a> {
     client: &'a StripeClient,
-    plan_id: String
+    plan_id: PlanId
 }
 
 impl<'a> RetrievePlanCall<'a> {
-    pub fn new(client: &'a StripeClient, plan_id: String) -> RetrievePlanCall<'a> {
+    pub fn new(client: &'a StripeClient, plan_id: PlanId) -> RetrievePlanCall<'a> {
         RetrievePlanCall {
             client: client,
             plan_id: plan_id
@@ -74,7 +78,11 @@ impl<'a> RetrievePlanCall<'a> {
 }
 
 impl<'a> ApiCall<Plan> for RetrievePlanCall<'a> {
-    fn call(self) -> Result<Plan> {
+    fn call_type(&self) -> &'static str {
+        "RetrievePlanCall"
+    }
+
+    fn execute(self) -> Result<Plan> {
         self.client.get(format!("/plans/{}", self.plan_id), &())
     }
 }
@@ -82,12 +90,12 @@ impl<'a> ApiCall<Plan> for RetrievePlanCall<'a> {
 #[derive(Debug)]
 pub struct UpdatePlanCall<'a> {
     client: &'a StripeClient,
-    plan_id: String,
+    plan_id: PlanId,
     args: CallArgs
 }
 
 impl<'a> UpdatePlanCall<'a> {
-    pub fn new(client: &'a StripeClient, plan_id: String) -> UpdatePlanCall<'a> {
+    pub fn new(client: &'a StripeClient, plan_id: PlanId) -> UpdatePlanCall<'a> {
         UpdatePlanCall {
             client: client,
             plan_id: plan_id,
@@ -112,7 +120,11 @@ impl<'a> UpdatePlanCall<'a> {
 }
 
 impl<'a> ApiCall<Plan> for UpdatePlanCall<'a> {
-    fn call(self) -> Result<Plan> {
+    fn call_type(&self) -> &'static str {
+        "UpdatePlanCall"
+    }
+
+    fn execute(self) -> Result<Plan> {
         self.client.post(format!("/plans/{}", self.plan_id), &self.args)
     }
 }
@@ -120,11 +132,11 @@ impl<'a> ApiCall<Plan> for UpdatePlanCall<'a> {
 #[derive(Debug)]
 pub struct DeletePlanCall<'a> {
     client: &'a StripeClient,
-    plan_id: String
+    plan_id: PlanId
 }
 
 impl<'a> DeletePlanCall<'a> {
-    pub fn new(client: &'a StripeClient, plan_id: String) -> DeletePlanCall<'a> {
+    pub fn new(client: &'a StripeClient, plan_id: PlanId) -> DeletePlanCall<'a> {
         DeletePlanCall {
             client: client,
             plan_id: plan_id
@@ -133,7 +145,11 @@ impl<'a> DeletePlanCall<'a> {
 }
 
 impl<'a> ApiCall<Delete> for DeletePlanCall<'a> {
-    fn call(self) -> Result<Delete> {
+    fn call_type(&self) -> &'static str {
+        "DeletePlanCall"
+    }
+
+    fn execute(self) -> Result<Delete> {
         self.client.delete(format!("/plans/{}", self.plan_id))
     }
 }
@@ -179,7 +195,11 @@ impl<'a> ListPlansCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<Plan>> for ListPlansCall<'a> {
-    fn call(self) -> Result<ApiList<Plan>> {
+    fn call_type(&self) -> &'static str {
+        "ListPlansCall"
+    }
+
+    fn execute(self) -> Result<ApiList<Plan>> {
         self.client.get("/plans", &self.args)
     }
 }
@@ -1,5 +1,5 @@
 use call_args::CallArgs;
-use model::{ApiList, Delete, Dimensions, Product};
+use model::{ApiList, Delete, Dimensions, Product, ProductId};
 use std::collections::BTreeMap;
 use super::ApiCall;
 use {Result, StripeClient};
@@ -18,7 +18,7 @@ impl<'a> CreateProductCall<'a> {
         }
     }
 
-    pub fn id(mut self, id: String) -> Self {
+    pub fn id(mut self, id: ProductId) -> Self {
         self.args.add_arg("id", id);
         self
     }
@@ -75,7 +75,11 @@ impl<'a> CreateProductCall<'a> {
 }
 
 impl<'a> ApiCall<Product> for CreateProductCall<'a> {
-    fn call(self) -> Result<Product> {
+    fn call_type(&self) -> &'static str {
+        "CreateProductCall"
+    }
+
+    fn execute(self) -> Result<Product> {
         self.client.post("/products", &self.args)
     }
 }
@@ -83,12 +87,12 @@ impl<'a> ApiCall<Product> for CreateProductCall<'a> {
 #[derive(Debug)]
 pub struct RetrieveProductCall<'a> {
     client: &'a StripeClient,
-    product_id: String,
+    product_id: ProductId,
     args: CallArgs
 }
 
 impl<'a> RetrieveProductCall<'a> {
-    pub fn new(client: &'a StripeClient, product_id: String) -> RetrieveProductCall<'a> {
+    pub fn new(client: &'a StripeClient, product_id: ProductId) -> RetrieveProductCall<'a> {
         RetrieveProductCall {
             client: client,
             product_id: product_id,
@@ -98,7 +102,11 @@ impl<'a> RetrieveProductCall<'a> {
 }
 
 impl<'a> ApiCall<Product> for RetrieveProductCall<'a> {
-    fn call(self) -> Result<Product> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveProductCall"
+    }
+
+    fn execute(self) -> Result<Product> {
         self.client.get(format!("/products/{}", self.product_id), &())
     }
 }
@@ -106,12 +114,12 @@ impl<'a> ApiCall<Product> for RetrieveProductCall<'a> {
 #[derive(Debug)]
 pub struct UpdateProductCall<'a> {
     client: &'a StripeClient,
-    product_id: String,
+    product_id: ProductId,
     args: CallArgs
 }
 
 impl<'a> UpdateProductCall<'a> {
-    pub fn new(client: &'a StripeClient, product_id: String) -> UpdateProductCall<'a> {
+    pub fn new(client: &'a StripeClient, product_id: ProductId) -> UpdateProductCall<'a> {
         UpdateProductCall {
             client: client,
             product_id: product_id,
@@ -171,7 +179,11 @@ impl<'a> UpdateProductCall<'a> {
 }
 
 impl<'a> ApiCall<Product> for UpdateProductCall<'a> {
-    fn call(self) -> Result<Product> {
+    fn call_type(&self) -> &'static str {
+        "UpdateProductCall"
+    }
+
+    fn execute(self) -> Result<Product> {
         self.client.post(format!("/products/{}", self.product_id), &self.args)
     }
 }
@@ -200,7 +212,7 @@ impl<'a> ListProductsCall<'a> {
         self
     }
 
-    pub fn ids(mut self, ids: Vec<String>) -> Self {
+    pub fn ids(mut self, ids: Vec<ProductId>) -> Self {
         self.args.add_list("ids", ids);
         self
     }
@@ -227,7 +239,11 @@ impl<'a> ListProductsCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<Product>> for ListProductsCall<'a> {
-    fn call(self) -> Result<ApiList<Product>> {
+    fn call_type(&self) -> &'static str {
+        "ListProductsCall"
+    }
+
+    fn execute(self) -> Result<ApiList<Product>> {
         self.client.get("/products", &self.args)
     }
 }
@@ -235,11 +251,11 @@ impl<'a> ApiCall<ApiList<Product>> for ListProductsCall<'a> {
 #[derive(Debug)]
 pub struct DeleteProductCall<'a> {
     client: &'a StripeClient,
-    product_id: String
+    product_id: ProductId
 }
 
 impl<'a> DeleteProductCall<'a> {
-    pub fn new(client: &'a StripeClient, product_id: String) -> DeleteProductCall<'a> {
+    pub fn new(client: &'a StripeClient, product_id: ProductId) -> DeleteProductCall<'a> {
         DeleteProductCall {
             client: client,
             product_id: product_id
@@ -248,7 +264,11 @@ impl<'a> DeleteProductCall<'a> {
 }
 
 impl<'a> ApiCall<Delete> for DeleteProductCall<'a> {
-    fn call(self) -> Result<Delete> {
+    fn call_type(&self) -> &'static str {
+        "DeleteProductCall"
+    }
+
+    fn execute(self) -> Result<Delete> {
         self.client.delete(format!("/products/{}", self.product_id))
     }
 }
@@ -1,5 +1,6 @@
 use call_args::CallArgs;
-use model::{ApiList, Refund, RefundReason};
+use errors::amount_error::AmountError;
+use model::{Amount, ApiList, ChargeId, Currency, Refund, RefundId, RefundReason};
 use std::collections::BTreeMap;
 use super::ApiCall;
 use {Result, StripeClient};
@@ -13,11 +14,11 @@ pub struct CreateRefundCall<'a> {
 impl<'a> CreateRefundCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        charge_id: String,
+        charge_id: ChargeId,
     ) -> CreateRefundCall<'a> {
         CreateRefundCall {
             client: client,
-            args: CallArgs(vec![("charge".to_string(), charge_id)])
+            args: CallArgs(vec![("charge".to_string(), charge_id.to_string())])
         }
     }
 
@@ -26,6 +27,25 @@ impl<'a> CreateRefundCall<'a> {
         self
     }
 
+    /// Like `amount`, for a charge in `charge_currency`. Stripe refunds in
+    /// the charge's currency and only takes the amount, so an `amount` in
+    /// any other currency is refused rather than read as that many of the
+    /// charge's minor units.
+    pub fn amount_for(
+        mut self,
+        amount: Amount,
+        charge_currency: &Currency
+    ) -> ::std::result::Result<Self, AmountError> {
+        if amount.currency != *charge_currency {
+            return Err(AmountError::CurrencyMismatch {
+                expected: charge_currency.clone(),
+                actual: amount.currency
+            });
+        }
+        self.args.add_arg("amount", amount.amount);
+        Ok(self)
+    }
+
     pub fn metadata(mut self, metadata: BTreeMap<String, String>) -> Self {
         self.args.add_object("metadata", metadata);
         self
@@ -48,7 +68,11 @@ impl<'a> CreateRefundCall<'a> {
 }
 
 impl<'a> ApiCall<Refund> for CreateRefundCall<'a> {
-    fn call(self) -> Result<Refund> {
+    fn call_type(&self) -> &'static str {
+        "CreateRefundCall"
+    }
+
+    fn execute(self) -> Result<Refund> {
         self.client.post("/refunds", &self.args)
     }
 }
@@ -56,11 +80,11 @@ impl<'a> ApiCall<Refund> for CreateRefundCall<'a> {
 #[derive(Debug)]
 pub struct RetrieveRefundCall<'a> {
     client: &'a StripeClient,
-    refund_id: String
+    refund_id: RefundId
 }
 
 impl<'a> RetrieveRefundCall<'a> {
-    pub fn new(client: &'a StripeClient, refund_id: String) -> RetrieveRefundCall<'a> {
+    pub fn new(client: &'a StripeClient, refund_id: RefundId) -> RetrieveRefundCall<'a> {
         RetrieveRefundCall {
             client: client,
             refund_id: refund_id
@@ -69,7 +93,11 @@ impl<'a> RetrieveRefundCall<'a> {
 }
 
 impl<'a> ApiCall<Refund> for RetrieveRefundCall<'a> {
-    fn call(self) -> Result<Refund> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveRefundCall"
+    }
+
+    fn execute(self) -> Result<Refund> {
         self.client.get(format!("/refunds/{}", self.refund_id), &())
     }
 }
@@ -77,12 +105,12 @@ impl<'a> ApiCall<Refund> for RetrieveRefundCall<'a> {
 #[derive(Debug)]
 pub struct UpdateRefundCall<'a> {
     client: &'a StripeClient,
-    refund_id: String,
+    refund_id: RefundId,
     args: CallArgs
 }
 
 impl<'a> UpdateRefundCall<'a> {
-    pub fn new(client: &'a StripeClient, refund_id: String) -> UpdateRefundCall<'a> {
+    pub fn new(client: &'a StripeClient, refund_id: RefundId) -> UpdateRefundCall<'a> {
         UpdateRefundCall {
             client: client,
             refund_id: refund_id,
@@ -97,7 +125,11 @@ impl<'a> UpdateRefundCall<'a> {
 }
 
 impl<'a> ApiCall<Refund> for UpdateRefundCall<'a> {
-    fn call(self) -> Result<Refund> {
+    fn call_type(&self) -> &'static str {
+        "UpdateRefundCall"
+    }
+
+    fn execute(self) -> Result<Refund> {
         self.client.post(format!("/refunds/{}", self.refund_id), &self.args)
     }
 }
@@ -116,7 +148,7 @@ impl<'a> ListRefundCall<'a> {
         }
     }
 
-    pub fn charge(mut self, charge: String) -> Self {
+    pub fn charge(mut self, charge: ChargeId) -> Self {
         self.args.add_arg("charge", charge);
         self
     }
@@ -138,7 +170,11 @@ impl<'a> ListRefundCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<Refund>> for ListRefundCall<'a> {
-    fn call(self) -> Result<ApiList<Refund>> {
+    fn call_type(&self) -> &'static str {
+        "ListRefundCall"
+    }
+
+    fn execute(self) -> Result<ApiList<Refund>> {
         self.client.get("/refunds", &self.args)
     }
 }
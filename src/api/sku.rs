@@ -1,5 +1,5 @@
 use call_args::CallArgs;
-use model::{ApiList, Currency, Delete, Dimensions, Inventory, Sku};
+use model::{Amount, ApiList, Currency, Delete, Dimensions, Inventory, ProductId, Sku, SkuId};
 use std::collections::BTreeMap;
 use super::ApiCall;
 use {Result, StripeClient};
@@ -16,7 +16,7 @@ impl<'a> CreateSkuCall<'a> {
         currency: Currency,
         inventory: Inventory,
         price: i64,
-        product: String
+        product: ProductId
     ) -> CreateSkuCall<'a> {
         let mut args = CallArgs::new();
         args.add_arg("currency", currency);
@@ -29,7 +29,7 @@ impl<'a> CreateSkuCall<'a> {
         }
     }
 
-    pub fn id(mut self, id: String) -> Self {
+    pub fn id(mut self, id: SkuId) -> Self {
         self.args.add_arg("id", id);
         self
     }
@@ -61,7 +61,11 @@ impl<'a> CreateSkuCall<'a> {
 }
 
 impl<'a> ApiCall<Sku> for CreateSkuCall<'a> {
-    fn call(self) -> Result<Sku> {
+    fn call_type(&self) -> &'static str {
+        "CreateSkuCall"
+    }
+
+    fn execute(self) -> Result<Sku> {
         self.client.post("/skus", &self.args)
     }
 }
@@ -69,11 +73,11 @@ impl<'a> ApiCall<Sku> for CreateSkuCall<'a> {
 #[derive(Debug)]
 pub struct RetrieveSkuCall<'a> {
     client: &'a StripeClient,
-    sku_id: String
+    sku_id: SkuId
 }
 
 impl<'a> RetrieveSkuCall<'a> {
-    pub fn new(client: &'a StripeClient, sku_id: String) -> RetrieveSkuCall<'a> {
+    pub fn new(client: &'a StripeClient, sku_id: SkuId) -> RetrieveSkuCall<'a> {
         RetrieveSkuCall {
             client: client,
             sku_id: sku_id
@@ -82,20 +86,24 @@ impl<'a> RetrieveSkuCall<'a> {
 }
 
 impl<'a> ApiCall<Sku> for RetrieveSkuCall<'a> {
-    fn call(self) -> Result<Sku> {
-        self.client.get(format!("/sku_id/{}", self.sku_id), &())
+    fn call_type(&self) -> &'static str {
+        "RetrieveSkuCall"
+    }
+
+    fn execute(self) -> Result<Sku> {
+        self.client.get(format!("/skus/{}", self.sku_id), &())
     }
 }
 
 #[derive(Debug)]
 pub struct UpdateSkuCall<'a> {
     client: &'a StripeClient,
-    sku_id: String,
+    sku_id: SkuId,
     args: CallArgs
 }
 
 impl<'a> UpdateSkuCall<'a> {
-    pub fn new(client: &'a StripeClient, sku_id: String) -> UpdateSkuCall<'a> {
+    pub fn new(client: &'a StripeClient, sku_id: SkuId) -> UpdateSkuCall<'a> {
         UpdateSkuCall {
             client: client,
             sku_id: sku_id,
@@ -138,14 +146,25 @@ impl<'a> UpdateSkuCall<'a> {
         self
     }
 
-    pub fn product(mut self, product: String) -> Self {
+    /// Like `price`, with the currency taken from `price` too.
+    pub fn price_for(mut self, price: Amount) -> Self {
+        self.args.add_arg("price", price.amount);
+        self.args.add_arg("currency", price.currency);
+        self
+    }
+
+    pub fn product(mut self, product: ProductId) -> Self {
         self.args.add_arg("product", product);
         self
     }
 }
 
 impl<'a> ApiCall<Sku> for UpdateSkuCall<'a> {
-    fn call(self) -> Result<Sku> {
+    fn call_type(&self) -> &'static str {
+        "UpdateSkuCall"
+    }
+
+    fn execute(self) -> Result<Sku> {
         self.client.post(format!("/skus/{}", self.sku_id), &self.args)
     }
 }
@@ -179,7 +198,7 @@ impl<'a> ListSkusCall<'a> {
         self
     }
 
-    pub fn ids(mut self, ids: Vec<String>) -> Self {
+    pub fn ids(mut self, ids: Vec<SkuId>) -> Self {
         self.args.add_list("ids", ids);
         self
     }
@@ -194,7 +213,7 @@ impl<'a> ListSkusCall<'a> {
         self
     }
 
-    pub fn product(mut self, product: String) -> Self {
+    pub fn product(mut self, product: ProductId) -> Self {
         self.args.add_arg("product", product);
         self
     }
@@ -206,7 +225,11 @@ impl<'a> ListSkusCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<Sku>> for ListSkusCall<'a> {
-    fn call(self) -> Result<ApiList<Sku>> {
+    fn call_type(&self) -> &'static str {
+        "ListSkusCall"
+    }
+
+    fn execute(self) -> Result<ApiList<Sku>> {
         self.client.get("/skus", &self.args)
     }
 }
@@ -214,11 +237,11 @@ impl<'a> ApiCall<ApiList<Sku>> for ListSkusCall<'a> {
 #[derive(Debug)]
 pub struct DeleteSkuCall<'a> {
     client: &'a StripeClient,
-    sku_id: String,
+    sku_id: SkuId,
 }
 
 impl<'a> DeleteSkuCall<'a> {
-    pub fn new(client: &'a StripeClient, sku_id: String) -> DeleteSkuCall<'a> {
+    pub fn new(client: &'a StripeClient, sku_id: SkuId) -> DeleteSkuCall<'a> {
         DeleteSkuCall {
             client: client,
             sku_id: sku_id
@@ -227,7 +250,11 @@ impl<'a> DeleteSkuCall<'a> {
 }
 
 impl<'a> ApiCall<Delete> for DeleteSkuCall<'a> {
-    fn call(self) -> Result<Delete> {
+    fn call_type(&self) -> &'static str {
+        "DeleteSkuCall"
+    }
+
+    fn execute(self) -> Result<Delete> {
         self.client.delete(format!("/skus/{}", self.sku_id))
     }
 }
@@ -1,5 +1,5 @@
 use call_args::CallArgs;
-use model::{ApiList, NewCard, Subscription};
+use model::{ApiList, CouponId, CustomerId, NewCard, PlanId, Subscription, SubscriptionId};
 use std::collections::BTreeMap;
 use super::ApiCall;
 use {Result, StripeClient};
@@ -7,20 +7,20 @@ use {Result, StripeClient};
 #[derive(Debug)]
 pub struct CreateSubscriptionCall<'a> {
     client: &'a StripeClient,
-    customer_id: String,
+    customer_id: CustomerId,
     args: CallArgs
 }
 
 impl<'a> CreateSubscriptionCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        customer_id: String,
-        plan_id: String
+        customer_id: CustomerId,
+        plan_id: PlanId
     ) -> CreateSubscriptionCall<'a> {
         CreateSubscriptionCall {
             client: client,
             customer_id: customer_id,
-            args: CallArgs::from(("plan", plan_id))
+            args: CallArgs::from(("plan", plan_id.to_string()))
         }
     }
 
@@ -29,7 +29,7 @@ impl<'a> CreateSubscriptionCall<'a> {
         self
     }
 
-    pub fn coupon(mut self, coupon: String) -> Self {
+    pub fn coupon(mut self, coupon: CouponId) -> Self {
         self.args.add_arg("coupon", coupon);
         self
     }
@@ -66,7 +66,11 @@ impl<'a> CreateSubscriptionCall<'a> {
 }
 
 impl<'a> ApiCall<Subscription> for CreateSubscriptionCall<'a> {
-    fn call(self) -> Result<Subscription> {
+    fn call_type(&self) -> &'static str {
+        "CreateSubscriptionCall"
+    }
+
+    fn execute(self) -> Result<Subscription> {
         self.client.post(format!("/customers/{}/subscriptions", self.customer_id), &self.args)
     }
 }
@@ -74,15 +78,15 @@ impl<'a> ApiCall<Subscription> for CreateSubscriptionCall<'a> {
 #[derive(Debug)]
 pub struct RetrieveSubscriptionCall<'a> {
     client: &'a StripeClient,
-    customer_id: String,
-    subscription_id: String
+    customer_id: CustomerId,
+    subscription_id: SubscriptionId
 }
 
 impl<'a> RetrieveSubscriptionCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        customer_id: String,
-        subscription_id: String
+        customer_id: CustomerId,
+        subscription_id: SubscriptionId
     ) -> RetrieveSubscriptionCall<'a> {
         RetrieveSubscriptionCall {
             client: client,
@@ -93,7 +97,11 @@ impl<'a> RetrieveSubscriptionCall<'a> {
 }
 
 impl<'a> ApiCall<Subscription> for RetrieveSubscriptionCall<'a> {
-    fn call(self) -> Result<Subscription> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveSubscriptionCall"
+    }
+
+    fn execute(self) -> Result<Subscription> {
         self.client.get(
             format!("/customers/{}/subscriptions/{}", self.customer_id, self.subscription_id),
             &()
@@ -104,16 +112,16 @@ impl<'a> ApiCall<Subscription> for RetrieveSubscriptionCall<'a> {
 #[derive(Debug)]
 pub struct UpdateSubscriptionCall<'a> {
     client: &'a StripeClient,
-    customer_id: String,
-    subscription_id: String,
+    customer_id: CustomerId,
+    subscription_id: SubscriptionId,
     args: CallArgs
 }
 
 impl<'a> UpdateSubscriptionCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        customer_id: String,
-        subscription_id: String
+        customer_id: CustomerId,
+        subscription_id: SubscriptionId
     ) -> UpdateSubscriptionCall<'a> {
         UpdateSubscriptionCall {
             client: client,
@@ -128,12 +136,12 @@ impl<'a> UpdateSubscriptionCall<'a> {
         self
     }
 
-    pub fn coupon(mut self, coupon: String) -> Self {
+    pub fn coupon(mut self, coupon: CouponId) -> Self {
         self.args.add_arg("coupon", coupon);
         self
     }
 
-    pub fn plan(mut self, plan: String) -> Self {
+    pub fn plan(mut self, plan: PlanId) -> Self {
         self.args.add_arg("plan", plan);
         self
     }
@@ -185,7 +193,11 @@ impl<'a> UpdateSubscriptionCall<'a> {
 }
 
 impl<'a> ApiCall<Subscription> for UpdateSubscriptionCall<'a> {
-    fn call(self) -> Result<Subscription> {
+    fn call_type(&self) -> &'static str {
+        "UpdateSubscriptionCall"
+    }
+
+    fn execute(self) -> Result<Subscription> {
         self.client.post(
             format!("/customers/{}/subscriptions/{}", self.customer_id, self.subscription_id),
             &self.args
@@ -196,16 +208,16 @@ impl<'a> ApiCall<Subscription> for UpdateSubscriptionCall<'a> {
 #[derive(Debug)]
 pub struct CancelSubscriptionCall<'a> {
     client: &'a StripeClient,
-    customer_id: String,
-    subscription_id: String,
+    customer_id: CustomerId,
+    subscription_id: SubscriptionId,
     args: CallArgs
 }
 
 impl<'a> CancelSubscriptionCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        customer_id: String,
-        subscription_id: String
+        customer_id: CustomerId,
+        subscription_id: SubscriptionId
     ) -> CancelSubscriptionCall<'a> {
         CancelSubscriptionCall {
             client: client,
@@ -222,7 +234,11 @@ impl<'a> CancelSubscriptionCall<'a> {
 }
 
 impl<'a> ApiCall<Subscription> for CancelSubscriptionCall<'a> {
-    fn call(self) -> Result<Subscription> {
+    fn call_type(&self) -> &'static str {
+        "CancelSubscriptionCall"
+    }
+
+    fn execute(self) -> Result<Subscription> {
         self.client.delete_with_args(
             format!("/customers/{}/subscriptions/{}", self.customer_id, self.subscription_id),
             &self.args
@@ -233,12 +249,12 @@ impl<'a> ApiCall<Subscription> for CancelSubscriptionCall<'a> {
 #[derive(Debug)]
 pub struct ListActiveSubscriptionsCall<'a> {
     client: &'a StripeClient,
-    customer_id: String,
+    customer_id: CustomerId,
     args: CallArgs
 }
 
 impl<'a> ListActiveSubscriptionsCall<'a> {
-    pub fn new(client: &'a StripeClient, customer_id: String) -> ListActiveSubscriptionsCall<'a> {
+    pub fn new(client: &'a StripeClient, customer_id: CustomerId) -> ListActiveSubscriptionsCall<'a> {
         ListActiveSubscriptionsCall {
             client: client,
             customer_id: customer_id,
@@ -263,7 +279,11 @@ impl<'a> ListActiveSubscriptionsCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<Subscription>> for ListActiveSubscriptionsCall<'a> {
-    fn call(self) -> Result<ApiList<Subscription>> {
+    fn call_type(&self) -> &'static str {
+        "ListActiveSubscriptionsCall"
+    }
+
+    fn execute(self) -> Result<ApiList<Subscription>> {
         self.client.get(format!("/customers/{}/subscriptions", self.customer_id), &self.args)
     }
 }
@@ -1,5 +1,5 @@
 use call_args::CallArgs;
-use model::{NewBankAccount, NewCard, Token};
+use model::{CardId, CustomerId, NewBankAccount, NewCard, Token, TokenId};
 use super::ApiCall;
 use {Result, StripeClient};
 
@@ -22,7 +22,7 @@ impl<'a> CreateCardTokenCall<'a> {
         self
     }
 
-    pub fn customer(mut self, customer: String, card_id: String) -> Self {
+    pub fn customer(mut self, customer: CustomerId, card_id: CardId) -> Self {
         self.args.add_arg("customer", customer);
         self.args.add_arg("card", card_id);
         self
@@ -30,7 +30,11 @@ impl<'a> CreateCardTokenCall<'a> {
 }
 
 impl<'a> ApiCall<Token> for CreateCardTokenCall<'a> {
-    fn call(self) -> Result<Token> {
+    fn call_type(&self) -> &'static str {
+        "CreateCardTokenCall"
+    }
+
+    fn execute(self) -> Result<Token> {
         self.client.post("/tokens", &self.args)
     }
 }
@@ -54,14 +58,18 @@ impl<'a> CreateBankAccountTokenCall<'a> {
         self
     }
 
-    pub fn customer(mut self, customer: String) -> Self {
+    pub fn customer(mut self, customer: CustomerId) -> Self {
         self.args.add_arg("customer", customer);
         self
     }
 }
 
 impl<'a> ApiCall<Token> for CreateBankAccountTokenCall<'a> {
-    fn call(self) -> Result<Token> {
+    fn call_type(&self) -> &'static str {
+        "CreateBankAccountTokenCall"
+    }
+
+    fn execute(self) -> Result<Token> {
         self.client.post("/tokens", &self.args)
     }
 }
@@ -82,7 +90,11 @@ impl<'a> CreatePiiTokenCall<'a> {
 }
 
 impl<'a> ApiCall<Token> for CreatePiiTokenCall<'a> {
-    fn call(self) -> Result<Token> {
+    fn call_type(&self) -> &'static str {
+        "CreatePiiTokenCall"
+    }
+
+    fn execute(self) -> Result<Token> {
         self.client.post("/tokens", &self.args)
     }
 }
@@ -90,11 +102,11 @@ impl<'a> ApiCall<Token> for CreatePiiTokenCall<'a> {
 #[derive(Debug)]
 pub struct RetrieveTokenCall<'a> {
     client: &'a StripeClient,
-    token_id: String
+    token_id: TokenId
 }
 
 impl<'a> RetrieveTokenCall<'a> {
-    pub fn new(client: &'a StripeClient, token_id: String) -> RetrieveTokenCall<'a> {
+    pub fn new(client: &'a StripeClient, token_id: TokenId) -> RetrieveTokenCall<'a> {
         RetrieveTokenCall {
             client: client,
             token_id: token_id
@@ -103,7 +115,11 @@ impl<'a> RetrieveTokenCall<'a> {
 }
 
 impl<'a> ApiCall<Token> for RetrieveTokenCall<'a> {
-    fn call(self) -> Result<Token> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveTokenCall"
+    }
+
+    fn execute(self) -> Result<Token> {
         self.client.get(format!("/tokens/{}", self.token_id), &())
     }
 }
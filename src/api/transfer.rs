@@ -1,5 +1,5 @@
 use call_args::CallArgs;
-use model::{ApiList, Currency, SourceType, Transfer, TransferStatus};
+use model::{AccountId, ApiList, Currency, RecipientId, SourceType, Transfer, TransferId, TransferStatus};
 use std::collections::BTreeMap;
 use super::ApiCall;
 use time_constraint::TimeConstraint;
@@ -16,14 +16,14 @@ impl<'a> CreateTransferCall<'a> {
         client: &'a StripeClient,
         amount: i64,
         currency: Currency,
-        destination: String
+        destination: AccountId
     ) -> CreateTransferCall<'a> {
         CreateTransferCall {
             client: client,
             args: CallArgs(vec![
                 ("amount".to_string(), amount.to_string()),
                 ("currency".to_string(), currency.to_string()),
-                ("destination".to_string(), destination)
+                ("destination".to_string(), destination.to_string())
             ])
         }
     }
@@ -55,7 +55,11 @@ impl<'a> CreateTransferCall<'a> {
 }
 
 impl<'a> ApiCall<Transfer> for CreateTransferCall<'a> {
-    fn call(self) -> Result<Transfer> {
+    fn call_type(&self) -> &'static str {
+        "CreateTransferCall"
+    }
+
+    fn execute(self) -> Result<Transfer> {
         self.client.post("/transfers", &self.args)
     }
 }
@@ -63,11 +67,11 @@ impl<'a> ApiCall<Transfer> for CreateTransferCall<'a> {
 #[derive(Debug)]
 pub struct RetrieveTransferCall<'a> {
     client: &'a StripeClient,
-    transfer_id: String
+    transfer_id: TransferId
 }
 
 impl<'a> RetrieveTransferCall<'a> {
-    pub fn new(client: &'a StripeClient, transfer_id: String) -> RetrieveTransferCall<'a> {
+    pub fn new(client: &'a StripeClient, transfer_id: TransferId) -> RetrieveTransferCall<'a> {
         RetrieveTransferCall {
             client: client,
             transfer_id: transfer_id
@@ -76,7 +80,11 @@ impl<'a> RetrieveTransferCall<'a> {
 }
 
 impl<'a> ApiCall<Transfer> for RetrieveTransferCall<'a> {
-    fn call(self) -> Result<Transfer> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveTransferCall"
+    }
+
+    fn execute(self) -> Result<Transfer> {
         self.client.get(format!("/transfers/{}", self.transfer_id), &())
     }
 }
@@ -84,12 +92,12 @@ impl<'a> ApiCall<Transfer> for RetrieveTransferCall<'a> {
 #[derive(Debug)]
 pub struct UpdateTransferCall<'a> {
     client: &'a StripeClient,
-    transfer_id: String,
+    transfer_id: TransferId,
     args: CallArgs
 }
 
 impl<'a> UpdateTransferCall<'a> {
-    pub fn new(client: &'a StripeClient, transfer_id: String) -> UpdateTransferCall<'a> {
+    pub fn new(client: &'a StripeClient, transfer_id: TransferId) -> UpdateTransferCall<'a> {
         UpdateTransferCall {
             client: client,
             transfer_id: transfer_id,
@@ -109,8 +117,12 @@ impl<'a> UpdateTransferCall<'a> {
 }
 
 impl<'a> ApiCall<Transfer> for UpdateTransferCall<'a> {
-    fn call(self) -> Result<Transfer> {
-        self.client.post(format!("transfers/{}", self.transfer_id), &self.args)
+    fn call_type(&self) -> &'static str {
+        "UpdateTransferCall"
+    }
+
+    fn execute(self) -> Result<Transfer> {
+        self.client.post(format!("/transfers/{}", self.transfer_id), &self.args)
     }
 }
 
@@ -163,7 +175,7 @@ impl<'a> ListTransfersCall<'a> {
         self
     }
 
-    pub fn recipient(mut self, recipient: String) -> Self {
+    pub fn recipient(mut self, recipient: RecipientId) -> Self {
         self.args.add_arg("recipient", recipient);
         self
     }
@@ -180,7 +192,11 @@ impl<'a> ListTransfersCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<Transfer>> for ListTransfersCall<'a> {
-    fn call(self) -> Result<ApiList<Transfer>> {
+    fn call_type(&self) -> &'static str {
+        "ListTransfersCall"
+    }
+
+    fn execute(self) -> Result<ApiList<Transfer>> {
         self.client.get("/transfers", &self.args)
     }
 }
@@ -1,5 +1,5 @@
 use call_args::CallArgs;
-use model::{ApiList, TransferReversal};
+use model::{ApiList, TransferId, TransferReversal, TransferReversalId};
 use std::collections::BTreeMap;
 use super::ApiCall;
 use {Result, StripeClient};
@@ -7,12 +7,12 @@ use {Result, StripeClient};
 #[derive(Debug)]
 pub struct CreateTransferReversalCall<'a> {
     client: &'a StripeClient,
-    transfer_id: String,
+    transfer_id: TransferId,
     args: CallArgs
 }
 
 impl<'a> CreateTransferReversalCall<'a> {
-    pub fn new(client: &'a StripeClient, transfer_id: String) -> CreateTransferReversalCall<'a> {
+    pub fn new(client: &'a StripeClient, transfer_id: TransferId) -> CreateTransferReversalCall<'a> {
         CreateTransferReversalCall {
             client: client,
             transfer_id: transfer_id,
@@ -42,7 +42,11 @@ impl<'a> CreateTransferReversalCall<'a> {
 }
 
 impl<'a> ApiCall<TransferReversal> for CreateTransferReversalCall<'a> {
-    fn call(self) -> Result<TransferReversal> {
+    fn call_type(&self) -> &'static str {
+        "CreateTransferReversalCall"
+    }
+
+    fn execute(self) -> Result<TransferReversal> {
         self.client.post(format!("/transfers/{}/reversals", self.transfer_id), &self.args)
     }
 }
@@ -50,15 +54,15 @@ impl<'a> ApiCall<TransferReversal> for CreateTransferReversalCall<'a> {
 #[derive(Debug)]
 pub struct RetrieveTransferReversalCall<'a> {
     client: &'a StripeClient,
-    transfer_id: String,
-    reversal_id: String,
+    transfer_id: TransferId,
+    reversal_id: TransferReversalId,
 }
 
 impl<'a> RetrieveTransferReversalCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        transfer_id: String,
-        reversal_id: String
+        transfer_id: TransferId,
+        reversal_id: TransferReversalId
     ) -> RetrieveTransferReversalCall<'a> {
         RetrieveTransferReversalCall {
             client: client,
@@ -69,7 +73,11 @@ impl<'a> RetrieveTransferReversalCall<'a> {
 }
 
 impl<'a> ApiCall<TransferReversal> for RetrieveTransferReversalCall<'a> {
-    fn call(self) -> Result<TransferReversal> {
+    fn call_type(&self) -> &'static str {
+        "RetrieveTransferReversalCall"
+    }
+
+    fn execute(self) -> Result<TransferReversal> {
         self.client.get(
             format!("/transfers/{}/reversals/{}", self.transfer_id, self.reversal_id),
             &()
@@ -80,16 +88,16 @@ impl<'a> ApiCall<TransferReversal> for RetrieveTransferReversalCall<'a> {
 #[derive(Debug)]
 pub struct UpdateTransferReversalCall<'a> {
     client: &'a StripeClient,
-    transfer_id: String,
-    reversal_id: String,
+    transfer_id: TransferId,
+    reversal_id: TransferReversalId,
     args: CallArgs
 }
 
 impl<'a> UpdateTransferReversalCall<'a> {
     pub fn new(
         client: &'a StripeClient,
-        transfer_id: String,
-        reversal_id: String
+        transfer_id: TransferId,
+        reversal_id: TransferReversalId
     ) -> UpdateTransferReversalCall<'a> {
         UpdateTransferReversalCall {
             client: client,
@@ -111,7 +119,11 @@ impl<'a> UpdateTransferReversalCall<'a> {
 }
 
 impl<'a> ApiCall<TransferReversal> for UpdateTransferReversalCall<'a> {
-    fn call(self) -> Result<TransferReversal> {
+    fn call_type(&self) -> &'static str {
+        "UpdateTransferReversalCall"
+    }
+
+    fn execute(self) -> Result<TransferReversal> {
         self.client.post(
             format!("/transfers/{}/reversals/{}", self.transfer_id, self.reversal_id),
             &self.args
@@ -122,12 +134,12 @@ impl<'a> ApiCall<TransferReversal> for UpdateTransferReversalCall<'a> {
 #[derive(Debug)]
 pub struct ListTransferReversalsCall<'a> {
     client: &'a StripeClient,
-    transfer_id: String,
+    transfer_id: TransferId,
     args: CallArgs
 }
 
 impl<'a> ListTransferReversalsCall<'a> {
-    pub fn new(client: &'a StripeClient, transfer_id: String) -> ListTransferReversalsCall<'a> {
+    pub fn new(client: &'a StripeClient, transfer_id: TransferId) -> ListTransferReversalsCall<'a> {
         ListTransferReversalsCall {
             client: client,
             transfer_id: transfer_id,
@@ -152,7 +164,11 @@ impl<'a> ListTransferReversalsCall<'a> {
 }
 
 impl<'a> ApiCall<ApiList<TransferReversal>> for ListTransferReversalsCall<'a> {
-    fn call(self) -> Result<ApiList<TransferReversal>> {
+    fn call_type(&self) -> &'static str {
+        "ListTransferReversalsCall"
+    }
+
+    fn execute(self) -> Result<ApiList<TransferReversal>> {
         self.client.get(format!("/transfers/{}/reversals", self.transfer_id), &self.args)
     }
 }
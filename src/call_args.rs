@@ -1,9 +1,17 @@
+use redact;
+use std::fmt;
 use std::fmt::Display;
 use url_encodable::UrlEncodable;
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct CallArgs(pub Vec<(String, String)>);
 
+impl fmt::Debug for CallArgs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CallArgs({:?})", redact::redact_params(&self.0))
+    }
+}
+
 impl CallArgs {
     pub fn new() -> Self {
         CallArgs(Vec::new())
@@ -0,0 +1,53 @@
+use std::cell::Cell;
+use std::thread::LocalKey;
+
+const UNKNOWN_CALL_TYPE: &'static str = "Unknown";
+
+thread_local!(static CALL_TYPE: Cell<&'static str> = Cell::new(UNKNOWN_CALL_TYPE));
+thread_local!(static PREPARING: Cell<bool> = Cell::new(false));
+
+/// Puts a thread-local back as it was when dropped, so that a panic caught
+/// further up the stack doesn't leave it set for later calls on the thread.
+struct Reset<T: Copy + 'static> {
+    key: &'static LocalKey<Cell<T>>,
+    previous: T
+}
+
+impl<T: Copy + 'static> Reset<T> {
+    fn set(key: &'static LocalKey<Cell<T>>, value: T) -> Reset<T> {
+        let previous = key.with(|current| {
+            let previous = current.get();
+            current.set(value);
+            previous
+        });
+        Reset { key: key, previous: previous }
+    }
+}
+
+impl<T: Copy + 'static> Drop for Reset<T> {
+    fn drop(&mut self) {
+        let previous = self.previous;
+        self.key.with(|current| current.set(previous));
+    }
+}
+
+/// Runs `f` with `call_type` recorded as the call being made on this thread.
+pub fn with_call_type<T, F: FnOnce() -> T>(call_type: &'static str, f: F) -> T {
+    let _reset = Reset::set(&CALL_TYPE, call_type);
+    f()
+}
+
+pub fn current_call_type() -> &'static str {
+    CALL_TYPE.with(|current| current.get())
+}
+
+/// Runs `f` with requests on this thread turned into `Error::DryRun`
+/// instead of being sent.
+pub fn preparing<T, F: FnOnce() -> T>(f: F) -> T {
+    let _reset = Reset::set(&PREPARING, true);
+    f()
+}
+
+pub fn is_preparing() -> bool {
+    PREPARING.with(|preparing| preparing.get())
+}
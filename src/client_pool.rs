@@ -0,0 +1,172 @@
+use key_provider::KeyProvider;
+use middleware::{Middleware, RateLimiter};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use transport::{HyperTransport, Transport};
+use {Result, StripeClient};
+
+/// How to configure the client for a single tenant of a `StripeClientPool`.
+pub struct TenantConfig {
+    key_provider: Box<KeyProvider>,
+    stripe_account: Option<String>,
+    rate_limit: Option<(u32, Duration)>,
+    labels: Vec<(String, String)>,
+    middleware: Vec<Box<Middleware>>
+}
+
+impl TenantConfig {
+    pub fn new<P: KeyProvider + 'static>(key_provider: P) -> TenantConfig {
+        TenantConfig {
+            key_provider: Box::new(key_provider),
+            stripe_account: None,
+            rate_limit: None,
+            labels: Vec::new(),
+            middleware: Vec::new()
+        }
+    }
+
+    /// Makes the tenant's requests on behalf of a connected account.
+    pub fn stripe_account(mut self, account_id: &str) -> TenantConfig {
+        self.stripe_account = Some(String::from(account_id));
+        self
+    }
+
+    /// Allows at most `requests` requests in any `per` window for the tenant.
+    /// A limit of 0 requests or a zero window makes `StripeClientPool::get`
+    /// return `Error::RateLimitError` for the tenant.
+    pub fn rate_limit(mut self, requests: u32, per: Duration) -> TenantConfig {
+        self.rate_limit = Some((requests, per));
+        self
+    }
+
+    pub fn label(mut self, key: &str, value: &str) -> TenantConfig {
+        self.labels.push((String::from(key), String::from(value)));
+        self
+    }
+
+    pub fn middleware<M: Middleware + 'static>(mut self, middleware: M) -> TenantConfig {
+        self.middleware.push(Box::new(middleware));
+        self
+    }
+}
+
+struct PooledClient {
+    client: Arc<StripeClient>,
+    last_used: Instant
+}
+
+/// Lazily creates and caches a `StripeClient` per tenant. All clients share
+/// one HTTP connection pool, and each is labelled with `tenant=<id>`.
+pub struct StripeClientPool {
+    transport: Arc<Transport>,
+    configure: Box<Fn(&str) -> Result<TenantConfig> + Send + Sync>,
+    clients: Mutex<HashMap<String, PooledClient>>,
+    idle_timeout: Option<Duration>
+}
+
+impl StripeClientPool {
+    /// `configure` is called the first time a tenant is used, and again if
+    /// the tenant is used after being evicted.
+    pub fn new<F>(configure: F) -> StripeClientPool
+        where F: Fn(&str) -> Result<TenantConfig> + Send + Sync + 'static
+    {
+        StripeClientPool {
+            transport: Arc::new(HyperTransport::new()),
+            configure: Box::new(configure),
+            clients: Mutex::new(HashMap::new()),
+            idle_timeout: None
+        }
+    }
+
+    /// Sends every tenant's requests through `transport`.
+    pub fn transport<T: Transport + 'static>(mut self, transport: T) -> StripeClientPool {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// Evicts tenants that haven't made a request in `idle_timeout`.
+    pub fn idle_timeout(mut self, idle_timeout: Duration) -> StripeClientPool {
+        self.idle_timeout = Some(idle_timeout);
+        self
+    }
+
+    pub fn get(&self, tenant: &str) -> Result<Arc<StripeClient>> {
+        self.evict_idle();
+
+        if let Some(pooled) = self.clients.lock().unwrap().get_mut(tenant) {
+            pooled.last_used = Instant::now();
+            return Ok(pooled.client.clone());
+        }
+
+        // `configure` runs without the lock held, so a slow or panicking
+        // tenant doesn't hold up the others. If another thread created the
+        // tenant's client meanwhile, theirs is kept and this one dropped.
+        let client = Arc::new(self.create(tenant)?);
+        let mut clients = self.clients.lock().unwrap();
+        let pooled = clients.entry(String::from(tenant)).or_insert(PooledClient {
+            client: client,
+            last_used: Instant::now()
+        });
+        pooled.last_used = Instant::now();
+        Ok(pooled.client.clone())
+    }
+
+    /// Drops the cached client for `tenant`, e.g. after its key changed.
+    pub fn remove(&self, tenant: &str) {
+        self.clients.lock().unwrap().remove(tenant);
+    }
+
+    /// Drops clients idle for longer than the idle timeout, returning how
+    /// many were evicted.
+    pub fn evict_idle(&self) -> usize {
+        let idle_timeout = match self.idle_timeout {
+            Some(idle_timeout) => idle_timeout,
+            None               => return 0
+        };
+        let mut clients = self.clients.lock().unwrap();
+        let idle: Vec<String> = clients.iter()
+            .filter(|&(_, pooled)| pooled.last_used.elapsed() > idle_timeout)
+            .map(|(tenant, _)| tenant.clone())
+            .collect();
+        for tenant in &idle {
+            clients.remove(tenant);
+        }
+        idle.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.clients.lock().unwrap().len()
+    }
+
+    fn create(&self, tenant: &str) -> Result<StripeClient> {
+        let TenantConfig { key_provider, stripe_account, rate_limit, labels, middleware } =
+            (self.configure)(tenant)?;
+
+        let mut client = StripeClient::with_shared_transport(key_provider, self.transport.clone())
+            .with_label("tenant", tenant);
+        if let Some(account_id) = stripe_account {
+            client = client.with_stripe_account(&account_id);
+        }
+        for (key, value) in labels {
+            client = client.with_label(&key, &value);
+        }
+        if let Some((requests, per)) = rate_limit {
+            client = client.with_middleware(RateLimiter::new(requests, per)?);
+        }
+        for middleware in middleware {
+            client.middleware.push(middleware);
+        }
+        Ok(client)
+    }
+}
+
+impl fmt::Debug for StripeClientPool {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StripeClientPool")
+            .field("tenants", &self.len())
+            .field("idle_timeout", &self.idle_timeout)
+            .finish()
+    }
+}
@@ -1,4 +1,7 @@
 use serde;
+use serde::de::Error;
+use serde_json;
+use serde_json::Value;
 
 #[derive(Clone, Debug)]
 pub enum Either<L, R> {
@@ -10,8 +13,23 @@ use Either::*;
 impl<L, R> serde::Deserialize for Either<L, R>
     where L: serde::Deserialize, R: serde::Deserialize
 {
+    /// Buffers the value first, since a failed attempt at `L` would
+    /// otherwise consume the input `R` needs, e.g. for an expanded object.
     fn deserialize<D: serde::Deserializer>(deserializer: &mut D) -> Result<Either<L, R>, D::Error> {
-        L::deserialize(deserializer).map(Left)
-            .or_else(|_| R::deserialize(deserializer).map(Right))
+        let value = Value::deserialize(deserializer)?;
+        serde_json::from_value(value.clone()).map(Left)
+            .or_else(|_| serde_json::from_value(value).map(Right))
+            .map_err(|err| D::Error::custom(err.to_string()))
+    }
+}
+
+impl<L, R> serde::Serialize for Either<L, R>
+    where L: serde::Serialize, R: serde::Serialize
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: &mut S) -> Result<(), S::Error> {
+        match *self {
+            Left(ref left)   => left.serialize(serializer),
+            Right(ref right) => right.serialize(serializer)
+        }
     }
 }
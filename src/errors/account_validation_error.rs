@@ -0,0 +1,63 @@
+use errors::address_validation_error::AddressValidationError;
+use std;
+
+/// Raised when account details are checked before sending them to create
+/// or update a managed account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountValidationError {
+    /// The address at `param` isn't one its country would take.
+    InvalidAddress {
+        param: String,
+        error: AddressValidationError
+    },
+    /// The date of birth at `param` is missing a part, or isn't a real
+    /// date.
+    InvalidDob {
+        param: String
+    },
+    /// The SSN last 4 at `param` isn't four digits.
+    InvalidSsnLast4 {
+        param: String
+    },
+    /// The transfer schedule's monthly anchor isn't between 1 and 31.
+    InvalidMonthlyAnchor
+}
+
+impl AccountValidationError {
+    /// The field at fault, named as Stripe names the param, e.g.
+    /// `legal_entity[additional_owners][0][dob]`.
+    pub fn param(&self) -> &str {
+        match *self {
+            AccountValidationError::InvalidAddress { ref param, .. } |
+            AccountValidationError::InvalidDob { ref param } |
+            AccountValidationError::InvalidSsnLast4 { ref param } => param,
+            AccountValidationError::InvalidMonthlyAnchor => "transfer_schedule[monthly_anchor]"
+        }
+    }
+}
+
+impl std::error::Error for AccountValidationError {
+    fn description(&self) -> &str {
+        match *self {
+            AccountValidationError::InvalidAddress { .. } => "The address is missing a field or has one in the wrong format",
+            AccountValidationError::InvalidDob { .. } => "The date of birth is incomplete or not a real date",
+            AccountValidationError::InvalidSsnLast4 { .. } => "The last 4 digits of the SSN aren't 4 digits",
+            AccountValidationError::InvalidMonthlyAnchor => "The monthly anchor isn't a day of the month"
+        }
+    }
+}
+
+impl std::fmt::Display for AccountValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            AccountValidationError::InvalidAddress { ref param, ref error } =>
+                write!(f, "AccountValidationError::InvalidAddress(param={},error={})", param, error),
+            AccountValidationError::InvalidDob { ref param } =>
+                write!(f, "AccountValidationError::InvalidDob(param={})", param),
+            AccountValidationError::InvalidSsnLast4 { ref param } =>
+                write!(f, "AccountValidationError::InvalidSsnLast4(param={})", param),
+            AccountValidationError::InvalidMonthlyAnchor =>
+                write!(f, "AccountValidationError::InvalidMonthlyAnchor")
+        }
+    }
+}
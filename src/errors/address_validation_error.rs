@@ -0,0 +1,32 @@
+use std;
+
+/// Raised when an address is checked against what its country needs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AddressValidationError {
+    /// The address has no `field`, and its country needs one.
+    Missing {
+        field: &'static str
+    },
+    /// `field` isn't in a form the address's country uses.
+    Invalid {
+        field: &'static str
+    }
+}
+
+impl std::error::Error for AddressValidationError {
+    fn description(&self) -> &str {
+        match *self {
+            AddressValidationError::Missing { .. } => "The address is missing a field its country requires",
+            AddressValidationError::Invalid { .. } => "The address has a field in the wrong format for its country"
+        }
+    }
+}
+
+impl std::fmt::Display for AddressValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            AddressValidationError::Missing { field } => write!(f, "AddressValidationError::Missing(field={})", field),
+            AddressValidationError::Invalid { field } => write!(f, "AddressValidationError::Invalid(field={})", field)
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use model::Currency;
+use std;
+
+/// Raised by `Amount` arithmetic and parsing.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AmountError {
+    /// The two amounts are in different currencies.
+    CurrencyMismatch {
+        expected: Currency,
+        actual: Currency
+    },
+    /// The result doesn't fit in an `i64` of minor units.
+    Overflow,
+    /// The string isn't a decimal amount, or has more decimal places than
+    /// the currency has minor units.
+    InvalidAmount {
+        amount: String,
+        currency: Currency
+    }
+}
+
+impl std::error::Error for AmountError {
+    fn description(&self) -> &str {
+        match *self {
+            AmountError::CurrencyMismatch { .. } => "The amounts are in different currencies",
+            AmountError::Overflow => "The amount is too large",
+            AmountError::InvalidAmount { .. } => "The string is not an amount of the currency"
+        }
+    }
+}
+
+impl std::fmt::Display for AmountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            AmountError::CurrencyMismatch { ref expected, ref actual } => write!(
+                f,
+                "AmountError::CurrencyMismatch(expected={},actual={})",
+                expected,
+                actual
+            ),
+            AmountError::Overflow => write!(f, "AmountError::Overflow"),
+            AmountError::InvalidAmount { ref amount, ref currency } => write!(
+                f,
+                "AmountError::InvalidAmount(amount={:?},currency={})",
+                amount,
+                currency
+            )
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use model::{Country, Currency};
+use std;
+
+/// Raised when bank account details are checked before sending them, for
+/// what Stripe would reject them for.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BankAccountValidationError {
+    /// The account number has the wrong characters or length for the
+    /// country, or isn't shaped like an IBAN where the country uses them.
+    InvalidAccountNumber,
+    /// The IBAN fails its mod-97 check.
+    IncorrectAccountNumber,
+    /// The IBAN is from a different country than the account.
+    IbanCountryMismatch,
+    /// The country needs a routing number and there isn't one.
+    MissingRoutingNumber,
+    /// The routing number has the wrong characters or length for the
+    /// country.
+    InvalidRoutingNumber,
+    /// The routing number fails its checksum.
+    IncorrectRoutingNumber,
+    /// Stripe doesn't pay out in the currency to bank accounts in the
+    /// country.
+    UnsupportedCurrency {
+        country: Country,
+        currency: Currency
+    }
+}
+
+impl BankAccountValidationError {
+    /// The `NewBankAccount` field at fault, named as Stripe names the param.
+    pub fn param(&self) -> &'static str {
+        match *self {
+            BankAccountValidationError::InvalidAccountNumber |
+            BankAccountValidationError::IncorrectAccountNumber |
+            BankAccountValidationError::IbanCountryMismatch => "account_number",
+            BankAccountValidationError::MissingRoutingNumber |
+            BankAccountValidationError::InvalidRoutingNumber |
+            BankAccountValidationError::IncorrectRoutingNumber => "routing_number",
+            BankAccountValidationError::UnsupportedCurrency { .. } => "currency"
+        }
+    }
+}
+
+impl std::error::Error for BankAccountValidationError {
+    fn description(&self) -> &str {
+        match *self {
+            BankAccountValidationError::InvalidAccountNumber => "The account number is invalid for the country",
+            BankAccountValidationError::IncorrectAccountNumber => "The IBAN's check digits are incorrect",
+            BankAccountValidationError::IbanCountryMismatch => "The IBAN is from a different country than the account",
+            BankAccountValidationError::MissingRoutingNumber => "The country requires a routing number",
+            BankAccountValidationError::InvalidRoutingNumber => "The routing number is invalid for the country",
+            BankAccountValidationError::IncorrectRoutingNumber => "The routing number's checksum is incorrect",
+            BankAccountValidationError::UnsupportedCurrency { .. } =>
+                "The currency is not supported for bank accounts in the country"
+        }
+    }
+}
+
+impl std::fmt::Display for BankAccountValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            BankAccountValidationError::UnsupportedCurrency { ref country, ref currency } => write!(
+                f,
+                "BankAccountValidationError::UnsupportedCurrency(country={},currency={})",
+                country,
+                currency
+            ),
+            ref err => write!(f, "BankAccountValidationError::{:?}", err)
+        }
+    }
+}
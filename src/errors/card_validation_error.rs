@@ -0,0 +1,71 @@
+use errors::stripe_error::StripeErrorCode;
+use std;
+
+/// Raised when card details are checked before sending them, for what
+/// Stripe would reject them for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CardValidationError {
+    /// The number has something other than digits and spaces in it, or is
+    /// the wrong length for its brand.
+    InvalidNumber,
+    /// The number fails the Luhn check.
+    IncorrectNumber,
+    /// The month isn't between 1 and 12.
+    InvalidExpiryMonth,
+    /// The year is negative.
+    InvalidExpiryYear,
+    /// The card expired before the current month.
+    Expired,
+    /// The CVC isn't exactly the brand's length, or isn't all digits.
+    InvalidCvc
+}
+
+impl CardValidationError {
+    /// The code Stripe gives when it rejects the card for the same reason.
+    /// Stripe reports expired card details as an invalid expiry year when
+    /// they're tokenized.
+    pub fn code(&self) -> StripeErrorCode {
+        match *self {
+            CardValidationError::InvalidNumber      => StripeErrorCode::InvalidNumber,
+            CardValidationError::IncorrectNumber    => StripeErrorCode::IncorrectNumber,
+            CardValidationError::InvalidExpiryMonth => StripeErrorCode::InvalidExpiryMonth,
+            CardValidationError::InvalidExpiryYear  => StripeErrorCode::InvalidExpiryYear,
+            CardValidationError::Expired            => StripeErrorCode::InvalidExpiryYear,
+            CardValidationError::InvalidCvc         => StripeErrorCode::InvalidCvC
+        }
+    }
+
+    /// The `NewCard` field at fault, named as Stripe names the param.
+    pub fn param(&self) -> &'static str {
+        match *self {
+            CardValidationError::InvalidNumber | CardValidationError::IncorrectNumber => "number",
+            CardValidationError::InvalidExpiryMonth => "exp_month",
+            CardValidationError::InvalidExpiryYear | CardValidationError::Expired => "exp_year",
+            CardValidationError::InvalidCvc => "cvc"
+        }
+    }
+
+    /// The message Stripe gives for the same error.
+    pub fn message(&self) -> &'static str {
+        match *self {
+            CardValidationError::InvalidNumber => "Your card number is not a valid credit card number.",
+            CardValidationError::IncorrectNumber => "Your card number is incorrect.",
+            CardValidationError::InvalidExpiryMonth => "Your card's expiration month is invalid.",
+            CardValidationError::InvalidExpiryYear |
+            CardValidationError::Expired => "Your card's expiration year is invalid.",
+            CardValidationError::InvalidCvc => "Your card's security code is invalid."
+        }
+    }
+}
+
+impl std::error::Error for CardValidationError {
+    fn description(&self) -> &str {
+        self.message()
+    }
+}
+
+impl std::fmt::Display for CardValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "CardValidationError(code={},param={})", self.code(), self.param())
+    }
+}
@@ -0,0 +1,19 @@
+use std;
+
+/// Raised when parsing a string that isn't a country code this crate knows.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CountryError {
+    pub code: String
+}
+
+impl std::error::Error for CountryError {
+    fn description(&self) -> &str {
+        "The string is not a known country code"
+    }
+}
+
+impl std::fmt::Display for CountryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "CountryError(code={:?})", self.code)
+    }
+}
@@ -0,0 +1,19 @@
+use std;
+
+/// Raised when parsing a string that isn't a currency code this crate knows.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CurrencyError {
+    pub code: String
+}
+
+impl std::error::Error for CurrencyError {
+    fn description(&self) -> &str {
+        "The string is not a known currency code"
+    }
+}
+
+impl std::fmt::Display for CurrencyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "CurrencyError(code={:?})", self.code)
+    }
+}
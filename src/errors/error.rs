@@ -1,6 +1,9 @@
 use hyper;
+use prepared_request::PreparedRequest;
 use serde_json;
 use std;
+use super::mode_error;
+use super::rate_limit_error;
 use super::stripe_error;
 
 #[derive(Debug)]
@@ -8,7 +11,11 @@ pub enum Error {
     HttpError(hyper::error::Error),
     SerializationError(serde_json::error::Error),
     IoError(std::io::Error),
-    StripeError(stripe_error::StripeError)
+    StripeError(stripe_error::StripeError),
+    ModeError(mode_error::ModeError),
+    RateLimitError(rate_limit_error::RateLimitError),
+    /// The request was not sent because the client is in dry-run mode.
+    DryRun(PreparedRequest)
 }
 
 impl From<hyper::error::Error> for Error {
@@ -35,6 +42,18 @@ impl From<stripe_error::StripeError> for Error {
     }
 }
 
+impl From<mode_error::ModeError> for Error {
+    fn from(err: mode_error::ModeError) -> Self {
+        Error::ModeError(err)
+    }
+}
+
+impl From<rate_limit_error::RateLimitError> for Error {
+    fn from(err: rate_limit_error::RateLimitError) -> Self {
+        Error::RateLimitError(err)
+    }
+}
+
 use errors::error::Error::*;
 impl std::error::Error for Error {
     fn description(&self) -> &str {
@@ -42,7 +61,10 @@ impl std::error::Error for Error {
             HttpError(ref err)          => err.description(),
             SerializationError(ref err) => err.description(),
             IoError(ref err)            => err.description(),
-            StripeError(ref err)        => err.description()
+            StripeError(ref err)        => err.description(),
+            ModeError(ref err)          => err.description(),
+            RateLimitError(ref err)     => err.description(),
+            DryRun(_)                   => "Request not sent in dry-run mode"
         }
     }
 
@@ -51,7 +73,10 @@ impl std::error::Error for Error {
             HttpError(ref err)          => Some(err),
             SerializationError(ref err) => Some(err),
             IoError(ref err)            => Some(err),
-            StripeError(ref err)        => Some(err)
+            StripeError(ref err)        => Some(err),
+            ModeError(ref err)          => Some(err),
+            RateLimitError(ref err)     => Some(err),
+            DryRun(_)                   => None
         }
     }
 }
@@ -62,7 +87,10 @@ impl std::fmt::Display for Error {
             HttpError(ref err)          => write!(f, "Error::HyperError({})", err),
             SerializationError(ref err) => write!(f, "Error::SerdeError({})", err),
             IoError(ref err)            => write!(f, "Error::IoError({})", err),
-            StripeError(ref err)        => write!(f, "Error::StripeError({})", err)
+            StripeError(ref err)        => write!(f, "Error::StripeError({})", err),
+            ModeError(ref err)          => write!(f, "Error::ModeError({})", err),
+            RateLimitError(ref err)     => write!(f, "Error::RateLimitError({})", err),
+            DryRun(ref request)         => write!(f, "Error::DryRun({})", request)
         }
     }
 }
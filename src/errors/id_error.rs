@@ -0,0 +1,31 @@
+use std;
+
+/// Raised when parsing a string that isn't an ID of the expected type, e.g.
+/// a customer ID given as a `ChargeId`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdError {
+    /// The ID type that was being parsed, e.g. `ChargeId`.
+    pub id_type: &'static str,
+    /// The prefixes an ID of that type starts with. Empty when any
+    /// non-empty ID is accepted.
+    pub prefixes: &'static [&'static str],
+    pub id: String
+}
+
+impl std::error::Error for IdError {
+    fn description(&self) -> &str {
+        "The string is not an ID of the expected type"
+    }
+}
+
+impl std::fmt::Display for IdError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "IdError(id_type={},prefixes={:?},id={:?})",
+            self.id_type,
+            self.prefixes,
+            self.id
+        )
+    }
+}
@@ -1,2 +1,12 @@
+pub mod account_validation_error;
+pub mod address_validation_error;
+pub mod amount_error;
+pub mod bank_account_validation_error;
+pub mod card_validation_error;
+pub mod country_error;
+pub mod currency_error;
 pub mod error;
+pub mod id_error;
+pub mod mode_error;
+pub mod rate_limit_error;
 pub mod stripe_error;
@@ -0,0 +1,47 @@
+use secret_key::KeyMode;
+use std;
+
+/// Raised when a request would cross between test and live data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ModeError {
+    /// The client requires one mode but its key is for another.
+    KeyModeNotAllowed {
+        required: KeyMode,
+        actual: KeyMode
+    },
+    /// Stripe returned an object whose `livemode` doesn't match the key.
+    LivemodeMismatch {
+        key_mode: KeyMode,
+        object: String,
+        id: Option<String>
+    }
+}
+
+impl std::error::Error for ModeError {
+    fn description(&self) -> &str {
+        match *self {
+            ModeError::KeyModeNotAllowed { .. } => "The API key's mode is not allowed by this client",
+            ModeError::LivemodeMismatch { .. } => "Stripe returned an object from the wrong mode"
+        }
+    }
+}
+
+impl std::fmt::Display for ModeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            ModeError::KeyModeNotAllowed { ref required, ref actual } => write!(
+                f,
+                "ModeError::KeyModeNotAllowed(required={:?},actual={:?})",
+                required,
+                actual
+            ),
+            ModeError::LivemodeMismatch { ref key_mode, ref object, ref id } => write!(
+                f,
+                "ModeError::LivemodeMismatch(key_mode={:?},object={},id={:?})",
+                key_mode,
+                object,
+                id
+            )
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use std;
+
+/// Raised when a `RateLimiter` is configured so that it would never let a
+/// request through, or let them all through at once.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RateLimitError {
+    /// It has to allow at least one request per window.
+    NoRequests,
+    /// The window has to be longer than zero.
+    ZeroWindow
+}
+
+impl std::error::Error for RateLimitError {
+    fn description(&self) -> &str {
+        match *self {
+            RateLimitError::NoRequests => "The rate limit allows no requests",
+            RateLimitError::ZeroWindow => "The rate limit's window is zero"
+        }
+    }
+}
+
+impl std::fmt::Display for RateLimitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "RateLimitError::{:?}", self)
+    }
+}
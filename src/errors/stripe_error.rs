@@ -1,3 +1,4 @@
+use schema_drift;
 use std;
 use serde;
 
@@ -38,11 +39,25 @@ impl serde::Deserialize for StripeErrorKind {
             "card_error"            => StripeErrorKind::CardError,
             "invalid_request_error" => StripeErrorKind::InvalidRequestError,
             "rate_limit_error"      => StripeErrorKind::RateLimitError,
-            unknown_kind            => StripeErrorKind::Unknown(String::from(unknown_kind))
+            unknown_kind            => StripeErrorKind::Unknown(schema_drift::unknown_value("StripeErrorKind", unknown_kind))
         })
     }
 }
 
+impl std::fmt::Display for StripeErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            StripeErrorKind::ApiConnectionError   => write!(f, "api_connection_error"),
+            StripeErrorKind::ApiError             => write!(f, "api_error"),
+            StripeErrorKind::AuthenticationError  => write!(f, "authentication_error"),
+            StripeErrorKind::CardError            => write!(f, "card_error"),
+            StripeErrorKind::InvalidRequestError  => write!(f, "invalid_request_error"),
+            StripeErrorKind::RateLimitError       => write!(f, "rate_limit_error"),
+            StripeErrorKind::Unknown(ref unknown) => write!(f, "{}", unknown),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum StripeErrorCode {
     InvalidNumber,
@@ -66,7 +81,7 @@ impl serde::Deserialize for StripeErrorCode {
         Ok(match String::deserialize(deserializer)?.as_ref() {
             "invalid_number"       => StripeErrorCode::InvalidNumber,
             "invalid_expiry_month" => StripeErrorCode::InvalidExpiryMonth,
-            "invalid_expirty_year" => StripeErrorCode::InvalidExpiryYear,
+            "invalid_expiry_year"  => StripeErrorCode::InvalidExpiryYear,
             "invalid_cvc"          => StripeErrorCode::InvalidCvC,
             "incorrect_number"     => StripeErrorCode::IncorrectNumber,
             "expired_card"         => StripeErrorCode::ExpiredCard,
@@ -75,11 +90,39 @@ impl serde::Deserialize for StripeErrorCode {
             "card_declined"        => StripeErrorCode::CardDeclined,
             "missing"              => StripeErrorCode::Missing,
             "processing_error"     => StripeErrorCode::ProcessingError,
-            unknown_code           => StripeErrorCode::Unknown(String::from(unknown_code))
+            unknown_code           => StripeErrorCode::Unknown(schema_drift::unknown_value("StripeErrorCode", unknown_code))
         })
     }
 }
 
+impl std::fmt::Display for StripeErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            StripeErrorCode::InvalidNumber        => write!(f, "invalid_number"),
+            StripeErrorCode::InvalidExpiryMonth   => write!(f, "invalid_expiry_month"),
+            StripeErrorCode::InvalidExpiryYear    => write!(f, "invalid_expiry_year"),
+            StripeErrorCode::InvalidCvC           => write!(f, "invalid_cvc"),
+            StripeErrorCode::IncorrectNumber      => write!(f, "incorrect_number"),
+            StripeErrorCode::ExpiredCard          => write!(f, "expired_card"),
+            StripeErrorCode::IncorrectCvc         => write!(f, "incorrect_cvc"),
+            StripeErrorCode::IncorrectZip         => write!(f, "incorrect_zip"),
+            StripeErrorCode::CardDeclined         => write!(f, "card_declined"),
+            StripeErrorCode::Missing              => write!(f, "missing"),
+            StripeErrorCode::ProcessingError      => write!(f, "processing_error"),
+            StripeErrorCode::Unknown(ref unknown) => write!(f, "{}", unknown),
+        }
+    }
+}
+
+/// Charges carry a `failure_code`, so this serializes for `Charge`'s sake.
+impl serde::Serialize for StripeErrorCode {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
 impl std::error::Error for StripeError {
     fn description(&self) -> &str {
         match self.kind {
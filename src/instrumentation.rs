@@ -0,0 +1,174 @@
+use errors::error::Error;
+use hyper::header::Headers;
+use hyper::method::Method;
+#[cfg(feature = "instrumentation")]
+use metrics::Label;
+use middleware::{Middleware, Request, Response};
+use std::time::Duration;
+#[cfg(feature = "instrumentation")]
+use tracing::{field, Level, Span};
+
+/// What happened on a single request to Stripe: the fields for a tracing
+/// span, and the labels and latency for metrics per call type.
+#[derive(Clone, Debug)]
+pub struct RequestRecord {
+    /// The `ApiCall` that issued the request, e.g. `CreateChargeCall`.
+    pub call_type: &'static str,
+    /// The resource the path belongs to, e.g. `charges` for
+    /// `/charges/ch_123/capture`.
+    pub resource: String,
+    pub method: Method,
+    /// The HTTP status, or `None` when no response was received.
+    pub status: Option<u16>,
+    /// Stripe's `Request-Id` header, when it sent one.
+    pub request_id: Option<String>,
+    /// How many times the request was retried before this response.
+    pub retries: u32,
+    pub latency: Duration,
+    /// Labels set on the client with `StripeClient::with_label`.
+    pub labels: Vec<(String, String)>,
+    /// The kind of error, e.g. `card_error` or `http_error`, when the
+    /// request failed. Stripe errors are labelled with Stripe's `type`.
+    pub error_kind: Option<String>,
+    /// Stripe's error `code`, e.g. `card_declined`, when it gave one.
+    pub error_code: Option<String>
+}
+
+impl RequestRecord {
+    pub fn new(request: &Request, response: &Response) -> RequestRecord {
+        let (error_kind, error_code) = match response.result {
+            Ok(_)    => (None, None),
+            Err(err) => error_labels(err)
+        };
+        RequestRecord {
+            call_type: request.call_type,
+            resource: resource_type(&request.path).to_string(),
+            method: request.method.clone(),
+            status: response.status.map(|status| status.to_u16()),
+            request_id: response.headers.and_then(request_id),
+            retries: request.retries,
+            latency: response.latency,
+            labels: request.labels.clone(),
+            error_kind: error_kind,
+            error_code: error_code
+        }
+    }
+
+    /// The latency in seconds, as latency histograms usually take it.
+    pub fn latency_seconds(&self) -> f64 {
+        self.latency.as_secs() as f64 + self.latency.subsec_nanos() as f64 / 1_000_000_000.0
+    }
+}
+
+/// Receives a `RequestRecord` for every request, e.g. to open a span or to
+/// update a latency histogram and error counters.
+pub trait Recorder: Send + Sync {
+    fn record(&self, record: &RequestRecord);
+}
+
+impl<F> Recorder for F where F: Fn(&RequestRecord) + Send + Sync {
+    fn record(&self, record: &RequestRecord) {
+        (*self)(record)
+    }
+}
+
+/// Middleware that hands a `RequestRecord` to its `Recorder` once each
+/// response is back, so every call is instrumented without wrapping the
+/// call sites. With the `instrumentation` feature, every request also gets
+/// a tracing span, a latency histogram and an error counter with the same
+/// labels, without any middleware.
+#[derive(Debug)]
+pub struct Instrumentation<R> {
+    recorder: R
+}
+
+impl<R: Recorder> Instrumentation<R> {
+    pub fn new(recorder: R) -> Instrumentation<R> {
+        Instrumentation {
+            recorder: recorder
+        }
+    }
+}
+
+impl<R: Recorder> Middleware for Instrumentation<R> {
+    fn after_receive(&self, request: &Request, response: &Response) {
+        self.recorder.record(&RequestRecord::new(request, response));
+    }
+}
+
+/// Opens the span covering a single Stripe request.
+#[cfg(feature = "instrumentation")]
+pub fn request_span(request: &Request) -> Span {
+    span!(
+        Level::INFO,
+        "stripe_request",
+        call_type = request.call_type,
+        resource = resource_type(&request.path),
+        method = %request.method,
+        retries = request.retries,
+        labels = ?request.labels,
+        status = field::Empty,
+        request_id = field::Empty,
+        error_kind = field::Empty,
+        error_code = field::Empty
+    )
+}
+
+/// Fills in the span fields only known once the response is back, and
+/// records the latency histogram and error counter for the call type,
+/// labelled with any labels set on the client.
+#[cfg(feature = "instrumentation")]
+pub fn record(span: &Span, request: &Request, response: &Response) {
+    let record = RequestRecord::new(request, response);
+    let status = record.status.map(|status| status.to_string()).unwrap_or("none".to_string());
+    span.record("status", &status.as_str());
+    if let Some(ref request_id) = record.request_id {
+        span.record("request_id", &request_id.as_str());
+    }
+
+    let mut labels = vec![
+        Label::new("call_type", record.call_type),
+        Label::new("method", record.method.to_string()),
+        Label::new("status", status)
+    ];
+    labels.extend(record.labels.iter().map(|&(ref k, ref v)| Label::new(k.clone(), v.clone())));
+    histogram!("stripe_request_duration_seconds", record.latency_seconds(), labels.clone());
+
+    if let Some(ref kind) = record.error_kind {
+        let code = record.error_code.clone().unwrap_or("none".to_string());
+        span.record("error_kind", &kind.as_str());
+        span.record("error_code", &code.as_str());
+
+        labels.push(Label::new("error_kind", kind.clone()));
+        labels.push(Label::new("error_code", code));
+        increment_counter!("stripe_request_errors_total", labels);
+    }
+}
+
+/// The resource a path belongs to, e.g. `charges` for `/charges/ch_123/capture`.
+fn resource_type(path: &str) -> &str {
+    path.trim_left_matches('/').split('/').next().unwrap_or("")
+}
+
+fn request_id(headers: &Headers) -> Option<String> {
+    headers.get_raw("Request-Id")
+        .and_then(|values| values.first())
+        .and_then(|value| String::from_utf8(value.clone()).ok())
+}
+
+fn error_labels(err: &Error) -> (Option<String>, Option<String>) {
+    let kind = match *err {
+        Error::HttpError(_)          => "http_error".to_string(),
+        Error::SerializationError(_) => "serialization_error".to_string(),
+        Error::IoError(_)            => "io_error".to_string(),
+        Error::ModeError(_)          => "mode_error".to_string(),
+        Error::RateLimitError(_)     => "rate_limit_error".to_string(),
+        Error::DryRun(_)             => "dry_run".to_string(),
+        Error::StripeError(ref err)  => err.kind.to_string()
+    };
+    let code = match *err {
+        Error::StripeError(ref err) => err.code.as_ref().map(|code| code.to_string()),
+        _                           => None
+    };
+    (Some(kind), code)
+}
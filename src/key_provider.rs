@@ -0,0 +1,176 @@
+use errors::error::Error;
+use secret_key::SecretKey;
+use std::env;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+use Result;
+
+/// Supplies the key for each request `StripeClient` sends.
+pub trait KeyProvider: Send + Sync {
+    /// The key to authenticate the next request with.
+    fn current_key(&self) -> Result<SecretKey>;
+
+    /// Called when Stripe rejected `rejected` with an `AuthenticationError`.
+    /// Returns the key to retry the request with, if there is one.
+    fn fallback_key(&self, _rejected: &SecretKey) -> Option<SecretKey> {
+        None
+    }
+}
+
+/// Always uses the same key.
+#[derive(Clone, Debug)]
+pub struct StaticKey(SecretKey);
+
+impl StaticKey {
+    pub fn new(key: SecretKey) -> StaticKey {
+        StaticKey(key)
+    }
+}
+
+impl KeyProvider for StaticKey {
+    fn current_key(&self) -> Result<SecretKey> {
+        Ok(self.0.clone())
+    }
+}
+
+/// Reads the key from an environment variable on every request, so changes
+/// made with `std::env::set_var` take effect immediately.
+#[derive(Clone, Debug)]
+pub struct EnvKey {
+    var: String
+}
+
+impl EnvKey {
+    pub fn new(var: &str) -> EnvKey {
+        EnvKey {
+            var: String::from(var)
+        }
+    }
+}
+
+impl KeyProvider for EnvKey {
+    fn current_key(&self) -> Result<SecretKey> {
+        env::var(&self.var)
+            .map(|key| SecretKey::from(key.trim().to_string()))
+            .map_err(|err| Error::IoError(io::Error::new(io::ErrorKind::NotFound, err)))
+    }
+}
+
+/// Reads the key from a file, reloading it whenever the file's modification
+/// time changes.
+#[derive(Debug)]
+pub struct FileKey {
+    path: PathBuf,
+    cached: Mutex<Option<(SystemTime, SecretKey)>>
+}
+
+impl FileKey {
+    pub fn new<P: AsRef<Path>>(path: P) -> FileKey {
+        FileKey {
+            path: path.as_ref().to_path_buf(),
+            cached: Mutex::new(None)
+        }
+    }
+}
+
+impl KeyProvider for FileKey {
+    fn current_key(&self) -> Result<SecretKey> {
+        let modified = self.path.metadata()?.modified()?;
+        let mut cached = self.cached.lock().unwrap();
+        if let Some((cached_modified, ref key)) = *cached {
+            if cached_modified == modified {
+                return Ok(key.clone());
+            }
+        }
+
+        let mut contents = String::new();
+        File::open(&self.path)?.read_to_string(&mut contents)?;
+        let key = SecretKey::from(contents.trim().to_string());
+        *cached = Some((modified, key.clone()));
+        Ok(key)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeySlot {
+    Primary,
+    Secondary
+}
+
+/// Uses the primary key until Stripe rejects it, then switches to the
+/// secondary key, and back again if the secondary is rejected in turn.
+pub struct RotatingKey {
+    primary: Box<KeyProvider>,
+    secondary: Box<KeyProvider>,
+    active: Mutex<KeySlot>
+}
+
+impl RotatingKey {
+    pub fn new<P, S>(primary: P, secondary: S) -> RotatingKey
+        where P: KeyProvider + 'static, S: KeyProvider + 'static
+    {
+        RotatingKey {
+            primary: Box::new(primary),
+            secondary: Box::new(secondary),
+            active: Mutex::new(KeySlot::Primary)
+        }
+    }
+
+    /// The slot the next request will be authenticated with. Another
+    /// request may switch slots at any time, so to know which key a
+    /// response came from, use `Response::key_fingerprint` in a middleware.
+    pub fn active(&self) -> KeySlot {
+        *self.active.lock().unwrap()
+    }
+
+    fn key_for(&self, slot: KeySlot) -> Result<SecretKey> {
+        match slot {
+            KeySlot::Primary   => self.primary.current_key(),
+            KeySlot::Secondary => self.secondary.current_key()
+        }
+    }
+}
+
+impl fmt::Debug for RotatingKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RotatingKey")
+            .field("active", &self.active())
+            .finish()
+    }
+}
+
+impl KeyProvider for RotatingKey {
+    fn current_key(&self) -> Result<SecretKey> {
+        self.key_for(self.active())
+    }
+
+    fn fallback_key(&self, rejected: &SecretKey) -> Option<SecretKey> {
+        let mut active = self.active.lock().unwrap();
+        let rejected_slot = *active;
+        match self.key_for(rejected_slot) {
+            Ok(ref key) if key == rejected => {},
+            // The active key changed since the request was sent, so retry
+            // with the current one rather than switching slots.
+            Ok(key) => return Some(key),
+            Err(_) => {}
+        }
+
+        let fallback_slot = match rejected_slot {
+            KeySlot::Primary   => KeySlot::Secondary,
+            KeySlot::Secondary => KeySlot::Primary
+        };
+        match self.key_for(fallback_slot) {
+            Ok(key) => {
+                warn!("stripe: {:?} key rejected, switching to {:?} key", rejected_slot, fallback_slot);
+                *active = fallback_slot;
+                Some(key)
+            },
+            Err(_) => None
+        }
+    }
+}
@@ -1,65 +1,221 @@
 #![feature(question_mark, rustc_macro)]
 #![feature(structural_match)]
 
+#[cfg(feature = "timestamps")]
+extern crate chrono;
 extern crate hyper;
+#[macro_use]
+extern crate log;
+#[cfg(feature = "instrumentation")]
+#[macro_use(histogram, increment_counter)]
+extern crate metrics;
 extern crate rand;
 extern crate serde;
 extern crate serde_json;
 #[macro_use]
 extern crate serde_derive;
+#[cfg(feature = "instrumentation")]
+#[macro_use(span)]
+extern crate tracing;
 
-use hyper::client::response::Response;
-use hyper::Client;
 use hyper::header::{Authorization, Basic, Headers};
+use hyper::method::Method;
 use hyper::status::StatusCode;
 use serde::de::Deserialize;
+use serde_json::Value;
 use std::collections::BTreeMap;
+use std::fmt;
 use std::fmt::Display;
-use std::io::Read;
+use std::sync::Arc;
+use std::time::Instant;
 
 pub mod api;
 pub mod either;
 pub mod errors;
+pub mod instrumentation;
+pub mod key_provider;
+pub mod middleware;
 pub mod model;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod transport;
 
 mod call_args;
+mod call_context;
+mod client_pool;
 mod idempotency_header;
+mod livemode;
+mod onboarding;
+mod prepared_request;
+mod publishable_client;
+mod redact;
+mod schema_drift;
+mod secret_key;
+mod stripe_account_header;
 mod stripe_version_header;
 mod url_encodable;
 mod time_constraint;
+mod unknown_fields;
 
+pub use client_pool::{StripeClientPool, TenantConfig};
 pub use either::Either;
+pub use onboarding::{LegalEntitySetter, OnboardingChecklist, Requirement, RequirementDue, RequirementKind};
+pub use prepared_request::PreparedRequest;
+pub use publishable_client::{PublishableClient, PublishableKey};
+pub use schema_drift::{SchemaDrift, TypeDrift};
+pub use secret_key::{KeyKind, KeyMode, SecretKey};
 pub use time_constraint::TimeConstraint;
+pub use unknown_fields::log_unknown_fields;
 
 use api::*;
 use errors::error::Error;
+use errors::mode_error::ModeError;
 use errors::stripe_error;
+use key_provider::{KeyProvider, StaticKey};
+use middleware::{Middleware, Request, Response};
 use model::*;
+use stripe_account_header::StripeAccount;
 use stripe_version_header::StripeVersion;
+use transport::{HyperTransport, Transport};
 use url_encodable::UrlEncodable;
 
 const BASE_URL: &'static str = "https://api.stripe.com/v1";
 const API_VERSION: &'static str = "2016-03-07";
+/// How many times a request is resent with a fallback key after an
+/// `AuthenticationError`.
+const MAX_KEY_FALLBACKS: u32 = 1;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 // TODO add file upload stuff
 
-#[derive(Debug)]
 pub struct StripeClient {
-    pub key: String,
-    client: Client
+    key_provider: Box<KeyProvider>,
+    transport: Arc<Transport>,
+    middleware: Vec<Box<Middleware>>,
+    stripe_account: Option<String>,
+    labels: Vec<(String, String)>,
+    required_mode: Option<KeyMode>,
+    dry_run: bool,
+    schema_drift: Option<SchemaDrift>,
+    auth: Auth
+}
+
+/// How the key is presented to Stripe.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Auth {
+    Basic,
+    KeyParam
+}
+
+impl fmt::Debug for StripeClient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StripeClient")
+            .field("middleware", &self.middleware.len())
+            .field("stripe_account", &self.stripe_account)
+            .field("labels", &self.labels)
+            .field("required_mode", &self.required_mode)
+            .field("dry_run", &self.dry_run)
+            .field("schema_drift", &self.schema_drift.is_some())
+            .field("auth", &self.auth)
+            .finish()
+    }
 }
 
 impl StripeClient {
 
     pub fn new(key: &str) -> StripeClient {
+        StripeClient::with_key_provider(StaticKey::new(SecretKey::new(key)))
+    }
+
+    /// Creates a client that asks `key_provider` for the key to use on every
+    /// request, e.g. to pick up a rotated key without a restart.
+    pub fn with_key_provider<P: KeyProvider + 'static>(key_provider: P) -> StripeClient {
+        StripeClient::with_shared_transport(Box::new(key_provider), Arc::new(HyperTransport::new()))
+    }
+
+    /// Lets several clients share one transport, and so one HTTP connection
+    /// pool.
+    fn with_shared_transport(key_provider: Box<KeyProvider>, transport: Arc<Transport>) -> StripeClient {
+        StripeClient {
+            key_provider: key_provider,
+            transport: transport,
+            middleware: Vec::new(),
+            stripe_account: None,
+            labels: Vec::new(),
+            required_mode: None,
+            dry_run: false,
+            schema_drift: None,
+            auth: Auth::Basic
+        }
+    }
+
+    /// Publishable keys are sent as a `key` parameter instead of through
+    /// basic auth. Only used by `PublishableClient`.
+    fn with_publishable_key(key: &PublishableKey) -> StripeClient {
         StripeClient {
-            key: String::from(key),
-            client: Client::new()
+            auth: Auth::KeyParam,
+            ..StripeClient::new(key.as_str())
         }
     }
 
+    /// The key the next request will be sent with.
+    pub fn key(&self) -> Result<SecretKey> {
+        self.key_provider.current_key()
+    }
+
+    /// Refuses to send any request unless the key is a test mode key.
+    pub fn require_test_mode(mut self) -> StripeClient {
+        self.required_mode = Some(KeyMode::Test);
+        self
+    }
+
+    /// Refuses to send any request unless the key is a live mode key.
+    pub fn require_live_mode(mut self) -> StripeClient {
+        self.required_mode = Some(KeyMode::Live);
+        self
+    }
+
+    /// Sends requests through `transport` instead of over HTTPS.
+    pub fn with_transport<T: Transport + 'static>(mut self, transport: T) -> StripeClient {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// Logs every request instead of sending it, failing each call with
+    /// `Error::DryRun`.
+    pub fn dry_run(mut self) -> StripeClient {
+        self.dry_run = true;
+        self
+    }
+
+    /// Records into `drift` how each response differs from the model it's
+    /// parsed into: ignored fields, missing fields and unknown enum values.
+    pub fn detect_schema_drift(mut self, drift: &SchemaDrift) -> StripeClient {
+        self.schema_drift = Some(drift.clone());
+        self
+    }
+
+    /// Makes every request on behalf of a connected account.
+    /// https://stripe.com/docs/connect/authentication#stripe-account-header
+    pub fn with_stripe_account(mut self, account_id: &str) -> StripeClient {
+        self.stripe_account = Some(String::from(account_id));
+        self
+    }
+
+    /// Attaches a label to every request, for middleware and metrics to tell
+    /// clients apart.
+    pub fn with_label(mut self, key: &str, value: &str) -> StripeClient {
+        self.labels.push((String::from(key), String::from(value)));
+        self
+    }
+
+    /// Adds a middleware to the end of the chain run around every request.
+    pub fn with_middleware<M: Middleware + 'static>(mut self, middleware: M) -> StripeClient {
+        self.middleware.push(Box::new(middleware));
+        self
+    }
+
     /// https://stripe.com/docs/api#retrieve_balance
     pub fn retrieve_balance<'a>(&'a self) -> RetrieveBalanceCall<'a> {
         RetrieveBalanceCall::new(self)
@@ -68,7 +224,7 @@ impl StripeClient {
     /// https://stripe.com/docs/api#retrieve_balance_transaction
     pub fn retrieve_balance_transaction<'a>(
         &'a self,
-        transaction_id: String
+        transaction_id: BalanceTransactionId
     ) -> RetrieveBalanceTransactionCall<'a> {
         RetrieveBalanceTransactionCall::new(self, transaction_id)
     }
@@ -87,18 +243,23 @@ impl StripeClient {
         CreateChargeCall::new(self, amount, currency)
     }
 
+    /// Like `create_charge`, with the amount and currency taken from `amount`.
+    pub fn create_charge_for<'a>(&'a self, amount: Amount) -> CreateChargeCall<'a> {
+        CreateChargeCall::new(self, amount.amount, amount.currency)
+    }
+
     /// https://stripe.com/docs/api#retrieve_charge
-    pub fn retrieve_charge<'a>(&'a self, charge_id: String) -> RetrieveChargeCall<'a> {
+    pub fn retrieve_charge<'a>(&'a self, charge_id: ChargeId) -> RetrieveChargeCall<'a> {
         RetrieveChargeCall::new(self, charge_id)
     }
 
     /// https://stripe.com/docs/api#update_charge
-    pub fn update_charge<'a>(&'a self, charge_id: String) -> UpdateChargeCall<'a> {
+    pub fn update_charge<'a>(&'a self, charge_id: ChargeId) -> UpdateChargeCall<'a> {
         UpdateChargeCall::new(self, charge_id)
     }
 
     /// https://stripe.com/docs/api#capture_charge
-    pub fn capture_charge<'a>(&'a self, charge_id: String) -> CaptureChargeCall<'a> {
+    pub fn capture_charge<'a>(&'a self, charge_id: ChargeId) -> CaptureChargeCall<'a> {
         CaptureChargeCall::new(self, charge_id)
     }
 
@@ -113,17 +274,17 @@ impl StripeClient {
     }
 
     /// https://stripe.com/docs/api#retrieve_customer
-    pub fn retrieve_customer<'a>(&'a self, customer_id: String) -> RetrieveCustomerCall<'a> {
+    pub fn retrieve_customer<'a>(&'a self, customer_id: CustomerId) -> RetrieveCustomerCall<'a> {
         RetrieveCustomerCall::new(self, customer_id)
     }
 
     /// https://stripe.com/docs/api#update_customer
-    pub fn update_customer<'a>(&'a self, customer_id: String) -> UpdateCustomerCall<'a> {
+    pub fn update_customer<'a>(&'a self, customer_id: CustomerId) -> UpdateCustomerCall<'a> {
         UpdateCustomerCall::new(self, customer_id)
     }
 
     /// https://stripe.com/docs/api#delete_customer
-    pub fn delete_customer<'a>(&'a self, customer_id: String) -> DeleteCustomerCall<'a> {
+    pub fn delete_customer<'a>(&'a self, customer_id: CustomerId) -> DeleteCustomerCall<'a> {
         DeleteCustomerCall::new(self, customer_id)
     }
 
@@ -133,17 +294,17 @@ impl StripeClient {
     }
 
     /// https://stripe.com/docs/api#retrieve_dispute
-    pub fn retrieve_dispute<'a>(&'a self, dispute_id: String) -> RetrieveDisputeCall<'a> {
+    pub fn retrieve_dispute<'a>(&'a self, dispute_id: DisputeId) -> RetrieveDisputeCall<'a> {
         RetrieveDisputeCall::new(self, dispute_id)
     }
 
     /// https://stripe.com/docs/api#update_dispute
-    pub fn update_dispute<'a>(&'a self, dispute_id: String) -> UpdateDisputeCall<'a> {
+    pub fn update_dispute<'a>(&'a self, dispute_id: DisputeId) -> UpdateDisputeCall<'a> {
         UpdateDisputeCall::new(self, dispute_id)
     }
 
     /// https://stripe.com/docs/api#close_dispute
-    pub fn close_dispute<'a>(&'a self, dispute_id: String) -> CloseDisputeCall<'a> {
+    pub fn close_dispute<'a>(&'a self, dispute_id: DisputeId) -> CloseDisputeCall<'a> {
         CloseDisputeCall::new(self, dispute_id)
     }
 
@@ -153,7 +314,7 @@ impl StripeClient {
     }
 
     /// https://stripe.com/docs/api#retrieve_event
-    pub fn retrieve_event<'a>(&'a self, event_id: String) -> RetrieveEventCall<'a> {
+    pub fn retrieve_event<'a>(&'a self, event_id: EventId) -> RetrieveEventCall<'a> {
         RetrieveEventCall::new(self, event_id)
     }
 
@@ -163,17 +324,17 @@ impl StripeClient {
     }
 
     /// https://stripe.com/docs/api#create_refund
-    pub fn create_refund<'a>(&'a self, charge_id: String) -> CreateRefundCall<'a> {
+    pub fn create_refund<'a>(&'a self, charge_id: ChargeId) -> CreateRefundCall<'a> {
         CreateRefundCall::new(self, charge_id)
     }
 
     /// https://stripe.com/docs/api#retrieve_refund
-    pub fn retrieve_refund<'a>(&'a self, refund_id: String) -> RetrieveRefundCall<'a> {
+    pub fn retrieve_refund<'a>(&'a self, refund_id: RefundId) -> RetrieveRefundCall<'a> {
         RetrieveRefundCall::new(self, refund_id)
     }
 
     /// https://stripe.com/docs/api#update_refund
-    pub fn update_refund<'a>(&'a self, refund_id: String) -> UpdateRefundCall<'a> {
+    pub fn update_refund<'a>(&'a self, refund_id: RefundId) -> UpdateRefundCall<'a> {
         UpdateRefundCall::new(self, refund_id)
     }
 
@@ -198,7 +359,7 @@ impl StripeClient {
     }
 
     /// https://stripe.com/docs/api#retrieve_token
-    pub fn retrieve_token<'a>(&'a self, token_id: String) -> RetrieveTokenCall<'a> {
+    pub fn retrieve_token<'a>(&'a self, token_id: TokenId) -> RetrieveTokenCall<'a> {
         RetrieveTokenCall::new(self, token_id)
     }
 
@@ -207,18 +368,23 @@ impl StripeClient {
         &'a self,
         amount: i64,
         currency: Currency,
-        destination: String
+        destination: AccountId
     ) -> CreateTransferCall<'a> {
         CreateTransferCall::new(self, amount, currency, destination)
     }
 
+    /// Like `create_transfer`, with the amount and currency taken from `amount`.
+    pub fn create_transfer_for<'a>(&'a self, amount: Amount, destination: AccountId) -> CreateTransferCall<'a> {
+        CreateTransferCall::new(self, amount.amount, amount.currency, destination)
+    }
+
     /// https://stripe.com/docs/api#retrieve_transfer
-    pub fn retrieve_transfer<'a>(&'a self, transfer_id: String) -> RetrieveTransferCall<'a> {
+    pub fn retrieve_transfer<'a>(&'a self, transfer_id: TransferId) -> RetrieveTransferCall<'a> {
         RetrieveTransferCall::new(self, transfer_id)
     }
 
     /// https://stripe.com/docs/api#update_transfer
-    pub fn update_transfer<'a>(&'a self, transfer_id: String) -> UpdateTransferCall<'a> {
+    pub fn update_transfer<'a>(&'a self, transfer_id: TransferId) -> UpdateTransferCall<'a> {
         UpdateTransferCall::new(self, transfer_id)
     }
 
@@ -228,15 +394,15 @@ impl StripeClient {
     }
 
     /// https://stripe.com/docs/api#create_transfer_reversal
-    pub fn create_transfer_reversal<'a>(&'a self, transfer_id: String) -> CreateTransferReversalCall<'a> {
+    pub fn create_transfer_reversal<'a>(&'a self, transfer_id: TransferId) -> CreateTransferReversalCall<'a> {
         CreateTransferReversalCall::new(self, transfer_id)
     }
 
     /// https://stripe.com/docs/api#retrieve_transfer_reversal
     pub fn retrieve_transfer_reversal<'a>(
         &'a self,
-        transfer_id: String,
-        reversal_id: String
+        transfer_id: TransferId,
+        reversal_id: TransferReversalId
     ) -> RetrieveTransferReversalCall<'a> {
         RetrieveTransferReversalCall::new(self, transfer_id, reversal_id)
     }
@@ -244,8 +410,8 @@ impl StripeClient {
     /// https://stripe.com/docs/api#update_transfer_reversal
     pub fn update_transfer_reversal<'a>(
         &'a self,
-        transfer_id: String,
-        reversal_id: String
+        transfer_id: TransferId,
+        reversal_id: TransferReversalId
     ) -> UpdateTransferReversalCall<'a> {
         UpdateTransferReversalCall::new(self, transfer_id, reversal_id)
     }
@@ -253,19 +419,19 @@ impl StripeClient {
     /// https://stripe.com/docs/api#list_transfer_reversals
     pub fn list_transfer_reversals<'a>(
         &'a self,
-        transfer_id: String
+        transfer_id: TransferId
     ) -> ListTransferReversalsCall<'a> {
         ListTransferReversalsCall::new(self, transfer_id)
     }
 
     /// https://stripe.com/docs/api#retrieve_account
-    /// Fetch account associated with self.key
+    /// Fetch account associated with the client's key
     pub fn retrieve_current_account<'a>(&'a self) -> RetrieveAccountCall<'a> {
         RetrieveAccountCall::new(self, None)
     }
 
     /// https://stripe.com/docs/api#retrieve_account
-    pub fn retrieve_account<'a>(&'a self, account_id: String) -> RetrieveAccountCall<'a> {
+    pub fn retrieve_account<'a>(&'a self, account_id: AccountId) -> RetrieveAccountCall<'a> {
         RetrieveAccountCall::new(self, Some(account_id))
     }
 
@@ -275,19 +441,19 @@ impl StripeClient {
     }
 
     /// https://stripe.com/docs/api#update_account
-    pub fn update_account<'a>(&'a self, account_id: String) -> UpdateAccountCall<'a> {
+    pub fn update_account<'a>(&'a self, account_id: AccountId) -> UpdateAccountCall<'a> {
         UpdateAccountCall::new(self, account_id)
     }
 
     /// https://stripe.com/docs/api#delete_account
-    pub fn delete_account<'a>(&'a self, account_id: String) -> DeleteAccountCall<'a> {
+    pub fn delete_account<'a>(&'a self, account_id: AccountId) -> DeleteAccountCall<'a> {
         DeleteAccountCall::new(self, account_id)
     }
 
     /// https://stripe.com/docs/api#reject_account
     pub fn reject_account<'a>(
         &'a self,
-        account_id: String,
+        account_id: AccountId,
         reason: AccountRejectReason
     ) -> RejectAccountCall<'a> {
         RejectAccountCall::new(self, account_id, reason)
@@ -299,15 +465,15 @@ impl StripeClient {
     }
 
     /// https://stripe.com/docs/api#create_fee_refund
-    pub fn create_fee_refund<'a>(&'a self, application_fee_id: String) -> CreateFeeRefundCall<'a> {
+    pub fn create_fee_refund<'a>(&'a self, application_fee_id: ApplicationFeeId) -> CreateFeeRefundCall<'a> {
         CreateFeeRefundCall::new(self, application_fee_id)
     }
 
     /// https://stripe.com/docs/api#retrieve_fee_refund
     pub fn retrieve_fee_refund<'a>(
         &'a self,
-        application_fee_id: String,
-        refund_id: String
+        application_fee_id: ApplicationFeeId,
+        refund_id: FeeRefundId
     ) -> RetrieveFeeRefundCall<'a> {
         RetrieveFeeRefundCall::new(self, application_fee_id, refund_id)
     }
@@ -315,19 +481,19 @@ impl StripeClient {
     /// https://stripe.com/docs/api#update_fee_refund
     pub fn update_fee_refund<'a>(
         &'a self,
-        application_fee_id: String,
-        refund_id: String
+        application_fee_id: ApplicationFeeId,
+        refund_id: FeeRefundId
     ) -> UpdateFeeRefundCall<'a> {
         UpdateFeeRefundCall::new(self, application_fee_id, refund_id)
     }
 
     /// https://stripe.com/docs/api#list_fee_refunds
-    pub fn list_fee_refunds<'a>(&'a self, application_fee_id: String) -> ListFeeRefundsCall<'a> {
+    pub fn list_fee_refunds<'a>(&'a self, application_fee_id: ApplicationFeeId) -> ListFeeRefundsCall<'a> {
         ListFeeRefundsCall::new(self, application_fee_id)
     }
 
     /// https://stripe.com/docs/api#retrieve_application_fee
-    pub fn retrieve_application_fee<'a>(&'a self, fee_id: String) -> RetrieveApplicationFeeCall<'a> {
+    pub fn retrieve_application_fee<'a>(&'a self, fee_id: ApplicationFeeId) -> RetrieveApplicationFeeCall<'a> {
         RetrieveApplicationFeeCall::new(self, fee_id)
     }
 
@@ -349,7 +515,7 @@ impl StripeClient {
     /// https://stripe.com/docs/api#account_create_bank_account
     pub fn account_create_bank_account<'a>(
         &'a self,
-        account_id: String
+        account_id: AccountId
     ) -> AccountCreateBankAccountCall<'a> {
         AccountCreateBankAccountCall::new(self, account_id)
     }
@@ -357,8 +523,8 @@ impl StripeClient {
     /// https://stripe.com/docs/api#account_retrieve_bank_account
     pub fn account_retrieve_bank_account<'a>(
         &'a self,
-        account_id: String,
-        bank_account_id: String
+        account_id: AccountId,
+        bank_account_id: BankAccountId
     ) -> AccountRetrieveBankAccountCall<'a> {
         AccountRetrieveBankAccountCall::new(self, account_id, bank_account_id)
     }
@@ -366,8 +532,8 @@ impl StripeClient {
     /// https://stripe.com/docs/api#account_update_bank_account
     pub fn account_update_bank_account<'a>(
         &'a self,
-        account_id: String,
-        bank_account_id: String
+        account_id: AccountId,
+        bank_account_id: BankAccountId
     ) -> AccountUpdateBankAccountCall<'a> {
         AccountUpdateBankAccountCall::new(self, account_id, bank_account_id)
     }
@@ -375,8 +541,8 @@ impl StripeClient {
     /// https://stripe.com/docs/api#account_delete_bank_account
     pub fn account_delete_bank_account<'a>(
         &'a self,
-        account_id: String,
-        bank_account_id: String
+        account_id: AccountId,
+        bank_account_id: BankAccountId
     ) -> AccountDeleteBankAccountCall<'a> {
         AccountDeleteBankAccountCall::new(self, account_id, bank_account_id)
     }
@@ -384,21 +550,21 @@ impl StripeClient {
     /// https://stripe.com/docs/api#account_list_bank_accounts
     pub fn account_list_bank_accounts<'a>(
         &'a self,
-        account_id: String
+        account_id: AccountId
     ) -> AccountListBankAccountsCall<'a> {
         AccountListBankAccountsCall::new(self, account_id)
     }
 
     /// https://stripe.com/docs/api#account_create_card
-    pub fn account_create_card<'a>(&'a self, account_id: String) -> AccountCreateCardCall<'a> {
+    pub fn account_create_card<'a>(&'a self, account_id: AccountId) -> AccountCreateCardCall<'a> {
         AccountCreateCardCall::new(self, account_id)
     }
 
     /// https://stripe.com/docs/api#account_retrieve_card
     pub fn account_retrieve_card<'a>(
         &'a self,
-        account_id: String,
-        card_id: String
+        account_id: AccountId,
+        card_id: CardId
     ) -> AccountRetrieveCardCall<'a> {
         AccountRetrieveCardCall::new(self, account_id, card_id)
     }
@@ -406,8 +572,8 @@ impl StripeClient {
     /// https://stripe.com/docs/api#account_update_card
     pub fn account_update_card<'a>(
         &'a self,
-        account_id: String,
-        card_id: String
+        account_id: AccountId,
+        card_id: CardId
     ) -> AccountUpdateCardCall<'a> {
         AccountUpdateCardCall::new(self, account_id, card_id)
     }
@@ -415,21 +581,21 @@ impl StripeClient {
     /// https://stripe.com/docs/api#account_delete_card
     pub fn account_delete_card<'a>(
         &'a self,
-        account_id: String,
-        card_id: String
+        account_id: AccountId,
+        card_id: CardId
     ) -> AccountDeleteCardCall<'a> {
         AccountDeleteCardCall::new(self, account_id, card_id)
     }
 
     /// https://stripe.com/docs/api#account_list_cards
-    pub fn account_list_cards<'a>(&'a self, account_id: String) -> AccountListCardsCall<'a> {
+    pub fn account_list_cards<'a>(&'a self, account_id: AccountId) -> AccountListCardsCall<'a> {
         AccountListCardsCall::new(self, account_id)
     }
 
     /// https://stripe.com/docs/api#customer_create_bank_account
     pub fn customer_create_bank_account<'a>(
         &'a self,
-        customer_id: String
+        customer_id: CustomerId
     ) -> CustomerCreateBankAccountCall<'a> {
         CustomerCreateBankAccountCall::new(self, customer_id)
     }
@@ -437,8 +603,8 @@ impl StripeClient {
     /// https://stripe.com/docs/api#customer_retrieve_bank_account
     pub fn customer_retrieve_bank_account<'a>(
         &'a self,
-        customer_id: String,
-        bank_account_id: String
+        customer_id: CustomerId,
+        bank_account_id: BankAccountId
     ) -> CustomerRetrieveBankAccountCall<'a> {
         CustomerRetrieveBankAccountCall::new(self, customer_id, bank_account_id)
     }
@@ -446,8 +612,8 @@ impl StripeClient {
     /// https://stripe.com/docs/api#customer_update_bank_account
     pub fn customer_update_bank_account<'a>(
         &'a self,
-        customer_id: String,
-        bank_account_id: String
+        customer_id: CustomerId,
+        bank_account_id: BankAccountId
     ) -> CustomerUpdateBankAccountCall<'a> {
         CustomerUpdateBankAccountCall::new(self, customer_id, bank_account_id)
     }
@@ -455,8 +621,8 @@ impl StripeClient {
     /// https://stripe.com/docs/api#customer_delete_bank_account
     pub fn customer_delete_bank_account<'a>(
         &'a self,
-        customer_id: String,
-        bank_account_id: String
+        customer_id: CustomerId,
+        bank_account_id: BankAccountId
     ) -> CustomerDeleteBankAccountCall<'a> {
         CustomerDeleteBankAccountCall::new(self, customer_id, bank_account_id)
     }
@@ -464,7 +630,7 @@ impl StripeClient {
     /// https://stripe.com/docs/api#customer_list_bank_accounts
     pub fn customer_list_bank_accounts<'a>(
         &'a self,
-        customer_id: String
+        customer_id: CustomerId
     ) -> CustomerListBankAccountsCall<'a> {
         CustomerListBankAccountsCall::new(self, customer_id)
     }
@@ -480,7 +646,7 @@ impl StripeClient {
     }
 
     /// https://stripe.com/docs/api#retrieve_bitcoin_receiver
-    pub fn retrieve_bitcoin_receiver<'a>(&'a self, receiver_id: String) -> RetrieveBitcoinReceiverCall<'a> {
+    pub fn retrieve_bitcoin_receiver<'a>(&'a self, receiver_id: BitcoinReceiverId) -> RetrieveBitcoinReceiverCall<'a> {
         RetrieveBitcoinReceiverCall::new(self, receiver_id)
     }
 
@@ -492,7 +658,7 @@ impl StripeClient {
     /// https://stripe.com/docs/api#create_card
     pub fn create_card<'a>(
         &'a self,
-        customer_id: String
+        customer_id: CustomerId
     ) -> CustomerCreateCardCall<'a> {
         CustomerCreateCardCall::new(self, customer_id)
     }
@@ -500,8 +666,8 @@ impl StripeClient {
     /// https://stripe.com/docs/api#retrieve_card
     pub fn retrieve_card<'a>(
         &'a self,
-        customer_id: String,
-        card_id: String
+        customer_id: CustomerId,
+        card_id: CardId
     ) -> CustomerRetrieveCardCall<'a> {
         CustomerRetrieveCardCall::new(self, customer_id, card_id)
     }
@@ -509,8 +675,8 @@ impl StripeClient {
     /// https://stripe.com/docs/api#update_card
     pub fn update_card<'a>(
         &'a self,
-        customer_id: String,
-        card_id: String
+        customer_id: CustomerId,
+        card_id: CardId
     ) -> CustomerUpdateCardCall<'a> {
         CustomerUpdateCardCall::new(self, customer_id, card_id)
     }
@@ -518,14 +684,14 @@ impl StripeClient {
     /// https://stripe.com/docs/api#delete_card
     pub fn delete_card<'a>(
         &'a self,
-        customer_id: String,
-        card_id: String
+        customer_id: CustomerId,
+        card_id: CardId
     ) -> CustomerDeleteCardCall<'a> {
         CustomerDeleteCardCall::new(self, customer_id, card_id)
     }
 
     /// https://stripe.com/docs/api#list_cards
-    pub fn list_cards<'a>(&'a self, customer_id: String) -> CustomerListCardsCall<'a> {
+    pub fn list_cards<'a>(&'a self, customer_id: CustomerId) -> CustomerListCardsCall<'a> {
         CustomerListCardsCall::new(self, customer_id)
     }
 
@@ -535,17 +701,17 @@ impl StripeClient {
     }
 
     /// https://stripe.com/docs/api#retrieve_order
-    pub fn retrieve_order<'a>(&'a self, order_id: String) -> RetrieveOrderCall<'a> {
+    pub fn retrieve_order<'a>(&'a self, order_id: OrderId) -> RetrieveOrderCall<'a> {
         RetrieveOrderCall::new(self, order_id)
     }
 
     /// https://stripe.com/docs/api#update_order
-    pub fn update_order<'a>(&'a self, order_id: String) -> UpdateOrderCall<'a> {
+    pub fn update_order<'a>(&'a self, order_id: OrderId) -> UpdateOrderCall<'a> {
         UpdateOrderCall::new(self, order_id)
     }
 
     /// https://stripe.com/docs/api#pay_order
-    pub fn pay_order<'a>(&'a self, order_id: String) -> PayOrderCall<'a> {
+    pub fn pay_order<'a>(&'a self, order_id: OrderId) -> PayOrderCall<'a> {
         PayOrderCall::new(self, order_id)
     }
 
@@ -560,17 +726,17 @@ impl StripeClient {
     }
 
     /// https://stripe.com/docs/api#retrieve_product
-    pub fn retrieve_product<'a>(&'a self, product_id: String) -> RetrieveProductCall<'a> {
+    pub fn retrieve_product<'a>(&'a self, product_id: ProductId) -> RetrieveProductCall<'a> {
         RetrieveProductCall::new(self, product_id)
     }
 
     /// https://stripe.com/docs/api#update_product
-    pub fn update_product<'a>(&'a self, product_id: String) -> UpdateProductCall<'a> {
+    pub fn update_product<'a>(&'a self, product_id: ProductId) -> UpdateProductCall<'a> {
         UpdateProductCall::new(self, product_id)
     }
 
     /// https://stripe.com/docs/api#delete_product
-    pub fn delete_product<'a>(&'a self, product_id: String) -> DeleteProductCall<'a> {
+    pub fn delete_product<'a>(&'a self, product_id: ProductId) -> DeleteProductCall<'a> {
         DeleteProductCall::new(self, product_id)
     }
 
@@ -585,18 +751,28 @@ impl StripeClient {
         currency: Currency,
         inventory: Inventory,
         price: i64,
-        product: String
+        product: ProductId
     ) -> CreateSkuCall<'a> {
         CreateSkuCall::new(self, currency, inventory, price, product)
     }
 
+    /// Like `create_sku`, with the price and currency taken from `price`.
+    pub fn create_sku_for<'a>(
+        &'a self,
+        price: Amount,
+        inventory: Inventory,
+        product: ProductId
+    ) -> CreateSkuCall<'a> {
+        CreateSkuCall::new(self, price.currency, inventory, price.amount, product)
+    }
+
     /// https://stripe.com/docs/api#retrieve_sku
-    pub fn retrieve_sku<'a>(&'a self, sku_id: String) -> RetrieveSkuCall<'a> {
+    pub fn retrieve_sku<'a>(&'a self, sku_id: SkuId) -> RetrieveSkuCall<'a> {
         RetrieveSkuCall::new(self, sku_id)
     }
 
     /// https://stripe.com/docs/api#update_sku
-    pub fn update_sku<'a>(&'a self, sku_id: String) -> UpdateSkuCall<'a> {
+    pub fn update_sku<'a>(&'a self, sku_id: SkuId) -> UpdateSkuCall<'a> {
         UpdateSkuCall::new(self, sku_id)
     }
 
@@ -606,7 +782,7 @@ impl StripeClient {
     }
 
     /// https://stripe.com/docs/api#delete_sku
-    pub fn delete_sku<'a>(&'a self, sku_id: String) -> DeleteSkuCall<'a> {
+    pub fn delete_sku<'a>(&'a self, sku_id: SkuId) -> DeleteSkuCall<'a> {
         DeleteSkuCall::new(self, sku_id)
     }
 
@@ -616,17 +792,17 @@ impl StripeClient {
     }
 
     /// https://stripe.com/docs/api#retrieve_coupon
-    pub fn retrieve_coupon<'a>(&'a self, coupon_id: String) -> RetrieveCouponCall<'a> {
+    pub fn retrieve_coupon<'a>(&'a self, coupon_id: CouponId) -> RetrieveCouponCall<'a> {
         RetrieveCouponCall::new(self, coupon_id)
     }
 
     /// https://stripe.com/docs/api#update_coupon
-    pub fn update_coupon<'a>(&'a self, coupon_id: String) -> UpdateCouponCall<'a> {
+    pub fn update_coupon<'a>(&'a self, coupon_id: CouponId) -> UpdateCouponCall<'a> {
         UpdateCouponCall::new(self, coupon_id)
     }
 
     /// https://stripe.com/docs/api#delete_coupon
-    pub fn delete_coupon<'a>(&'a self, coupon_id: String) -> DeleteCouponCall<'a> {
+    pub fn delete_coupon<'a>(&'a self, coupon_id: CouponId) -> DeleteCouponCall<'a> {
         DeleteCouponCall::new(self, coupon_id)
     }
 
@@ -636,49 +812,49 @@ impl StripeClient {
     }
 
     /// https://stripe.com/docs/api#delete_discount
-    pub fn delete_discount<'a>(&'a self, customer_id: String) -> DeleteCustomerDiscountCall<'a> {
+    pub fn delete_discount<'a>(&'a self, customer_id: CustomerId) -> DeleteCustomerDiscountCall<'a> {
         DeleteCustomerDiscountCall::new(self, customer_id)
     }
 
     /// https://stripe.com/docs/api#delete_subscription_discount
     pub fn delete_subscription_discount<'a>(
         &'a self,
-        customer_id: String,
-        subscription_id: String
+        customer_id: CustomerId,
+        subscription_id: SubscriptionId
     ) -> DeleteSubscriptionDiscountCall<'a> {
         DeleteSubscriptionDiscountCall::new(self, customer_id, subscription_id)
     }
 
     /// https://stripe.com/docs/api#create_invoice
-    pub fn create_invoice<'a>(&'a self, customer_id: String) -> CreateInvoiceCall<'a> {
+    pub fn create_invoice<'a>(&'a self, customer_id: CustomerId) -> CreateInvoiceCall<'a> {
         CreateInvoiceCall::new(self, customer_id)
     }
 
     /// https://stripe.com/docs/api#retrieve_invoice
-    pub fn retrieve_invoice<'a>(&'a self, invoice_id: String) -> RetrieveInvoiceCall<'a> {
+    pub fn retrieve_invoice<'a>(&'a self, invoice_id: InvoiceId) -> RetrieveInvoiceCall<'a> {
         RetrieveInvoiceCall::new(self, invoice_id)
     }
 
     /// https://stripe.com/docs/api#invoice_lines
-    pub fn retrieve_invoice_lines<'a>(&'a self, invoice_id: String) -> RetrieveInvoiceLinesCall<'a> {
+    pub fn retrieve_invoice_lines<'a>(&'a self, invoice_id: InvoiceId) -> RetrieveInvoiceLinesCall<'a> {
         RetrieveInvoiceLinesCall::new(self, invoice_id)
     }
 
     /// https://stripe.com/docs/api#upcoming_invoice
     pub fn retrieve_upcoming_invoice<'a>(
         &'a self,
-        customer_id: String
+        customer_id: CustomerId
     ) -> RetrieveUpcomingInvoiceCall<'a> {
         RetrieveUpcomingInvoiceCall::new(self, customer_id)
     }
 
     /// https://stripe.com/docs/api#update_invoice
-    pub fn update_invoice<'a>(&'a self, invoice_id: String) -> UpdateInvoiceCall<'a> {
+    pub fn update_invoice<'a>(&'a self, invoice_id: InvoiceId) -> UpdateInvoiceCall<'a> {
         UpdateInvoiceCall::new(self, invoice_id)
     }
 
     /// https://stripe.com/docs/api#pay_invoice
-    pub fn pay_invoice<'a>(&'a self, invoice_id: String) -> PayInvoiceCall<'a> {
+    pub fn pay_invoice<'a>(&'a self, invoice_id: InvoiceId) -> PayInvoiceCall<'a> {
         PayInvoiceCall::new(self, invoice_id)
     }
 
@@ -692,15 +868,25 @@ impl StripeClient {
         &'a self,
         amount: i64,
         currency: Currency,
-        customer_id: String
+        customer_id: CustomerId
     ) -> CreateInvoiceitemCall<'a> {
         CreateInvoiceitemCall::new(self, amount, currency, customer_id)
     }
 
+    /// Like `create_invoiceitem`, with the amount and currency taken from
+    /// `amount`.
+    pub fn create_invoiceitem_for<'a>(
+        &'a self,
+        amount: Amount,
+        customer_id: CustomerId
+    ) -> CreateInvoiceitemCall<'a> {
+        CreateInvoiceitemCall::new(self, amount.amount, amount.currency, customer_id)
+    }
+
     /// https://stripe.com/docs/api#retrieve_invoiceitem
     pub fn retrieve_invoiceitem<'a>(
         &'a self,
-        invoiceitem_id: String
+        invoiceitem_id: InvoiceItemId
     ) -> RetrieveInvoiceitemCall<'a> {
         RetrieveInvoiceitemCall::new(self, invoiceitem_id)
     }
@@ -708,7 +894,7 @@ impl StripeClient {
     /// https://stripe.com/docs/api#update_invoiceitem
     pub fn update_invoiceitem<'a>(
         &'a self,
-        invoiceitem_id: String
+        invoiceitem_id: InvoiceItemId
     ) -> UpdateInvoiceitemCall<'a> {
         UpdateInvoiceitemCall::new(self, invoiceitem_id)
     }
@@ -716,7 +902,7 @@ impl StripeClient {
     /// https://stripe.com/docs/api#delete_invoiceitem
     pub fn delete_invoiceitem<'a>(
         &'a self,
-        invoiceitem_id: String
+        invoiceitem_id: InvoiceItemId
     ) -> DeleteInvoiceitemCall<'a> {
         DeleteInvoiceitemCall::new(self, invoiceitem_id)
     }
@@ -729,7 +915,7 @@ impl StripeClient {
     /// https://stripe.com/docs/api#create_plan
     pub fn create_plan<'a>(
         &'a self,
-        plan_id: String,
+        plan_id: PlanId,
         amount: i64,
         currency: Currency,
         interval: Interval,
@@ -738,18 +924,29 @@ impl StripeClient {
         CreatePlanCall::new(self, plan_id, amount, currency, interval, name)
     }
 
+    /// Like `create_plan`, with the amount and currency taken from `amount`.
+    pub fn create_plan_for<'a>(
+        &'a self,
+        plan_id: PlanId,
+        amount: Amount,
+        interval: Interval,
+        name: String
+    ) -> CreatePlanCall<'a> {
+        CreatePlanCall::new(self, plan_id, amount.amount, amount.currency, interval, name)
+    }
+
     /// https://stripe.com/docs/api#retrieve_plan
-    pub fn retrieve_plan<'a>(&'a self, plan_id: String) -> RetrievePlanCall<'a> {
+    pub fn retrieve_plan<'a>(&'a self, plan_id: PlanId) -> RetrievePlanCall<'a> {
         RetrievePlanCall::new(self, plan_id)
     }
 
     /// https://stripe.com/docs/api#update_plan
-    pub fn update_plan<'a>(&'a self, plan_id: String) -> UpdatePlanCall<'a> {
+    pub fn update_plan<'a>(&'a self, plan_id: PlanId) -> UpdatePlanCall<'a> {
         UpdatePlanCall::new(self, plan_id)
     }
 
     /// https://stripe.com/docs/api#delete_plan
-    pub fn delete_plan<'a>(&'a self, plan_id: String) -> DeletePlanCall<'a> {
+    pub fn delete_plan<'a>(&'a self, plan_id: PlanId) -> DeletePlanCall<'a> {
         DeletePlanCall::new(self, plan_id)
     }
 
@@ -761,8 +958,8 @@ impl StripeClient {
     /// https://stripe.com/docs/api#create_subscription
     pub fn create_subscription<'a>(
         &'a self,
-        customer_id: String,
-        plan_id: String
+        customer_id: CustomerId,
+        plan_id: PlanId
     ) -> CreateSubscriptionCall<'a> {
         CreateSubscriptionCall::new(self, customer_id, plan_id)
     }
@@ -770,8 +967,8 @@ impl StripeClient {
     /// https://stripe.com/docs/api#retrieve_subscription
     pub fn retrieve_subscription<'a>(
         &'a self,
-        customer_id: String,
-        subscription_id: String
+        customer_id: CustomerId,
+        subscription_id: SubscriptionId
     ) -> RetrieveSubscriptionCall<'a> {
         RetrieveSubscriptionCall::new(self, customer_id, subscription_id)
     }
@@ -779,8 +976,8 @@ impl StripeClient {
     /// https://stripe.com/docs/api#update_subscription
     pub fn update_subscription<'a>(
         &'a self,
-        customer_id: String,
-        subscription_id: String
+        customer_id: CustomerId,
+        subscription_id: SubscriptionId
     ) -> UpdateSubscriptionCall<'a> {
         UpdateSubscriptionCall::new(self, customer_id, subscription_id)
     }
@@ -788,8 +985,8 @@ impl StripeClient {
     /// https://stripe.com/docs/api#cancel_subscription
     pub fn cancel_subscription<'a>(
         &'a self,
-        customer_id: String,
-        subscription_id: String
+        customer_id: CustomerId,
+        subscription_id: SubscriptionId
     ) -> CancelSubscriptionCall<'a> {
         CancelSubscriptionCall::new(self, customer_id, subscription_id)
     }
@@ -797,7 +994,7 @@ impl StripeClient {
     /// https://stripe.com/docs/api#list_subscriptions
     pub fn list_subscriptions<'a>(
         &'a self,
-        customer_id: String
+        customer_id: CustomerId
     ) -> ListActiveSubscriptionsCall<'a> {
         ListActiveSubscriptionsCall::new(self, customer_id)
     }
@@ -807,16 +1004,7 @@ impl StripeClient {
         endpoint: E,
         args: &UrlEncodable
     ) -> Result<T> {
-        let params = args.encoded_string();
-        let url = if params.is_empty() {
-            StripeClient::endpoint(endpoint)
-        } else {
-            StripeClient::endpoint(&format!("{}?{}", endpoint, params))
-        };
-        let res = self.client.get(&url)
-            .headers(self.default_headers())
-            .send()?;
-        StripeClient::parse_response::<T>(res)
+        self.send(Method::Get, endpoint, args, Headers::new())
     }
 
     pub fn post<T: Deserialize, E: Display>(
@@ -824,14 +1012,7 @@ impl StripeClient {
         endpoint: E,
         args: &UrlEncodable,
     ) -> Result<T> {
-        let body = args.encoded_string();
-        let mut req_builder = self.client.post(&StripeClient::endpoint(endpoint))
-            .headers(self.default_headers());
-
-        if !body.is_empty() {
-            req_builder = req_builder.body(body.as_bytes());
-        }
-        StripeClient::parse_response::<T>(req_builder.send()?)
+        self.send(Method::Post, endpoint, args, Headers::new())
     }
 
     pub fn post_with_custom_headers<T: Deserialize, E: Display>(
@@ -840,25 +1021,14 @@ impl StripeClient {
         args: &UrlEncodable,
         custom_headers: Headers
     ) -> Result<T> {
-        let body = args.encoded_string();
-        let mut req_builder = self.client.post(&StripeClient::endpoint(endpoint))
-            .headers(self.default_headers())
-            .headers(custom_headers);
-
-        if !body.is_empty() {
-            req_builder = req_builder.body(body.as_bytes());
-        }
-        StripeClient::parse_response::<T>(req_builder.send()?)
+        self.send(Method::Post, endpoint, args, custom_headers)
     }
 
     pub fn delete<T: Deserialize, E: Display>(
         &self,
         endpoint: E
     ) -> Result<T> {
-        let res = self.client.delete(&StripeClient::endpoint(endpoint))
-            .headers(self.default_headers())
-            .send()?;
-        StripeClient::parse_response(res)
+        self.send(Method::Delete, endpoint, &(), Headers::new())
     }
 
     pub fn delete_with_args<T: Deserialize, E: Display>(
@@ -866,15 +1036,7 @@ impl StripeClient {
         endpoint: E,
         args: &UrlEncodable
     ) -> Result<T> {
-        let body = args.encoded_string();
-        let mut req = self.client.delete(&StripeClient::endpoint(endpoint))
-            .headers(self.default_headers());
-
-        if !body.is_empty() {
-            req = req.body(body.as_bytes());
-        }
-        let res = req.send()?;
-        StripeClient::parse_response(res)
+        self.send(Method::Delete, endpoint, args, Headers::new())
     }
 
     pub fn retrieve_all<T: StripeObject>(
@@ -917,6 +1079,152 @@ impl StripeClient {
         }
     }
 
+    fn send<T: Deserialize, E: Display>(
+        &self,
+        method: Method,
+        endpoint: E,
+        args: &UrlEncodable,
+        custom_headers: Headers
+    ) -> Result<T> {
+        let mut key = self.key_provider.current_key()?;
+        let mut headers = self.default_headers();
+        headers.extend(custom_headers.iter());
+        let unauthenticated = Request {
+            call_type: call_context::current_call_type(),
+            retries: 0,
+            labels: self.labels.clone(),
+            method: method,
+            path: endpoint.to_string(),
+            params: args.key_value_pairs(),
+            headers: headers
+        };
+
+        // Each attempt, including retries with a fallback key, goes through
+        // the whole middleware chain.
+        let mut retries = 0;
+        loop {
+            let mut request = unauthenticated.clone();
+            request.retries = retries;
+            self.authenticate(&mut request, &key);
+            for middleware in &self.middleware {
+                middleware.before_send(&mut request);
+            }
+
+            if self.dry_run || call_context::is_preparing() {
+                let prepared = PreparedRequest::new(&request);
+                if self.dry_run {
+                    info!("stripe dry run: {}", prepared);
+                }
+                return Err(Error::DryRun(prepared));
+            }
+
+            #[cfg(feature = "instrumentation")]
+            let span = instrumentation::request_span(&request);
+            #[cfg(feature = "instrumentation")]
+            let _entered = span.enter();
+
+            let start = Instant::now();
+            let (status, headers, value) = self.attempt(&request, &key);
+            let latency = start.elapsed();
+
+            let result = value.and_then(|value| {
+                let t = schema_drift::collecting(self.schema_drift.as_ref(), || {
+                    serde_json::from_value(value.clone())
+                })?;
+                Ok((value, t))
+            });
+            {
+                let response = Response {
+                    status: status,
+                    headers: headers.as_ref(),
+                    latency: latency,
+                    key_fingerprint: key.fingerprint(),
+                    result: match result {
+                        Ok((ref value, _)) => Ok(value),
+                        Err(ref err)       => Err(err)
+                    }
+                };
+                for middleware in &self.middleware {
+                    middleware.after_receive(&request, &response);
+                }
+                #[cfg(feature = "instrumentation")]
+                instrumentation::record(&span, &request, &response);
+            }
+
+            if retries < MAX_KEY_FALLBACKS && StripeClient::is_authentication_error(&result) {
+                if let Some(fallback) = self.key_provider.fallback_key(&key) {
+                    key = fallback;
+                    retries += 1;
+                    continue;
+                }
+            }
+            return result.map(|(_, t)| t);
+        }
+    }
+
+    /// Sends `request` once, authenticated with `key`.
+    fn attempt(
+        &self,
+        request: &Request,
+        key: &SecretKey
+    ) -> (Option<StatusCode>, Option<Headers>, Result<Value>) {
+        match self.check_key_mode(key).and_then(|_| self.execute(request)) {
+            Ok((status, headers, body)) => {
+                let value = StripeClient::parse_response(status, &body).and_then(|value| {
+                    livemode::check(key.mode(), &value)?;
+                    Ok(value)
+                });
+                (Some(status), Some(headers), value)
+            },
+            Err(err) => (None, None, Err(err))
+        }
+    }
+
+    fn is_authentication_error<T>(result: &Result<T>) -> bool {
+        match *result {
+            Err(Error::StripeError(ref err)) => err.kind == stripe_error::StripeErrorKind::AuthenticationError,
+            _                                => false
+        }
+    }
+
+    fn check_key_mode(&self, key: &SecretKey) -> Result<()> {
+        match self.required_mode {
+            Some(required) if required != key.mode() => Err(Error::from(ModeError::KeyModeNotAllowed {
+                required: required,
+                actual: key.mode()
+            })),
+            _ => Ok(())
+        }
+    }
+
+    fn authenticate(&self, request: &mut Request, key: &SecretKey) {
+        match self.auth {
+            Auth::Basic => request.headers.set(Authorization(Basic {
+                username: key.expose().to_string(),
+                password: None
+            })),
+            Auth::KeyParam => {
+                request.params.retain(|&(ref k, _)| k != "key");
+                request.params.push(("key".to_string(), key.expose().to_string()));
+            }
+        }
+    }
+
+    fn execute(&self, request: &Request) -> Result<(StatusCode, Headers, String)> {
+        let response = self.transport.send(request)?;
+        Ok((response.status, response.headers, response.body))
+    }
+
+    /// The full URL for `request`, including the query string for GETs.
+    fn url(request: &Request) -> String {
+        let params = request.params.encoded_string();
+        if request.method == Method::Get && !params.is_empty() {
+            StripeClient::endpoint(&format!("{}?{}", request.path, params))
+        } else {
+            StripeClient::endpoint(&request.path)
+        }
+    }
+
     fn endpoint<E: Display>(endpoint: E) -> String {
         let endpoint = endpoint.to_string();
         if endpoint.starts_with("/") {
@@ -926,18 +1234,11 @@ impl StripeClient {
         }
     }
 
-    fn parse_response<T: Deserialize>(mut res: Response) -> Result<T> {
-        match res.status {
-            StatusCode::Ok => {
-                let mut body = String::new();
-                res.read_to_string(&mut body)?;
-                let t = serde_json::from_str(&body)?;
-                Ok(t)
-            },
+    fn parse_response(status: StatusCode, body: &str) -> Result<Value> {
+        match status {
+            StatusCode::Ok => Ok(serde_json::from_str(body)?),
             _ => {
-                let mut body = String::new();
-                res.read_to_string(&mut body)?;
-                let err = serde_json::from_str::<stripe_error::StripeErrorWrapper>(&body)?.error;
+                let err = serde_json::from_str::<stripe_error::StripeErrorWrapper>(body)?.error;
                 Err(Error::StripeError(err))
             }
         }
@@ -945,33 +1246,33 @@ impl StripeClient {
 
     fn default_headers(&self) -> Headers {
         let mut headers = Headers::new();
-        headers.set(Authorization(Basic {
-                username: self.key.clone(),
-                password: None
-        }));
         headers.set(StripeVersion::new(API_VERSION));
+        if let Some(ref account_id) = self.stripe_account {
+            headers.set(StripeAccount::new(account_id));
+        }
         headers
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "testing"))]
 mod test {
-    use hyper::Client;
+    use api::ApiCall;
+    use std::env;
     use super::StripeClient;
-    use std::error::Error;
+    use testing::CassetteTransport;
+
+    /// Replays `tests/cassettes/<name>.json`. Run with `STRIPE_RECORD=1` and
+    /// `STRIPE_SECRET_KEY` set to a test key to re-record it.
+    fn client(name: &str) -> StripeClient {
+        let cassette = format!("{}/tests/cassettes/{}.json", env!("CARGO_MANIFEST_DIR"), name);
+        let key = env::var("STRIPE_SECRET_KEY").unwrap_or("sk_test_replay".to_string());
+        StripeClient::new(&key).with_transport(CassetteTransport::from_env(cassette).unwrap())
+    }
 
     #[test]
-    fn it_works() {
-        let key = "sk_test_GBE7dKKNklsixtSM4VG876qt";
-        // let key = "not_a_key";
-        let client = StripeClient::new(&key);
-        match client.fetch_account() {
-            Ok(account) => println!("{:?}", account),
-            Err(err) => {
-                println!("{}", &err);
-                println!("{}", err.description());
-            }
-        }
-        panic!("boom");
+    fn retrieve_balance() {
+        let balance = client("retrieve_balance").retrieve_balance().call().unwrap();
+        assert!(!balance.livemode);
+        assert_eq!(balance.available[0].amount, 0);
     }
 }
@@ -0,0 +1,46 @@
+use errors::mode_error::ModeError;
+use secret_key::KeyMode;
+use serde_json::Value;
+
+/// Checks the `livemode` flag of every object in `value`, including expanded
+/// objects and list entries, against the mode of the key that fetched it.
+pub fn check(key_mode: KeyMode, value: &Value) -> Result<(), ModeError> {
+    let expected = match key_mode {
+        KeyMode::Test    => false,
+        KeyMode::Live    => true,
+        KeyMode::Unknown => return Ok(())
+    };
+
+    match *value {
+        Value::Object(ref map) => {
+            if let Some(&Value::Bool(livemode)) = map.get("livemode") {
+                if livemode != expected {
+                    let object = match map.get("object") {
+                        Some(&Value::String(ref object)) => object.clone(),
+                        _                                => "unknown".to_string()
+                    };
+                    let id = match map.get("id") {
+                        Some(&Value::String(ref id)) => Some(id.clone()),
+                        _                            => None
+                    };
+                    return Err(ModeError::LivemodeMismatch {
+                        key_mode: key_mode,
+                        object: object,
+                        id: id
+                    });
+                }
+            }
+            for value in map.values() {
+                check(key_mode, value)?;
+            }
+            Ok(())
+        },
+        Value::Array(ref values) => {
+            for value in values {
+                check(key_mode, value)?;
+            }
+            Ok(())
+        },
+        _ => Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+use errors::error::Error;
+use errors::rate_limit_error::RateLimitError;
+use hyper::header::Headers;
+use hyper::method::Method;
+use hyper::status::StatusCode;
+use redact;
+use serde_json::Value;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// A request about to be sent to Stripe. `before_send` hooks may modify any
+/// of its fields.
+#[derive(Clone, Debug)]
+pub struct Request {
+    /// The `ApiCall` that issued this request, e.g. `CreateChargeCall`.
+    pub call_type: &'static str,
+    /// How many times this request has already been retried.
+    pub retries: u32,
+    /// Labels set on the client with `StripeClient::with_label`, e.g. the
+    /// tenant a request is made for.
+    pub labels: Vec<(String, String)>,
+    pub method: Method,
+    pub path: String,
+    pub params: Vec<(String, String)>,
+    pub headers: Headers
+}
+
+/// What came back from Stripe for a `Request`. `status` and `headers` are
+/// `None` when no HTTP response was received.
+#[derive(Debug)]
+pub struct Response<'a> {
+    pub status: Option<StatusCode>,
+    pub headers: Option<&'a Headers>,
+    pub latency: Duration,
+    /// `SecretKey::fingerprint` of the key the request was sent with.
+    pub key_fingerprint: String,
+    pub result: ::std::result::Result<&'a Value, &'a Error>
+}
+
+/// Hooks run by `StripeClient` around every request, in the order the
+/// middleware was added.
+pub trait Middleware: Send + Sync {
+    fn before_send(&self, _request: &mut Request) {}
+
+    fn after_receive(&self, _request: &Request, _response: &Response) {}
+}
+
+/// Logs every request and response with card numbers, CVCs and the secret
+/// key redacted.
+#[derive(Clone, Debug)]
+pub struct LoggingMiddleware;
+
+impl Middleware for LoggingMiddleware {
+    fn before_send(&self, request: &mut Request) {
+        debug!(
+            "stripe request: {} {} {} params={:?} headers={:?}",
+            request.call_type,
+            request.method,
+            request.path,
+            redact::redact_params(&request.params),
+            redact::redact_headers(&request.headers)
+        );
+    }
+
+    fn after_receive(&self, request: &Request, response: &Response) {
+        let status = response.status
+            .map(|status| status.to_string())
+            .unwrap_or("no response".to_string());
+        let millis = response.latency.as_secs() * 1000
+            + (response.latency.subsec_nanos() / 1_000_000) as u64;
+
+        match response.result {
+            Ok(_) => info!(
+                "stripe response: {} {} {} -> {} in {}ms",
+                request.call_type, request.method, request.path, status, millis
+            ),
+            Err(err) => warn!(
+                "stripe response: {} {} {} -> {} in {}ms: {}",
+                request.call_type, request.method, request.path, status, millis, err
+            )
+        }
+    }
+}
+
+/// Blocks requests so that no more than `requests` are sent in any `per`
+/// window, refilling evenly over the window.
+#[derive(Debug)]
+pub struct RateLimiter {
+    requests: u32,
+    per: Duration,
+    bucket: Mutex<(f64, Instant)>
+}
+
+impl RateLimiter {
+    /// Fails if `requests` is 0 or `per` is zero, which would never let a
+    /// request through or let them all through at once.
+    pub fn new(requests: u32, per: Duration) -> Result<RateLimiter, RateLimitError> {
+        if requests == 0 {
+            return Err(RateLimitError::NoRequests);
+        }
+        if per == Duration::new(0, 0) {
+            return Err(RateLimitError::ZeroWindow);
+        }
+        Ok(RateLimiter {
+            requests: requests,
+            per: per,
+            bucket: Mutex::new((requests as f64, Instant::now()))
+        })
+    }
+
+    fn seconds(duration: Duration) -> f64 {
+        duration.as_secs() as f64 + duration.subsec_nanos() as f64 / 1_000_000_000.0
+    }
+}
+
+impl Middleware for RateLimiter {
+    fn before_send(&self, _request: &mut Request) {
+        let refill_per_second = self.requests as f64 / RateLimiter::seconds(self.per);
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().unwrap();
+                let (tokens, last) = *bucket;
+                let now = Instant::now();
+                let tokens = (tokens + RateLimiter::seconds(now - last) * refill_per_second)
+                    .min(self.requests as f64);
+                if tokens >= 1.0 {
+                    *bucket = (tokens - 1.0, now);
+                    return;
+                }
+                *bucket = (tokens, now);
+                (1.0 - tokens) / refill_per_second
+            };
+            let secs = wait.floor();
+            thread::sleep(Duration::new(secs as u64, ((wait - secs) * 1_000_000_000.0) as u32));
+        }
+    }
+}
@@ -1,57 +1,83 @@
+use errors::account_validation_error::AccountValidationError;
 use std::collections::BTreeMap;
+use std::fmt;
+use std::net::IpAddr;
 use super::api_list::ApiList;
+use super::country::Country;
 use super::currency::Currency;
+use super::id::{AccountId, CustomerId};
 use super::legal_entity::LegalEntity;
 use super::StripeObject;
+use super::timestamp::Timestamp;
 use super::verification::Verification;
 use url_encodable::UrlEncodable;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct Account {
-    pub id: String,
-    pub business_name: Option<String>,
-    pub business_logo: Option<String>,
-    pub business_url: Option<String>,
-    pub charges_enabled: bool,
-    pub country: String,
-    pub debit_negative_balances: Option<bool>,
-    pub decline_charge_on: Option<DeclineChargeOn>,
-    pub default_currency: Currency,
-    pub details_submitted: bool,
-    pub display_name: Option<String>,
-    pub email: String,
-    pub external_accounts: Option<ApiList<ExternalAccount>>,
-    pub legal_entity: Option<LegalEntity>,
-    pub managed: bool,
-    pub metadata: Option<BTreeMap<String, String>>,
-    pub product_description: Option<bool>,
-    pub statement_descriptor: Option<String>,
-    pub support_email: Option<String>,
-    pub support_phone: Option<String>,
-    pub timezone: String,
-    pub tos_acceptance: Option<TosAcceptance>,
-    pub transfer_schedule: Option<TransferSchedule>,
-    pub transfers_enabled: bool,
-    pub verification: Option<Verification>
+stripe_object! {
+    pub struct Account {
+        pub id: AccountId,
+        pub business_name: Option<String>,
+        pub business_logo: Option<String>,
+        pub business_url: Option<String>,
+        pub charges_enabled: bool,
+        pub country: Country,
+        pub debit_negative_balances: Option<bool>,
+        pub decline_charge_on: Option<DeclineChargeOn>,
+        pub default_currency: Currency,
+        pub details_submitted: bool,
+        pub display_name: Option<String>,
+        pub email: Option<String>,
+        pub external_accounts: Option<ApiList<ExternalAccount>>,
+        pub legal_entity: Option<LegalEntity>,
+        pub managed: bool,
+        pub metadata: Option<BTreeMap<String, String>>,
+        pub product_description: Option<String>,
+        pub statement_descriptor: Option<String>,
+        pub support_email: Option<String>,
+        pub support_phone: Option<String>,
+        pub timezone: String,
+        pub tos_acceptance: Option<TosAcceptance>,
+        pub transfer_schedule: Option<TransferSchedule>,
+        pub transfers_enabled: bool,
+        pub verification: Option<Verification>
+    }
 }
 
 impl StripeObject for Account {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeclineChargeOn {
     pub avs_failure: bool,
-    pub cvs_failure: bool
+    pub cvc_failure: bool
+}
+
+impl DeclineChargeOn {
+    pub fn new(avs_failure: bool, cvc_failure: bool) -> DeclineChargeOn {
+        DeclineChargeOn {
+            avs_failure: avs_failure,
+            cvc_failure: cvc_failure
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ExternalAccount {
-    pub id: String,
-    pub customer: String,
-    pub account: String
+impl UrlEncodable for DeclineChargeOn {
+    fn key_value_pairs(&self) -> Vec<(String, String)> {
+        vec![
+            ("avs_failure".to_string(), self.avs_failure.to_string()),
+            ("cvc_failure".to_string(), self.cvc_failure.to_string())
+        ]
+    }
+}
+
+stripe_object! {
+    pub struct ExternalAccount {
+        pub id: String,
+        pub customer: Option<CustomerId>,
+        pub account: Option<AccountId>
+    }
 }
 
 impl StripeObject for ExternalAccount {
@@ -60,17 +86,36 @@ impl StripeObject for ExternalAccount {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TosAcceptance {
-    date: i64,
-    ip: Option<String>,
-    user_agent: Option<String>
+    pub date: Option<Timestamp>,
+    pub ip: Option<String>,
+    pub user_agent: Option<String>
+}
+
+impl TosAcceptance {
+    /// The account holder accepted Stripe's terms at `date`, from `ip`.
+    pub fn new<T: Into<Timestamp>>(date: T, ip: IpAddr) -> TosAcceptance {
+        TosAcceptance {
+            date: Some(date.into()),
+            ip: Some(ip.to_string()),
+            user_agent: None
+        }
+    }
+
+    pub fn user_agent(mut self, user_agent: String) -> Self {
+        self.user_agent = Some(user_agent);
+        self
+    }
 }
 
 impl UrlEncodable for TosAcceptance {
     fn key_value_pairs(&self) -> Vec<(String, String)> {
         let TosAcceptance { ref date, ref ip, ref user_agent } = *self;
-        let mut v = vec![("date".to_string(), date.to_string())];
+        let mut v = Vec::new();
+        if let Some(ref date) = *date {
+            v.push(("date".to_string(), date.to_string()));
+        }
         if let Some(ref ip) = *ip {
             v.push(("ip".to_string(), ip.to_string()));
         }
@@ -81,12 +126,63 @@ impl UrlEncodable for TosAcceptance {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TransferSchedule {
-    delay_days: Option<i64>,
-    interval: Option<String>,
-    monthly_anchor: Option<i64>,
-    weekly_anchor: Option<String>
+    pub delay_days: Option<i64>,
+    pub interval: Option<String>,
+    pub monthly_anchor: Option<i64>,
+    pub weekly_anchor: Option<String>
+}
+
+impl TransferSchedule {
+    /// Funds stay in the account's balance until transferred by hand.
+    pub fn manual() -> TransferSchedule {
+        TransferSchedule::with_interval("manual")
+    }
+
+    pub fn daily() -> TransferSchedule {
+        TransferSchedule::with_interval("daily")
+    }
+
+    pub fn weekly(anchor: Weekday) -> TransferSchedule {
+        TransferSchedule {
+            weekly_anchor: Some(anchor.to_string()),
+            ..TransferSchedule::with_interval("weekly")
+        }
+    }
+
+    /// Transfers on day `anchor` of each month, or the last day of months
+    /// shorter than that. `validate`, which the account calls run, checks
+    /// it's between 1 and 31.
+    pub fn monthly(anchor: i64) -> TransferSchedule {
+        TransferSchedule {
+            monthly_anchor: Some(anchor),
+            ..TransferSchedule::with_interval("monthly")
+        }
+    }
+
+    /// How many days charges wait before they're paid out. Stripe's minimum
+    /// for the account's country is used if this isn't set.
+    pub fn delay_days(mut self, delay_days: i64) -> Self {
+        self.delay_days = Some(delay_days);
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), AccountValidationError> {
+        match self.monthly_anchor {
+            Some(anchor) if anchor < 1 || anchor > 31 => Err(AccountValidationError::InvalidMonthlyAnchor),
+            _                                         => Ok(())
+        }
+    }
+
+    fn with_interval(interval: &str) -> TransferSchedule {
+        TransferSchedule {
+            delay_days: None,
+            interval: Some(interval.to_string()),
+            monthly_anchor: None,
+            weekly_anchor: None
+        }
+    }
 }
 
 impl UrlEncodable for TransferSchedule {
@@ -116,3 +212,29 @@ impl UrlEncodable for TransferSchedule {
         v
     }
 }
+
+/// The day of the week weekly transfers are made on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", match *self {
+            Weekday::Monday    => "monday",
+            Weekday::Tuesday   => "tuesday",
+            Weekday::Wednesday => "wednesday",
+            Weekday::Thursday  => "thursday",
+            Weekday::Friday    => "friday",
+            Weekday::Saturday  => "saturday",
+            Weekday::Sunday    => "sunday"
+        })
+    }
+}
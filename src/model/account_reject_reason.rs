@@ -22,6 +22,14 @@ impl serde::Deserialize for AccountRejectReason {
     }
 }
 
+impl serde::Serialize for AccountRejectReason {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
 impl fmt::Display for AccountRejectReason {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
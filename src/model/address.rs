@@ -1,15 +1,98 @@
+use errors::address_validation_error::AddressValidationError;
+use super::country::Country;
 use url_encodable::UrlEncodable;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Address {
-    pub line1: String,
+    pub line1: Option<String>,
     pub line2: Option<String>,
-    pub city: String,
-    pub country: String,
-    pub postal_code: String,
+    pub city: Option<String>,
+    pub country: Option<Country>,
+    pub postal_code: Option<String>,
     pub state: Option<String>
 }
 
+impl Address {
+    /// Checks the fields the address has against its country's formats: a
+    /// state that is one of `Country::states`, by code or name, and a postal
+    /// code shaped like one of `Country::postal_code_formats`. Missing
+    /// fields are fine, so partial updates can be checked; without a
+    /// country, only the country itself is.
+    pub fn validate(&self) -> Result<(), AddressValidationError> {
+        self.check(None, false)
+    }
+
+    /// Like `validate`, in `country` unless the address has its own.
+    pub fn validate_in(&self, country: &Country) -> Result<(), AddressValidationError> {
+        self.check(Some(country), false)
+    }
+
+    /// Like `validate_in`, and also requires a street and whatever else the
+    /// country needs: a state where it has them, and a postal code where it
+    /// uses them.
+    pub fn validate_complete_in(&self, country: &Country) -> Result<(), AddressValidationError> {
+        self.check(Some(country), true)
+    }
+
+    fn check(&self, default_country: Option<&Country>, complete: bool) -> Result<(), AddressValidationError> {
+        let country = match (self.country.as_ref(), default_country) {
+            (Some(&Country::Unknown(_)), _) => return Err(AddressValidationError::Invalid { field: "country" }),
+            (Some(country), _) | (None, Some(country)) => country,
+            (None, None) => return Ok(())
+        };
+        if complete && non_blank(&self.line1).is_none() {
+            return Err(AddressValidationError::Missing { field: "line1" });
+        }
+
+        match non_blank(&self.state) {
+            Some(state) => {
+                if country.states().is_some() && country.state_code(state).is_none() {
+                    return Err(AddressValidationError::Invalid { field: "state" });
+                }
+            },
+            None => {
+                if complete && country.requires_state() {
+                    return Err(AddressValidationError::Missing { field: "state" });
+                }
+            }
+        }
+
+        let formats = country.postal_code_formats();
+        match non_blank(&self.postal_code) {
+            Some(postal_code) => {
+                if !formats.is_empty() && !formats.iter().any(|format| has_format(postal_code, format)) {
+                    return Err(AddressValidationError::Invalid { field: "postal_code" });
+                }
+            },
+            None => {
+                if complete && !formats.is_empty() {
+                    return Err(AddressValidationError::Missing { field: "postal_code" });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn non_blank(field: &Option<String>) -> Option<&str> {
+    match *field {
+        Some(ref field) if !field.trim().is_empty() => Some(field.trim()),
+        _                                           => None
+    }
+}
+
+/// Whether `value` has `format`'s shape, where `9` is any digit and `A` any
+/// letter. Spaces and case don't count.
+fn has_format(value: &str, format: &str) -> bool {
+    let value: Vec<char> = value.to_uppercase().chars().filter(|c| *c != ' ').collect();
+    let format: Vec<char> = format.chars().filter(|c| *c != ' ').collect();
+    value.len() == format.len() && value.iter().zip(&format).all(|(&c, &f)| match f {
+        '9' => c.is_digit(10),
+        'A' => c >= 'A' && c <= 'Z',
+        _   => c == f
+    })
+}
+
 impl UrlEncodable for Address {
     fn key_value_pairs(&self) -> Vec<(String, String)> {
         let mut vec = Vec::new();
@@ -21,13 +104,21 @@ impl UrlEncodable for Address {
             ref postal_code,
             ref state
         } => {
-            vec.push(("line1".to_string(), line1.to_string()));
+            if let &Some(ref line1) = line1 {
+                vec.push(("line1".to_string(), line1.to_string()));
+            }
             if let &Some(ref line2) = line2 {
                 vec.push(("line2".to_string(), line2.to_string()));
             }
-            vec.push(("city".to_string(), city.to_string()));
-            vec.push(("country".to_string(), country.to_string()));
-            vec.push(("postal_code".to_string(), postal_code.to_string()));
+            if let &Some(ref city) = city {
+                vec.push(("city".to_string(), city.to_string()));
+            }
+            if let &Some(ref country) = country {
+                vec.push(("country".to_string(), country.to_string()));
+            }
+            if let &Some(ref postal_code) = postal_code {
+                vec.push(("postal_code".to_string(), postal_code.to_string()));
+            }
             if let &Some(ref state) = state {
                 vec.push(("state".to_string(), state.to_string()));
             }
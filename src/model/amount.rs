@@ -0,0 +1,122 @@
+use errors::amount_error::AmountError;
+use std::fmt;
+use super::currency::Currency;
+
+/// An amount in a currency's minor units, as Stripe takes it: cents for
+/// `usd`, whole yen for `jpy`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Amount {
+    pub amount: i64,
+    pub currency: Currency
+}
+
+impl Amount {
+    pub fn new(amount: i64, currency: Currency) -> Amount {
+        Amount {
+            amount: amount,
+            currency: currency
+        }
+    }
+
+    /// Parses a decimal amount in major units, e.g. "12.50" in `usd` is 1250
+    /// and "1250" in `jpy` is 1250. Fails if there are more decimal places
+    /// than the currency has, other than trailing zeros.
+    pub fn parse(decimal: &str, currency: Currency) -> Result<Amount, AmountError> {
+        let exponent = currency.exponent();
+        let (negative, digits) = if decimal.starts_with('-') {
+            (true, &decimal[1..])
+        } else {
+            (false, decimal)
+        };
+        let mut parts = digits.splitn(2, '.');
+        let whole = parts.next().unwrap_or("");
+        let fraction = match parts.next() {
+            Some(fraction) if !fraction.is_empty() => fraction.trim_right_matches('0'),
+            Some(_) => return Err(invalid_amount(decimal, &currency)),
+            None => ""
+        };
+        if !is_digits(whole) || !fraction.chars().all(|c| c.is_digit(10)) ||
+            fraction.len() > exponent as usize {
+            return Err(invalid_amount(decimal, &currency));
+        }
+
+        let mut amount = whole.parse::<i64>().map_err(|_| AmountError::Overflow)?;
+        for i in 0..exponent as usize {
+            let digit = fraction.chars().nth(i).and_then(|c| c.to_digit(10)).unwrap_or(0);
+            amount = amount.checked_mul(10)
+                .and_then(|amount| amount.checked_add(digit as i64))
+                .ok_or(AmountError::Overflow)?;
+        }
+        Ok(Amount::new(if negative { -amount } else { amount }, currency))
+    }
+
+    /// The amount in major units, with as many decimal places as the
+    /// currency has, e.g. "12.50" for 1250 in `usd`.
+    pub fn to_decimal(&self) -> String {
+        let exponent = self.currency.exponent();
+        if exponent == 0 {
+            return self.amount.to_string();
+        }
+        let scale = 10i64.pow(exponent);
+        format!(
+            "{}{}.{:0width$}",
+            if self.amount < 0 { "-" } else { "" },
+            (self.amount / scale).abs(),
+            (self.amount % scale).abs(),
+            width = exponent as usize
+        )
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.amount == 0
+    }
+
+    pub fn checked_add(&self, other: &Amount) -> Result<Amount, AmountError> {
+        self.check_currency(other)?;
+        self.amount.checked_add(other.amount)
+            .map(|amount| Amount::new(amount, self.currency.clone()))
+            .ok_or(AmountError::Overflow)
+    }
+
+    pub fn checked_sub(&self, other: &Amount) -> Result<Amount, AmountError> {
+        self.check_currency(other)?;
+        self.amount.checked_sub(other.amount)
+            .map(|amount| Amount::new(amount, self.currency.clone()))
+            .ok_or(AmountError::Overflow)
+    }
+
+    pub fn checked_mul(&self, factor: i64) -> Result<Amount, AmountError> {
+        self.amount.checked_mul(factor)
+            .map(|amount| Amount::new(amount, self.currency.clone()))
+            .ok_or(AmountError::Overflow)
+    }
+
+    fn check_currency(&self, other: &Amount) -> Result<(), AmountError> {
+        if self.currency == other.currency {
+            Ok(())
+        } else {
+            Err(AmountError::CurrencyMismatch {
+                expected: self.currency.clone(),
+                actual: other.currency.clone()
+            })
+        }
+    }
+}
+
+fn is_digits(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_digit(10))
+}
+
+fn invalid_amount(decimal: &str, currency: &Currency) -> AmountError {
+    AmountError::InvalidAmount {
+        amount: decimal.to_string(),
+        currency: currency.clone()
+    }
+}
+
+/// Formats as the decimal amount and the currency code, e.g. "12.50 usd".
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.to_decimal(), self.currency)
+    }
+}
@@ -1,9 +1,24 @@
+use serde;
 use super::StripeObject;
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct ApiList<T: StripeObject> {
     pub data: Vec<T>,
     pub has_more: bool,
-    pub total_count: i64,
+    pub total_count: Option<i64>,
     pub url: String
 }
+
+impl<T: StripeObject + serde::Serialize> serde::Serialize for ApiList<T> {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        let mut state = serializer.serialize_struct("ApiList", 5)?;
+        serializer.serialize_struct_elt(&mut state, "object", "list")?;
+        serializer.serialize_struct_elt(&mut state, "data", &self.data)?;
+        serializer.serialize_struct_elt(&mut state, "has_more", self.has_more)?;
+        serializer.serialize_struct_elt(&mut state, "total_count", self.total_count)?;
+        serializer.serialize_struct_elt(&mut state, "url", &self.url)?;
+        serializer.serialize_struct_end(state)
+    }
+}
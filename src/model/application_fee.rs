@@ -1,25 +1,28 @@
 use super::api_list::ApiList;
 use super::fee_refund::FeeRefund;
+use super::id::{AccountId, ApplicationFeeId, BalanceTransactionId, ChargeId};
 use super::StripeObject;
+use super::timestamp::Timestamp;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ApplicationFee {
-    pub id: String,
-    pub account: String,
-    pub amount: i64,
-    pub amount_refunded: i64,
-    pub application: String,
-    pub balance_transaction: String,
-    pub charge: String,
-    pub created: i64,
-    pub livemode: bool,
-    pub originating_transaction: Option<String>,
-    pub refunded: bool,
-    pub refunds: ApiList<FeeRefund>,
+stripe_object! {
+    pub struct ApplicationFee {
+        pub id: ApplicationFeeId,
+        pub account: AccountId,
+        pub amount: i64,
+        pub amount_refunded: i64,
+        pub application: String,
+        pub balance_transaction: BalanceTransactionId,
+        pub charge: ChargeId,
+        pub created: Timestamp,
+        pub livemode: bool,
+        pub originating_transaction: Option<String>,
+        pub refunded: bool,
+        pub refunds: ApiList<FeeRefund>,
+    }
 }
 
 impl StripeObject for ApplicationFee {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
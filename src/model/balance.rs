@@ -1,3 +1,4 @@
+use serde;
 use super::money::Money;
 
 #[derive(Clone, Debug, Deserialize)]
@@ -6,3 +7,16 @@ pub struct Balance {
     pub livemode: bool,
     pub pending: Vec<Money>
 }
+
+impl serde::Serialize for Balance {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        let mut state = serializer.serialize_struct("Balance", 4)?;
+        serializer.serialize_struct_elt(&mut state, "object", "balance")?;
+        serializer.serialize_struct_elt(&mut state, "available", &self.available)?;
+        serializer.serialize_struct_elt(&mut state, "livemode", self.livemode)?;
+        serializer.serialize_struct_elt(&mut state, "pending", &self.pending)?;
+        serializer.serialize_struct_end(state)
+    }
+}
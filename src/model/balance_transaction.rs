@@ -1,38 +1,42 @@
+use schema_drift;
 use serde;
 use std::fmt;
 use super::api_list::ApiList;
 use super::currency::Currency;
+use super::id::BalanceTransactionId;
 use super::StripeObject;
+use super::timestamp::Timestamp;
 use super::transfer::Transfer;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct BalanceTransaction {
-    pub id: String,
-    pub amount: i64,
-    pub available_on: i64,
-    pub created: i64,
-    pub currency: Currency,
-    pub description: String,
-    pub fee: i64,
-    pub fee_details: Vec<FeeDetails>,
-    pub net: i64,
-    pub souce: String,
-    pub sourced_transfers: ApiList<Transfer>,
-    pub status: TransactionStatus,
-    #[serde(rename="type")]
-    pub transaction_type: TransactionType
+stripe_object! {
+    pub struct BalanceTransaction {
+        pub id: BalanceTransactionId,
+        pub amount: i64,
+        pub available_on: Timestamp,
+        pub created: Timestamp,
+        pub currency: Currency,
+        pub description: Option<String>,
+        pub fee: i64,
+        pub fee_details: Vec<FeeDetails>,
+        pub net: i64,
+        pub source: String,
+        pub sourced_transfers: Option<ApiList<Transfer>>,
+        pub status: TransactionStatus,
+        #[serde(rename="type")]
+        pub transaction_type: TransactionType
+    }
 }
 
 impl StripeObject for BalanceTransaction {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct FeeDetails {
     pub amount: i64,
-    pub application: String,
+    pub application: Option<String>,
     pub currency: Currency,
     pub description: String,
     #[serde(rename="type")]
@@ -53,11 +57,29 @@ impl serde::Deserialize for TransactionStatus {
         Ok(match String::deserialize(deserializer)?.as_ref() {
             "available" => TransactionStatus::Available,
             "pending"   => TransactionStatus::Pending,
-            other       => TransactionStatus::Other(String::from(other)),
+            other       => TransactionStatus::Other(schema_drift::unknown_value("TransactionStatus", other)),
         })
     }
 }
 
+impl fmt::Display for TransactionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TransactionStatus::Available        => write!(f, "available"),
+            TransactionStatus::Pending          => write!(f, "pending"),
+            TransactionStatus::Other(ref other) => write!(f, "{}", other),
+        }
+    }
+}
+
+impl serde::Serialize for TransactionStatus {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum TransactionType {
     ApplicationFee,
@@ -106,7 +128,15 @@ impl serde::Deserialize for TransactionType {
             "transfer_cancel"        => TransactionType::TransferCancel,
             "transfer_failure"       => TransactionType::TransferFailure,
             "transfer_refund"        => TransactionType::TransferRefund,
-            other                    => TransactionType::Other(String::from(other)),
+            other                    => TransactionType::Other(schema_drift::unknown_value("TransactionType", other)),
         })
     }
 }
+
+impl serde::Serialize for TransactionType {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
@@ -1,30 +1,36 @@
+use errors::bank_account_validation_error::BankAccountValidationError;
+use redact;
+use schema_drift;
 use std::collections::BTreeMap;
-use serde::ser::Error;
 use serde;
 use std::fmt;
+use super::country::Country;
+use super::country_spec::CountrySpec;
 use super::currency::Currency;
+use super::id::{AccountId, BankAccountId};
 use super::StripeObject;
 use url_encodable::UrlEncodable;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct BankAccount {
-    pub id: String,
-    pub account: Option<String>,
-    pub account_holder_name: String,
-    pub account_holder_type: AccountHolderType,
-    pub bank_name: String,
-    pub country: String,
-    pub currency: Currency,
-    pub fingerprint: String,
-    pub last4: Option<String>,
-    pub metadata: Option<BTreeMap<String, String>>,
-    pub routing_number: String,
-    pub status: BankAccountStatus,
+stripe_object! {
+    pub struct BankAccount {
+        pub id: BankAccountId,
+        pub account: Option<AccountId>,
+        pub account_holder_name: Option<String>,
+        pub account_holder_type: Option<AccountHolderType>,
+        pub bank_name: Option<String>,
+        pub country: Country,
+        pub currency: Currency,
+        pub fingerprint: Option<String>,
+        pub last4: Option<String>,
+        pub metadata: Option<BTreeMap<String, String>>,
+        pub routing_number: Option<String>,
+        pub status: BankAccountStatus,
+    }
 }
 
 impl StripeObject for BankAccount {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
 
@@ -52,7 +58,7 @@ impl serde::Deserialize for AccountHolderType {
         Ok(match String::deserialize(deserializer)?.as_ref() {
             "company"    => AccountHolderType::Company,
             "individual" => AccountHolderType::Individual,
-            unknown      => AccountHolderType::Unknown(String::from(unknown)),
+            unknown      => AccountHolderType::Unknown(schema_drift::unknown_value("AccountHolderType", unknown)),
         })
     }
 }
@@ -61,11 +67,7 @@ impl serde::Serialize for AccountHolderType {
     fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
         where S: serde::Serializer
     {
-        match *self {
-            AccountHolderType::Company    => str::serialize("company", serializer),
-            AccountHolderType::Individual => str::serialize("individual", serializer),
-            _                             => Err(S::Error::invalid_value("expected [company, individual]")),
-        }
+        serializer.serialize_str(&self.to_string())
     }
 }
 
@@ -89,23 +91,57 @@ impl serde::Deserialize for BankAccountStatus {
             "verified"            => BankAccountStatus::Verified,
             "verification_failed" => BankAccountStatus::VerificationFailed,
             "errored"             => BankAccountStatus::Errored,
-            unknown               => BankAccountStatus::Unknown(String::from(unknown)),
+            unknown               => BankAccountStatus::Unknown(schema_drift::unknown_value("BankAccountStatus", unknown)),
         })
     }
 }
 
-#[derive(Clone, Debug)]
+impl fmt::Display for BankAccountStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BankAccountStatus::New                  => write!(f, "new"),
+            BankAccountStatus::Validated            => write!(f, "validated"),
+            BankAccountStatus::Verified             => write!(f, "verified"),
+            BankAccountStatus::VerificationFailed   => write!(f, "verification_failed"),
+            BankAccountStatus::Errored              => write!(f, "errored"),
+            BankAccountStatus::Unknown(ref unknown) => write!(f, "{}", unknown),
+        }
+    }
+}
+
+impl serde::Serialize for BankAccountStatus {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[derive(Clone)]
 pub struct NewBankAccount {
     pub account_number: String,
-    pub country: String,
+    pub country: Country,
     pub currency: Currency,
     pub routing_number: Option<String>,
     pub account_holder_name: Option<String>,
     pub account_holder_type: Option<AccountHolderType>
 }
 
+impl fmt::Debug for NewBankAccount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NewBankAccount")
+            .field("account_number", &redact::mask("account_number", &self.account_number))
+            .field("country", &self.country)
+            .field("currency", &self.currency)
+            .field("routing_number", &self.routing_number)
+            .field("account_holder_name", &self.account_holder_name)
+            .field("account_holder_type", &self.account_holder_type)
+            .finish()
+    }
+}
+
 impl NewBankAccount {
-    pub fn new(account_number: String, country: String, currency: Currency) -> NewBankAccount {
+    pub fn new(account_number: String, country: Country, currency: Currency) -> NewBankAccount {
         NewBankAccount {
             account_number: account_number,
             country: country,
@@ -116,6 +152,69 @@ impl NewBankAccount {
         }
     }
 
+    /// Checks the account and routing numbers against the country's
+    /// format, without a round trip: the ABA checksum in the US, sort codes
+    /// in GB, IBANs in SEPA countries, BSBs in AU, and transit and
+    /// institution numbers in CA. Numbers in other countries only have to
+    /// be there.
+    pub fn validate(&self) -> Result<(), BankAccountValidationError> {
+        let country = self.country.code();
+        let account_number: String = self.account_number.chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        let routing_number = self.routing_number.as_ref().map(|routing_number| {
+            routing_number.chars().filter(|c| !c.is_whitespace() && *c != '-').collect::<String>()
+        });
+
+        if iban_length(country).is_some() && !is_digits(&account_number) {
+            return validate_iban(&account_number, country);
+        }
+        match country {
+            "US" => {
+                check_account_number(&account_number, 4, 17)?;
+                let routing_number = require_routing_number(&routing_number, &[9])?;
+                if aba_checksum(routing_number) % 10 != 0 {
+                    return Err(BankAccountValidationError::IncorrectRoutingNumber);
+                }
+            },
+            "GB" => {
+                check_account_number(&account_number, 8, 8)?;
+                require_routing_number(&routing_number, &[6])?;
+            },
+            "AU" => {
+                check_account_number(&account_number, 4, 9)?;
+                require_routing_number(&routing_number, &[6])?;
+            },
+            "CA" => {
+                check_account_number(&account_number, 7, 12)?;
+                // Transit then institution number, e.g. "11000-000".
+                require_routing_number(&routing_number, &[8])?;
+            },
+            _ if iban_length(country).is_some() => return validate_iban(&account_number, country),
+            _ => {
+                if account_number.is_empty() {
+                    return Err(BankAccountValidationError::InvalidAccountNumber);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Like `validate`, and also checks that Stripe pays out in the
+    /// currency to bank accounts in the country, for an account in `spec`'s
+    /// country.
+    pub fn validate_for(&self, spec: &CountrySpec) -> Result<(), BankAccountValidationError> {
+        self.validate()?;
+        if spec.supports_bank_account_currency(&self.country, &self.currency) {
+            Ok(())
+        } else {
+            Err(BankAccountValidationError::UnsupportedCurrency {
+                country: self.country.clone(),
+                currency: self.currency.clone()
+            })
+        }
+    }
+
     pub fn routing_number(mut self, routing_number: String) -> Self {
         self.routing_number = Some(routing_number);
         self
@@ -132,6 +231,81 @@ impl NewBankAccount {
     }
 }
 
+fn is_digits(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_digit(10))
+}
+
+fn check_account_number(account_number: &str, min: usize, max: usize) -> Result<(), BankAccountValidationError> {
+    if is_digits(account_number) && account_number.len() >= min && account_number.len() <= max {
+        Ok(())
+    } else {
+        Err(BankAccountValidationError::InvalidAccountNumber)
+    }
+}
+
+fn require_routing_number<'a>(
+    routing_number: &'a Option<String>,
+    lengths: &[usize]
+) -> Result<&'a str, BankAccountValidationError> {
+    match *routing_number {
+        Some(ref routing_number) if is_digits(routing_number) && lengths.contains(&routing_number.len()) =>
+            Ok(routing_number),
+        Some(_) => Err(BankAccountValidationError::InvalidRoutingNumber),
+        None    => Err(BankAccountValidationError::MissingRoutingNumber)
+    }
+}
+
+/// https://en.wikipedia.org/wiki/ABA_routing_transit_number#Check_digit
+fn aba_checksum(routing_number: &str) -> u32 {
+    routing_number.chars()
+        .filter_map(|c| c.to_digit(10))
+        .zip([3, 7, 1].iter().cycle())
+        .fold(0, |sum, (digit, weight)| sum + digit * weight)
+}
+
+/// The length of IBANs in a SEPA country.
+fn iban_length(country: &str) -> Option<usize> {
+    Some(match country {
+        "AT" | "EE" | "LT" | "LU" => 20,
+        "BE"                      => 16,
+        "BG" | "DE" | "GB" | "IE" => 22,
+        "CH" | "HR" | "LI" | "LV" => 21,
+        "CY" | "HU" | "PL"        => 28,
+        "CZ" | "ES" | "RO" | "SE" | "SK" => 24,
+        "DK" | "FI" | "NL"        => 18,
+        "FR" | "GR" | "IT" | "MC" | "SM" => 27,
+        "GI"                      => 23,
+        "IS"                      => 26,
+        "MT"                      => 31,
+        "NO"                      => 15,
+        "PT"                      => 25,
+        "SI"                      => 19,
+        _                         => return None
+    })
+}
+
+/// Checks that the IBAN is from `country`, its length for the country and
+/// its mod-97 check digits.
+/// https://en.wikipedia.org/wiki/International_Bank_Account_Number#Validating_the_IBAN
+fn validate_iban(iban: &str, country: &str) -> Result<(), BankAccountValidationError> {
+    let iban = iban.to_uppercase();
+    if iban.len() < 4 || !iban.chars().all(|c| c.is_digit(36)) || iban_length(&iban[..2]) != Some(iban.len()) {
+        return Err(BankAccountValidationError::InvalidAccountNumber);
+    }
+    if &iban[..2] != country {
+        return Err(BankAccountValidationError::IbanCountryMismatch);
+    }
+    let remainder = iban[4..].chars().chain(iban[..4].chars()).fold(0, |remainder, c| {
+        let value = c.to_digit(36).unwrap();
+        if value < 10 { (remainder * 10 + value) % 97 } else { (remainder * 100 + value) % 97 }
+    });
+    if remainder == 1 {
+        Ok(())
+    } else {
+        Err(BankAccountValidationError::IncorrectAccountNumber)
+    }
+}
+
 impl UrlEncodable for NewBankAccount {
     fn key_value_pairs(&self) -> Vec<(String, String)> {
         let NewBankAccount {
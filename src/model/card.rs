@@ -1,43 +1,52 @@
+use either::Either;
+use errors::card_validation_error::CardValidationError;
+use redact;
+use schema_drift;
 use serde;
 use std::collections::BTreeMap;
+use std::fmt;
+use super::country::Country;
 use super::currency::Currency;
 use super::customer::Customer;
+use super::id::{AccountId, CardId, CustomerId, RecipientId};
 use super::StripeObject;
+use super::timestamp::Timestamp;
 use url_encodable::UrlEncodable;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct Card {
-    pub id: String,
-    pub account: Option<String>,
-    pub address_city: Option<String>,
-    pub address_country: Option<String>,
-    pub address_line1: Option<String>,
-    pub address_line1_check: Option<Check>,
-    pub address_line2: Option<String>,
-    pub address_state: Option<String>,
-    pub address_zip: Option<String>,
-    pub address_zip_check: Option<Check>,
-    pub brand: CardBrand,
-    pub country: String,
-    pub currency: Option<String>,
-    pub customer: Option<Customer>,
-    pub cvc_check: Option<Check>,
-    pub default_for_currency: Option<bool>,
-    pub dynamic_last4: Option<String>,
-    pub exp_month: i64,
-    pub exp_year: i64,
-    pub fingerprint: Option<String>,
-    pub funding: CardType,
-    pub last4: String,
-    pub metadata: Option<BTreeMap<String, String>>,
-    pub name: Option<String>,
-    pub recipient: Option<String>,
-    pub tokenization_method: Option<TokenizationMethod>,
+stripe_object! {
+    pub struct Card {
+        pub id: CardId,
+        pub account: Option<AccountId>,
+        pub address_city: Option<String>,
+        pub address_country: Option<String>,
+        pub address_line1: Option<String>,
+        pub address_line1_check: Option<Check>,
+        pub address_line2: Option<String>,
+        pub address_state: Option<String>,
+        pub address_zip: Option<String>,
+        pub address_zip_check: Option<Check>,
+        pub brand: CardBrand,
+        pub country: Option<Country>,
+        pub currency: Option<String>,
+        pub customer: Option<Either<CustomerId, Customer>>,
+        pub cvc_check: Option<Check>,
+        pub default_for_currency: Option<bool>,
+        pub dynamic_last4: Option<String>,
+        pub exp_month: i64,
+        pub exp_year: i64,
+        pub fingerprint: Option<String>,
+        pub funding: CardType,
+        pub last4: String,
+        pub metadata: Option<BTreeMap<String, String>>,
+        pub name: Option<String>,
+        pub recipient: Option<RecipientId>,
+        pub tokenization_method: Option<TokenizationMethod>,
+    }
 }
 
 impl StripeObject for Card {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
 
@@ -59,11 +68,31 @@ impl serde::Deserialize for Check {
             "fail"        => Check::Fail,
             "unavailable" => Check::Unavailable,
             "unchecked"   => Check::Unchecked,
-            unknown       => Check::Unknown(String::from(unknown)),
+            unknown       => Check::Unknown(schema_drift::unknown_value("Check", unknown)),
         })
     }
 }
 
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Check::Pass                 => write!(f, "pass"),
+            Check::Fail                 => write!(f, "fail"),
+            Check::Unavailable          => write!(f, "unavailable"),
+            Check::Unchecked            => write!(f, "unchecked"),
+            Check::Unknown(ref unknown) => write!(f, "{}", unknown),
+        }
+    }
+}
+
+impl serde::Serialize for Check {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum CardType {
     Credit,
@@ -80,11 +109,30 @@ impl serde::Deserialize for CardType {
             "credit"  => CardType::Credit,
             "debit"   => CardType::Debit,
             "prepaid" => CardType::Prepaid,
-            unknown   => CardType::Unknown(String::from(unknown)),
+            unknown   => CardType::Unknown(schema_drift::unknown_value("CardType", unknown)),
         })
     }
 }
 
+impl fmt::Display for CardType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CardType::Credit               => write!(f, "credit"),
+            CardType::Debit                => write!(f, "debit"),
+            CardType::Prepaid              => write!(f, "prepaid"),
+            CardType::Unknown(ref unknown) => write!(f, "{}", unknown),
+        }
+    }
+}
+
+impl serde::Serialize for CardType {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum TokenizationMethod {
     AndroidPay,
@@ -99,12 +147,30 @@ impl serde::Deserialize for TokenizationMethod {
         Ok(match String::deserialize(deserializer)?.as_ref() {
             "android_pay" => TokenizationMethod::AndroidPay,
             "apple_pay"   => TokenizationMethod::ApplePay,
-            unknown       => TokenizationMethod::Unknown(String::from(unknown)),
+            unknown       => TokenizationMethod::Unknown(schema_drift::unknown_value("TokenizationMethod", unknown)),
         })
     }
 }
 
-#[derive(Clone, Debug)]
+impl fmt::Display for TokenizationMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TokenizationMethod::AndroidPay           => write!(f, "android_pay"),
+            TokenizationMethod::ApplePay             => write!(f, "apple_pay"),
+            TokenizationMethod::Unknown(ref unknown) => write!(f, "{}", unknown),
+        }
+    }
+}
+
+impl serde::Serialize for TokenizationMethod {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum CardBrand {
     Visa,
     AmericanExpress,
@@ -115,6 +181,51 @@ pub enum CardBrand {
     Unknown(String)
 }
 
+impl CardBrand {
+    /// The brand a card number belongs to, from its leading digits.
+    /// Numbers of no brand this crate knows give `Unknown("Unknown")`, as
+    /// Stripe reports them.
+    pub fn from_number(number: &str) -> CardBrand {
+        let digits: String = number.chars().filter(|c| !c.is_whitespace()).collect();
+        if !digits.chars().all(|c| c.is_digit(10)) {
+            return CardBrand::Unknown("Unknown".to_string());
+        }
+        let prefix = |len: usize| {
+            if digits.len() >= len { digits[..len].parse::<u32>().unwrap_or(0) } else { 0 }
+        };
+        match (prefix(1), prefix(2), prefix(3), prefix(4)) {
+            (4, _, _, _)                                           => CardBrand::Visa,
+            (_, 34, _, _) | (_, 37, _, _)                          => CardBrand::AmericanExpress,
+            (_, 51...55, _, _) | (_, _, _, 2221...2720)            => CardBrand::MasterCard,
+            (_, 65, _, _) | (_, _, 644...649, _) | (_, _, _, 6011) => CardBrand::Discover,
+            (_, 35, _, _)                                          => CardBrand::Jcb,
+            (_, 36, _, _) | (_, 38, _, _) | (_, _, 300...305, _)   => CardBrand::DinersClub,
+            _                                                      => CardBrand::Unknown("Unknown".to_string())
+        }
+    }
+
+    /// How many digits the brand's card numbers have.
+    pub fn number_lengths(&self) -> &'static [usize] {
+        match *self {
+            CardBrand::Visa            => &[13, 16, 19],
+            CardBrand::AmericanExpress => &[15],
+            CardBrand::MasterCard      => &[16],
+            CardBrand::Discover        => &[16, 17, 18, 19],
+            CardBrand::Jcb             => &[16, 17, 18, 19],
+            CardBrand::DinersClub      => &[14, 15, 16, 17, 18, 19],
+            CardBrand::Unknown(_)      => &[12, 13, 14, 15, 16, 17, 18, 19]
+        }
+    }
+
+    /// How many digits the brand's CVCs have.
+    pub fn cvc_length(&self) -> u32 {
+        match *self {
+            CardBrand::AmericanExpress => 4,
+            _                          => 3
+        }
+    }
+}
+
 impl serde::Deserialize for CardBrand {
     fn deserialize<D>(deserializer: &mut D) -> Result<CardBrand, D::Error>
         where D: serde::Deserializer
@@ -126,12 +237,34 @@ impl serde::Deserialize for CardBrand {
             "Discover"         => CardBrand::Discover,
             "JCB"              => CardBrand::Jcb,
             "Diners Club"      => CardBrand::DinersClub,
-            unknown            => CardBrand::Unknown(String::from(unknown)),
+            unknown            => CardBrand::Unknown(schema_drift::unknown_value("CardBrand", unknown)),
         })
     }
 }
 
-#[derive(Clone, Debug)]
+impl fmt::Display for CardBrand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CardBrand::Visa                 => write!(f, "Visa"),
+            CardBrand::AmericanExpress      => write!(f, "American Express"),
+            CardBrand::MasterCard           => write!(f, "MasterCard"),
+            CardBrand::Discover             => write!(f, "Discover"),
+            CardBrand::Jcb                  => write!(f, "JCB"),
+            CardBrand::DinersClub           => write!(f, "Diners Club"),
+            CardBrand::Unknown(ref unknown) => write!(f, "{}", unknown),
+        }
+    }
+}
+
+impl serde::Serialize for CardBrand {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[derive(Clone)]
 pub struct NewCard {
     pub exp_month: i64,
     pub exp_year: i64,
@@ -143,13 +276,76 @@ pub struct NewCard {
     pub address_state: Option<String>,
     pub address_zip: Option<String>,
     pub currency: Option<Currency>,
-    pub cvc: Option<i64>,
+    pub cvc: Option<String>,
     pub default_for_currency: Option<bool>,
     pub metadata: Option<BTreeMap<String, String>>,
     pub name: Option<String>
 }
 
+impl fmt::Debug for NewCard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NewCard")
+            .field("exp_month", &self.exp_month)
+            .field("exp_year", &self.exp_year)
+            .field("number", &redact::mask("number", &self.number))
+            .field("address_city", &self.address_city)
+            .field("address_country", &self.address_country)
+            .field("address_line1", &self.address_line1)
+            .field("address_line2", &self.address_line2)
+            .field("address_state", &self.address_state)
+            .field("address_zip", &self.address_zip)
+            .field("currency", &self.currency)
+            .field("cvc", &self.cvc.as_ref().map(|_| redact::REDACTED))
+            .field("default_for_currency", &self.default_for_currency)
+            .field("metadata", &self.metadata)
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
 impl NewCard {
+    /// Checks the card details for what Stripe would reject them for when
+    /// tokenizing them, without a round trip, and gives the card's brand.
+    /// Whether the card can be charged is still up to Stripe.
+    pub fn validate(&self) -> Result<CardBrand, CardValidationError> {
+        self.validate_at(Timestamp::now())
+    }
+
+    /// Like `validate`, with the expiry checked against `now` rather than
+    /// the clock.
+    pub fn validate_at(&self, now: Timestamp) -> Result<CardBrand, CardValidationError> {
+        let number: String = self.number.chars().filter(|c| !c.is_whitespace()).collect();
+        if number.is_empty() || !number.chars().all(|c| c.is_digit(10)) {
+            return Err(CardValidationError::InvalidNumber);
+        }
+        let brand = CardBrand::from_number(&number);
+        if !brand.number_lengths().contains(&number.len()) {
+            return Err(CardValidationError::InvalidNumber);
+        }
+        if !luhn(&number) {
+            return Err(CardValidationError::IncorrectNumber);
+        }
+
+        if self.exp_month < 1 || self.exp_month > 12 {
+            return Err(CardValidationError::InvalidExpiryMonth);
+        }
+        if self.exp_year < 0 {
+            return Err(CardValidationError::InvalidExpiryYear);
+        }
+        // Stripe takes two-digit years as this century's.
+        let exp_year = if self.exp_year < 100 { self.exp_year + 2000 } else { self.exp_year };
+        if (exp_year, self.exp_month) < now.year_month() {
+            return Err(CardValidationError::Expired);
+        }
+
+        if let Some(ref cvc) = self.cvc {
+            if cvc.len() != brand.cvc_length() as usize || !cvc.chars().all(|c| c.is_digit(10)) {
+                return Err(CardValidationError::InvalidCvc);
+            }
+        }
+        Ok(brand)
+    }
+
     pub fn new(number: String, exp_month: i64, exp_year: i64) -> Self {
         NewCard {
             exp_month: exp_month,
@@ -204,7 +400,8 @@ impl NewCard {
         self
     }
 
-    pub fn cvc(mut self, cvc: i64) -> Self {
+    /// The CVC as printed on the card, so that leading zeros are kept.
+    pub fn cvc(mut self, cvc: String) -> Self {
         self.cvc = Some(cvc);
         self
     }
@@ -275,3 +472,19 @@ impl UrlEncodable for NewCard {
         v
     }
 }
+
+fn luhn(number: &str) -> bool {
+    let mut sum = 0;
+    for (i, c) in number.chars().rev().enumerate() {
+        let digit = match c.to_digit(10) {
+            Some(digit) => digit,
+            None        => return false
+        };
+        sum += match (i % 2, digit * 2) {
+            (1, doubled) if doubled > 9 => doubled - 9,
+            (1, doubled)                => doubled,
+            _                           => digit
+        };
+    }
+    sum % 10 == 0
+}
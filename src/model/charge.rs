@@ -1,50 +1,54 @@
 use either::Either;
 use errors::stripe_error::StripeErrorCode;
+use schema_drift;
+use serde;
 use serde::de::{Deserialize, Deserializer};
 use std::collections::BTreeMap;
 use std::fmt;
 use super::{
-    Account, ApiList, ApplicationFee, BalanceTransaction, Currency, Customer, Dispute, Order,
-    Refund, Shipping, Source, StripeObject, Transfer
+    Account, AccountId, ApiList, ApplicationFee, ApplicationFeeId, BalanceTransaction,
+    BalanceTransactionId, ChargeId, Currency, Customer, CustomerId, Dispute, DisputeId, InvoiceId,
+    Order, OrderId, Refund, Shipping, Source, StripeObject, Timestamp, Transfer, TransferId
 };
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct Charge {
-    pub id: String,
-    pub amount: i64,
-    pub amount_refunded: i64,
-    pub application_fee: Option<Either<String, ApplicationFee>>,
-    pub balance_transaction: Either<String, BalanceTransaction>,
-    pub captured: bool,
-    pub created: i64,
-    pub currency: Currency,
-    pub customer: Option<Either<String, Customer>>,
-    pub description: Option<String>,
-    pub destination: Option<Either<String, Account>>,
-    pub dispute: Option<Dispute>,
-    pub failure_code: Option<StripeErrorCode>,
-    pub failure_message: Option<String>,
-    pub fraud_details: Option<BTreeMap<String, String>>,
-    pub invoice: Option<String>,
-    pub livemode: bool,
-    pub metadata: Option<BTreeMap<String, String>>,
-    pub order: Option<Either<String, Order>>,
-    pub paid: bool,
-    pub receipt_email: String,
-    pub receipt_number: String,
-    pub refunded: bool,
-    pub refunds: Option<ApiList<Refund>>,
-    pub shipping: Option<Shipping>,
-    pub source: Source,
-    pub source_transfer: Option<Either<String, Transfer>>,
-    pub statement_descriptor: Option<String>,
-    pub status: ChargeStatus,
-    pub transfer: Option<Either<String, Transfer>>
+stripe_object! {
+    pub struct Charge {
+        pub id: ChargeId,
+        pub amount: i64,
+        pub amount_refunded: i64,
+        pub application_fee: Option<Either<ApplicationFeeId, ApplicationFee>>,
+        pub balance_transaction: Option<Either<BalanceTransactionId, BalanceTransaction>>,
+        pub captured: bool,
+        pub created: Timestamp,
+        pub currency: Currency,
+        pub customer: Option<Either<CustomerId, Customer>>,
+        pub description: Option<String>,
+        pub destination: Option<Either<AccountId, Account>>,
+        pub dispute: Option<Either<DisputeId, Dispute>>,
+        pub failure_code: Option<StripeErrorCode>,
+        pub failure_message: Option<String>,
+        pub fraud_details: Option<BTreeMap<String, String>>,
+        pub invoice: Option<InvoiceId>,
+        pub livemode: bool,
+        pub metadata: Option<BTreeMap<String, String>>,
+        pub order: Option<Either<OrderId, Order>>,
+        pub paid: bool,
+        pub receipt_email: Option<String>,
+        pub receipt_number: Option<String>,
+        pub refunded: bool,
+        pub refunds: Option<ApiList<Refund>>,
+        pub shipping: Option<Shipping>,
+        pub source: Source,
+        pub source_transfer: Option<Either<TransferId, Transfer>>,
+        pub statement_descriptor: Option<String>,
+        pub status: ChargeStatus,
+        pub transfer: Option<Either<TransferId, Transfer>>
+    }
 }
 
 impl StripeObject for Charge {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
 
@@ -75,7 +79,15 @@ impl Deserialize for ChargeStatus {
             "succeeded" => ChargeStatus::Succeeded,
             "pending"   => ChargeStatus::Pending,
             "failed"    => ChargeStatus::Failed,
-            unknown     => ChargeStatus::Unknown(String::from(unknown)),
+            unknown     => ChargeStatus::Unknown(schema_drift::unknown_value("ChargeStatus", unknown)),
         })
     }
 }
+
+impl serde::Serialize for ChargeStatus {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
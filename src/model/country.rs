@@ -0,0 +1,1181 @@
+use errors::country_error::CountryError;
+use schema_drift;
+use serde;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// An ISO 3166-1 country, as Stripe sends and takes its alpha-2 code.
+#[derive(Clone, Debug)]
+pub enum Country {
+    Andorra,
+    UnitedArabEmirates,
+    Afghanistan,
+    AntiguaAndBarbuda,
+    Anguilla,
+    Albania,
+    Armenia,
+    Angola,
+    Antarctica,
+    Argentina,
+    AmericanSamoa,
+    Austria,
+    Australia,
+    Aruba,
+    AlandIslands,
+    Azerbaijan,
+    BosniaAndHerzegovina,
+    Barbados,
+    Bangladesh,
+    Belgium,
+    BurkinaFaso,
+    Bulgaria,
+    Bahrain,
+    Burundi,
+    Benin,
+    SaintBarthelemy,
+    Bermuda,
+    Brunei,
+    Bolivia,
+    CaribbeanNetherlands,
+    Brazil,
+    Bahamas,
+    Bhutan,
+    BouvetIsland,
+    Botswana,
+    Belarus,
+    Belize,
+    Canada,
+    CocosIslands,
+    CongoKinshasa,
+    CentralAfricanRepublic,
+    CongoBrazzaville,
+    Switzerland,
+    CoteDIvoire,
+    CookIslands,
+    Chile,
+    Cameroon,
+    China,
+    Colombia,
+    CostaRica,
+    Cuba,
+    CapeVerde,
+    Curacao,
+    ChristmasIsland,
+    Cyprus,
+    Czechia,
+    Germany,
+    Djibouti,
+    Denmark,
+    Dominica,
+    DominicanRepublic,
+    Algeria,
+    Ecuador,
+    Estonia,
+    Egypt,
+    WesternSahara,
+    Eritrea,
+    Spain,
+    Ethiopia,
+    Finland,
+    Fiji,
+    FalklandIslands,
+    Micronesia,
+    FaroeIslands,
+    France,
+    Gabon,
+    UnitedKingdom,
+    Grenada,
+    Georgia,
+    FrenchGuiana,
+    Guernsey,
+    Ghana,
+    Gibraltar,
+    Greenland,
+    Gambia,
+    Guinea,
+    Guadeloupe,
+    EquatorialGuinea,
+    Greece,
+    SouthGeorgiaAndSouthSandwichIslands,
+    Guatemala,
+    Guam,
+    GuineaBissau,
+    Guyana,
+    HongKong,
+    HeardIslandAndMcDonaldIslands,
+    Honduras,
+    Croatia,
+    Haiti,
+    Hungary,
+    Indonesia,
+    Ireland,
+    Israel,
+    IsleOfMan,
+    India,
+    BritishIndianOceanTerritory,
+    Iraq,
+    Iran,
+    Iceland,
+    Italy,
+    Jersey,
+    Jamaica,
+    Jordan,
+    Japan,
+    Kenya,
+    Kyrgyzstan,
+    Cambodia,
+    Kiribati,
+    Comoros,
+    SaintKittsAndNevis,
+    NorthKorea,
+    SouthKorea,
+    Kuwait,
+    CaymanIslands,
+    Kazakhstan,
+    Laos,
+    Lebanon,
+    SaintLucia,
+    Liechtenstein,
+    SriLanka,
+    Liberia,
+    Lesotho,
+    Lithuania,
+    Luxembourg,
+    Latvia,
+    Libya,
+    Morocco,
+    Monaco,
+    Moldova,
+    Montenegro,
+    SaintMartin,
+    Madagascar,
+    MarshallIslands,
+    NorthMacedonia,
+    Mali,
+    Myanmar,
+    Mongolia,
+    Macao,
+    NorthernMarianaIslands,
+    Martinique,
+    Mauritania,
+    Montserrat,
+    Malta,
+    Mauritius,
+    Maldives,
+    Malawi,
+    Mexico,
+    Malaysia,
+    Mozambique,
+    Namibia,
+    NewCaledonia,
+    Niger,
+    NorfolkIsland,
+    Nigeria,
+    Nicaragua,
+    Netherlands,
+    Norway,
+    Nepal,
+    Nauru,
+    Niue,
+    NewZealand,
+    Oman,
+    Panama,
+    Peru,
+    FrenchPolynesia,
+    PapuaNewGuinea,
+    Philippines,
+    Pakistan,
+    Poland,
+    SaintPierreAndMiquelon,
+    Pitcairn,
+    PuertoRico,
+    Palestine,
+    Portugal,
+    Palau,
+    Paraguay,
+    Qatar,
+    Reunion,
+    Romania,
+    Serbia,
+    Russia,
+    Rwanda,
+    SaudiArabia,
+    SolomonIslands,
+    Seychelles,
+    Sudan,
+    Sweden,
+    Singapore,
+    SaintHelena,
+    Slovenia,
+    SvalbardAndJanMayen,
+    Slovakia,
+    SierraLeone,
+    SanMarino,
+    Senegal,
+    Somalia,
+    Suriname,
+    SouthSudan,
+    SaoTomeAndPrincipe,
+    ElSalvador,
+    SintMaarten,
+    Syria,
+    Eswatini,
+    TurksAndCaicosIslands,
+    Chad,
+    FrenchSouthernTerritories,
+    Togo,
+    Thailand,
+    Tajikistan,
+    Tokelau,
+    TimorLeste,
+    Turkmenistan,
+    Tunisia,
+    Tonga,
+    Turkey,
+    TrinidadAndTobago,
+    Tuvalu,
+    Taiwan,
+    Tanzania,
+    Ukraine,
+    Uganda,
+    UnitedStatesMinorOutlyingIslands,
+    UnitedStates,
+    Uruguay,
+    Uzbekistan,
+    VaticanCity,
+    SaintVincentAndTheGrenadines,
+    Venezuela,
+    BritishVirginIslands,
+    UsVirginIslands,
+    VietNam,
+    Vanuatu,
+    WallisAndFutuna,
+    Samoa,
+    Yemen,
+    Mayotte,
+    SouthAfrica,
+    Zambia,
+    Zimbabwe,
+    Unknown(String)
+}
+
+use self::Country::*;
+impl Country {
+    /// The ISO 3166-1 alpha-2 code, e.g. "US".
+    pub fn code(&self) -> &str {
+        match *self {
+            Andorra                             => "AD",
+            UnitedArabEmirates                  => "AE",
+            Afghanistan                         => "AF",
+            AntiguaAndBarbuda                   => "AG",
+            Anguilla                            => "AI",
+            Albania                             => "AL",
+            Armenia                             => "AM",
+            Angola                              => "AO",
+            Antarctica                          => "AQ",
+            Argentina                           => "AR",
+            AmericanSamoa                       => "AS",
+            Austria                             => "AT",
+            Australia                           => "AU",
+            Aruba                               => "AW",
+            AlandIslands                        => "AX",
+            Azerbaijan                          => "AZ",
+            BosniaAndHerzegovina                => "BA",
+            Barbados                            => "BB",
+            Bangladesh                          => "BD",
+            Belgium                             => "BE",
+            BurkinaFaso                         => "BF",
+            Bulgaria                            => "BG",
+            Bahrain                             => "BH",
+            Burundi                             => "BI",
+            Benin                               => "BJ",
+            SaintBarthelemy                     => "BL",
+            Bermuda                             => "BM",
+            Brunei                              => "BN",
+            Bolivia                             => "BO",
+            CaribbeanNetherlands                => "BQ",
+            Brazil                              => "BR",
+            Bahamas                             => "BS",
+            Bhutan                              => "BT",
+            BouvetIsland                        => "BV",
+            Botswana                            => "BW",
+            Belarus                             => "BY",
+            Belize                              => "BZ",
+            Canada                              => "CA",
+            CocosIslands                        => "CC",
+            CongoKinshasa                       => "CD",
+            CentralAfricanRepublic              => "CF",
+            CongoBrazzaville                    => "CG",
+            Switzerland                         => "CH",
+            CoteDIvoire                         => "CI",
+            CookIslands                         => "CK",
+            Chile                               => "CL",
+            Cameroon                            => "CM",
+            China                               => "CN",
+            Colombia                            => "CO",
+            CostaRica                           => "CR",
+            Cuba                                => "CU",
+            CapeVerde                           => "CV",
+            Curacao                             => "CW",
+            ChristmasIsland                     => "CX",
+            Cyprus                              => "CY",
+            Czechia                             => "CZ",
+            Germany                             => "DE",
+            Djibouti                            => "DJ",
+            Denmark                             => "DK",
+            Dominica                            => "DM",
+            DominicanRepublic                   => "DO",
+            Algeria                             => "DZ",
+            Ecuador                             => "EC",
+            Estonia                             => "EE",
+            Egypt                               => "EG",
+            WesternSahara                       => "EH",
+            Eritrea                             => "ER",
+            Spain                               => "ES",
+            Ethiopia                            => "ET",
+            Finland                             => "FI",
+            Fiji                                => "FJ",
+            FalklandIslands                     => "FK",
+            Micronesia                          => "FM",
+            FaroeIslands                        => "FO",
+            France                              => "FR",
+            Gabon                               => "GA",
+            UnitedKingdom                       => "GB",
+            Grenada                             => "GD",
+            Georgia                             => "GE",
+            FrenchGuiana                        => "GF",
+            Guernsey                            => "GG",
+            Ghana                               => "GH",
+            Gibraltar                           => "GI",
+            Greenland                           => "GL",
+            Gambia                              => "GM",
+            Guinea                              => "GN",
+            Guadeloupe                          => "GP",
+            EquatorialGuinea                    => "GQ",
+            Greece                              => "GR",
+            SouthGeorgiaAndSouthSandwichIslands => "GS",
+            Guatemala                           => "GT",
+            Guam                                => "GU",
+            GuineaBissau                        => "GW",
+            Guyana                              => "GY",
+            HongKong                            => "HK",
+            HeardIslandAndMcDonaldIslands       => "HM",
+            Honduras                            => "HN",
+            Croatia                             => "HR",
+            Haiti                               => "HT",
+            Hungary                             => "HU",
+            Indonesia                           => "ID",
+            Ireland                             => "IE",
+            Israel                              => "IL",
+            IsleOfMan                           => "IM",
+            India                               => "IN",
+            BritishIndianOceanTerritory         => "IO",
+            Iraq                                => "IQ",
+            Iran                                => "IR",
+            Iceland                             => "IS",
+            Italy                               => "IT",
+            Jersey                              => "JE",
+            Jamaica                             => "JM",
+            Jordan                              => "JO",
+            Japan                               => "JP",
+            Kenya                               => "KE",
+            Kyrgyzstan                          => "KG",
+            Cambodia                            => "KH",
+            Kiribati                            => "KI",
+            Comoros                             => "KM",
+            SaintKittsAndNevis                  => "KN",
+            NorthKorea                          => "KP",
+            SouthKorea                          => "KR",
+            Kuwait                              => "KW",
+            CaymanIslands                       => "KY",
+            Kazakhstan                          => "KZ",
+            Laos                                => "LA",
+            Lebanon                             => "LB",
+            SaintLucia                          => "LC",
+            Liechtenstein                       => "LI",
+            SriLanka                            => "LK",
+            Liberia                             => "LR",
+            Lesotho                             => "LS",
+            Lithuania                           => "LT",
+            Luxembourg                          => "LU",
+            Latvia                              => "LV",
+            Libya                               => "LY",
+            Morocco                             => "MA",
+            Monaco                              => "MC",
+            Moldova                             => "MD",
+            Montenegro                          => "ME",
+            SaintMartin                         => "MF",
+            Madagascar                          => "MG",
+            MarshallIslands                     => "MH",
+            NorthMacedonia                      => "MK",
+            Mali                                => "ML",
+            Myanmar                             => "MM",
+            Mongolia                            => "MN",
+            Macao                               => "MO",
+            NorthernMarianaIslands              => "MP",
+            Martinique                          => "MQ",
+            Mauritania                          => "MR",
+            Montserrat                          => "MS",
+            Malta                               => "MT",
+            Mauritius                           => "MU",
+            Maldives                            => "MV",
+            Malawi                              => "MW",
+            Mexico                              => "MX",
+            Malaysia                            => "MY",
+            Mozambique                          => "MZ",
+            Namibia                             => "NA",
+            NewCaledonia                        => "NC",
+            Niger                               => "NE",
+            NorfolkIsland                       => "NF",
+            Nigeria                             => "NG",
+            Nicaragua                           => "NI",
+            Netherlands                         => "NL",
+            Norway                              => "NO",
+            Nepal                               => "NP",
+            Nauru                               => "NR",
+            Niue                                => "NU",
+            NewZealand                          => "NZ",
+            Oman                                => "OM",
+            Panama                              => "PA",
+            Peru                                => "PE",
+            FrenchPolynesia                     => "PF",
+            PapuaNewGuinea                      => "PG",
+            Philippines                         => "PH",
+            Pakistan                            => "PK",
+            Poland                              => "PL",
+            SaintPierreAndMiquelon              => "PM",
+            Pitcairn                            => "PN",
+            PuertoRico                          => "PR",
+            Palestine                           => "PS",
+            Portugal                            => "PT",
+            Palau                               => "PW",
+            Paraguay                            => "PY",
+            Qatar                               => "QA",
+            Reunion                             => "RE",
+            Romania                             => "RO",
+            Serbia                              => "RS",
+            Russia                              => "RU",
+            Rwanda                              => "RW",
+            SaudiArabia                         => "SA",
+            SolomonIslands                      => "SB",
+            Seychelles                          => "SC",
+            Sudan                               => "SD",
+            Sweden                              => "SE",
+            Singapore                           => "SG",
+            SaintHelena                         => "SH",
+            Slovenia                            => "SI",
+            SvalbardAndJanMayen                 => "SJ",
+            Slovakia                            => "SK",
+            SierraLeone                         => "SL",
+            SanMarino                           => "SM",
+            Senegal                             => "SN",
+            Somalia                             => "SO",
+            Suriname                            => "SR",
+            SouthSudan                          => "SS",
+            SaoTomeAndPrincipe                  => "ST",
+            ElSalvador                          => "SV",
+            SintMaarten                         => "SX",
+            Syria                               => "SY",
+            Eswatini                            => "SZ",
+            TurksAndCaicosIslands               => "TC",
+            Chad                                => "TD",
+            FrenchSouthernTerritories           => "TF",
+            Togo                                => "TG",
+            Thailand                            => "TH",
+            Tajikistan                          => "TJ",
+            Tokelau                             => "TK",
+            TimorLeste                          => "TL",
+            Turkmenistan                        => "TM",
+            Tunisia                             => "TN",
+            Tonga                               => "TO",
+            Turkey                              => "TR",
+            TrinidadAndTobago                   => "TT",
+            Tuvalu                              => "TV",
+            Taiwan                              => "TW",
+            Tanzania                            => "TZ",
+            Ukraine                             => "UA",
+            Uganda                              => "UG",
+            UnitedStatesMinorOutlyingIslands    => "UM",
+            UnitedStates                        => "US",
+            Uruguay                             => "UY",
+            Uzbekistan                          => "UZ",
+            VaticanCity                         => "VA",
+            SaintVincentAndTheGrenadines        => "VC",
+            Venezuela                           => "VE",
+            BritishVirginIslands                => "VG",
+            UsVirginIslands                     => "VI",
+            VietNam                             => "VN",
+            Vanuatu                             => "VU",
+            WallisAndFutuna                     => "WF",
+            Samoa                               => "WS",
+            Yemen                               => "YE",
+            Mayotte                             => "YT",
+            SouthAfrica                         => "ZA",
+            Zambia                              => "ZM",
+            Zimbabwe                            => "ZW",
+            Unknown(ref code)                   => code.as_str(),
+        }
+    }
+
+    /// The ISO 3166-1 short name in English. `None` for unknown countries.
+    pub fn name(&self) -> Option<&'static str> {
+        Some(match *self {
+            Andorra                             => "Andorra",
+            UnitedArabEmirates                  => "United Arab Emirates",
+            Afghanistan                         => "Afghanistan",
+            AntiguaAndBarbuda                   => "Antigua and Barbuda",
+            Anguilla                            => "Anguilla",
+            Albania                             => "Albania",
+            Armenia                             => "Armenia",
+            Angola                              => "Angola",
+            Antarctica                          => "Antarctica",
+            Argentina                           => "Argentina",
+            AmericanSamoa                       => "American Samoa",
+            Austria                             => "Austria",
+            Australia                           => "Australia",
+            Aruba                               => "Aruba",
+            AlandIslands                        => "Åland Islands",
+            Azerbaijan                          => "Azerbaijan",
+            BosniaAndHerzegovina                => "Bosnia and Herzegovina",
+            Barbados                            => "Barbados",
+            Bangladesh                          => "Bangladesh",
+            Belgium                             => "Belgium",
+            BurkinaFaso                         => "Burkina Faso",
+            Bulgaria                            => "Bulgaria",
+            Bahrain                             => "Bahrain",
+            Burundi                             => "Burundi",
+            Benin                               => "Benin",
+            SaintBarthelemy                     => "Saint Barthélemy",
+            Bermuda                             => "Bermuda",
+            Brunei                              => "Brunei",
+            Bolivia                             => "Bolivia",
+            CaribbeanNetherlands                => "Bonaire, Sint Eustatius and Saba",
+            Brazil                              => "Brazil",
+            Bahamas                             => "Bahamas",
+            Bhutan                              => "Bhutan",
+            BouvetIsland                        => "Bouvet Island",
+            Botswana                            => "Botswana",
+            Belarus                             => "Belarus",
+            Belize                              => "Belize",
+            Canada                              => "Canada",
+            CocosIslands                        => "Cocos (Keeling) Islands",
+            CongoKinshasa                       => "Congo (Kinshasa)",
+            CentralAfricanRepublic              => "Central African Republic",
+            CongoBrazzaville                    => "Congo (Brazzaville)",
+            Switzerland                         => "Switzerland",
+            CoteDIvoire                         => "Côte d'Ivoire",
+            CookIslands                         => "Cook Islands",
+            Chile                               => "Chile",
+            Cameroon                            => "Cameroon",
+            China                               => "China",
+            Colombia                            => "Colombia",
+            CostaRica                           => "Costa Rica",
+            Cuba                                => "Cuba",
+            CapeVerde                           => "Cape Verde",
+            Curacao                             => "Curaçao",
+            ChristmasIsland                     => "Christmas Island",
+            Cyprus                              => "Cyprus",
+            Czechia                             => "Czechia",
+            Germany                             => "Germany",
+            Djibouti                            => "Djibouti",
+            Denmark                             => "Denmark",
+            Dominica                            => "Dominica",
+            DominicanRepublic                   => "Dominican Republic",
+            Algeria                             => "Algeria",
+            Ecuador                             => "Ecuador",
+            Estonia                             => "Estonia",
+            Egypt                               => "Egypt",
+            WesternSahara                       => "Western Sahara",
+            Eritrea                             => "Eritrea",
+            Spain                               => "Spain",
+            Ethiopia                            => "Ethiopia",
+            Finland                             => "Finland",
+            Fiji                                => "Fiji",
+            FalklandIslands                     => "Falkland Islands",
+            Micronesia                          => "Micronesia",
+            FaroeIslands                        => "Faroe Islands",
+            France                              => "France",
+            Gabon                               => "Gabon",
+            UnitedKingdom                       => "United Kingdom",
+            Grenada                             => "Grenada",
+            Georgia                             => "Georgia",
+            FrenchGuiana                        => "French Guiana",
+            Guernsey                            => "Guernsey",
+            Ghana                               => "Ghana",
+            Gibraltar                           => "Gibraltar",
+            Greenland                           => "Greenland",
+            Gambia                              => "Gambia",
+            Guinea                              => "Guinea",
+            Guadeloupe                          => "Guadeloupe",
+            EquatorialGuinea                    => "Equatorial Guinea",
+            Greece                              => "Greece",
+            SouthGeorgiaAndSouthSandwichIslands => "South Georgia and the South Sandwich Islands",
+            Guatemala                           => "Guatemala",
+            Guam                                => "Guam",
+            GuineaBissau                        => "Guinea-Bissau",
+            Guyana                              => "Guyana",
+            HongKong                            => "Hong Kong",
+            HeardIslandAndMcDonaldIslands       => "Heard Island and McDonald Islands",
+            Honduras                            => "Honduras",
+            Croatia                             => "Croatia",
+            Haiti                               => "Haiti",
+            Hungary                             => "Hungary",
+            Indonesia                           => "Indonesia",
+            Ireland                             => "Ireland",
+            Israel                              => "Israel",
+            IsleOfMan                           => "Isle of Man",
+            India                               => "India",
+            BritishIndianOceanTerritory         => "British Indian Ocean Territory",
+            Iraq                                => "Iraq",
+            Iran                                => "Iran",
+            Iceland                             => "Iceland",
+            Italy                               => "Italy",
+            Jersey                              => "Jersey",
+            Jamaica                             => "Jamaica",
+            Jordan                              => "Jordan",
+            Japan                               => "Japan",
+            Kenya                               => "Kenya",
+            Kyrgyzstan                          => "Kyrgyzstan",
+            Cambodia                            => "Cambodia",
+            Kiribati                            => "Kiribati",
+            Comoros                             => "Comoros",
+            SaintKittsAndNevis                  => "Saint Kitts and Nevis",
+            NorthKorea                          => "North Korea",
+            SouthKorea                          => "South Korea",
+            Kuwait                              => "Kuwait",
+            CaymanIslands                       => "Cayman Islands",
+            Kazakhstan                          => "Kazakhstan",
+            Laos                                => "Laos",
+            Lebanon                             => "Lebanon",
+            SaintLucia                          => "Saint Lucia",
+            Liechtenstein                       => "Liechtenstein",
+            SriLanka                            => "Sri Lanka",
+            Liberia                             => "Liberia",
+            Lesotho                             => "Lesotho",
+            Lithuania                           => "Lithuania",
+            Luxembourg                          => "Luxembourg",
+            Latvia                              => "Latvia",
+            Libya                               => "Libya",
+            Morocco                             => "Morocco",
+            Monaco                              => "Monaco",
+            Moldova                             => "Moldova",
+            Montenegro                          => "Montenegro",
+            SaintMartin                         => "Saint Martin (French part)",
+            Madagascar                          => "Madagascar",
+            MarshallIslands                     => "Marshall Islands",
+            NorthMacedonia                      => "North Macedonia",
+            Mali                                => "Mali",
+            Myanmar                             => "Myanmar",
+            Mongolia                            => "Mongolia",
+            Macao                               => "Macao",
+            NorthernMarianaIslands              => "Northern Mariana Islands",
+            Martinique                          => "Martinique",
+            Mauritania                          => "Mauritania",
+            Montserrat                          => "Montserrat",
+            Malta                               => "Malta",
+            Mauritius                           => "Mauritius",
+            Maldives                            => "Maldives",
+            Malawi                              => "Malawi",
+            Mexico                              => "Mexico",
+            Malaysia                            => "Malaysia",
+            Mozambique                          => "Mozambique",
+            Namibia                             => "Namibia",
+            NewCaledonia                        => "New Caledonia",
+            Niger                               => "Niger",
+            NorfolkIsland                       => "Norfolk Island",
+            Nigeria                             => "Nigeria",
+            Nicaragua                           => "Nicaragua",
+            Netherlands                         => "Netherlands",
+            Norway                              => "Norway",
+            Nepal                               => "Nepal",
+            Nauru                               => "Nauru",
+            Niue                                => "Niue",
+            NewZealand                          => "New Zealand",
+            Oman                                => "Oman",
+            Panama                              => "Panama",
+            Peru                                => "Peru",
+            FrenchPolynesia                     => "French Polynesia",
+            PapuaNewGuinea                      => "Papua New Guinea",
+            Philippines                         => "Philippines",
+            Pakistan                            => "Pakistan",
+            Poland                              => "Poland",
+            SaintPierreAndMiquelon              => "Saint Pierre and Miquelon",
+            Pitcairn                            => "Pitcairn",
+            PuertoRico                          => "Puerto Rico",
+            Palestine                           => "Palestine",
+            Portugal                            => "Portugal",
+            Palau                               => "Palau",
+            Paraguay                            => "Paraguay",
+            Qatar                               => "Qatar",
+            Reunion                             => "Réunion",
+            Romania                             => "Romania",
+            Serbia                              => "Serbia",
+            Russia                              => "Russia",
+            Rwanda                              => "Rwanda",
+            SaudiArabia                         => "Saudi Arabia",
+            SolomonIslands                      => "Solomon Islands",
+            Seychelles                          => "Seychelles",
+            Sudan                               => "Sudan",
+            Sweden                              => "Sweden",
+            Singapore                           => "Singapore",
+            SaintHelena                         => "Saint Helena, Ascension and Tristan da Cunha",
+            Slovenia                            => "Slovenia",
+            SvalbardAndJanMayen                 => "Svalbard and Jan Mayen",
+            Slovakia                            => "Slovakia",
+            SierraLeone                         => "Sierra Leone",
+            SanMarino                           => "San Marino",
+            Senegal                             => "Senegal",
+            Somalia                             => "Somalia",
+            Suriname                            => "Suriname",
+            SouthSudan                          => "South Sudan",
+            SaoTomeAndPrincipe                  => "São Tomé and Príncipe",
+            ElSalvador                          => "El Salvador",
+            SintMaarten                         => "Sint Maarten (Dutch part)",
+            Syria                               => "Syria",
+            Eswatini                            => "Eswatini",
+            TurksAndCaicosIslands               => "Turks and Caicos Islands",
+            Chad                                => "Chad",
+            FrenchSouthernTerritories           => "French Southern Territories",
+            Togo                                => "Togo",
+            Thailand                            => "Thailand",
+            Tajikistan                          => "Tajikistan",
+            Tokelau                             => "Tokelau",
+            TimorLeste                          => "Timor-Leste",
+            Turkmenistan                        => "Turkmenistan",
+            Tunisia                             => "Tunisia",
+            Tonga                               => "Tonga",
+            Turkey                              => "Turkey",
+            TrinidadAndTobago                   => "Trinidad and Tobago",
+            Tuvalu                              => "Tuvalu",
+            Taiwan                              => "Taiwan",
+            Tanzania                            => "Tanzania",
+            Ukraine                             => "Ukraine",
+            Uganda                              => "Uganda",
+            UnitedStatesMinorOutlyingIslands    => "United States Minor Outlying Islands",
+            UnitedStates                        => "United States",
+            Uruguay                             => "Uruguay",
+            Uzbekistan                          => "Uzbekistan",
+            VaticanCity                         => "Vatican City",
+            SaintVincentAndTheGrenadines        => "Saint Vincent and the Grenadines",
+            Venezuela                           => "Venezuela",
+            BritishVirginIslands                => "British Virgin Islands",
+            UsVirginIslands                     => "U.S. Virgin Islands",
+            VietNam                             => "Viet Nam",
+            Vanuatu                             => "Vanuatu",
+            WallisAndFutuna                     => "Wallis and Futuna",
+            Samoa                               => "Samoa",
+            Yemen                               => "Yemen",
+            Mayotte                             => "Mayotte",
+            SouthAfrica                         => "South Africa",
+            Zambia                              => "Zambia",
+            Zimbabwe                            => "Zimbabwe",
+            Unknown(_)                          => return None,
+        })
+    }
+
+    /// Whether addresses in the country need a state, province or
+    /// prefecture.
+    pub fn requires_state(&self) -> bool {
+        match *self {
+            Australia | Brazil | Canada | India | Japan | Mexico | UnitedStates => true,
+            _ => false
+        }
+    }
+
+    /// The codes and names a `state` must be one of, for countries where
+    /// Stripe checks them. `None` when any non-empty state will do.
+    pub fn states(&self) -> Option<&'static [(&'static str, &'static str)]> {
+        match *self {
+            Australia => Some(&[
+                ("ACT", "Australian Capital Territory"), ("NSW", "New South Wales"),
+                ("NT", "Northern Territory"), ("QLD", "Queensland"), ("SA", "South Australia"),
+                ("TAS", "Tasmania"), ("VIC", "Victoria"), ("WA", "Western Australia")
+            ]),
+            Canada => Some(&[
+                ("AB", "Alberta"), ("BC", "British Columbia"), ("MB", "Manitoba"),
+                ("NB", "New Brunswick"), ("NL", "Newfoundland and Labrador"), ("NS", "Nova Scotia"),
+                ("NT", "Northwest Territories"), ("NU", "Nunavut"), ("ON", "Ontario"),
+                ("PE", "Prince Edward Island"), ("QC", "Quebec"), ("SK", "Saskatchewan"),
+                ("YT", "Yukon")
+            ]),
+            UnitedStates => Some(&[
+                ("AL", "Alabama"), ("AK", "Alaska"), ("AZ", "Arizona"), ("AR", "Arkansas"),
+                ("CA", "California"), ("CO", "Colorado"), ("CT", "Connecticut"), ("DE", "Delaware"),
+                ("FL", "Florida"), ("GA", "Georgia"), ("HI", "Hawaii"), ("ID", "Idaho"),
+                ("IL", "Illinois"), ("IN", "Indiana"), ("IA", "Iowa"), ("KS", "Kansas"),
+                ("KY", "Kentucky"), ("LA", "Louisiana"), ("ME", "Maine"), ("MD", "Maryland"),
+                ("MA", "Massachusetts"), ("MI", "Michigan"), ("MN", "Minnesota"),
+                ("MS", "Mississippi"), ("MO", "Missouri"), ("MT", "Montana"), ("NE", "Nebraska"),
+                ("NV", "Nevada"), ("NH", "New Hampshire"), ("NJ", "New Jersey"),
+                ("NM", "New Mexico"), ("NY", "New York"), ("NC", "North Carolina"),
+                ("ND", "North Dakota"), ("OH", "Ohio"), ("OK", "Oklahoma"), ("OR", "Oregon"),
+                ("PA", "Pennsylvania"), ("RI", "Rhode Island"), ("SC", "South Carolina"),
+                ("SD", "South Dakota"), ("TN", "Tennessee"), ("TX", "Texas"), ("UT", "Utah"),
+                ("VT", "Vermont"), ("VA", "Virginia"), ("WA", "Washington"),
+                ("WV", "West Virginia"), ("WI", "Wisconsin"), ("WY", "Wyoming"),
+                ("DC", "District of Columbia"), ("AS", "American Samoa"), ("GU", "Guam"),
+                ("MP", "Northern Mariana Islands"), ("PR", "Puerto Rico"),
+                ("VI", "U.S. Virgin Islands"), ("UM", "U.S. Minor Outlying Islands"),
+                ("AA", "Armed Forces Americas"), ("AE", "Armed Forces Europe"),
+                ("AP", "Armed Forces Pacific")
+            ]),
+            _ => None
+        }
+    }
+
+    /// The code of the state `state` names, given as either its code or its
+    /// name in any case. `None` when `state` isn't one of the country's
+    /// `states`, or the country doesn't list them.
+    pub fn state_code(&self, state: &str) -> Option<&'static str> {
+        let state = state.trim().to_lowercase();
+        self.states().and_then(|states| {
+            states.iter()
+                .find(|&&(code, name)| code.to_lowercase() == state || name.to_lowercase() == state)
+                .map(|&(code, _)| code)
+        })
+    }
+
+    /// The shapes a postal code in the country takes, where `9` is any
+    /// digit, `A` any letter and anything else itself; spaces don't count.
+    /// Empty for countries whose addresses don't need one.
+    pub fn postal_code_formats(&self) -> &'static [&'static str] {
+        match *self {
+            Australia | Austria | Belgium | Denmark | Luxembourg | NewZealand | Norway |
+            Switzerland => &["9999"],
+            Brazil => &["99999-999", "99999999"],
+            Canada => &["A9A 9A9"],
+            France | Germany | Italy | Mexico | Spain | Finland | Sweden => &["99999"],
+            India | Singapore => &["999999"],
+            Japan => &["999-9999", "9999999"],
+            Netherlands => &["9999 AA"],
+            Poland => &["99-999"],
+            Portugal => &["9999-999"],
+            UnitedKingdom => &["A9 9AA", "A99 9AA", "AA9 9AA", "AA99 9AA", "A9A 9AA", "AA9A 9AA"],
+            UnitedStates => &["99999", "99999-9999"],
+            _ => &[]
+        }
+    }
+
+    /// The country with `code`, in any case. Unlike deserializing, gives
+    /// `None` rather than `Unknown` for codes this crate doesn't know.
+    pub fn from_code(code: &str) -> Option<Country> {
+        Some(match code.to_uppercase().as_ref() {
+            "AD" => Andorra,
+            "AE" => UnitedArabEmirates,
+            "AF" => Afghanistan,
+            "AG" => AntiguaAndBarbuda,
+            "AI" => Anguilla,
+            "AL" => Albania,
+            "AM" => Armenia,
+            "AO" => Angola,
+            "AQ" => Antarctica,
+            "AR" => Argentina,
+            "AS" => AmericanSamoa,
+            "AT" => Austria,
+            "AU" => Australia,
+            "AW" => Aruba,
+            "AX" => AlandIslands,
+            "AZ" => Azerbaijan,
+            "BA" => BosniaAndHerzegovina,
+            "BB" => Barbados,
+            "BD" => Bangladesh,
+            "BE" => Belgium,
+            "BF" => BurkinaFaso,
+            "BG" => Bulgaria,
+            "BH" => Bahrain,
+            "BI" => Burundi,
+            "BJ" => Benin,
+            "BL" => SaintBarthelemy,
+            "BM" => Bermuda,
+            "BN" => Brunei,
+            "BO" => Bolivia,
+            "BQ" => CaribbeanNetherlands,
+            "BR" => Brazil,
+            "BS" => Bahamas,
+            "BT" => Bhutan,
+            "BV" => BouvetIsland,
+            "BW" => Botswana,
+            "BY" => Belarus,
+            "BZ" => Belize,
+            "CA" => Canada,
+            "CC" => CocosIslands,
+            "CD" => CongoKinshasa,
+            "CF" => CentralAfricanRepublic,
+            "CG" => CongoBrazzaville,
+            "CH" => Switzerland,
+            "CI" => CoteDIvoire,
+            "CK" => CookIslands,
+            "CL" => Chile,
+            "CM" => Cameroon,
+            "CN" => China,
+            "CO" => Colombia,
+            "CR" => CostaRica,
+            "CU" => Cuba,
+            "CV" => CapeVerde,
+            "CW" => Curacao,
+            "CX" => ChristmasIsland,
+            "CY" => Cyprus,
+            "CZ" => Czechia,
+            "DE" => Germany,
+            "DJ" => Djibouti,
+            "DK" => Denmark,
+            "DM" => Dominica,
+            "DO" => DominicanRepublic,
+            "DZ" => Algeria,
+            "EC" => Ecuador,
+            "EE" => Estonia,
+            "EG" => Egypt,
+            "EH" => WesternSahara,
+            "ER" => Eritrea,
+            "ES" => Spain,
+            "ET" => Ethiopia,
+            "FI" => Finland,
+            "FJ" => Fiji,
+            "FK" => FalklandIslands,
+            "FM" => Micronesia,
+            "FO" => FaroeIslands,
+            "FR" => France,
+            "GA" => Gabon,
+            "GB" => UnitedKingdom,
+            "GD" => Grenada,
+            "GE" => Georgia,
+            "GF" => FrenchGuiana,
+            "GG" => Guernsey,
+            "GH" => Ghana,
+            "GI" => Gibraltar,
+            "GL" => Greenland,
+            "GM" => Gambia,
+            "GN" => Guinea,
+            "GP" => Guadeloupe,
+            "GQ" => EquatorialGuinea,
+            "GR" => Greece,
+            "GS" => SouthGeorgiaAndSouthSandwichIslands,
+            "GT" => Guatemala,
+            "GU" => Guam,
+            "GW" => GuineaBissau,
+            "GY" => Guyana,
+            "HK" => HongKong,
+            "HM" => HeardIslandAndMcDonaldIslands,
+            "HN" => Honduras,
+            "HR" => Croatia,
+            "HT" => Haiti,
+            "HU" => Hungary,
+            "ID" => Indonesia,
+            "IE" => Ireland,
+            "IL" => Israel,
+            "IM" => IsleOfMan,
+            "IN" => India,
+            "IO" => BritishIndianOceanTerritory,
+            "IQ" => Iraq,
+            "IR" => Iran,
+            "IS" => Iceland,
+            "IT" => Italy,
+            "JE" => Jersey,
+            "JM" => Jamaica,
+            "JO" => Jordan,
+            "JP" => Japan,
+            "KE" => Kenya,
+            "KG" => Kyrgyzstan,
+            "KH" => Cambodia,
+            "KI" => Kiribati,
+            "KM" => Comoros,
+            "KN" => SaintKittsAndNevis,
+            "KP" => NorthKorea,
+            "KR" => SouthKorea,
+            "KW" => Kuwait,
+            "KY" => CaymanIslands,
+            "KZ" => Kazakhstan,
+            "LA" => Laos,
+            "LB" => Lebanon,
+            "LC" => SaintLucia,
+            "LI" => Liechtenstein,
+            "LK" => SriLanka,
+            "LR" => Liberia,
+            "LS" => Lesotho,
+            "LT" => Lithuania,
+            "LU" => Luxembourg,
+            "LV" => Latvia,
+            "LY" => Libya,
+            "MA" => Morocco,
+            "MC" => Monaco,
+            "MD" => Moldova,
+            "ME" => Montenegro,
+            "MF" => SaintMartin,
+            "MG" => Madagascar,
+            "MH" => MarshallIslands,
+            "MK" => NorthMacedonia,
+            "ML" => Mali,
+            "MM" => Myanmar,
+            "MN" => Mongolia,
+            "MO" => Macao,
+            "MP" => NorthernMarianaIslands,
+            "MQ" => Martinique,
+            "MR" => Mauritania,
+            "MS" => Montserrat,
+            "MT" => Malta,
+            "MU" => Mauritius,
+            "MV" => Maldives,
+            "MW" => Malawi,
+            "MX" => Mexico,
+            "MY" => Malaysia,
+            "MZ" => Mozambique,
+            "NA" => Namibia,
+            "NC" => NewCaledonia,
+            "NE" => Niger,
+            "NF" => NorfolkIsland,
+            "NG" => Nigeria,
+            "NI" => Nicaragua,
+            "NL" => Netherlands,
+            "NO" => Norway,
+            "NP" => Nepal,
+            "NR" => Nauru,
+            "NU" => Niue,
+            "NZ" => NewZealand,
+            "OM" => Oman,
+            "PA" => Panama,
+            "PE" => Peru,
+            "PF" => FrenchPolynesia,
+            "PG" => PapuaNewGuinea,
+            "PH" => Philippines,
+            "PK" => Pakistan,
+            "PL" => Poland,
+            "PM" => SaintPierreAndMiquelon,
+            "PN" => Pitcairn,
+            "PR" => PuertoRico,
+            "PS" => Palestine,
+            "PT" => Portugal,
+            "PW" => Palau,
+            "PY" => Paraguay,
+            "QA" => Qatar,
+            "RE" => Reunion,
+            "RO" => Romania,
+            "RS" => Serbia,
+            "RU" => Russia,
+            "RW" => Rwanda,
+            "SA" => SaudiArabia,
+            "SB" => SolomonIslands,
+            "SC" => Seychelles,
+            "SD" => Sudan,
+            "SE" => Sweden,
+            "SG" => Singapore,
+            "SH" => SaintHelena,
+            "SI" => Slovenia,
+            "SJ" => SvalbardAndJanMayen,
+            "SK" => Slovakia,
+            "SL" => SierraLeone,
+            "SM" => SanMarino,
+            "SN" => Senegal,
+            "SO" => Somalia,
+            "SR" => Suriname,
+            "SS" => SouthSudan,
+            "ST" => SaoTomeAndPrincipe,
+            "SV" => ElSalvador,
+            "SX" => SintMaarten,
+            "SY" => Syria,
+            "SZ" => Eswatini,
+            "TC" => TurksAndCaicosIslands,
+            "TD" => Chad,
+            "TF" => FrenchSouthernTerritories,
+            "TG" => Togo,
+            "TH" => Thailand,
+            "TJ" => Tajikistan,
+            "TK" => Tokelau,
+            "TL" => TimorLeste,
+            "TM" => Turkmenistan,
+            "TN" => Tunisia,
+            "TO" => Tonga,
+            "TR" => Turkey,
+            "TT" => TrinidadAndTobago,
+            "TV" => Tuvalu,
+            "TW" => Taiwan,
+            "TZ" => Tanzania,
+            "UA" => Ukraine,
+            "UG" => Uganda,
+            "UM" => UnitedStatesMinorOutlyingIslands,
+            "US" => UnitedStates,
+            "UY" => Uruguay,
+            "UZ" => Uzbekistan,
+            "VA" => VaticanCity,
+            "VC" => SaintVincentAndTheGrenadines,
+            "VE" => Venezuela,
+            "VG" => BritishVirginIslands,
+            "VI" => UsVirginIslands,
+            "VN" => VietNam,
+            "VU" => Vanuatu,
+            "WF" => WallisAndFutuna,
+            "WS" => Samoa,
+            "YE" => Yemen,
+            "YT" => Mayotte,
+            "ZA" => SouthAfrica,
+            "ZM" => Zambia,
+            "ZW" => Zimbabwe,
+            _    => return None
+        })
+    }
+}
+
+/// Compares by code, so an `Unknown` holding a known code equals the
+/// country it names.
+impl PartialEq for Country {
+    fn eq(&self, other: &Country) -> bool {
+        self.code() == other.code()
+    }
+}
+
+impl Eq for Country {}
+
+impl Hash for Country {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.code().hash(state)
+    }
+}
+
+/// Orders alphabetically by code.
+impl PartialOrd for Country {
+    fn partial_cmp(&self, other: &Country) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Country {
+    fn cmp(&self, other: &Country) -> Ordering {
+        self.code().cmp(other.code())
+    }
+}
+
+impl fmt::Display for Country {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// Parses a code in any case, e.g. "US" or "us".
+impl FromStr for Country {
+    type Err = CountryError;
+
+    fn from_str(code: &str) -> Result<Country, CountryError> {
+        Country::from_code(code).ok_or_else(|| CountryError { code: code.to_string() })
+    }
+}
+
+impl serde::Deserialize for Country {
+    fn deserialize<D>(deserializer: &mut D) -> Result<Country, D::Error>
+        where D: serde::Deserializer
+    {
+        let code = String::deserialize(deserializer)?;
+        Ok(match Country::from_code(&code) {
+            Some(country) => country,
+            None          => Unknown(schema_drift::unknown_value("Country", &code))
+        })
+    }
+}
+
+impl serde::Serialize for Country {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(self.code())
+    }
+}
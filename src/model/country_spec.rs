@@ -1,15 +1,26 @@
 use std::collections::BTreeMap;
+use super::country::Country;
 use super::currency::Currency;
 use super::StripeObject;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct CountrySpec {
-    pub id: String,
-    pub default_currency: Currency,
-    pub supported_bank_account_currencies: BTreeMap<String, Vec<String>>,
-    pub supported_payment_currencies: Vec<String>,
-    pub supported_payment_methods: Vec<String>,
-    pub verification_fields: BTreeMap<String, VerificationFields>,
+stripe_object! {
+    pub struct CountrySpec {
+        pub id: String,
+        pub default_currency: Currency,
+        pub supported_bank_account_currencies: BTreeMap<String, Vec<String>>,
+        pub supported_payment_currencies: Vec<String>,
+        pub supported_payment_methods: Vec<String>,
+        pub verification_fields: BTreeMap<String, VerificationFields>,
+    }
+}
+
+impl CountrySpec {
+    /// Whether Stripe pays out in `currency` to bank accounts in `country`
+    /// for accounts in this spec's country.
+    pub fn supports_bank_account_currency(&self, country: &Country, currency: &Currency) -> bool {
+        self.supported_bank_account_currencies.get(currency.code())
+            .map_or(false, |countries| countries.iter().any(|c| c == country.code()))
+    }
 }
 
 impl StripeObject for CountrySpec {
@@ -18,7 +29,7 @@ impl StripeObject for CountrySpec {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct VerificationFields {
     pub minimum: Vec<String>,
     pub additional: Vec<String>,
@@ -1,29 +1,33 @@
+use schema_drift;
 use serde;
 use std::collections::BTreeMap;
 use std::fmt;
 use super::currency::Currency;
+use super::id::CouponId;
 use super::StripeObject;
+use super::timestamp::Timestamp;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct Coupon {
-    pub id: String,
-    pub amount_off: Option<i64>,
-    pub created: i64,
-    pub currency: Currency,
-    pub duration: CouponDuration,
-    pub duration_in_months: Option<i64>,
-    pub livemode: bool,
-    pub max_redemptions: Option<i64>,
-    pub metadata: Option<BTreeMap<String, String>>,
-    pub percent_off: i64,
-    pub redeem_by: i64,
-    pub times_redeemed: i64,
-    pub valid: bool,
+stripe_object! {
+    pub struct Coupon {
+        pub id: CouponId,
+        pub amount_off: Option<i64>,
+        pub created: Timestamp,
+        pub currency: Option<Currency>,
+        pub duration: CouponDuration,
+        pub duration_in_months: Option<i64>,
+        pub livemode: bool,
+        pub max_redemptions: Option<i64>,
+        pub metadata: Option<BTreeMap<String, String>>,
+        pub percent_off: Option<i64>,
+        pub redeem_by: Option<Timestamp>,
+        pub times_redeemed: i64,
+        pub valid: bool,
+    }
 }
 
 impl StripeObject for Coupon {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
 
@@ -54,7 +58,15 @@ impl serde::Deserialize for CouponDuration {
             "forever"   => CouponDuration::Forever,
             "once"      => CouponDuration::Once,
             "repeating" => CouponDuration::Repeating,
-            unknown     => CouponDuration::Unknown(String::from(unknown)),
+            unknown     => CouponDuration::Unknown(schema_drift::unknown_value("CouponDuration", unknown)),
         })
     }
 }
+
+impl serde::Serialize for CouponDuration {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
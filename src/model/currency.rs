@@ -1,5 +1,10 @@
+use errors::currency_error::CurrencyError;
+use schema_drift;
 use serde;
+use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 
 #[derive(Clone, Debug)]
 pub enum Currency {
@@ -26,6 +31,7 @@ pub enum Currency {
     BahamasDollar,
     BhutanNgultrum,
     BotswanaPula,
+    /// Obsolete: replaced by the new Belarusian ruble (byn) in 2016.
     BelarusRuble,
     BelizeDollar,
     CanadaDollar,
@@ -35,6 +41,7 @@ pub enum Currency {
     ChinaYuanRenminbi,
     ColombiaPeso,
     CostaRicaColon,
+    /// Obsolete: withdrawn in 2021.
     CubaConvertiblePeso,
     CubaPeso,
     CapeVerdeEscudo,
@@ -60,6 +67,7 @@ pub enum Currency {
     GuyanaDollar,
     HongKongDollar,
     HondurasLempira,
+    /// Obsolete: replaced by the euro in 2023.
     CroatiaKuna,
     HaitiGourde,
     HungaryForint,
@@ -96,6 +104,7 @@ pub enum Currency {
     MyanmarKyat,
     MongoliaTughrik,
     MacauPataca,
+    /// Obsolete: replaced by the new ouguiya (mru) in 2018.
     MauritaniaOuguiya,
     MauritiusRupee,
     MaldivesRufiyaa,
@@ -131,8 +140,10 @@ pub enum Currency {
     SaintHelenaPound,
     SierraLeoneLeone,
     SomaliaShilling,
+    /// Obsolete: not legal tender, and never an ISO 4217 currency.
     SeborgaLuigino,
     SurinameDollar,
+    /// Obsolete: replaced by the new dobra (stn) in 2018.
     SaoTomeAndPrincipeDobra,
     ElSalvadorColon,
     SyriaPound,
@@ -152,6 +163,7 @@ pub enum Currency {
     UnitedStatesDollar,
     UruguayPeso,
     UzbekistanSom,
+    /// Obsolete: replaced by the bolívar soberano (ves) in 2018.
     VenezuelaBolivar,
     VietNamDong,
     VanuatuVatu,
@@ -163,185 +175,265 @@ pub enum Currency {
     YemenRial,
     SouthAfricaRand,
     ZambiaKwacha,
+    /// Obsolete: replaced by later Zimbabwe dollars in 2009.
     ZimbabweDollar,
     Unknown(String)
 }
 
 use self::Currency::*;
-impl fmt::Display for Currency {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+impl Currency {
+    /// The lowercase ISO 4217 code, as Stripe takes it.
+    pub fn code(&self) -> &str {
         match *self {
-            UnitedArabEmiratesDirham             => write!(f, "aed"),
-            AfghanistanAfghani                   => write!(f, "afn"),
-            AlbaniaLek                           => write!(f, "all"),
-            ArmeniaDram                          => write!(f, "amd"),
-            NetherlandsAntillesGuilder           => write!(f, "ang"),
-            AngolaKwanza                         => write!(f, "aoa"),
-            ArgentinaPeso                        => write!(f, "ars"),
-            AustraliaDollar                      => write!(f, "aud"),
-            ArubaGuilder                         => write!(f, "awg"),
-            AzerbaijanNewManat                   => write!(f, "azn"),
-            BosniaAndHerzegovinaConvertibleMarka => write!(f, "bam"),
-            BarbadosDollar                       => write!(f, "bbd"),
-            BangladeshTaka                       => write!(f, "bdt"),
-            BulgariaLev                          => write!(f, "bgn"),
-            BahrainDinar                         => write!(f, "bhd"),
-            BurundiFranc                         => write!(f, "bif"),
-            BermudaDollar                        => write!(f, "bmd"),
-            BruneiDarussalamDollar               => write!(f, "bnd"),
-            BoliviaBoliviano                     => write!(f, "bob"),
-            BrazilReal                           => write!(f, "brl"),
-            BahamasDollar                        => write!(f, "bsd"),
-            BhutanNgultrum                       => write!(f, "btn"),
-            BotswanaPula                         => write!(f, "bwp"),
-            BelarusRuble                         => write!(f, "byr"),
-            BelizeDollar                         => write!(f, "bzd"),
-            CanadaDollar                         => write!(f, "cad"),
-            CongoKinshasaFranc                   => write!(f, "cdf"),
-            SwitzerlandFranc                     => write!(f, "chf"),
-            ChilePeso                            => write!(f, "clp"),
-            ChinaYuanRenminbi                    => write!(f, "cny"),
-            ColombiaPeso                         => write!(f, "cop"),
-            CostaRicaColon                       => write!(f, "crc"),
-            CubaConvertiblePeso                  => write!(f, "cuc"),
-            CubaPeso                             => write!(f, "cup"),
-            CapeVerdeEscudo                      => write!(f, "cve"),
-            CzechRepublicKoruna                  => write!(f, "czk"),
-            DjiboutiFranc                        => write!(f, "djf"),
-            DenmarkKrone                         => write!(f, "dkk"),
-            DominicanRepublicPeso                => write!(f, "dop"),
-            AlgeriaDinar                         => write!(f, "dzd"),
-            EgyptPound                           => write!(f, "egp"),
-            EritreaNakfa                         => write!(f, "ern"),
-            EthiopiaBirr                         => write!(f, "etb"),
-            EuroMemberCountries                  => write!(f, "eur"),
-            FijiDollar                           => write!(f, "fjd"),
-            FalklandIslandsPound                 => write!(f, "fkp"),
-            UnitedKingdomPound                   => write!(f, "gbp"),
-            GeorgiaLari                          => write!(f, "gel"),
-            GuernseyPound                        => write!(f, "ggp"),
-            GhanaCedi                            => write!(f, "ghs"),
-            GibraltarPound                       => write!(f, "gip"),
-            GambiaDalasi                         => write!(f, "gmd"),
-            GuineaFranc                          => write!(f, "gnf"),
-            GuatemalaQuetzal                     => write!(f, "gtq"),
-            GuyanaDollar                         => write!(f, "gyd"),
-            HongKongDollar                       => write!(f, "hkd"),
-            HondurasLempira                      => write!(f, "hnl"),
-            CroatiaKuna                          => write!(f, "hrk"),
-            HaitiGourde                          => write!(f, "htg"),
-            HungaryForint                        => write!(f, "huf"),
-            IndonesiaRupiah                      => write!(f, "idr"),
-            IsraelShekel                         => write!(f, "ils"),
-            IsleOfManPound                       => write!(f, "imp"),
-            IndiaRupee                           => write!(f, "inr"),
-            IraqDinar                            => write!(f, "iqd"),
-            IranRial                             => write!(f, "irr"),
-            IcelandKrona                         => write!(f, "isk"),
-            JerseyPound                          => write!(f, "jep"),
-            JamaicaDollar                        => write!(f, "jmd"),
-            JordanDinar                          => write!(f, "jod"),
-            JapanYen                             => write!(f, "jpy"),
-            KenyaShilling                        => write!(f, "kes"),
-            KyrgyzstanSom                        => write!(f, "kgs"),
-            CambodiaRiel                         => write!(f, "khr"),
-            ComorosFranc                         => write!(f, "kmf"),
-            NorthKoreaWon                        => write!(f, "kpw"),
-            SouthKoreaWon                        => write!(f, "krw"),
-            KuwaitDinar                          => write!(f, "kwd"),
-            CaymanIslandsDollar                  => write!(f, "kyd"),
-            KazakhstanTenge                      => write!(f, "kzt"),
-            LaosKip                              => write!(f, "lak"),
-            LebanonPound                         => write!(f, "lbp"),
-            SriLankaRupee                        => write!(f, "lkr"),
-            LiberiaDollar                        => write!(f, "lrd"),
-            LesothoLoti                          => write!(f, "lsl"),
-            LibyaDinar                           => write!(f, "lyd"),
-            MoroccoDirham                        => write!(f, "mad"),
-            MoldovaLeu                           => write!(f, "mdl"),
-            MadagascarAriary                     => write!(f, "mga"),
-            MacedoniaDenar                       => write!(f, "mkd"),
-            MyanmarKyat                          => write!(f, "mmk"),
-            MongoliaTughrik                      => write!(f, "mnt"),
-            MacauPataca                          => write!(f, "mop"),
-            MauritaniaOuguiya                    => write!(f, "mro"),
-            MauritiusRupee                       => write!(f, "mur"),
-            MaldivesRufiyaa                      => write!(f, "mvr"),
-            MalawiKwacha                         => write!(f, "mwk"),
-            MexicoPeso                           => write!(f, "mxn"),
-            MalaysiaRinggit                      => write!(f, "myr"),
-            MozambiqueMetical                    => write!(f, "mzn"),
-            NamibiaDollar                        => write!(f, "nad"),
-            NigeriaNaira                         => write!(f, "ngn"),
-            NicaraguaCordoba                     => write!(f, "nio"),
-            NorwayKrone                          => write!(f, "nok"),
-            NepalRupee                           => write!(f, "npr"),
-            NewZealandDollar                     => write!(f, "nzd"),
-            OmanRial                             => write!(f, "omr"),
-            PanamaBalboa                         => write!(f, "pab"),
-            PeruSol                              => write!(f, "pen"),
-            PapuaNewGuineaKina                   => write!(f, "pgk"),
-            PhilippinesPeso                      => write!(f, "php"),
-            PakistanRupee                        => write!(f, "pkr"),
-            PolandZloty                          => write!(f, "pln"),
-            ParaguayGuarani                      => write!(f, "pyg"),
-            QatarRiyal                           => write!(f, "qar"),
-            RomaniaNewLeu                        => write!(f, "ron"),
-            SerbiaDinar                          => write!(f, "rsd"),
-            RussiaRuble                          => write!(f, "rub"),
-            RwandaFranc                          => write!(f, "rwf"),
-            SaudiArabiaRiyal                     => write!(f, "sar"),
-            SolomonIslandsDollar                 => write!(f, "sbd"),
-            SeychellesRupee                      => write!(f, "scr"),
-            SudanPound                           => write!(f, "sdg"),
-            SwedenKrona                          => write!(f, "sek"),
-            SingaporeDollar                      => write!(f, "sgd"),
-            SaintHelenaPound                     => write!(f, "shp"),
-            SierraLeoneLeone                     => write!(f, "sll"),
-            SomaliaShilling                      => write!(f, "sos"),
-            SeborgaLuigino                       => write!(f, "spl"),
-            SurinameDollar                       => write!(f, "srd"),
-            SaoTomeAndPrincipeDobra              => write!(f, "std"),
-            ElSalvadorColon                      => write!(f, "svc"),
-            SyriaPound                           => write!(f, "syp"),
-            SwazilandLilangeni                   => write!(f, "szl"),
-            ThailandBaht                         => write!(f, "thb"),
-            TajikistanSomoni                     => write!(f, "tjs"),
-            TurkmenistanManat                    => write!(f, "tmt"),
-            TunisiaDinar                         => write!(f, "tnd"),
-            TongaPaanga                          => write!(f, "top"),
-            TurkeyLira                           => write!(f, "try"),
-            TrinidadAndTobagoDollar              => write!(f, "ttd"),
-            TuvaluDollar                         => write!(f, "tvd"),
-            TaiwanNewDollar                      => write!(f, "twd"),
-            TanzaniaShilling                     => write!(f, "tzs"),
-            UkraineHryvnia                       => write!(f, "uah"),
-            UgandaShilling                       => write!(f, "ugx"),
-            UnitedStatesDollar                   => write!(f, "usd"),
-            UruguayPeso                          => write!(f, "uyu"),
-            UzbekistanSom                        => write!(f, "uzs"),
-            VenezuelaBolivar                     => write!(f, "vef"),
-            VietNamDong                          => write!(f, "vnd"),
-            VanuatuVatu                          => write!(f, "vuv"),
-            SamoaTala                            => write!(f, "wst"),
-            CentralAfricanCfaFranc               => write!(f, "xaf"),
-            WestAfricanCfaFranc                  => write!(f, "xof"),
-            CfpFranc                             => write!(f, "xpf"),
-            EastCaribbeanDollar                  => write!(f, "xcd"),
-            YemenRial                            => write!(f, "yer"),
-            SouthAfricaRand                      => write!(f, "zar"),
-            ZambiaKwacha                         => write!(f, "zmw"),
-            ZimbabweDollar                       => write!(f, "zwd"),
-            Unknown(ref s)                       => write!(f, "{}", s),
+            UnitedArabEmiratesDirham             => "aed",
+            AfghanistanAfghani                   => "afn",
+            AlbaniaLek                           => "all",
+            ArmeniaDram                          => "amd",
+            NetherlandsAntillesGuilder           => "ang",
+            AngolaKwanza                         => "aoa",
+            ArgentinaPeso                        => "ars",
+            AustraliaDollar                      => "aud",
+            ArubaGuilder                         => "awg",
+            AzerbaijanNewManat                   => "azn",
+            BosniaAndHerzegovinaConvertibleMarka => "bam",
+            BarbadosDollar                       => "bbd",
+            BangladeshTaka                       => "bdt",
+            BulgariaLev                          => "bgn",
+            BahrainDinar                         => "bhd",
+            BurundiFranc                         => "bif",
+            BermudaDollar                        => "bmd",
+            BruneiDarussalamDollar               => "bnd",
+            BoliviaBoliviano                     => "bob",
+            BrazilReal                           => "brl",
+            BahamasDollar                        => "bsd",
+            BhutanNgultrum                       => "btn",
+            BotswanaPula                         => "bwp",
+            BelarusRuble                         => "byr",
+            BelizeDollar                         => "bzd",
+            CanadaDollar                         => "cad",
+            CongoKinshasaFranc                   => "cdf",
+            SwitzerlandFranc                     => "chf",
+            ChilePeso                            => "clp",
+            ChinaYuanRenminbi                    => "cny",
+            ColombiaPeso                         => "cop",
+            CostaRicaColon                       => "crc",
+            CubaConvertiblePeso                  => "cuc",
+            CubaPeso                             => "cup",
+            CapeVerdeEscudo                      => "cve",
+            CzechRepublicKoruna                  => "czk",
+            DjiboutiFranc                        => "djf",
+            DenmarkKrone                         => "dkk",
+            DominicanRepublicPeso                => "dop",
+            AlgeriaDinar                         => "dzd",
+            EgyptPound                           => "egp",
+            EritreaNakfa                         => "ern",
+            EthiopiaBirr                         => "etb",
+            EuroMemberCountries                  => "eur",
+            FijiDollar                           => "fjd",
+            FalklandIslandsPound                 => "fkp",
+            UnitedKingdomPound                   => "gbp",
+            GeorgiaLari                          => "gel",
+            GuernseyPound                        => "ggp",
+            GhanaCedi                            => "ghs",
+            GibraltarPound                       => "gip",
+            GambiaDalasi                         => "gmd",
+            GuineaFranc                          => "gnf",
+            GuatemalaQuetzal                     => "gtq",
+            GuyanaDollar                         => "gyd",
+            HongKongDollar                       => "hkd",
+            HondurasLempira                      => "hnl",
+            CroatiaKuna                          => "hrk",
+            HaitiGourde                          => "htg",
+            HungaryForint                        => "huf",
+            IndonesiaRupiah                      => "idr",
+            IsraelShekel                         => "ils",
+            IsleOfManPound                       => "imp",
+            IndiaRupee                           => "inr",
+            IraqDinar                            => "iqd",
+            IranRial                             => "irr",
+            IcelandKrona                         => "isk",
+            JerseyPound                          => "jep",
+            JamaicaDollar                        => "jmd",
+            JordanDinar                          => "jod",
+            JapanYen                             => "jpy",
+            KenyaShilling                        => "kes",
+            KyrgyzstanSom                        => "kgs",
+            CambodiaRiel                         => "khr",
+            ComorosFranc                         => "kmf",
+            NorthKoreaWon                        => "kpw",
+            SouthKoreaWon                        => "krw",
+            KuwaitDinar                          => "kwd",
+            CaymanIslandsDollar                  => "kyd",
+            KazakhstanTenge                      => "kzt",
+            LaosKip                              => "lak",
+            LebanonPound                         => "lbp",
+            SriLankaRupee                        => "lkr",
+            LiberiaDollar                        => "lrd",
+            LesothoLoti                          => "lsl",
+            LibyaDinar                           => "lyd",
+            MoroccoDirham                        => "mad",
+            MoldovaLeu                           => "mdl",
+            MadagascarAriary                     => "mga",
+            MacedoniaDenar                       => "mkd",
+            MyanmarKyat                          => "mmk",
+            MongoliaTughrik                      => "mnt",
+            MacauPataca                          => "mop",
+            MauritaniaOuguiya                    => "mro",
+            MauritiusRupee                       => "mur",
+            MaldivesRufiyaa                      => "mvr",
+            MalawiKwacha                         => "mwk",
+            MexicoPeso                           => "mxn",
+            MalaysiaRinggit                      => "myr",
+            MozambiqueMetical                    => "mzn",
+            NamibiaDollar                        => "nad",
+            NigeriaNaira                         => "ngn",
+            NicaraguaCordoba                     => "nio",
+            NorwayKrone                          => "nok",
+            NepalRupee                           => "npr",
+            NewZealandDollar                     => "nzd",
+            OmanRial                             => "omr",
+            PanamaBalboa                         => "pab",
+            PeruSol                              => "pen",
+            PapuaNewGuineaKina                   => "pgk",
+            PhilippinesPeso                      => "php",
+            PakistanRupee                        => "pkr",
+            PolandZloty                          => "pln",
+            ParaguayGuarani                      => "pyg",
+            QatarRiyal                           => "qar",
+            RomaniaNewLeu                        => "ron",
+            SerbiaDinar                          => "rsd",
+            RussiaRuble                          => "rub",
+            RwandaFranc                          => "rwf",
+            SaudiArabiaRiyal                     => "sar",
+            SolomonIslandsDollar                 => "sbd",
+            SeychellesRupee                      => "scr",
+            SudanPound                           => "sdg",
+            SwedenKrona                          => "sek",
+            SingaporeDollar                      => "sgd",
+            SaintHelenaPound                     => "shp",
+            SierraLeoneLeone                     => "sll",
+            SomaliaShilling                      => "sos",
+            SeborgaLuigino                       => "spl",
+            SurinameDollar                       => "srd",
+            SaoTomeAndPrincipeDobra              => "std",
+            ElSalvadorColon                      => "svc",
+            SyriaPound                           => "syp",
+            SwazilandLilangeni                   => "szl",
+            ThailandBaht                         => "thb",
+            TajikistanSomoni                     => "tjs",
+            TurkmenistanManat                    => "tmt",
+            TunisiaDinar                         => "tnd",
+            TongaPaanga                          => "top",
+            TurkeyLira                           => "try",
+            TrinidadAndTobagoDollar              => "ttd",
+            TuvaluDollar                         => "tvd",
+            TaiwanNewDollar                      => "twd",
+            TanzaniaShilling                     => "tzs",
+            UkraineHryvnia                       => "uah",
+            UgandaShilling                       => "ugx",
+            UnitedStatesDollar                   => "usd",
+            UruguayPeso                          => "uyu",
+            UzbekistanSom                        => "uzs",
+            VenezuelaBolivar                     => "vef",
+            VietNamDong                          => "vnd",
+            VanuatuVatu                          => "vuv",
+            SamoaTala                            => "wst",
+            CentralAfricanCfaFranc               => "xaf",
+            WestAfricanCfaFranc                  => "xof",
+            CfpFranc                             => "xpf",
+            EastCaribbeanDollar                  => "xcd",
+            YemenRial                            => "yer",
+            SouthAfricaRand                      => "zar",
+            ZambiaKwacha                         => "zmw",
+            ZimbabweDollar                       => "zwd",
+            Unknown(ref code)                    => code.as_str(),
         }
     }
-}
 
-impl serde::Deserialize for Currency {
-    fn deserialize<D>(deserializer: &mut D) -> Result<Currency, D::Error>
-        where D: serde::Deserializer
-    {
-        Ok(match String::deserialize(deserializer)?.as_ref() {
+    /// The ISO 4217 numeric code. `None` for currencies ISO doesn't list,
+    /// like `ggp`, and for unknown ones.
+    pub fn numeric_code(&self) -> Option<u16> {
+        self.metadata().and_then(|(numeric_code, _, _)| numeric_code)
+    }
+
+    /// The ISO 4217 name, e.g. "Pound Sterling" for `gbp`.
+    pub fn name(&self) -> Option<&'static str> {
+        self.metadata().map(|(_, name, _)| name)
+    }
+
+    /// The symbol amounts are usually written with locally, e.g. "$" for
+    /// `usd` as well as `cad`.
+    pub fn symbol(&self) -> Option<&'static str> {
+        self.metadata().map(|(_, _, symbol)| symbol)
+    }
+
+    /// How many digits of an amount come after the decimal point. Stripe
+    /// takes amounts in minor units, so 1000 is ¥1000 but $10.00, and
+    /// 1.000 KD for the three-decimal dinars and rials.
+    pub fn exponent(&self) -> u32 {
+        match *self {
+            BahrainDinar | JordanDinar | KuwaitDinar | OmanRial | TunisiaDinar => 3,
+            _ if self.is_zero_decimal() => 0,
+            _ => 2
+        }
+    }
+
+    /// Whether Stripe takes amounts in this currency in whole units.
+    /// https://stripe.com/docs/currencies#zero-decimal
+    pub fn is_zero_decimal(&self) -> bool {
+        match *self {
+            BurundiFranc | ChilePeso | DjiboutiFranc | GuineaFranc | JapanYen | ComorosFranc |
+            SouthKoreaWon | MadagascarAriary | ParaguayGuarani | RwandaFranc | UgandaShilling |
+            VietNamDong | VanuatuVatu | CentralAfricanCfaFranc | WestAfricanCfaFranc | CfpFranc => true,
+            _ => false
+        }
+    }
+
+    /// Whether the currency has been withdrawn or replaced, or was never a
+    /// currency ISO 4217 lists.
+    pub fn is_obsolete(&self) -> bool {
+        match *self {
+            BelarusRuble | CubaConvertiblePeso | CroatiaKuna | MauritaniaOuguiya | SeborgaLuigino |
+            SaoTomeAndPrincipeDobra | VenezuelaBolivar | ZimbabweDollar => true,
+            _ => false
+        }
+    }
+
+    /// Whether Stripe can charge in this currency. Card brands and
+    /// countries narrow this further.
+    /// https://stripe.com/docs/currencies#presentment-currencies
+    pub fn is_chargeable(&self) -> bool {
+        match *self {
+            BahrainDinar | BhutanNgultrum | BelarusRuble | CubaConvertiblePeso | CubaPeso |
+            EritreaNakfa | GuernseyPound | GhanaCedi | CroatiaKuna | IsleOfManPound | IraqDinar |
+            IranRial | JerseyPound | JordanDinar | NorthKoreaWon | KuwaitDinar | LibyaDinar |
+            MauritaniaOuguiya | OmanRial | SudanPound | SeborgaLuigino | SaoTomeAndPrincipeDobra |
+            SyriaPound | TurkmenistanManat | TunisiaDinar | TuvaluDollar | VenezuelaBolivar |
+            ZimbabweDollar => false,
+            Unknown(_) => false,
+            _ => true
+        }
+    }
+
+    /// Whether Stripe pays out to bank accounts in this currency in some
+    /// country. `CountrySpec::supported_bank_account_currencies` has which
+    /// ones for a given country.
+    pub fn is_payout_currency(&self) -> bool {
+        match *self {
+            UnitedArabEmiratesDirham | AustraliaDollar | BulgariaLev | BrazilReal | CanadaDollar |
+            SwitzerlandFranc | CzechRepublicKoruna | DenmarkKrone | EuroMemberCountries |
+            UnitedKingdomPound | HongKongDollar | HungaryForint | IndiaRupee | JapanYen |
+            MexicoPeso | MalaysiaRinggit | NorwayKrone | NewZealandDollar | PolandZloty |
+            RomaniaNewLeu | SwedenKrona | SingaporeDollar | ThailandBaht |
+            UnitedStatesDollar => true,
+            _ => false
+        }
+    }
+
+    /// The currency with `code`, in any case. Unlike deserializing, gives
+    /// `None` rather than `Unknown` for codes this crate doesn't know.
+    pub fn from_code(code: &str) -> Option<Currency> {
+        Some(match code.to_lowercase().as_ref() {
             "aed"   => UnitedArabEmiratesDirham,
             "afn"   => AfghanistanAfghani,
             "all"   => AlbaniaLek,
@@ -503,7 +595,238 @@ impl serde::Deserialize for Currency {
             "zar"   => SouthAfricaRand,
             "zmw"   => ZambiaKwacha,
             "zwd"   => ZimbabweDollar,
-            unknown => Unknown(String::from(unknown)),
+            _       => return None
+        })
+    }
+
+    fn metadata(&self) -> Option<(Option<u16>, &'static str, &'static str)> {
+        Some(match *self {
+            UnitedArabEmiratesDirham             => (Some(784), "UAE Dirham", "د.إ"),
+            AfghanistanAfghani                   => (Some(971), "Afghani", "؋"),
+            AlbaniaLek                           => (Some(8), "Lek", "L"),
+            ArmeniaDram                          => (Some(51), "Armenian Dram", "֏"),
+            NetherlandsAntillesGuilder           => (Some(532), "Netherlands Antillean Guilder", "ƒ"),
+            AngolaKwanza                         => (Some(973), "Kwanza", "Kz"),
+            ArgentinaPeso                        => (Some(32), "Argentine Peso", "$"),
+            AustraliaDollar                      => (Some(36), "Australian Dollar", "$"),
+            ArubaGuilder                         => (Some(533), "Aruban Florin", "ƒ"),
+            AzerbaijanNewManat                   => (Some(944), "Azerbaijan Manat", "₼"),
+            BosniaAndHerzegovinaConvertibleMarka => (Some(977), "Convertible Mark", "KM"),
+            BarbadosDollar                       => (Some(52), "Barbados Dollar", "$"),
+            BangladeshTaka                       => (Some(50), "Taka", "৳"),
+            BulgariaLev                          => (Some(975), "Bulgarian Lev", "лв"),
+            BahrainDinar                         => (Some(48), "Bahraini Dinar", ".د.ب"),
+            BurundiFranc                         => (Some(108), "Burundi Franc", "FBu"),
+            BermudaDollar                        => (Some(60), "Bermudian Dollar", "$"),
+            BruneiDarussalamDollar               => (Some(96), "Brunei Dollar", "$"),
+            BoliviaBoliviano                     => (Some(68), "Boliviano", "Bs."),
+            BrazilReal                           => (Some(986), "Brazilian Real", "R$"),
+            BahamasDollar                        => (Some(44), "Bahamian Dollar", "$"),
+            BhutanNgultrum                       => (Some(64), "Ngultrum", "Nu."),
+            BotswanaPula                         => (Some(72), "Pula", "P"),
+            BelarusRuble                         => (Some(974), "Belarusian Ruble", "Br"),
+            BelizeDollar                         => (Some(84), "Belize Dollar", "$"),
+            CanadaDollar                         => (Some(124), "Canadian Dollar", "$"),
+            CongoKinshasaFranc                   => (Some(976), "Congolese Franc", "FC"),
+            SwitzerlandFranc                     => (Some(756), "Swiss Franc", "CHF"),
+            ChilePeso                            => (Some(152), "Chilean Peso", "$"),
+            ChinaYuanRenminbi                    => (Some(156), "Yuan Renminbi", "¥"),
+            ColombiaPeso                         => (Some(170), "Colombian Peso", "$"),
+            CostaRicaColon                       => (Some(188), "Costa Rican Colon", "₡"),
+            CubaConvertiblePeso                  => (Some(931), "Peso Convertible", "$"),
+            CubaPeso                             => (Some(192), "Cuban Peso", "₱"),
+            CapeVerdeEscudo                      => (Some(132), "Cabo Verde Escudo", "$"),
+            CzechRepublicKoruna                  => (Some(203), "Czech Koruna", "Kč"),
+            DjiboutiFranc                        => (Some(262), "Djibouti Franc", "Fdj"),
+            DenmarkKrone                         => (Some(208), "Danish Krone", "kr"),
+            DominicanRepublicPeso                => (Some(214), "Dominican Peso", "$"),
+            AlgeriaDinar                         => (Some(12), "Algerian Dinar", "د.ج"),
+            EgyptPound                           => (Some(818), "Egyptian Pound", "£"),
+            EritreaNakfa                         => (Some(232), "Nakfa", "Nfk"),
+            EthiopiaBirr                         => (Some(230), "Ethiopian Birr", "Br"),
+            EuroMemberCountries                  => (Some(978), "Euro", "€"),
+            FijiDollar                           => (Some(242), "Fiji Dollar", "$"),
+            FalklandIslandsPound                 => (Some(238), "Falkland Islands Pound", "£"),
+            UnitedKingdomPound                   => (Some(826), "Pound Sterling", "£"),
+            GeorgiaLari                          => (Some(981), "Lari", "₾"),
+            GuernseyPound                        => (None, "Guernsey Pound", "£"),
+            GhanaCedi                            => (Some(936), "Ghana Cedi", "₵"),
+            GibraltarPound                       => (Some(292), "Gibraltar Pound", "£"),
+            GambiaDalasi                         => (Some(270), "Dalasi", "D"),
+            GuineaFranc                          => (Some(324), "Guinean Franc", "FG"),
+            GuatemalaQuetzal                     => (Some(320), "Quetzal", "Q"),
+            GuyanaDollar                         => (Some(328), "Guyana Dollar", "$"),
+            HongKongDollar                       => (Some(344), "Hong Kong Dollar", "$"),
+            HondurasLempira                      => (Some(340), "Lempira", "L"),
+            CroatiaKuna                          => (Some(191), "Kuna", "kn"),
+            HaitiGourde                          => (Some(332), "Gourde", "G"),
+            HungaryForint                        => (Some(348), "Forint", "Ft"),
+            IndonesiaRupiah                      => (Some(360), "Rupiah", "Rp"),
+            IsraelShekel                         => (Some(376), "New Israeli Sheqel", "₪"),
+            IsleOfManPound                       => (None, "Manx Pound", "£"),
+            IndiaRupee                           => (Some(356), "Indian Rupee", "₹"),
+            IraqDinar                            => (Some(368), "Iraqi Dinar", "ع.د"),
+            IranRial                             => (Some(364), "Iranian Rial", "﷼"),
+            IcelandKrona                         => (Some(352), "Iceland Krona", "kr"),
+            JerseyPound                          => (None, "Jersey Pound", "£"),
+            JamaicaDollar                        => (Some(388), "Jamaican Dollar", "$"),
+            JordanDinar                          => (Some(400), "Jordanian Dinar", "د.ا"),
+            JapanYen                             => (Some(392), "Yen", "¥"),
+            KenyaShilling                        => (Some(404), "Kenyan Shilling", "KSh"),
+            KyrgyzstanSom                        => (Some(417), "Som", "с"),
+            CambodiaRiel                         => (Some(116), "Riel", "៛"),
+            ComorosFranc                         => (Some(174), "Comorian Franc", "CF"),
+            NorthKoreaWon                        => (Some(408), "North Korean Won", "₩"),
+            SouthKoreaWon                        => (Some(410), "Won", "₩"),
+            KuwaitDinar                          => (Some(414), "Kuwaiti Dinar", "د.ك"),
+            CaymanIslandsDollar                  => (Some(136), "Cayman Islands Dollar", "$"),
+            KazakhstanTenge                      => (Some(398), "Tenge", "₸"),
+            LaosKip                              => (Some(418), "Lao Kip", "₭"),
+            LebanonPound                         => (Some(422), "Lebanese Pound", "ل.ل"),
+            SriLankaRupee                        => (Some(144), "Sri Lanka Rupee", "Rs"),
+            LiberiaDollar                        => (Some(430), "Liberian Dollar", "$"),
+            LesothoLoti                          => (Some(426), "Loti", "L"),
+            LibyaDinar                           => (Some(434), "Libyan Dinar", "ل.د"),
+            MoroccoDirham                        => (Some(504), "Moroccan Dirham", "د.م."),
+            MoldovaLeu                           => (Some(498), "Moldovan Leu", "L"),
+            MadagascarAriary                     => (Some(969), "Malagasy Ariary", "Ar"),
+            MacedoniaDenar                       => (Some(807), "Denar", "ден"),
+            MyanmarKyat                          => (Some(104), "Kyat", "K"),
+            MongoliaTughrik                      => (Some(496), "Tugrik", "₮"),
+            MacauPataca                          => (Some(446), "Pataca", "MOP$"),
+            MauritaniaOuguiya                    => (Some(478), "Ouguiya", "UM"),
+            MauritiusRupee                       => (Some(480), "Mauritius Rupee", "Rs"),
+            MaldivesRufiyaa                      => (Some(462), "Rufiyaa", "Rf"),
+            MalawiKwacha                         => (Some(454), "Malawi Kwacha", "MK"),
+            MexicoPeso                           => (Some(484), "Mexican Peso", "$"),
+            MalaysiaRinggit                      => (Some(458), "Malaysian Ringgit", "RM"),
+            MozambiqueMetical                    => (Some(943), "Mozambique Metical", "MT"),
+            NamibiaDollar                        => (Some(516), "Namibia Dollar", "$"),
+            NigeriaNaira                         => (Some(566), "Naira", "₦"),
+            NicaraguaCordoba                     => (Some(558), "Cordoba Oro", "C$"),
+            NorwayKrone                          => (Some(578), "Norwegian Krone", "kr"),
+            NepalRupee                           => (Some(524), "Nepalese Rupee", "Rs"),
+            NewZealandDollar                     => (Some(554), "New Zealand Dollar", "$"),
+            OmanRial                             => (Some(512), "Rial Omani", "ر.ع."),
+            PanamaBalboa                         => (Some(590), "Balboa", "B/."),
+            PeruSol                              => (Some(604), "Sol", "S/"),
+            PapuaNewGuineaKina                   => (Some(598), "Kina", "K"),
+            PhilippinesPeso                      => (Some(608), "Philippine Peso", "₱"),
+            PakistanRupee                        => (Some(586), "Pakistan Rupee", "Rs"),
+            PolandZloty                          => (Some(985), "Zloty", "zł"),
+            ParaguayGuarani                      => (Some(600), "Guarani", "₲"),
+            QatarRiyal                           => (Some(634), "Qatari Rial", "ر.ق"),
+            RomaniaNewLeu                        => (Some(946), "Romanian Leu", "lei"),
+            SerbiaDinar                          => (Some(941), "Serbian Dinar", "дин."),
+            RussiaRuble                          => (Some(643), "Russian Ruble", "₽"),
+            RwandaFranc                          => (Some(646), "Rwanda Franc", "FRw"),
+            SaudiArabiaRiyal                     => (Some(682), "Saudi Riyal", "ر.س"),
+            SolomonIslandsDollar                 => (Some(90), "Solomon Islands Dollar", "$"),
+            SeychellesRupee                      => (Some(690), "Seychelles Rupee", "Rs"),
+            SudanPound                           => (Some(938), "Sudanese Pound", "ج.س."),
+            SwedenKrona                          => (Some(752), "Swedish Krona", "kr"),
+            SingaporeDollar                      => (Some(702), "Singapore Dollar", "$"),
+            SaintHelenaPound                     => (Some(654), "Saint Helena Pound", "£"),
+            SierraLeoneLeone                     => (Some(694), "Leone", "Le"),
+            SomaliaShilling                      => (Some(706), "Somali Shilling", "Sh"),
+            SeborgaLuigino                       => (None, "Luigino", "L"),
+            SurinameDollar                       => (Some(968), "Surinam Dollar", "$"),
+            SaoTomeAndPrincipeDobra              => (Some(678), "Dobra", "Db"),
+            ElSalvadorColon                      => (Some(222), "El Salvador Colon", "₡"),
+            SyriaPound                           => (Some(760), "Syrian Pound", "£"),
+            SwazilandLilangeni                   => (Some(748), "Lilangeni", "L"),
+            ThailandBaht                         => (Some(764), "Baht", "฿"),
+            TajikistanSomoni                     => (Some(972), "Somoni", "SM"),
+            TurkmenistanManat                    => (Some(934), "Turkmenistan New Manat", "m"),
+            TunisiaDinar                         => (Some(788), "Tunisian Dinar", "د.ت"),
+            TongaPaanga                          => (Some(776), "Pa'anga", "T$"),
+            TurkeyLira                           => (Some(949), "Turkish Lira", "₺"),
+            TrinidadAndTobagoDollar              => (Some(780), "Trinidad and Tobago Dollar", "$"),
+            TuvaluDollar                         => (None, "Tuvaluan Dollar", "$"),
+            TaiwanNewDollar                      => (Some(901), "New Taiwan Dollar", "NT$"),
+            TanzaniaShilling                     => (Some(834), "Tanzanian Shilling", "TSh"),
+            UkraineHryvnia                       => (Some(980), "Hryvnia", "₴"),
+            UgandaShilling                       => (Some(800), "Uganda Shilling", "USh"),
+            UnitedStatesDollar                   => (Some(840), "US Dollar", "$"),
+            UruguayPeso                          => (Some(858), "Peso Uruguayo", "$"),
+            UzbekistanSom                        => (Some(860), "Uzbekistan Sum", "soʻm"),
+            VenezuelaBolivar                     => (Some(937), "Bolívar", "Bs"),
+            VietNamDong                          => (Some(704), "Dong", "₫"),
+            VanuatuVatu                          => (Some(548), "Vatu", "VT"),
+            SamoaTala                            => (Some(882), "Tala", "T"),
+            CentralAfricanCfaFranc               => (Some(950), "CFA Franc BEAC", "FCFA"),
+            WestAfricanCfaFranc                  => (Some(952), "CFA Franc BCEAO", "CFA"),
+            CfpFranc                             => (Some(953), "CFP Franc", "₣"),
+            EastCaribbeanDollar                  => (Some(951), "East Caribbean Dollar", "$"),
+            YemenRial                            => (Some(886), "Yemeni Rial", "﷼"),
+            SouthAfricaRand                      => (Some(710), "Rand", "R"),
+            ZambiaKwacha                         => (Some(967), "Zambian Kwacha", "ZK"),
+            ZimbabweDollar                       => (Some(716), "Zimbabwe Dollar", "Z$"),
+            Unknown(_)                           => return None,
         })
     }
 }
+
+/// Compares by code, so an `Unknown` holding a known code equals the
+/// currency it names.
+impl PartialEq for Currency {
+    fn eq(&self, other: &Currency) -> bool {
+        self.code() == other.code()
+    }
+}
+
+impl Eq for Currency {}
+
+impl Hash for Currency {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.code().hash(state)
+    }
+}
+
+/// Orders alphabetically by code.
+impl PartialOrd for Currency {
+    fn partial_cmp(&self, other: &Currency) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Currency {
+    fn cmp(&self, other: &Currency) -> Ordering {
+        self.code().cmp(other.code())
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// Parses a code in any case, e.g. "usd" or "USD".
+impl FromStr for Currency {
+    type Err = CurrencyError;
+
+    fn from_str(code: &str) -> Result<Currency, CurrencyError> {
+        Currency::from_code(code).ok_or_else(|| CurrencyError { code: code.to_string() })
+    }
+}
+
+impl serde::Deserialize for Currency {
+    fn deserialize<D>(deserializer: &mut D) -> Result<Currency, D::Error>
+        where D: serde::Deserializer
+    {
+        let code = String::deserialize(deserializer)?;
+        Ok(match Currency::from_code(&code) {
+            Some(currency) => currency,
+            None           => Unknown(schema_drift::unknown_value("Currency", &code))
+        })
+    }
+}
+
+impl serde::Serialize for Currency {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(self.code())
+    }
+}
@@ -2,31 +2,34 @@ use std::collections::BTreeMap;
 use super::api_list::ApiList;
 use super::currency::Currency;
 use super::discount::Discount;
+use super::id::{CustomerId, SourceId};
 use super::shipping::Shipping;
 use super::source::Source;
 use super::StripeObject;
 use super::subscription::Subscription;
+use super::timestamp::Timestamp;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct Customer {
-    id: String,
-    account_balance: i64,
-    created: i64,
-    currency: Currency,
-    default_source: Option<String>,
-    delinquent: bool,
-    description: Option<String>,
-    discount: Option<Discount>,
-    email: Option<String>,
-    livemode: bool,
-    metadata: Option<BTreeMap<String, String>>,
-    shipping: Option<Shipping>,
-    sources: Option<ApiList<Source>>,
-    subscriptions: Option<ApiList<Subscription>>,
+stripe_object! {
+    pub struct Customer {
+        id: CustomerId,
+        account_balance: i64,
+        created: Timestamp,
+        currency: Option<Currency>,
+        default_source: Option<SourceId>,
+        delinquent: bool,
+        description: Option<String>,
+        discount: Option<Discount>,
+        email: Option<String>,
+        livemode: bool,
+        metadata: Option<BTreeMap<String, String>>,
+        shipping: Option<Shipping>,
+        sources: Option<ApiList<Source>>,
+        subscriptions: Option<ApiList<Subscription>>,
+    }
 }
 
 impl StripeObject for Customer {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
@@ -1,18 +1,52 @@
 use url_encodable::UrlEncodable;
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DateOfBirth {
-    pub day: i64,
-    pub month: i64,
-    pub year: i64
+    pub day: Option<i64>,
+    pub month: Option<i64>,
+    pub year: Option<i64>
+}
+
+impl DateOfBirth {
+    pub fn new(day: i64, month: i64, year: i64) -> DateOfBirth {
+        DateOfBirth {
+            day: Some(day),
+            month: Some(month),
+            year: Some(year)
+        }
+    }
+
+    /// Whether the day, month and year are all there and make a real date
+    /// after 1900, which is the earliest Stripe takes.
+    pub fn is_valid(&self) -> bool {
+        let (day, month, year) = match (self.day, self.month, self.year) {
+            (Some(day), Some(month), Some(year)) => (day, month, year),
+            _                                    => return false
+        };
+        let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11              => 30,
+            2 if leap                   => 29,
+            2                           => 28,
+            _                           => return false
+        };
+        year > 1900 && day >= 1 && day <= days_in_month
+    }
 }
 
 impl UrlEncodable for DateOfBirth {
     fn key_value_pairs(&self) -> Vec<(String, String)> {
-        vec![
-            ("day".to_string(), format!("{:02}", self.day)),
-            ("month".to_string(), format!("{:02}", self.month)),
-            ("year".to_string(), format!("{:04}", self.year))
-        ]
+        let mut vec = Vec::new();
+        if let Some(day) = self.day {
+            vec.push(("day".to_string(), format!("{:02}", day)));
+        }
+        if let Some(month) = self.month {
+            vec.push(("month".to_string(), format!("{:02}", month)));
+        }
+        if let Some(year) = self.year {
+            vec.push(("year".to_string(), format!("{:04}", year)));
+        }
+        vec
     }
 }
@@ -1,6 +1,6 @@
 use url_encodable::UrlEncodable;
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Dimensions {
     pub height: f64,
     pub length: f64,
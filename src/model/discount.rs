@@ -1,10 +1,28 @@
+use serde;
 use super::coupon::Coupon;
+use super::id::{CustomerId, SubscriptionId};
+use super::timestamp::Timestamp;
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct Discount {
     pub coupon: Coupon,
-    pub customer: String,
-    pub end: Option<i64>,
-    pub start: Option<i64>,
-    pub subscription: Option<String>,
+    pub customer: CustomerId,
+    pub end: Option<Timestamp>,
+    pub start: Option<Timestamp>,
+    pub subscription: Option<SubscriptionId>,
+}
+
+impl serde::Serialize for Discount {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        let mut state = serializer.serialize_struct("Discount", 6)?;
+        serializer.serialize_struct_elt(&mut state, "object", "discount")?;
+        serializer.serialize_struct_elt(&mut state, "coupon", &self.coupon)?;
+        serializer.serialize_struct_elt(&mut state, "customer", &self.customer)?;
+        serializer.serialize_struct_elt(&mut state, "end", &self.end)?;
+        serializer.serialize_struct_elt(&mut state, "start", &self.start)?;
+        serializer.serialize_struct_elt(&mut state, "subscription", &self.subscription)?;
+        serializer.serialize_struct_end(state)
+    }
 }
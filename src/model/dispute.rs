@@ -1,32 +1,37 @@
+use schema_drift;
 use serde;
 use std::collections::BTreeMap;
+use std::fmt;
 use super::balance_transaction::BalanceTransaction;
 use super::currency::Currency;
+use super::id::{ChargeId, DisputeId};
 use super::StripeObject;
+use super::timestamp::Timestamp;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct Dispute {
-    pub id: String,
-    pub amount: i64,
-    pub balance_transactions: Vec<BalanceTransaction>,
-    pub charge: String,
-    pub created: i64,
-    pub currency: Currency,
-    pub evidence: DisputeEvidence,
-    pub evidence_details: EvidenceDetails,
-    pub is_charge_refundable: bool,
-    pub livemode: bool,
-    pub metadata: Option<BTreeMap<String, String>>,
-    pub reason: DisputeReason,
-    pub status: DisputeStatus,
+stripe_object! {
+    pub struct Dispute {
+        pub id: DisputeId,
+        pub amount: i64,
+        pub balance_transactions: Vec<BalanceTransaction>,
+        pub charge: ChargeId,
+        pub created: Timestamp,
+        pub currency: Currency,
+        pub evidence: DisputeEvidence,
+        pub evidence_details: EvidenceDetails,
+        pub is_charge_refundable: bool,
+        pub livemode: bool,
+        pub metadata: Option<BTreeMap<String, String>>,
+        pub reason: DisputeReason,
+        pub status: DisputeStatus,
+    }
 }
 
 impl StripeObject for Dispute {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DisputeEvidence {
     pub access_activity_log: Option<String>,
     pub billing_address: Option<String>,
@@ -57,9 +62,9 @@ pub struct DisputeEvidence {
     pub uncategorized_text: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EvidenceDetails {
-    due_by: Option<i64>,
+    due_by: Option<Timestamp>,
     has_evidence: bool,
     past_due: bool,
     submission_count: i64
@@ -99,11 +104,39 @@ impl serde::Deserialize for DisputeReason {
             "bank_cannot_process"       => DisputeReason::BankCannotProcess,
             "debit_not_authorized"      => DisputeReason::DebitNotAuthorized,
             "general"                   => DisputeReason::General,
-            other                       => DisputeReason::Other(String::from(other)),
+            other                       => DisputeReason::Other(schema_drift::unknown_value("DisputeReason", other)),
         })
     }
 }
 
+impl fmt::Display for DisputeReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DisputeReason::Duplicate               => write!(f, "duplicate"),
+            DisputeReason::Fraudulent              => write!(f, "fraudulent"),
+            DisputeReason::SubscriptionCanceled    => write!(f, "subscription_canceled"),
+            DisputeReason::ProductUnacceptable     => write!(f, "product_unacceptable"),
+            DisputeReason::ProductNotReceived      => write!(f, "product_not_received"),
+            DisputeReason::Unrecognized            => write!(f, "unrecognized"),
+            DisputeReason::CreditNotProcessed      => write!(f, "credit_not_processed"),
+            DisputeReason::IncorrectAccountDetails => write!(f, "incorrect_account_details"),
+            DisputeReason::InsufficientFunds       => write!(f, "insufficient_funds"),
+            DisputeReason::BankCannotProcess       => write!(f, "bank_cannot_process"),
+            DisputeReason::DebitNotAuthorized      => write!(f, "debit_not_authorized"),
+            DisputeReason::General                 => write!(f, "general"),
+            DisputeReason::Other(ref other)        => write!(f, "{}", other),
+        }
+    }
+}
+
+impl serde::Serialize for DisputeReason {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum DisputeStatus {
     WarningNeedsResponse,
@@ -132,7 +165,32 @@ impl serde::Deserialize for DisputeStatus {
             "charge_refunded"        => DisputeStatus::ChargeRefunded,
             "won"                    => DisputeStatus::Won,
             "lost"                   => DisputeStatus::Lost,
-            other                    => DisputeStatus::Other(String::from(other)),
+            other                    => DisputeStatus::Other(schema_drift::unknown_value("DisputeStatus", other)),
         })
     }
 }
+
+impl fmt::Display for DisputeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DisputeStatus::WarningNeedsResponse => write!(f, "warning_needs_response"),
+            DisputeStatus::WarningUnderReview   => write!(f, "warning_under_review"),
+            DisputeStatus::WarningClosed        => write!(f, "warning_closed"),
+            DisputeStatus::NeedsResponse        => write!(f, "needs_response"),
+            DisputeStatus::ResponseDisabled     => write!(f, "response_disabled"),
+            DisputeStatus::UnderReview          => write!(f, "under_review"),
+            DisputeStatus::ChargeRefunded       => write!(f, "charge_refunded"),
+            DisputeStatus::Won                  => write!(f, "won"),
+            DisputeStatus::Lost                 => write!(f, "lost"),
+            DisputeStatus::Other(ref other)     => write!(f, "{}", other),
+        }
+    }
+}
+
+impl serde::Serialize for DisputeStatus {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
@@ -3,32 +3,35 @@ use serde;
 use serde_json::value::Value;
 use serde_json;
 use std::collections::BTreeMap;
+use super::id::EventId;
 use super::StripeObject;
+use super::timestamp::Timestamp;
 
-/// https://stripe.com/docs/api#event_object
-#[derive(Clone, Debug, Deserialize)]
-pub struct Event {
-    pub id: String,
-    pub api_version: Option<String>,
-    pub created: i64,
-    pub data: EventData,
-    pub livemode: bool,
-    pub pending_webhooks: i64,
-    pub request: Option<String>,
-    #[serde(rename="type")]
-    pub event_type: String,
+stripe_object! {
+    /// https://stripe.com/docs/api#event_object
+    pub struct Event {
+        pub id: EventId,
+        pub api_version: Option<String>,
+        pub created: Timestamp,
+        pub data: EventData,
+        pub livemode: bool,
+        pub pending_webhooks: i64,
+        pub request: Option<String>,
+        #[serde(rename="type")]
+        pub event_type: String,
+    }
 }
 
 impl StripeObject for Event {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EventDataRaw {
     pub object: Value,
-    pub previous_attributes: Option<BTreeMap<String, String>>,
+    pub previous_attributes: Option<BTreeMap<String, Value>>,
 }
 
 #[derive(Clone, Debug)]
@@ -36,7 +39,7 @@ pub struct EventData {
     pub object: Value,
     pub id: String,
     pub object_name: String,
-    pub previous_attributes: Option<BTreeMap<String, String>>,
+    pub previous_attributes: Option<BTreeMap<String, Value>>,
 }
 
 impl EventData {
@@ -76,3 +79,13 @@ impl serde::Deserialize for EventData {
     }
 }
 
+impl serde::Serialize for EventData {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        EventDataRaw {
+            object: self.object.clone(),
+            previous_attributes: self.previous_attributes.clone()
+        }.serialize(serializer)
+    }
+}
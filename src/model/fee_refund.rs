@@ -1,20 +1,23 @@
 use std::collections::BTreeMap;
 use super::currency::Currency;
+use super::id::{ApplicationFeeId, BalanceTransactionId, FeeRefundId};
 use super::StripeObject;
+use super::timestamp::Timestamp;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct FeeRefund {
-    pub id: String,
-    pub amount: i64,
-    pub balance_transaction: Option<String>,
-    pub created: i64,
-    pub currency: Currency,
-    pub fee: String,
-    pub metadata: Option<BTreeMap<String, String>>,
+stripe_object! {
+    pub struct FeeRefund {
+        pub id: FeeRefundId,
+        pub amount: i64,
+        pub balance_transaction: Option<BalanceTransactionId>,
+        pub created: Timestamp,
+        pub currency: Currency,
+        pub fee: ApplicationFeeId,
+        pub metadata: Option<BTreeMap<String, String>>,
+    }
 }
 
 impl StripeObject for FeeRefund {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
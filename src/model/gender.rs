@@ -1,5 +1,8 @@
+use schema_drift;
+use serde;
 use std::fmt;
 
+#[derive(Clone, Debug)]
 pub enum Gender {
     Male,
     Female,
@@ -15,3 +18,23 @@ impl fmt::Display for Gender {
         }
     }
 }
+
+impl serde::Deserialize for Gender {
+    fn deserialize<D>(deserializer: &mut D) -> Result<Gender, D::Error>
+        where D: serde::Deserializer
+    {
+        Ok(match String::deserialize(deserializer)?.as_ref() {
+            "male"   => Gender::Male,
+            "female" => Gender::Female,
+            other    => Gender::Other(schema_drift::unknown_value("Gender", other)),
+        })
+    }
+}
+
+impl serde::Serialize for Gender {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
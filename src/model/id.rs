@@ -0,0 +1,202 @@
+use errors::id_error::IdError;
+
+/// Declares an ID newtype that only parses from strings starting with one of
+/// `prefixes`, or from any non-empty string when there are none, for IDs
+/// that can be chosen by the caller.
+///
+/// Deserializing accepts any non-empty string, so that a response isn't
+/// rejected if Stripe starts issuing IDs with a new prefix.
+macro_rules! stripe_id {
+    ($(#[$attr:meta])* pub struct $name:ident($($prefix:expr),*);) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(String);
+
+        impl $name {
+            /// The prefixes an ID of this type starts with.
+            pub fn prefixes() -> &'static [&'static str] {
+                const PREFIXES: &'static [&'static str] = &[$($prefix),*];
+                PREFIXES
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = IdError;
+
+            fn from_str(id: &str) -> Result<$name, IdError> {
+                let prefixes = $name::prefixes();
+                let valid = if prefixes.is_empty() {
+                    !id.is_empty()
+                } else {
+                    prefixes.iter().any(|prefix| id.len() > prefix.len() && id.starts_with(prefix))
+                };
+                if valid {
+                    Ok($name(String::from(id)))
+                } else {
+                    Err(IdError {
+                        id_type: stringify!($name),
+                        prefixes: prefixes,
+                        id: String::from(id)
+                    })
+                }
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl ::serde::Deserialize for $name {
+            fn deserialize<D>(deserializer: &mut D) -> Result<$name, D::Error>
+                where D: ::serde::Deserializer
+            {
+                use serde::Deserialize;
+                use serde::de::Error;
+
+                let id = String::deserialize(deserializer)?;
+                if id.is_empty() {
+                    let err = IdError {
+                        id_type: stringify!($name),
+                        prefixes: $name::prefixes(),
+                        id: id
+                    };
+                    return Err(D::Error::custom(err.to_string()));
+                }
+                Ok($name(id))
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+                where S: ::serde::Serializer
+            {
+                serializer.serialize_str(&self.0)
+            }
+        }
+    };
+}
+
+stripe_id! {
+    pub struct AccountId("acct_");
+}
+
+stripe_id! {
+    pub struct ApplicationFeeId("fee_");
+}
+
+stripe_id! {
+    pub struct BalanceTransactionId("txn_");
+}
+
+stripe_id! {
+    pub struct BankAccountId("ba_");
+}
+
+stripe_id! {
+    pub struct BitcoinReceiverId("btcrcv_");
+}
+
+stripe_id! {
+    pub struct CardId("card_");
+}
+
+stripe_id! {
+    /// `py_` for payments made to a connected account, e.g. by a destination
+    /// charge.
+    pub struct ChargeId("ch_", "py_");
+}
+
+stripe_id! {
+    /// Chosen by the caller, or generated by Stripe when left out.
+    pub struct CouponId();
+}
+
+stripe_id! {
+    pub struct CustomerId("cus_");
+}
+
+stripe_id! {
+    /// Stripe issues dispute IDs with either prefix.
+    pub struct DisputeId("dp_", "du_");
+}
+
+stripe_id! {
+    pub struct EventId("evt_");
+}
+
+stripe_id! {
+    pub struct FeeRefundId("fr_");
+}
+
+stripe_id! {
+    pub struct InvoiceId("in_");
+}
+
+stripe_id! {
+    pub struct InvoiceItemId("ii_");
+}
+
+stripe_id! {
+    pub struct OrderId("or_");
+}
+
+stripe_id! {
+    /// Chosen by the caller.
+    pub struct PlanId();
+}
+
+stripe_id! {
+    /// Chosen by the caller, or generated by Stripe with a `prod_` prefix
+    /// when left out.
+    pub struct ProductId();
+}
+
+stripe_id! {
+    pub struct RecipientId("rp_");
+}
+
+stripe_id! {
+    /// `pyr_` for refunds of payments made to a connected account.
+    pub struct RefundId("re_", "pyr_");
+}
+
+stripe_id! {
+    /// Chosen by the caller, or generated by Stripe with a `sku_` prefix
+    /// when left out.
+    pub struct SkuId();
+}
+
+stripe_id! {
+    /// A payment source attached to a customer: a card, a bank account, a
+    /// bitcoin receiver or a source.
+    pub struct SourceId("card_", "ba_", "btcrcv_", "src_");
+}
+
+stripe_id! {
+    pub struct SubscriptionId("sub_");
+}
+
+stripe_id! {
+    /// `btok_` for bank account tokens and `pii_` for PII tokens.
+    pub struct TokenId("tok_", "btok_", "pii_");
+}
+
+stripe_id! {
+    pub struct TransferId("tr_");
+}
+
+stripe_id! {
+    pub struct TransferReversalId("trr_");
+}
@@ -1,3 +1,4 @@
+use schema_drift;
 use serde;
 use std::fmt;
 
@@ -32,7 +33,15 @@ impl serde::Deserialize for Interval {
             "week"  => Interval::Week,
             "month" => Interval::Month,
             "year"  => Interval::Year,
-            unknown => Interval::Unknown(String::from(unknown)),
+            unknown => Interval::Unknown(schema_drift::unknown_value("Interval", unknown)),
         })
     }
 }
+
+impl serde::Serialize for Interval {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
@@ -1,4 +1,5 @@
 use either::Either;
+use schema_drift;
 use serde;
 use std::collections::BTreeMap;
 use std::fmt;
@@ -6,67 +7,71 @@ use super::api_list::ApiList;
 use super::currency::Currency;
 use super::charge::Charge;
 use super::discount::Discount;
+use super::id::{ChargeId, CustomerId, InvoiceId, SubscriptionId};
 use super::period::Period;
 use super::plan::Plan;
 use super::StripeObject;
+use super::timestamp::Timestamp;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct Invoice {
-    pub id: String,
-    pub amount_due: i64,
-    pub application_fee: Option<i64>,
-    pub attempt_count: i64,
-    pub attempted: bool,
-    pub charge: Either<String, Charge>,
-    pub closed: bool,
-    pub currency: Currency,
-    pub customer: String,
-    pub date: i64,
-    pub description: Option<String>,
-    pub discount: Option<Discount>,
-    pub ending_balance: Option<i64>,
-    pub forgiven: bool,
-    pub lines: ApiList<InvoiceLineItem>,
-    pub livemode: bool,
-    pub metadata: Option<BTreeMap<String, String>>,
-    pub next_payment_attempt: Option<i64>,
-    pub paid: bool,
-    pub period_end: i64,
-    pub period_start: i64,
-    pub receipt_number: String,
-    pub starting_balance: i64,
-    pub statement_descriptor: Option<String>,
-    pub subscription: Option<String>,
-    pub subscription_proration_date: Option<i64>,
-    pub subtotal: i64,
-    pub tax: Option<i64>,
-    pub tax_percent: Option<i64>,
-    pub total: i64,
-    pub webhooks_delivered_at: i64
+stripe_object! {
+    pub struct Invoice {
+        pub id: InvoiceId,
+        pub amount_due: i64,
+        pub application_fee: Option<i64>,
+        pub attempt_count: i64,
+        pub attempted: bool,
+        pub charge: Option<Either<ChargeId, Charge>>,
+        pub closed: bool,
+        pub currency: Currency,
+        pub customer: CustomerId,
+        pub date: Timestamp,
+        pub description: Option<String>,
+        pub discount: Option<Discount>,
+        pub ending_balance: Option<i64>,
+        pub forgiven: bool,
+        pub lines: ApiList<InvoiceLineItem>,
+        pub livemode: bool,
+        pub metadata: Option<BTreeMap<String, String>>,
+        pub next_payment_attempt: Option<Timestamp>,
+        pub paid: bool,
+        pub period_end: Timestamp,
+        pub period_start: Timestamp,
+        pub receipt_number: Option<String>,
+        pub starting_balance: i64,
+        pub statement_descriptor: Option<String>,
+        pub subscription: Option<SubscriptionId>,
+        pub subscription_proration_date: Option<Timestamp>,
+        pub subtotal: i64,
+        pub tax: Option<i64>,
+        pub tax_percent: Option<f64>,
+        pub total: i64,
+        pub webhooks_delivered_at: Option<Timestamp>
+    }
 }
 
 impl StripeObject for Invoice {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct InvoiceLineItem {
-    pub id: String,
-    pub amount: i64,
-    pub currency: Currency,
-    pub description: Option<String>,
-    pub discountable: bool,
-    pub livemode: bool,
-    pub metadata: Option<BTreeMap<String, String>>,
-    pub period: Period,
-    pub plan: Option<Plan>,
-    pub proration: bool,
-    pub quantity: Option<i64>,
-    pub subscription: Option<String>,
-    #[serde(rename="type")]
-    pub line_item_type: LineItemType,
+stripe_object! {
+    pub struct InvoiceLineItem {
+        pub id: String,
+        pub amount: i64,
+        pub currency: Currency,
+        pub description: Option<String>,
+        pub discountable: bool,
+        pub livemode: bool,
+        pub metadata: Option<BTreeMap<String, String>>,
+        pub period: Period,
+        pub plan: Option<Plan>,
+        pub proration: bool,
+        pub quantity: Option<i64>,
+        pub subscription: Option<SubscriptionId>,
+        #[serde(rename="type")]
+        pub line_item_type: LineItemType,
+    }
 }
 
 impl StripeObject for InvoiceLineItem {
@@ -99,7 +104,15 @@ impl serde::Deserialize for LineItemType {
         Ok(match String::deserialize(deserializer)?.as_ref() {
             "invoiceitem"  => LineItemType::InvoiceItem,
             "subscription" => LineItemType::Subscription,
-            unknown        => LineItemType::Unknown(String::from(unknown)),
+            unknown        => LineItemType::Unknown(schema_drift::unknown_value("LineItemType", unknown)),
         })
     }
 }
+
+impl serde::Serialize for LineItemType {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
@@ -1,14 +1,17 @@
+use errors::account_validation_error::AccountValidationError;
+use redact;
+use schema_drift;
 use serde;
 use super::address::Address;
+use super::country::Country;
 use super::date_of_birth::DateOfBirth;
 use super::gender::Gender;
 use super::owner::{Owner, NewOwner};
-use super::verification::Verification;
-use std::collections::BTreeMap;
+use super::verification::IdentityVerification;
 use std::fmt;
 use url_encodable::UrlEncodable;
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct LegalEntity {
     pub additional_owners: Option<Vec<Owner>>,
     pub address: Option<Address>,
@@ -21,8 +24,8 @@ pub struct LegalEntity {
     pub personal_id_number_provided: Option<bool>,
     pub ssn_last_4_provided: Option<bool>,
     #[serde(rename="type")]
-    pub entity_type: LegalEntityType,
-    pub verification: Verification,
+    pub entity_type: Option<LegalEntityType>,
+    pub verification: IdentityVerification,
 }
 
 #[derive(Clone, Debug)]
@@ -49,88 +52,265 @@ impl serde::Deserialize for LegalEntityType {
         Ok(match String::deserialize(deserializer)?.as_ref() {
             "individual" => LegalEntityType::Individual,
             "company"    => LegalEntityType::Company,
-            unknown      => LegalEntityType::Unknown(String::from(unknown)),
+            unknown      => LegalEntityType::Unknown(schema_drift::unknown_value("LegalEntityType", unknown)),
         })
     }
 }
 
-pub struct NewLegalEntity(BTreeMap<String, String>);
+impl serde::Serialize for LegalEntityType {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// The legal entity behind a managed account: an individual, or a company
+/// and its additional owners. Start from `individual` or `company`, then
+/// `validate` before sending it with `CreateAccountCall::legal_entity` or
+/// `UpdateAccountCall::legal_entity`.
+#[derive(Clone, Default)]
+pub struct NewLegalEntity {
+    pub additional_owners: Option<Vec<NewOwner>>,
+    pub address: Option<Address>,
+    pub business_name: Option<String>,
+    pub business_tax_id: Option<String>,
+    pub business_vat_id: Option<String>,
+    pub dob: Option<DateOfBirth>,
+    pub entity_type: Option<LegalEntityType>,
+    pub first_name: Option<String>,
+    pub gender: Option<Gender>,
+    pub last_name: Option<String>,
+    pub maiden_name: Option<String>,
+    pub personal_address: Option<Address>,
+    pub personal_id_number: Option<String>,
+    pub phone_number: Option<String>,
+    pub ssn_last_4: Option<String>,
+    pub verification: Option<String>
+}
+
+impl fmt::Debug for NewLegalEntity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NewLegalEntity")
+            .field("additional_owners", &self.additional_owners)
+            .field("address", &self.address)
+            .field("business_name", &self.business_name)
+            .field("business_tax_id", &self.business_tax_id)
+            .field("business_vat_id", &self.business_vat_id)
+            .field("dob", &self.dob)
+            .field("entity_type", &self.entity_type)
+            .field("first_name", &self.first_name)
+            .field("gender", &self.gender)
+            .field("last_name", &self.last_name)
+            .field("maiden_name", &self.maiden_name)
+            .field("personal_address", &self.personal_address)
+            .field("personal_id_number", &self.personal_id_number.as_ref().map(|_| redact::REDACTED))
+            .field("phone_number", &self.phone_number)
+            .field("ssn_last_4", &self.ssn_last_4.as_ref().map(|_| redact::REDACTED))
+            .field("verification", &self.verification)
+            .finish()
+    }
+}
 
 impl NewLegalEntity {
+    pub fn new() -> NewLegalEntity {
+        NewLegalEntity::default()
+    }
+
+    pub fn individual() -> NewLegalEntity {
+        NewLegalEntity::new().entity_type(LegalEntityType::Individual)
+    }
+
+    pub fn company() -> NewLegalEntity {
+        NewLegalEntity::new().entity_type(LegalEntityType::Company)
+    }
+
     pub fn additional_owners(mut self, additional_owners: Vec<NewOwner>) -> Self {
-        self.0.extend(UrlEncodable::structured_list("additional_owners", &additional_owners));
+        self.additional_owners = Some(additional_owners);
         self
     }
 
     pub fn address(mut self, address: Address) -> Self {
-        self.0.extend(UrlEncodable::named("address", &address));
+        self.address = Some(address);
         self
     }
 
     pub fn business_name(mut self, business_name: String) -> Self {
-        self.0.insert("business_name".to_string(), business_name);
+        self.business_name = Some(business_name);
         self
     }
 
     pub fn business_tax_id(mut self, business_tax_id: String) -> Self {
-        self.0.insert("business_tax_id".to_string(), business_tax_id);
+        self.business_tax_id = Some(business_tax_id);
         self
     }
 
     pub fn business_vat_id(mut self, business_vat_id: String) -> Self {
-        self.0.insert("business_vat_id".to_string(), business_vat_id);
+        self.business_vat_id = Some(business_vat_id);
         self
     }
 
     pub fn dob(mut self, dob: DateOfBirth) -> Self {
-        self.0.extend(UrlEncodable::named("dob", &dob));
+        self.dob = Some(dob);
         self
     }
 
     pub fn first_name(mut self, first_name: String) -> Self {
-        self.0.insert("first_name".to_string(), first_name);
+        self.first_name = Some(first_name);
         self
     }
 
     pub fn gender(mut self, gender: Gender) -> Self {
-        self.0.insert("gender".to_string(), gender.to_string());
+        self.gender = Some(gender);
         self
     }
 
     pub fn last_name(mut self, last_name: String) -> Self {
-        self.0.insert("last_name".to_string(), last_name);
+        self.last_name = Some(last_name);
         self
     }
 
     pub fn maiden_name(mut self, maiden_name: String) -> Self {
-        self.0.insert("maiden_name".to_string(), maiden_name);
+        self.maiden_name = Some(maiden_name);
         self
     }
 
     pub fn personal_address(mut self, personal_address: Address) -> Self {
-        self.0.extend(UrlEncodable::named("personal_address", &personal_address));
+        self.personal_address = Some(personal_address);
+        self
+    }
+
+    /// The full government ID number, e.g. an SSN or SIN. A PII token from
+    /// `create_pii_token` can be given instead.
+    pub fn personal_id_number(mut self, personal_id_number: String) -> Self {
+        self.personal_id_number = Some(personal_id_number);
+        self
+    }
+
+    pub fn phone_number(mut self, phone_number: String) -> Self {
+        self.phone_number = Some(phone_number);
         self
     }
 
     pub fn ssn_last_4(mut self, ssn_last_4: String) -> Self {
-        self.0.insert("ssn_last_4".to_string(), ssn_last_4);
+        self.ssn_last_4 = Some(ssn_last_4);
         self
     }
 
     pub fn entity_type(mut self, entity_type: LegalEntityType) -> Self {
-        self.0.insert("type".to_string(), entity_type.to_string());
+        self.entity_type = Some(entity_type);
         self
     }
 
+    /// The ID of an uploaded scan of an identity document.
     pub fn verification(mut self, verification_file_id: String) -> Self {
-        self.0.insert("verification".to_string(), verification_file_id);
+        self.verification = Some(verification_file_id);
         self
     }
+
+    /// Checks what Stripe would reject outright, for an account in
+    /// `country`: address fields that don't suit their country (`country`
+    /// unless the address has its own), incomplete or impossible dates of
+    /// birth, and an SSN last 4 that isn't four digits, here and on each
+    /// additional owner. Only the fields given are checked, so partial
+    /// updates pass; what's still needed for verification is for
+    /// `OnboardingChecklist`.
+    pub fn validate(&self, country: &Country) -> Result<(), AccountValidationError> {
+        validate_address("legal_entity[address]", &self.address, country)?;
+        validate_address("legal_entity[personal_address]", &self.personal_address, country)?;
+        validate_dob("legal_entity[dob]", &self.dob)?;
+        if let Some(ref ssn_last_4) = self.ssn_last_4 {
+            if ssn_last_4.len() != 4 || !ssn_last_4.chars().all(|c| c.is_digit(10)) {
+                return Err(AccountValidationError::InvalidSsnLast4 {
+                    param: "legal_entity[ssn_last_4]".to_string()
+                });
+            }
+        }
+        if let Some(ref owners) = self.additional_owners {
+            for (i, owner) in owners.iter().enumerate() {
+                let param = format!("legal_entity[additional_owners][{}]", i);
+                validate_address(&format!("{}[address]", param), &owner.address, country)?;
+                validate_dob(&format!("{}[dob]", param), &owner.dob)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn validate_address(
+    param: &str,
+    address: &Option<Address>,
+    country: &Country
+) -> Result<(), AccountValidationError> {
+    match *address {
+        Some(ref address) => address.validate_in(country).map_err(|error| {
+            AccountValidationError::InvalidAddress {
+                param: param.to_string(),
+                error: error
+            }
+        }),
+        None => Ok(())
+    }
+}
+
+fn validate_dob(param: &str, dob: &Option<DateOfBirth>) -> Result<(), AccountValidationError> {
+    match *dob {
+        Some(ref dob) if !dob.is_valid() => Err(AccountValidationError::InvalidDob { param: param.to_string() }),
+        _                                => Ok(())
+    }
 }
 
 impl UrlEncodable for NewLegalEntity {
     fn key_value_pairs(&self) -> Vec<(String, String)> {
-        let NewLegalEntity(ref args) = *self;
-        args.key_value_pairs()
+        let mut vec = Vec::new();
+        if let Some(ref additional_owners) = self.additional_owners {
+            vec.extend(UrlEncodable::structured_list("additional_owners", additional_owners));
+        }
+        if let Some(ref address) = self.address {
+            vec.extend(UrlEncodable::named("address", address));
+        }
+        if let Some(ref business_name) = self.business_name {
+            vec.push(("business_name".to_string(), business_name.to_string()));
+        }
+        if let Some(ref business_tax_id) = self.business_tax_id {
+            vec.push(("business_tax_id".to_string(), business_tax_id.to_string()));
+        }
+        if let Some(ref business_vat_id) = self.business_vat_id {
+            vec.push(("business_vat_id".to_string(), business_vat_id.to_string()));
+        }
+        if let Some(ref dob) = self.dob {
+            vec.extend(UrlEncodable::named("dob", dob));
+        }
+        if let Some(ref first_name) = self.first_name {
+            vec.push(("first_name".to_string(), first_name.to_string()));
+        }
+        if let Some(ref gender) = self.gender {
+            vec.push(("gender".to_string(), gender.to_string()));
+        }
+        if let Some(ref last_name) = self.last_name {
+            vec.push(("last_name".to_string(), last_name.to_string()));
+        }
+        if let Some(ref maiden_name) = self.maiden_name {
+            vec.push(("maiden_name".to_string(), maiden_name.to_string()));
+        }
+        if let Some(ref personal_address) = self.personal_address {
+            vec.extend(UrlEncodable::named("personal_address", personal_address));
+        }
+        if let Some(ref personal_id_number) = self.personal_id_number {
+            vec.push(("personal_id_number".to_string(), personal_id_number.to_string()));
+        }
+        if let Some(ref phone_number) = self.phone_number {
+            vec.push(("phone_number".to_string(), phone_number.to_string()));
+        }
+        if let Some(ref ssn_last_4) = self.ssn_last_4 {
+            vec.push(("ssn_last_4".to_string(), ssn_last_4.to_string()));
+        }
+        if let Some(ref entity_type) = self.entity_type {
+            vec.push(("type".to_string(), entity_type.to_string()));
+        }
+        if let Some(ref verification) = self.verification {
+            vec.push(("verification[document]".to_string(), verification.to_string()));
+        }
+        vec
     }
 }
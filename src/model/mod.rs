@@ -1,8 +1,12 @@
+#[macro_use]
+mod stripe_object;
+
 mod account;
 mod account_reject_reason;
 mod account_update;
 mod address;
 mod alipay_account;
+mod amount;
 mod api_list;
 mod application_fee;
 mod balance;
@@ -11,6 +15,7 @@ mod bank_account;
 mod bitcoin_receiver;
 mod card;
 mod charge;
+mod country;
 mod country_spec;
 mod coupon;
 mod create_charge;
@@ -24,6 +29,7 @@ mod dispute;
 mod event;
 mod fee_refund;
 mod gender;
+mod id;
 mod interval;
 mod invoice;
 mod invoiceitem;
@@ -41,6 +47,7 @@ mod sku;
 mod source;
 mod source_type;
 mod subscription;
+mod timestamp;
 mod token;
 mod transfer;
 mod transfer_reversal;
@@ -52,6 +59,7 @@ pub use model::account_reject_reason::*;
 pub use model::account_update::*;
 pub use model::address::*;
 pub use model::alipay_account::*;
+pub use model::amount::*;
 pub use model::api_list::*;
 pub use model::application_fee::*;
 pub use model::balance::*;
@@ -60,6 +68,7 @@ pub use model::bank_account::*;
 pub use model::bitcoin_receiver::*;
 pub use model::card::*;
 pub use model::charge::*;
+pub use model::country::*;
 pub use model::country_spec::*;
 pub use model::coupon::*;
 pub use model::create_charge::*;
@@ -73,6 +82,7 @@ pub use model::dispute::*;
 pub use model::event::*;
 pub use model::fee_refund::*;
 pub use model::gender::*;
+pub use model::id::*;
 pub use model::interval::*;
 pub use model::invoice::*;
 pub use model::invoiceitem::*;
@@ -90,6 +100,7 @@ pub use model::sku::*;
 pub use model::source::*;
 pub use model::source_type::*;
 pub use model::subscription::*;
+pub use model::timestamp::*;
 pub use model::token::*;
 pub use model::transfer::*;
 pub use model::transfer_reversal::*;
@@ -97,8 +108,9 @@ pub use model::update_charge::*;
 pub use model::verification::*;
 
 use serde::de::Deserialize;
+use serde::ser::Serialize;
 use std::fmt::Debug;
 
-pub trait StripeObject : Clone + Debug + Deserialize {
+pub trait StripeObject : Clone + Debug + Deserialize + Serialize {
     fn id(&self) -> &str;
 }
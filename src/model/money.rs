@@ -1,10 +1,18 @@
 use std::collections::BTreeMap;
+use super::amount::Amount;
 use super::currency::Currency;
 use super::source_type::SourceType;
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Money {
     pub currency: Currency,
     pub amount: i64,
     pub source_types: Option<BTreeMap<SourceType, i64>>
 }
+
+impl Money {
+    /// The amount and currency, without the breakdown by source type.
+    pub fn to_amount(&self) -> Amount {
+        Amount::new(self.amount, self.currency.clone())
+    }
+}
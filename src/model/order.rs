@@ -1,41 +1,46 @@
+use schema_drift;
 use serde;
 use std::collections::BTreeMap;
 use std::fmt;
+use super::amount::Amount;
 use super::currency::Currency;
+use super::id::{ChargeId, CustomerId, OrderId};
 use super::shipping::{Shipping, ShippingMethod};
 use super::StripeObject;
+use super::timestamp::Timestamp;
 use url_encodable::UrlEncodable;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct Order {
-    pub id: String,
-    pub amount: i64,
-    pub application: Option<String>,
-    pub application_fee: Option<i64>,
-    pub charge: Option<String>,
-    pub created: i64,
-    pub currency: Currency,
-    pub customer: String,
-    pub email: Option<String>,
-    pub external_coupon_code: Option<String>,
-    pub items: Vec<OrderItem>,
-    pub livemode: bool,
-    pub metadata: Option<BTreeMap<String, String>>,
-    pub selected_shipping_method: Option<String>,
-    pub shipping: Option<Shipping>,
-    pub shipping_methods: Vec<ShippingMethod>,
-    pub status: OrderStatus,
-    pub status_transitions: Option<BTreeMap<OrderStatus, i64>>,
-    pub updated: i64,
+stripe_object! {
+    pub struct Order {
+        pub id: OrderId,
+        pub amount: i64,
+        pub application: Option<String>,
+        pub application_fee: Option<i64>,
+        pub charge: Option<ChargeId>,
+        pub created: Timestamp,
+        pub currency: Currency,
+        pub customer: Option<CustomerId>,
+        pub email: Option<String>,
+        pub external_coupon_code: Option<String>,
+        pub items: Vec<OrderItem>,
+        pub livemode: bool,
+        pub metadata: Option<BTreeMap<String, String>>,
+        pub selected_shipping_method: Option<String>,
+        pub shipping: Option<Shipping>,
+        pub shipping_methods: Option<Vec<ShippingMethod>>,
+        pub status: OrderStatus,
+        pub status_transitions: Option<BTreeMap<OrderStatus, Option<i64>>>,
+        pub updated: Timestamp,
+    }
 }
 
 impl StripeObject for Order {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Default, Deserialize)]
 pub struct OrderItem {
     pub amount: Option<i64>,
     pub currency: Option<String>,
@@ -46,6 +51,31 @@ pub struct OrderItem {
     pub item_type: Option<ItemType>
 }
 
+impl OrderItem {
+    /// Sets the item's amount and currency from `amount`.
+    pub fn amount_for(mut self, amount: Amount) -> OrderItem {
+        self.amount = Some(amount.amount);
+        self.currency = Some(amount.currency.to_string());
+        self
+    }
+}
+
+impl serde::Serialize for OrderItem {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        let mut state = serializer.serialize_struct("OrderItem", 7)?;
+        serializer.serialize_struct_elt(&mut state, "object", "order_item")?;
+        serializer.serialize_struct_elt(&mut state, "amount", self.amount)?;
+        serializer.serialize_struct_elt(&mut state, "currency", &self.currency)?;
+        serializer.serialize_struct_elt(&mut state, "description", &self.description)?;
+        serializer.serialize_struct_elt(&mut state, "parent", &self.parent)?;
+        serializer.serialize_struct_elt(&mut state, "quantity", self.quantity)?;
+        serializer.serialize_struct_elt(&mut state, "type", &self.item_type)?;
+        serializer.serialize_struct_end(state)
+    }
+}
+
 impl UrlEncodable for OrderItem {
     fn key_value_pairs(&self) -> Vec<(String, String)> {
         let mut vec = Vec::new();
@@ -104,11 +134,19 @@ impl serde::Deserialize for ItemType {
             "tax"      => ItemType::Tax,
             "shipping" => ItemType::Shipping,
             "discount" => ItemType::Discount,
-            unknown    => ItemType::Unknown(String::from(unknown)),
+            unknown    => ItemType::Unknown(schema_drift::unknown_value("ItemType", unknown)),
         })
     }
 }
 
+impl serde::Serialize for ItemType {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum OrderStatus {
     Created,
@@ -142,7 +180,15 @@ impl serde::Deserialize for OrderStatus {
             "canceled"  => OrderStatus::Canceled,
             "fulfilled" => OrderStatus::Fulfilled,
             "returned"  => OrderStatus::Returned,
-            unknown     => OrderStatus::Unknown(String::from(unknown)),
+            unknown     => OrderStatus::Unknown(schema_drift::unknown_value("OrderStatus", unknown)),
         })
     }
 }
+
+impl serde::Serialize for OrderStatus {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
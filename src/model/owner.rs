@@ -1,50 +1,79 @@
 use super::address::Address;
 use super::date_of_birth::DateOfBirth;
-use super::verification::Verification;
+use super::verification::IdentityVerification;
 use url_encodable::UrlEncodable;
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Owner {
     address: Option<Address>,
     dob: Option<DateOfBirth>,
     first_name: Option<String>,
     last_name: Option<String>,
-    verification: Option<Verification>
+    verification: Option<IdentityVerification>
 }
 
-pub struct NewOwner(Vec<(String, String)>);
+/// An additional owner of a company, sent with
+/// `NewLegalEntity::additional_owners` and checked with
+/// `NewLegalEntity::validate`.
+#[derive(Clone, Debug, Default)]
+pub struct NewOwner {
+    pub address: Option<Address>,
+    pub dob: Option<DateOfBirth>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub verification: Option<String>
+}
 
 impl NewOwner {
+    pub fn new() -> NewOwner {
+        NewOwner::default()
+    }
+
     pub fn address(mut self, address: Address) -> Self {
-        self.0.extend(UrlEncodable::named("address", &address));
+        self.address = Some(address);
         self
     }
 
     pub fn dob(mut self, dob: DateOfBirth) -> Self {
-        self.0.extend(UrlEncodable::named("dob", &dob));
+        self.dob = Some(dob);
         self
     }
 
     pub fn first_name(mut self, first_name: String) -> Self {
-        self.0.push(("first_name".to_string(), first_name));
+        self.first_name = Some(first_name);
         self
     }
 
     pub fn last_name(mut self, last_name: String) -> Self {
-        self.0.push(("last_name".to_string(), last_name));
+        self.last_name = Some(last_name);
         self
     }
 
-    pub fn verification(mut self, verification_file_token: String) -> Self {
-        self.0.push(("verification".to_string(), verification_file_token));
+    /// The ID of an uploaded scan of an identity document.
+    pub fn verification(mut self, verification_file_id: String) -> Self {
+        self.verification = Some(verification_file_id);
         self
     }
 }
 
 impl UrlEncodable for NewOwner {
     fn key_value_pairs(&self) -> Vec<(String, String)> {
-        self.0.key_value_pairs()
+        let mut vec = Vec::new();
+        if let Some(ref address) = self.address {
+            vec.extend(UrlEncodable::named("address", address));
+        }
+        if let Some(ref dob) = self.dob {
+            vec.extend(UrlEncodable::named("dob", dob));
+        }
+        if let Some(ref first_name) = self.first_name {
+            vec.push(("first_name".to_string(), first_name.to_string()));
+        }
+        if let Some(ref last_name) = self.last_name {
+            vec.push(("last_name".to_string(), last_name.to_string()));
+        }
+        if let Some(ref verification) = self.verification {
+            vec.push(("verification[document]".to_string(), verification.to_string()));
+        }
+        vec
     }
 }
-
-
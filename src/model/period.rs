@@ -1,5 +1,7 @@
-#[derive(Clone, Debug, Deserialize)]
+use super::timestamp::Timestamp;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Period {
-    pub start: i64,
-    pub end: i64
+    pub start: Timestamp,
+    pub end: Timestamp
 }
@@ -1,25 +1,28 @@
 use std::collections::BTreeMap;
 use super::currency::Currency;
+use super::id::PlanId;
 use super::interval::Interval;
 use super::StripeObject;
+use super::timestamp::Timestamp;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct Plan {
-    pub id: String,
-    pub amount: i64,
-    pub created: i64,
-    pub currency: Currency,
-    pub interval: Interval,
-    pub interval_count: i64,
-    pub livemode: bool,
-    pub metadata: Option<BTreeMap<String, String>>,
-    pub name: String,
-    pub statement_descriptor: Option<String>,
-    pub trial_period_days: Option<i64>
+stripe_object! {
+    pub struct Plan {
+        pub id: PlanId,
+        pub amount: i64,
+        pub created: Timestamp,
+        pub currency: Currency,
+        pub interval: Interval,
+        pub interval_count: i64,
+        pub livemode: bool,
+        pub metadata: Option<BTreeMap<String, String>>,
+        pub name: String,
+        pub statement_descriptor: Option<String>,
+        pub trial_period_days: Option<i64>
+    }
 }
 
 impl StripeObject for Plan {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
@@ -1,28 +1,32 @@
+use schema_drift;
 use serde;
 use std::collections::BTreeMap;
-use super::account::Account;
+use std::fmt;
 use super::api_list::ApiList;
+use super::bank_account::BankAccount;
 use super::card::Card;
+use super::id::RecipientId;
 use super::StripeObject;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct Recipient {
-    pub id: String,
-    pub active_account: Option<Account>,
-    pub cards: ApiList<Card>,
-    pub description: Option<String>,
-    pub email: Option<String>,
-    pub livemode: bool,
-    pub metadata: Option<BTreeMap<String, String>>,
-    pub migrated_to: Option<String>,
-    pub name: String,
-    #[serde(rename="type")]
-    pub recipient_type: RecipientType,
+stripe_object! {
+    pub struct Recipient {
+        pub id: RecipientId,
+        pub active_account: Option<BankAccount>,
+        pub cards: ApiList<Card>,
+        pub description: Option<String>,
+        pub email: Option<String>,
+        pub livemode: bool,
+        pub metadata: Option<BTreeMap<String, String>>,
+        pub migrated_to: Option<String>,
+        pub name: String,
+        #[serde(rename="type")]
+        pub recipient_type: RecipientType,
+    }
 }
 
 impl StripeObject for Recipient {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
 
@@ -40,7 +44,25 @@ impl serde::Deserialize for RecipientType {
         Ok(match String::deserialize(deserializer)?.as_ref() {
             "individual"  => RecipientType::Individual,
             "corporation" => RecipientType::Corporation,
-            unknown       => RecipientType::Unknown(String::from(unknown)),
+            unknown       => RecipientType::Unknown(schema_drift::unknown_value("RecipientType", unknown)),
         })
     }
 }
+
+impl fmt::Display for RecipientType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RecipientType::Individual           => write!(f, "individual"),
+            RecipientType::Corporation          => write!(f, "corporation"),
+            RecipientType::Unknown(ref unknown) => write!(f, "{}", unknown),
+        }
+    }
+}
+
+impl serde::Serialize for RecipientType {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
@@ -1,27 +1,31 @@
+use schema_drift;
 use serde;
 use std::collections::BTreeMap;
 use std::fmt;
 use super::currency::Currency;
+use super::id::{BalanceTransactionId, ChargeId, RefundId};
 use super::StripeObject;
+use super::timestamp::Timestamp;
 
-/// https://stripe.com/docs/api#refund_object
-#[derive(Clone, Debug, Deserialize)]
-pub struct Refund {
-    pub id: String,
-    pub amount: i64,
-    pub balance_transaction: Option<String>,
-    pub charge: String,
-    pub created: i64,
-    pub currency: Currency,
-    pub description: Option<String>,
-    pub metadata: Option<BTreeMap<String, String>>,
-    pub reason: RefundReason,
-    pub receipt_number: String
+stripe_object! {
+    /// https://stripe.com/docs/api#refund_object
+    pub struct Refund {
+        pub id: RefundId,
+        pub amount: i64,
+        pub balance_transaction: Option<BalanceTransactionId>,
+        pub charge: ChargeId,
+        pub created: Timestamp,
+        pub currency: Currency,
+        pub description: Option<String>,
+        pub metadata: Option<BTreeMap<String, String>>,
+        pub reason: Option<RefundReason>,
+        pub receipt_number: Option<String>
+    }
 }
 
 impl StripeObject for Refund {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
 
@@ -52,7 +56,15 @@ impl serde::Deserialize for RefundReason {
             "duplicate"             => RefundReason::Duplicate,
             "fraudulent"            => RefundReason::Fraudulent,
             "requested_by_customer" => RefundReason::RequestedByCustomer,
-            unknown                 => RefundReason::Unknown(String::from(unknown)),
+            unknown                 => RefundReason::Unknown(schema_drift::unknown_value("RefundReason", unknown)),
         })
     }
 }
+
+impl serde::Serialize for RefundReason {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
@@ -1,4 +1,6 @@
+use schema_drift;
 use serde;
+use std::fmt;
 use super::address::Address;
 use super::currency::Currency;
 use super::StripeObject;
@@ -39,13 +41,14 @@ impl UrlEncodable for Shipping {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ShippingMethod {
-    pub id: String,
-    pub amount: i64,
-    pub currency: Currency,
-    pub delivery_estimate: Option<DeliveryEstimate>,
-    pub description: Option<String>,
+stripe_object! {
+    pub struct ShippingMethod {
+        pub id: String,
+        pub amount: i64,
+        pub currency: Currency,
+        pub delivery_estimate: Option<DeliveryEstimate>,
+        pub description: Option<String>,
+    }
 }
 
 impl StripeObject for ShippingMethod {
@@ -54,7 +57,7 @@ impl StripeObject for ShippingMethod {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeliveryEstimate {
     pub date: Option<String>,
     pub earliest: Option<String>,
@@ -77,7 +80,25 @@ impl serde::Deserialize for DeliveryEstimateType {
         Ok(match String::deserialize(deserializer)?.as_ref() {
             "range" => DeliveryEstimateType::Range,
             "exact" => DeliveryEstimateType::Exact,
-            unknown => DeliveryEstimateType::Unknown(String::from(unknown)),
+            unknown => DeliveryEstimateType::Unknown(schema_drift::unknown_value("DeliveryEstimateType", unknown)),
         })
     }
 }
+
+impl fmt::Display for DeliveryEstimateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DeliveryEstimateType::Range                => write!(f, "range"),
+            DeliveryEstimateType::Exact                => write!(f, "exact"),
+            DeliveryEstimateType::Unknown(ref unknown) => write!(f, "{}", unknown),
+        }
+    }
+}
+
+impl serde::Serialize for DeliveryEstimateType {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
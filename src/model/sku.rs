@@ -1,37 +1,41 @@
 use either::Either;
+use schema_drift;
 use serde;
 use std::collections::BTreeMap;
 use std::fmt;
 use super::currency::Currency;
+use super::id::{ProductId, SkuId};
 use super::product::Product;
 use super::dimensions::Dimensions;
 use super::StripeObject;
+use super::timestamp::Timestamp;
 use url_encodable::UrlEncodable;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct Sku {
-    pub id: String,
-    pub active: bool,
-    pub attributes: BTreeMap<String, String>,
-    pub created: i64,
-    pub currency: Currency,
-    pub image: Option<String>,
-    pub inventory: Inventory,
-    pub livemode: bool,
-    pub metadata: Option<BTreeMap<String, String>>,
-    pub package_dimensions: Option<Dimensions>,
-    pub price: i64,
-    pub product: Either<String, Product>,
-    pub updated: i64
+stripe_object! {
+    pub struct Sku {
+        pub id: SkuId,
+        pub active: bool,
+        pub attributes: BTreeMap<String, String>,
+        pub created: Timestamp,
+        pub currency: Currency,
+        pub image: Option<String>,
+        pub inventory: Inventory,
+        pub livemode: bool,
+        pub metadata: Option<BTreeMap<String, String>>,
+        pub package_dimensions: Option<Dimensions>,
+        pub price: i64,
+        pub product: Either<ProductId, Product>,
+        pub updated: Timestamp
+    }
 }
 
 impl StripeObject for Sku {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Inventory {
     pub quantity: Option<i64>,
     #[serde(rename="type")]
@@ -46,7 +50,7 @@ impl UrlEncodable for Inventory {
             v.push(("quantity".to_string(), quantity.to_string()));
         }
         v.push(("type".to_string(), self.inventory_type.to_string()));
-        if let Some(ref value) = self.quantity {
+        if let Some(ref value) = self.value {
             v.push(("value".to_string(), value.to_string()));
         }
         v
@@ -80,11 +84,19 @@ impl serde::Deserialize for InventoryType {
             "finite"   => InventoryType::Finite,
             "bucket"   => InventoryType::Bucket,
             "infinite" => InventoryType::Infinite,
-            unknown    => InventoryType::Unknown(String::from(unknown)),
+            unknown    => InventoryType::Unknown(schema_drift::unknown_value("InventoryType", unknown)),
         })
     }
 }
 
+impl serde::Serialize for InventoryType {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum InventoryState {
     InStock,
@@ -112,7 +124,15 @@ impl serde::Deserialize for InventoryState {
             "in_stock"     => InventoryState::InStock,
             "limited"      => InventoryState::Limited,
             "out_of_stock" => InventoryState::OutOfStock,
-            unknown        => InventoryState::Unknown(String::from(unknown)),
+            unknown        => InventoryState::Unknown(schema_drift::unknown_value("InventoryState", unknown)),
         })
     }
 }
+
+impl serde::Serialize for InventoryState {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
@@ -1,6 +1,8 @@
-use serde::Deserialize;
+use schema_drift;
+use serde::{Deserialize, Serialize};
 use serde;
 use serde::de::Error;
+use serde_json;
 use serde_json::value::Value;
 use super::bitcoin_receiver::BitcoinReceiver;
 use super::card::Card;
@@ -8,6 +10,8 @@ use super::StripeObject;
 
 type UnknownId = String;
 
+/// A charge's source. Serializes with the `object` field Stripe sends, so
+/// a serialized `Source` deserializes back into the same variant.
 #[derive(Clone, Debug)]
 pub enum Source {
     Card(Card),
@@ -18,8 +22,8 @@ pub enum Source {
 impl StripeObject for Source {
     fn id(&self) -> &str {
         match *self {
-            Source::Card(ref card)                => &card.id,
-            Source::BitcoinReceiver(ref receiver) => &receiver.id,
+            Source::Card(ref card)                => card.id.as_str(),
+            Source::BitcoinReceiver(ref receiver) => receiver.id.as_str(),
             Source::Unknown(_, ref id)            => id
         }
     }
@@ -29,22 +33,42 @@ impl Deserialize for Source {
     fn deserialize<D>(deserializer: &mut D) -> Result<Source, D::Error>
         where D: serde::Deserializer
     {
-        if let Ok(card) = Card::deserialize(deserializer) {
-            return Ok(Source::Card(card));
-        }
-        if let Ok(receiver) = BitcoinReceiver::deserialize(deserializer) {
-            return Ok(Source::BitcoinReceiver(receiver));
-        }
-        if let Ok(json) = Value::deserialize(deserializer) {
-            if let Some(id) = {
-                match json.find("id") {
-                    Some(&Value::String(ref id)) => Some(id.clone()),
-                    _                            => None
-                }
-            } {
-                return Ok(Source::Unknown(json, id))
+        let json = Value::deserialize(deserializer)?;
+        let object = match json.find("object") {
+            Some(&Value::String(ref object)) => object.clone(),
+            _                                => String::new()
+        };
+        match object.as_ref() {
+            "card" => serde_json::from_value(json)
+                .map(Source::Card)
+                .map_err(|err| D::Error::custom(err.to_string())),
+            "bitcoin_receiver" => serde_json::from_value(json)
+                .map(Source::BitcoinReceiver)
+                .map_err(|err| D::Error::custom(err.to_string())),
+            _ => {
+                let id = match json.find("id") {
+                    Some(&Value::String(ref id)) => id.clone(),
+                    _ => return Err(D::Error::missing_field("id"))
+                };
+                schema_drift::unknown_value("Source", &object);
+                Ok(Source::Unknown(json, id))
             }
         }
-        Err(D::Error::unknown_variant("Expected card, receiver, or object with id"))
+    }
+}
+
+impl Serialize for Source {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        let (mut json, object) = match *self {
+            Source::Card(ref card)                => (serde_json::to_value(card), "card"),
+            Source::BitcoinReceiver(ref receiver) => (serde_json::to_value(receiver), "bitcoin_receiver"),
+            Source::Unknown(ref json, _)          => return json.serialize(serializer)
+        };
+        if let Value::Object(ref mut map) = json {
+            map.insert("object".to_string(), Value::String(object.to_string()));
+        }
+        json.serialize(serializer)
     }
 }
@@ -1,3 +1,4 @@
+use schema_drift;
 use serde;
 use std::fmt;
 
@@ -19,11 +20,19 @@ impl serde::Deserialize for SourceType {
             "bank_account"     => SourceType::BankAccount,
             "bitcoin_receiver" => SourceType::BitcoinReceiver,
             "alipay_account"   => SourceType::AlipayAccount,
-            other              => SourceType::Other(String::from(other)),
+            other              => SourceType::Other(schema_drift::unknown_value("SourceType", other)),
         })
     }
 }
 
+impl serde::Serialize for SourceType {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
 impl fmt::Display for SourceType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
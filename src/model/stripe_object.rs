@@ -0,0 +1,106 @@
+/// Declares a top-level Stripe model: the struct as written, plus the
+/// `object` name Stripe sent (e.g. `charge`) and an `extra` map of any fields
+/// Stripe sent that the struct doesn't declare. Both are filled in when
+/// deserializing and written back out when serializing, so a model
+/// round-trips without losing fields from newer API versions.
+///
+/// Fields must be either all `pub` or all private.
+macro_rules! stripe_object {
+    (
+        $(#[$attr:meta])*
+        pub struct $name:ident {
+            $($(#[$field_attr:meta])* pub $field:ident: $ty:ty),* $(,)*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug)]
+        pub struct $name {
+            $($(#[$field_attr])* pub $field: $ty,)*
+            /// Stripe's name for the type of object, e.g. `charge`, when it
+            /// sent one.
+            pub object: Option<String>,
+            /// Fields Stripe sent that this struct doesn't declare, by name.
+            pub extra: ::std::collections::BTreeMap<String, ::serde_json::Value>
+        }
+
+        stripe_object!(@serde $name { $($(#[$field_attr])* $field: $ty),* });
+    };
+
+    (
+        $(#[$attr:meta])*
+        pub struct $name:ident {
+            $($(#[$field_attr:meta])* $field:ident: $ty:ty),* $(,)*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug)]
+        pub struct $name {
+            $($(#[$field_attr])* $field: $ty,)*
+            /// Stripe's name for the type of object, e.g. `charge`, when it
+            /// sent one.
+            pub object: Option<String>,
+            /// Fields Stripe sent that this struct doesn't declare, by name.
+            pub extra: ::std::collections::BTreeMap<String, ::serde_json::Value>
+        }
+
+        stripe_object!(@serde $name { $($(#[$field_attr])* $field: $ty),* });
+    };
+
+    (@serde $name:ident { $($(#[$field_attr:meta])* $field:ident: $ty:ty),* }) => {
+        /// Deserializes the declared fields through a private twin of the
+        /// struct, then keeps whatever the twin doesn't serialize back out.
+        impl ::serde::Deserialize for $name {
+            fn deserialize<D>(deserializer: &mut D) -> Result<$name, D::Error>
+                where D: ::serde::Deserializer
+            {
+                use serde::de::Error;
+
+                #[derive(Deserialize, Serialize)]
+                struct Fields {
+                    $($(#[$field_attr])* $field: $ty,)*
+                }
+
+                let json = <::serde_json::Value as ::serde::Deserialize>::deserialize(deserializer)?;
+                let fields: Fields = ::serde_json::from_value(json.clone())
+                    .map_err(|err| D::Error::custom(err.to_string()))?;
+                let object = match json.find("object") {
+                    Some(&::serde_json::Value::String(ref object)) => Some(object.clone()),
+                    _                                             => None
+                };
+                let extra = ::unknown_fields::extract(
+                    stringify!($name),
+                    json,
+                    &::serde_json::to_value(&fields)
+                );
+                let Fields { $($field),* } = fields;
+                Ok($name {
+                    $($field: $field,)*
+                    object: object,
+                    extra: extra
+                })
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+                where S: ::serde::Serializer
+            {
+                #[derive(Serialize)]
+                struct Fields<'a> {
+                    $($(#[$field_attr])* $field: &'a $ty,)*
+                }
+
+                let fields = Fields {
+                    $($field: &self.$field),*
+                };
+                let mut json = ::unknown_fields::merge(::serde_json::to_value(&fields), &self.extra);
+                if let Some(ref object) = self.object {
+                    if let ::serde_json::Value::Object(ref mut map) = json {
+                        map.insert("object".to_string(), ::serde_json::Value::String(object.clone()));
+                    }
+                }
+                ::serde::Serialize::serialize(&json, serializer)
+            }
+        }
+    };
+}
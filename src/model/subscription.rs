@@ -1,35 +1,40 @@
 use either::Either;
+use schema_drift;
 use serde;
 use std::collections::BTreeMap;
+use std::fmt;
 use super::customer::Customer;
 use super::discount::Discount;
+use super::id::{CustomerId, SubscriptionId};
 use super::plan::Plan;
 use super::StripeObject;
+use super::timestamp::Timestamp;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct Subscription {
-    pub id: String,
-    pub application_fee_percent: Option<f64>,
-    pub cancel_at_period_end: bool,
-    pub canceled_at: Option<i64>,
-    pub current_period_end: Option<i64>,
-    pub current_period_start: Option<i64>,
-    pub customer: Either<String, Customer>,
-    pub discount: Discount,
-    pub ended_at: Option<i64>,
-    pub metadata: Option<BTreeMap<String, String>>,
-    pub plan: Plan,
-    pub quantity: i64,
-    pub start: i64,
-    pub status: SubscriptionStatus,
-    pub tax_percent: Option<f64>,
-    pub trial_end: Option<i64>,
-    pub trial_start: Option<i64>,
+stripe_object! {
+    pub struct Subscription {
+        pub id: SubscriptionId,
+        pub application_fee_percent: Option<f64>,
+        pub cancel_at_period_end: bool,
+        pub canceled_at: Option<Timestamp>,
+        pub current_period_end: Option<Timestamp>,
+        pub current_period_start: Option<Timestamp>,
+        pub customer: Either<CustomerId, Customer>,
+        pub discount: Option<Discount>,
+        pub ended_at: Option<Timestamp>,
+        pub metadata: Option<BTreeMap<String, String>>,
+        pub plan: Plan,
+        pub quantity: i64,
+        pub start: Timestamp,
+        pub status: SubscriptionStatus,
+        pub tax_percent: Option<f64>,
+        pub trial_end: Option<Timestamp>,
+        pub trial_start: Option<Timestamp>,
+    }
 }
 
 impl StripeObject for Subscription {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
 
@@ -53,7 +58,28 @@ impl serde::Deserialize for SubscriptionStatus {
             "past_due" => SubscriptionStatus::PastDue,
             "canceled" => SubscriptionStatus::Canceled,
             "unpaid"   => SubscriptionStatus::Unpaid,
-            unknown    => SubscriptionStatus::Unknown(String::from(unknown)),
+            unknown    => SubscriptionStatus::Unknown(schema_drift::unknown_value("SubscriptionStatus", unknown)),
         })
     }
 }
+
+impl fmt::Display for SubscriptionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SubscriptionStatus::Trialing             => write!(f, "trialing"),
+            SubscriptionStatus::Active               => write!(f, "active"),
+            SubscriptionStatus::PastDue              => write!(f, "past_due"),
+            SubscriptionStatus::Canceled             => write!(f, "canceled"),
+            SubscriptionStatus::Unpaid               => write!(f, "unpaid"),
+            SubscriptionStatus::Unknown(ref unknown) => write!(f, "{}", unknown),
+        }
+    }
+}
+
+impl serde::Serialize for SubscriptionStatus {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(feature = "timestamps")]
+use chrono::{DateTime, TimeZone, UTC};
+use serde;
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A point in time, as Stripe sends and takes it: whole seconds since the
+/// Unix epoch. Converts to and from `SystemTime`, and to and from chrono's
+/// `DateTime` with the `timestamps` feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(i64);
+
+impl Timestamp {
+    pub fn from_secs(secs: i64) -> Timestamp {
+        Timestamp(secs)
+    }
+
+    pub fn now() -> Timestamp {
+        Timestamp::from(SystemTime::now())
+    }
+
+    /// Seconds since the Unix epoch.
+    pub fn as_secs(&self) -> i64 {
+        self.0
+    }
+
+    /// The (year, month) it falls in, in UTC.
+    pub fn year_month(&self) -> (i64, i64) {
+        // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = if self.0 >= 0 { self.0 / 86400 } else { (self.0 - 86399) / 86400 };
+        let z = days + 719468;
+        let era = (if z >= 0 { z } else { z - 146096 }) / 146097;
+        let day_of_era = z - era * 146097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        (year, month)
+    }
+
+    pub fn to_system_time(&self) -> SystemTime {
+        if self.0 >= 0 {
+            UNIX_EPOCH + Duration::from_secs(self.0 as u64)
+        } else {
+            UNIX_EPOCH - Duration::from_secs(-self.0 as u64)
+        }
+    }
+
+    #[cfg(feature = "timestamps")]
+    pub fn to_date_time(&self) -> DateTime<UTC> {
+        UTC.timestamp(self.0, 0)
+    }
+}
+
+impl From<i64> for Timestamp {
+    fn from(secs: i64) -> Timestamp {
+        Timestamp(secs)
+    }
+}
+
+/// Truncates to the second, toward the past.
+impl From<SystemTime> for Timestamp {
+    fn from(time: SystemTime) -> Timestamp {
+        match time.duration_since(UNIX_EPOCH) {
+            Ok(since) => Timestamp(since.as_secs() as i64),
+            Err(err)  => {
+                let before = err.duration();
+                let secs = before.as_secs() as i64;
+                Timestamp(if before.subsec_nanos() > 0 { -secs - 1 } else { -secs })
+            }
+        }
+    }
+}
+
+impl From<Timestamp> for SystemTime {
+    fn from(timestamp: Timestamp) -> SystemTime {
+        timestamp.to_system_time()
+    }
+}
+
+/// Truncates to the second.
+#[cfg(feature = "timestamps")]
+impl<Tz: TimeZone> From<DateTime<Tz>> for Timestamp {
+    fn from(time: DateTime<Tz>) -> Timestamp {
+        Timestamp(time.timestamp())
+    }
+}
+
+#[cfg(feature = "timestamps")]
+impl From<Timestamp> for DateTime<UTC> {
+    fn from(timestamp: Timestamp) -> DateTime<UTC> {
+        timestamp.to_date_time()
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl serde::Deserialize for Timestamp {
+    fn deserialize<D>(deserializer: &mut D) -> Result<Timestamp, D::Error>
+        where D: serde::Deserializer
+    {
+        <i64 as serde::Deserialize>::deserialize(deserializer).map(Timestamp)
+    }
+}
+
+impl serde::Serialize for Timestamp {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_i64(self.0)
+    }
+}
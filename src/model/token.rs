@@ -1,24 +1,29 @@
+use schema_drift;
 use serde;
+use std::fmt;
 use super::bank_account::BankAccount;
 use super::card::Card;
+use super::id::TokenId;
 use super::StripeObject;
+use super::timestamp::Timestamp;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct Token {
-    pub id: String,
-    pub bank_account: Option<BankAccount>,
-    pub card: Option<Card>,
-    pub client_ip: Option<String>,
-    pub created: i64,
-    pub livemode: bool,
-    #[serde(rename="type")]
-    pub token_type: TokenType,
-    pub used: bool
+stripe_object! {
+    pub struct Token {
+        pub id: TokenId,
+        pub bank_account: Option<BankAccount>,
+        pub card: Option<Card>,
+        pub client_ip: Option<String>,
+        pub created: Timestamp,
+        pub livemode: bool,
+        #[serde(rename="type")]
+        pub token_type: TokenType,
+        pub used: bool
+    }
 }
 
 impl StripeObject for Token {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
 
@@ -38,7 +43,26 @@ impl serde::Deserialize for TokenType {
             "bank_account" => TokenType::BankAccount,
             "card"         => TokenType::Card,
             "pii"          => TokenType::Pii,
-            unknown        => TokenType::Unknown(String::from(unknown)),
+            unknown        => TokenType::Unknown(schema_drift::unknown_value("TokenType", unknown)),
         })
     }
 }
+
+impl fmt::Display for TokenType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TokenType::BankAccount          => write!(f, "bank_account"),
+            TokenType::Card                 => write!(f, "card"),
+            TokenType::Pii                  => write!(f, "pii"),
+            TokenType::Unknown(ref unknown) => write!(f, "{}", unknown),
+        }
+    }
+}
+
+impl serde::Serialize for TokenType {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
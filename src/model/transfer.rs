@@ -1,41 +1,45 @@
+use schema_drift;
 use serde;
 use std::collections::BTreeMap;
 use std::fmt;
 use super::api_list::ApiList;
 use super::currency::Currency;
+use super::id::{ApplicationFeeId, BalanceTransactionId, ChargeId, TransferId, TransferReversalId};
 use super::source_type::SourceType;
 use super::StripeObject;
+use super::timestamp::Timestamp;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct Transfer {
-    pub id: String,
-    pub amount: i64,
-    pub amount_reversed: i64,
-    pub application_fee: String,
-    pub balance_transaction: String,
-    pub created: i64,
-    pub currency: Currency,
-    pub date: i64,
-    pub description: Option<String>,
-    pub destination: String,
-    pub destination_payment: Option<String>,
-    pub failure_code: Option<TransferFailureCode>,
-    pub failure_message: Option<String>,
-    pub livemode: bool,
-    pub metadata: Option<BTreeMap<String, String>>,
-    pub reversals: ApiList<Reversal>,
-    pub reversed: bool,
-    pub source_transaction: Option<String>,
-    pub source_type: SourceType,
-    pub statement_descriptor: Option<String>,
-    pub status: TransferStatus,
-    #[serde(rename="type")]
-    pub transfer_type: TransferType
+stripe_object! {
+    pub struct Transfer {
+        pub id: TransferId,
+        pub amount: i64,
+        pub amount_reversed: i64,
+        pub application_fee: Option<ApplicationFeeId>,
+        pub balance_transaction: BalanceTransactionId,
+        pub created: Timestamp,
+        pub currency: Currency,
+        pub date: Timestamp,
+        pub description: Option<String>,
+        pub destination: String,
+        pub destination_payment: Option<ChargeId>,
+        pub failure_code: Option<TransferFailureCode>,
+        pub failure_message: Option<String>,
+        pub livemode: bool,
+        pub metadata: Option<BTreeMap<String, String>>,
+        pub reversals: ApiList<Reversal>,
+        pub reversed: bool,
+        pub source_transaction: Option<String>,
+        pub source_type: SourceType,
+        pub statement_descriptor: Option<String>,
+        pub status: TransferStatus,
+        #[serde(rename="type")]
+        pub transfer_type: TransferType
+    }
 }
 
 impl StripeObject for Transfer {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
 
@@ -72,11 +76,19 @@ impl serde::Deserialize for TransferStatus {
             "in_transit" => TransferStatus::InTransit,
             "canceled"   => TransferStatus::Canceled,
             "failed"     => TransferStatus::Failed,
-            other        => TransferStatus::Other(String::from(other)),
+            other        => TransferStatus::Other(schema_drift::unknown_value("TransferStatus", other)),
         })
     }
 }
 
+impl serde::Serialize for TransferStatus {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum TransferType {
     Card,
@@ -90,14 +102,33 @@ impl serde::Deserialize for TransferType {
         where D: serde::Deserializer
     {
         Ok(match String::deserialize(deserializer)?.as_ref() {
-            "card"          => TransferType::Card,
-            "bank_account"  => TransferType::BankAccount,
-            "strip_account" => TransferType::StripeAccount,
-            other           => TransferType::Other(String::from(other)),
+            "card"           => TransferType::Card,
+            "bank_account"   => TransferType::BankAccount,
+            "stripe_account" => TransferType::StripeAccount,
+            other            => TransferType::Other(schema_drift::unknown_value("TransferType", other)),
         })
     }
 }
 
+impl fmt::Display for TransferType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TransferType::Card             => write!(f, "card"),
+            TransferType::BankAccount      => write!(f, "bank_account"),
+            TransferType::StripeAccount    => write!(f, "stripe_account"),
+            TransferType::Other(ref other) => write!(f, "{}", other),
+        }
+    }
+}
+
+impl serde::Serialize for TransferType {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum TransferFailureCode {
     InsufficientFunds,
@@ -146,24 +177,33 @@ impl serde::Deserialize for TransferFailureCode {
             "could_not_process"       => TransferFailureCode::CouldNotProcess,
             "bank_account_restricted" => TransferFailureCode::BankAccountRestricted,
             "invalid_currency"        => TransferFailureCode::InvalidCurrency,
-            other                     => TransferFailureCode::Other(String::from(other)),
+            other                     => TransferFailureCode::Other(schema_drift::unknown_value("TransferFailureCode", other)),
         })
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct Reversal {
-    pub id: String,
-    pub amount: i64,
-    pub balance_transaction: String,
-    pub created: i64,
-    pub currency: Currency,
-    pub metadata: BTreeMap<String, String>,
-    pub transfer: String
+impl serde::Serialize for TransferFailureCode {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+stripe_object! {
+    pub struct Reversal {
+        pub id: TransferReversalId,
+        pub amount: i64,
+        pub balance_transaction: BalanceTransactionId,
+        pub created: Timestamp,
+        pub currency: Currency,
+        pub metadata: BTreeMap<String, String>,
+        pub transfer: TransferId
+    }
 }
 
 impl StripeObject for Reversal {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
@@ -1,20 +1,23 @@
 use std::collections::BTreeMap;
 use super::currency::Currency;
+use super::id::{BalanceTransactionId, TransferId, TransferReversalId};
 use super::StripeObject;
+use super::timestamp::Timestamp;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct TransferReversal {
-    pub id: String,
-    pub amount: i64,
-    pub balance_transaction: String,
-    pub created: i64,
-    pub currency: Currency,
-    pub metadata: Option<BTreeMap<String, String>>,
-    pub transfer: String,
+stripe_object! {
+    pub struct TransferReversal {
+        pub id: TransferReversalId,
+        pub amount: i64,
+        pub balance_transaction: BalanceTransactionId,
+        pub created: Timestamp,
+        pub currency: Currency,
+        pub metadata: Option<BTreeMap<String, String>>,
+        pub transfer: TransferId,
+    }
 }
 
 impl StripeObject for TransferReversal {
     fn id(&self) -> &str {
-        &self.id
+        self.id.as_str()
     }
 }
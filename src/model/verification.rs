@@ -1,7 +1,65 @@
-#[derive(Clone, Debug, Deserialize)]
+use schema_drift;
+use serde;
+use std::fmt;
+use super::timestamp::Timestamp;
+
+/// What Stripe still needs to verify a managed account.
+/// https://stripe.com/docs/api#account_object-verification
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Verification {
-    fields_needed: Vec<String>,
-    due_by: i64,
-    contacted: bool,
-    disabled_reason: String
+    /// Fields Stripe needs now, named like `legal_entity.dob.day`.
+    pub fields_needed: Vec<String>,
+    pub due_by: Option<Timestamp>,
+    pub contacted: Option<bool>,
+    pub disabled_reason: Option<String>
+}
+
+/// The state of verifying the identity of a legal entity or owner.
+/// https://stripe.com/docs/api#account_object-legal_entity-verification
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct IdentityVerification {
+    pub details: Option<String>,
+    pub details_code: Option<String>,
+    pub document: Option<String>,
+    pub status: IdentityVerificationStatus
+}
+
+#[derive(Clone, Debug)]
+pub enum IdentityVerificationStatus {
+    Unverified,
+    Pending,
+    Verified,
+    Unknown(String)
+}
+
+impl fmt::Display for IdentityVerificationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IdentityVerificationStatus::Unverified     => write!(f, "unverified"),
+            IdentityVerificationStatus::Pending        => write!(f, "pending"),
+            IdentityVerificationStatus::Verified       => write!(f, "verified"),
+            IdentityVerificationStatus::Unknown(ref s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl serde::Deserialize for IdentityVerificationStatus {
+    fn deserialize<D>(deserializer: &mut D) -> Result<IdentityVerificationStatus, D::Error>
+        where D: serde::Deserializer
+    {
+        Ok(match String::deserialize(deserializer)?.as_ref() {
+            "unverified" => IdentityVerificationStatus::Unverified,
+            "pending"    => IdentityVerificationStatus::Pending,
+            "verified"   => IdentityVerificationStatus::Verified,
+            unknown      => IdentityVerificationStatus::Unknown(schema_drift::unknown_value("IdentityVerificationStatus", unknown)),
+        })
+    }
+}
+
+impl serde::Serialize for IdentityVerificationStatus {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
 }
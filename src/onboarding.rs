@@ -0,0 +1,186 @@
+use model::{
+    Account, Address, CountrySpec, DateOfBirth, Gender, LegalEntity, LegalEntityType, NewLegalEntity, NewOwner,
+    Timestamp
+};
+
+/// What a managed account still has to provide before Stripe verifies it,
+/// from the account's `verification` and its country's `CountrySpec`.
+/// https://stripe.com/docs/connect/identity-verification
+#[derive(Clone, Debug, PartialEq)]
+pub struct OnboardingChecklist {
+    /// Fields Stripe needs now come first, in the order Stripe lists them,
+    /// then fields it may ask for later.
+    pub requirements: Vec<Requirement>,
+    pub disabled_reason: Option<String>
+}
+
+/// One field a managed account has to provide.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Requirement {
+    /// The field as Stripe names it, e.g. `legal_entity.dob.day`.
+    pub field: String,
+    pub kind: RequirementKind,
+    pub due: RequirementDue
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RequirementKind {
+    LegalEntity,
+    TosAcceptance,
+    ExternalAccount,
+    Other
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RequirementDue {
+    /// Stripe needs it now. Transfers or charges are disabled if it isn't
+    /// provided by `due_by`, or right away without one.
+    Now { due_by: Option<Timestamp> },
+    /// The country's spec lists it as additional: Stripe asks for it once
+    /// the account reaches a volume threshold, or if verification fails.
+    Eventually
+}
+
+/// The `NewLegalEntity` builder method that provides a requirement's field,
+/// typed by the value it takes.
+#[derive(Clone, Copy, Debug)]
+pub enum LegalEntitySetter {
+    AdditionalOwners(fn(NewLegalEntity, Vec<NewOwner>) -> NewLegalEntity),
+    Address(fn(NewLegalEntity, Address) -> NewLegalEntity),
+    BusinessName(fn(NewLegalEntity, String) -> NewLegalEntity),
+    BusinessTaxId(fn(NewLegalEntity, String) -> NewLegalEntity),
+    BusinessVatId(fn(NewLegalEntity, String) -> NewLegalEntity),
+    Dob(fn(NewLegalEntity, DateOfBirth) -> NewLegalEntity),
+    EntityType(fn(NewLegalEntity, LegalEntityType) -> NewLegalEntity),
+    FirstName(fn(NewLegalEntity, String) -> NewLegalEntity),
+    Gender(fn(NewLegalEntity, Gender) -> NewLegalEntity),
+    LastName(fn(NewLegalEntity, String) -> NewLegalEntity),
+    MaidenName(fn(NewLegalEntity, String) -> NewLegalEntity),
+    PersonalAddress(fn(NewLegalEntity, Address) -> NewLegalEntity),
+    PersonalIdNumber(fn(NewLegalEntity, String) -> NewLegalEntity),
+    PhoneNumber(fn(NewLegalEntity, String) -> NewLegalEntity),
+    SsnLast4(fn(NewLegalEntity, String) -> NewLegalEntity),
+    Verification(fn(NewLegalEntity, String) -> NewLegalEntity)
+}
+
+impl OnboardingChecklist {
+    /// The account's `fields_needed`, plus the additional fields `spec`
+    /// lists for its legal entity type (individual when it has none yet)
+    /// that it hasn't obviously provided.
+    pub fn new(account: &Account, spec: &CountrySpec) -> OnboardingChecklist {
+        let mut checklist = OnboardingChecklist::from_account(account);
+        let legal_entity = account.legal_entity.as_ref();
+        let entity_type = match legal_entity.and_then(|entity| entity.entity_type.as_ref()) {
+            Some(&LegalEntityType::Company) => "company",
+            _                               => "individual"
+        };
+        if let Some(fields) = spec.verification_fields.get(entity_type) {
+            for field in &fields.additional {
+                let listed = checklist.requirements.iter().any(|requirement| requirement.field == *field);
+                let provided = legal_entity.map_or(false, |entity| is_provided(entity, field));
+                if !listed && !provided {
+                    checklist.requirements.push(Requirement::new(field.clone(), RequirementDue::Eventually));
+                }
+            }
+        }
+        checklist
+    }
+
+    /// Only what the account's `verification` says Stripe needs now.
+    pub fn from_account(account: &Account) -> OnboardingChecklist {
+        match account.verification {
+            Some(ref verification) => OnboardingChecklist {
+                requirements: verification.fields_needed.iter()
+                    .map(|field| {
+                        Requirement::new(field.clone(), RequirementDue::Now { due_by: verification.due_by })
+                    })
+                    .collect(),
+                disabled_reason: verification.disabled_reason.clone()
+            },
+            None => OnboardingChecklist {
+                requirements: Vec::new(),
+                disabled_reason: None
+            }
+        }
+    }
+
+    /// Whether Stripe needs nothing more now.
+    pub fn is_complete(&self) -> bool {
+        !self.requirements.iter().any(Requirement::is_due_now)
+    }
+
+    pub fn due_now(&self) -> Vec<&Requirement> {
+        self.requirements.iter().filter(|requirement| requirement.is_due_now()).collect()
+    }
+
+    pub fn of_kind(&self, kind: RequirementKind) -> Vec<&Requirement> {
+        self.requirements.iter().filter(|requirement| requirement.kind == kind).collect()
+    }
+}
+
+impl Requirement {
+    pub fn new(field: String, due: RequirementDue) -> Requirement {
+        let kind = if field.starts_with("legal_entity.") {
+            RequirementKind::LegalEntity
+        } else if field.starts_with("tos_acceptance.") {
+            RequirementKind::TosAcceptance
+        } else if field == "external_account" {
+            RequirementKind::ExternalAccount
+        } else {
+            RequirementKind::Other
+        };
+        Requirement {
+            field: field,
+            kind: kind,
+            due: due
+        }
+    }
+
+    pub fn is_due_now(&self) -> bool {
+        match self.due {
+            RequirementDue::Now { .. } => true,
+            RequirementDue::Eventually => false
+        }
+    }
+
+    /// The `NewLegalEntity` method that provides the field, e.g.
+    /// `LegalEntitySetter::Dob(NewLegalEntity::dob)` for
+    /// `legal_entity.dob.day`. `None` for fields outside the legal entity.
+    pub fn legal_entity_setter(&self) -> Option<LegalEntitySetter> {
+        if !self.field.starts_with("legal_entity.") {
+            return None;
+        }
+        Some(match self.field["legal_entity.".len()..].split('.').next().unwrap_or("") {
+            "additional_owners"  => LegalEntitySetter::AdditionalOwners(NewLegalEntity::additional_owners),
+            "address"            => LegalEntitySetter::Address(NewLegalEntity::address),
+            "business_name"      => LegalEntitySetter::BusinessName(NewLegalEntity::business_name),
+            "business_tax_id"    => LegalEntitySetter::BusinessTaxId(NewLegalEntity::business_tax_id),
+            "business_vat_id"    => LegalEntitySetter::BusinessVatId(NewLegalEntity::business_vat_id),
+            "dob"                => LegalEntitySetter::Dob(NewLegalEntity::dob),
+            "first_name"         => LegalEntitySetter::FirstName(NewLegalEntity::first_name),
+            "gender"             => LegalEntitySetter::Gender(NewLegalEntity::gender),
+            "last_name"          => LegalEntitySetter::LastName(NewLegalEntity::last_name),
+            "maiden_name"        => LegalEntitySetter::MaidenName(NewLegalEntity::maiden_name),
+            "personal_address"   => LegalEntitySetter::PersonalAddress(NewLegalEntity::personal_address),
+            "personal_id_number" => LegalEntitySetter::PersonalIdNumber(NewLegalEntity::personal_id_number),
+            "phone_number"       => LegalEntitySetter::PhoneNumber(NewLegalEntity::phone_number),
+            "ssn_last_4"         => LegalEntitySetter::SsnLast4(NewLegalEntity::ssn_last_4),
+            "type"               => LegalEntitySetter::EntityType(NewLegalEntity::entity_type),
+            "verification"       => LegalEntitySetter::Verification(NewLegalEntity::verification),
+            _                    => return None
+        })
+    }
+}
+
+/// Whether the legal entity evidently has `field`. Stripe only says whether
+/// ID numbers were provided, and most fields are left for
+/// `fields_needed` to report.
+fn is_provided(entity: &LegalEntity, field: &str) -> bool {
+    match field {
+        "legal_entity.business_tax_id"       => entity.business_tax_id_provided,
+        "legal_entity.personal_id_number"    => entity.personal_id_number_provided == Some(true),
+        "legal_entity.ssn_last_4"            => entity.ssn_last_4_provided == Some(true),
+        "legal_entity.verification.document" => entity.verification.document.is_some(),
+        _                                    => false
+    }
+}
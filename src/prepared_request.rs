@@ -0,0 +1,86 @@
+use hyper::method::Method;
+use middleware::Request;
+use redact;
+use std::fmt;
+use url_encodable::UrlEncodable;
+use StripeClient;
+
+/// A request as it would be sent to Stripe, after middleware, with the API
+/// key redacted. `params` are kept as sent, but card and account numbers are
+/// redacted from `Debug`, `Display` and `to_curl`.
+#[derive(Clone)]
+pub struct PreparedRequest {
+    pub call_type: &'static str,
+    pub method: Method,
+    /// The endpoint without a query string. GET requests send their
+    /// `params` as one.
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub params: Vec<(String, String)>
+}
+
+impl PreparedRequest {
+    pub fn new(request: &Request) -> PreparedRequest {
+        PreparedRequest {
+            call_type: request.call_type,
+            method: request.method.clone(),
+            url: StripeClient::endpoint(&request.path),
+            headers: redact::redact_headers(&request.headers),
+            params: request.params.clone()
+        }
+    }
+
+    /// A curl command reproducing the request. The API key is read from
+    /// `$STRIPE_API_KEY`, and card and account numbers are redacted.
+    pub fn to_curl(&self) -> String {
+        let params = redact::redact_params(&self.params);
+        let url = if self.method == Method::Get && !params.is_empty() {
+            format!("{}?{}", self.url, params.encoded_string())
+        } else {
+            self.url.clone()
+        };
+        let mut curl = format!("curl -X {} {}", self.method, quote(&url));
+        for &(ref name, ref value) in &self.headers {
+            if name.to_lowercase() == "authorization" {
+                curl.push_str(" -u \"$STRIPE_API_KEY:\"");
+            } else {
+                curl.push_str(&format!(" -H {}", quote(&format!("{}: {}", name, value))));
+            }
+        }
+        if self.method != Method::Get {
+            for &(ref key, ref value) in &params {
+                curl.push_str(&format!(" -d {}", quote(&format!("{}={}", key, value))));
+            }
+        }
+        curl
+    }
+}
+
+impl fmt::Debug for PreparedRequest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PreparedRequest")
+            .field("call_type", &self.call_type)
+            .field("method", &self.method)
+            .field("url", &self.url)
+            .field("headers", &self.headers)
+            .field("params", &redact::redact_params(&self.params))
+            .finish()
+    }
+}
+
+impl fmt::Display for PreparedRequest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.call_type,
+            self.method,
+            self.url,
+            redact::redact_params(&self.params).encoded_string()
+        )
+    }
+}
+
+fn quote(s: &str) -> String {
+    format!("'{}'", s.replace("'", "'\\''"))
+}
@@ -0,0 +1,78 @@
+use api::{CreateBankAccountTokenCall, CreateCardTokenCall, CreatePiiTokenCall};
+use middleware::Middleware;
+use secret_key::{KeyMode, SecretKey};
+use std::fmt;
+use StripeClient;
+
+/// A publishable (`pk_`) key. Kept distinct from `SecretKey` so the two
+/// can't be swapped by accident.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PublishableKey(String);
+
+impl PublishableKey {
+    /// Returns `None` unless `key` is a publishable key.
+    pub fn new(key: &str) -> Option<PublishableKey> {
+        if key.starts_with("pk_") {
+            Some(PublishableKey(String::from(key)))
+        } else {
+            None
+        }
+    }
+
+    pub fn mode(&self) -> KeyMode {
+        SecretKey::new(&self.0).mode()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for PublishableKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PublishableKey({})", self.0)
+    }
+}
+
+impl fmt::Display for PublishableKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A client for the endpoints that accept a publishable key, i.e. token
+/// creation. The key is sent as the `key` parameter rather than through
+/// basic auth.
+#[derive(Debug)]
+pub struct PublishableClient {
+    client: StripeClient
+}
+
+impl PublishableClient {
+    pub fn new(key: PublishableKey) -> PublishableClient {
+        PublishableClient {
+            client: StripeClient::with_publishable_key(&key)
+        }
+    }
+
+    /// Adds a middleware to the end of the chain run around every request.
+    pub fn with_middleware<M: Middleware + 'static>(mut self, middleware: M) -> PublishableClient {
+        self.client = self.client.with_middleware(middleware);
+        self
+    }
+
+    /// https://stripe.com/docs/api#create_card_token
+    pub fn create_card_token<'a>(&'a self) -> CreateCardTokenCall<'a> {
+        CreateCardTokenCall::new(&self.client)
+    }
+
+    /// https://stripe.com/docs/api#create_bank_account_token
+    pub fn create_bank_account_token<'a>(&'a self) -> CreateBankAccountTokenCall<'a> {
+        CreateBankAccountTokenCall::new(&self.client)
+    }
+
+    /// https://stripe.com/docs/api#create_pii_token
+    pub fn create_pii_token<'a>(&'a self, personal_id_number: String) -> CreatePiiTokenCall<'a> {
+        CreatePiiTokenCall::new(&self.client, personal_id_number)
+    }
+}
@@ -0,0 +1,85 @@
+use hyper::header::Headers;
+use serde_json::Value;
+
+pub const REDACTED: &'static str = "[REDACTED]";
+
+/// Returns the innermost field name of a form key, so `card[number]` and
+/// `number` both yield `number`.
+fn field_name(key: &str) -> &str {
+    let key = key.trim_right_matches(']');
+    match key.rfind('[') {
+        Some(i) => &key[i + 1..],
+        None    => key
+    }
+}
+
+pub fn is_sensitive_param(key: &str) -> bool {
+    match field_name(key) {
+        "number" | "cvc" | "account_number" | "personal_id_number" | "ssn_last_4" => true,
+        _                                                                        => false
+    }
+}
+
+/// Masks all but the last four characters of a card or account number, and
+/// the whole of anything else sensitive.
+pub fn mask(key: &str, value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    match field_name(key) {
+        "number" | "account_number" if chars.len() > 4 => {
+            let split = chars.len() - 4;
+            let mut masked: String = (0..split).map(|_| '*').collect();
+            masked.extend(&chars[split..]);
+            masked
+        },
+        _ => REDACTED.to_string()
+    }
+}
+
+pub fn is_secret_key(value: &str) -> bool {
+    value.starts_with("sk_") || value.starts_with("rk_")
+}
+
+pub fn redact_value(key: &str, value: &str) -> String {
+    if is_sensitive_param(key) {
+        mask(key, value)
+    } else if is_secret_key(value) {
+        REDACTED.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn redact_params(params: &[(String, String)]) -> Vec<(String, String)> {
+    params.iter()
+        .map(|&(ref k, ref v)| (k.clone(), redact_value(k, v)))
+        .collect()
+}
+
+pub fn redact_headers(headers: &Headers) -> Vec<(String, String)> {
+    headers.iter()
+        .map(|header| {
+            let name = header.name().to_string();
+            let value = if name.to_lowercase() == "authorization" {
+                REDACTED.to_string()
+            } else {
+                header.value_string()
+            };
+            (name, value)
+        })
+        .collect()
+}
+
+/// Redacts a JSON document the way `redact_value` redacts form params, e.g.
+/// a response body.
+pub fn redact_json(key: &str, value: &Value) -> Value {
+    match *value {
+        Value::String(ref s) => Value::String(redact_value(key, s)),
+        Value::Array(ref values) => Value::Array(
+            values.iter().map(|value| redact_json(key, value)).collect()
+        ),
+        Value::Object(ref map) => Value::Object(
+            map.iter().map(|(k, v)| (k.clone(), redact_json(k, v))).collect()
+        ),
+        ref other => other.clone()
+    }
+}
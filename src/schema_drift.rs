@@ -0,0 +1,149 @@
+use serde_json::Value;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::mem;
+use std::sync::{Arc, Mutex};
+
+thread_local!(static COLLECTING: RefCell<Option<SchemaDrift>> = RefCell::new(None));
+
+/// How the responses parsed into one type differed from it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TypeDrift {
+    /// Fields Stripe sent that the model doesn't declare, with how many
+    /// responses had each.
+    pub ignored_fields: BTreeMap<String, u64>,
+    /// Fields the model declares that Stripe left out, with how many
+    /// responses were missing each.
+    pub missing_fields: BTreeMap<String, u64>,
+    /// Enum values that fell into an `Unknown` or `Other` variant, with how
+    /// many times each was seen.
+    pub unknown_values: BTreeMap<String, u64>
+}
+
+impl TypeDrift {
+    pub fn is_empty(&self) -> bool {
+        self.ignored_fields.is_empty() && self.missing_fields.is_empty() && self.unknown_values.is_empty()
+    }
+}
+
+/// Collects, per type, how the responses a `StripeClient` receives differ
+/// from the models they're parsed into, e.g. to find out in staging when
+/// the pinned `API_VERSION` models have fallen behind Stripe.
+///
+/// Models are keyed by their name, e.g. `Charge`, with ignored and missing
+/// fields; enums are keyed by theirs, e.g. `ChargeStatus`, with unknown
+/// values. Clones share one report, so keep a clone to read what the client
+/// records:
+///
+/// ```ignore
+/// let drift = SchemaDrift::new();
+/// let client = StripeClient::new(key).detect_schema_drift(&drift);
+/// // ... make calls ...
+/// info!("{}", drift);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SchemaDrift {
+    types: Arc<Mutex<BTreeMap<String, TypeDrift>>>
+}
+
+impl SchemaDrift {
+    pub fn new() -> SchemaDrift {
+        SchemaDrift::default()
+    }
+
+    /// Everything recorded so far, by type name. Types that matched their
+    /// model every time are left out.
+    pub fn report(&self) -> BTreeMap<String, TypeDrift> {
+        self.types.lock().unwrap().clone()
+    }
+
+    pub fn clear(&self) {
+        self.types.lock().unwrap().clear();
+    }
+
+    fn record<F: FnOnce(&mut TypeDrift)>(&self, type_name: &str, f: F) {
+        let mut types = self.types.lock().unwrap();
+        f(types.entry(type_name.to_string()).or_insert_with(TypeDrift::default));
+    }
+}
+
+/// One line per type, e.g.
+/// `Charge: ignored outcome (3), review (3); missing order (1)`.
+impl fmt::Display for SchemaDrift {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (type_name, drift) in self.report() {
+            let mut parts = Vec::new();
+            for &(label, counts) in &[
+                ("ignored", &drift.ignored_fields),
+                ("missing", &drift.missing_fields),
+                ("unknown values", &drift.unknown_values)
+            ] {
+                if !counts.is_empty() {
+                    let counts: Vec<String> = counts.iter()
+                        .map(|(name, count)| format!("{} ({})", name, count))
+                        .collect();
+                    parts.push(format!("{} {}", label, counts.join(", ")));
+                }
+            }
+            writeln!(f, "{}: {}", type_name, parts.join("; "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs `f` with drift found while parsing on this thread recorded into
+/// `drift`, if there is one.
+pub fn collecting<T, F: FnOnce() -> T>(drift: Option<&SchemaDrift>, f: F) -> T {
+    match drift {
+        Some(drift) => COLLECTING.with(|current| {
+            let previous = mem::replace(&mut *current.borrow_mut(), Some(drift.clone()));
+            let t = f();
+            *current.borrow_mut() = previous;
+            t
+        }),
+        None => f()
+    }
+}
+
+fn with_current<F: FnOnce(&SchemaDrift)>(f: F) {
+    COLLECTING.with(|current| {
+        if let Some(ref drift) = *current.borrow() {
+            f(drift);
+        }
+    })
+}
+
+/// Records the fields of `json` that aren't in `known`, the model's declared
+/// fields serialized back out, and the other way around. `object` isn't
+/// counted as ignored, since the model's type already says what it is.
+pub fn record_fields(type_name: &str, json: &BTreeMap<String, Value>, known: &BTreeMap<String, Value>) {
+    with_current(|drift| {
+        let ignored: Vec<&String> = json.keys()
+            .filter(|key| *key != "object" && !known.contains_key(*key))
+            .collect();
+        let missing: Vec<&String> = known.keys().filter(|key| !json.contains_key(*key)).collect();
+        if ignored.is_empty() && missing.is_empty() {
+            return;
+        }
+        drift.record(type_name, |type_drift| {
+            for key in ignored {
+                *type_drift.ignored_fields.entry(key.clone()).or_insert(0) += 1;
+            }
+            for key in missing {
+                *type_drift.missing_fields.entry(key.clone()).or_insert(0) += 1;
+            }
+        });
+    })
+}
+
+/// Records `value` as unknown to the enum `type_name`, and returns it for
+/// the enum's `Unknown` or `Other` variant.
+pub fn unknown_value(type_name: &str, value: &str) -> String {
+    with_current(|drift| {
+        drift.record(type_name, |type_drift| {
+            *type_drift.unknown_values.entry(value.to_string()).or_insert(0) += 1;
+        })
+    });
+    String::from(value)
+}
@@ -0,0 +1,105 @@
+use redact::REDACTED;
+use std::fmt;
+use std::ptr;
+
+/// Whether a key operates on test or live data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyMode {
+    Test,
+    Live,
+    Unknown
+}
+
+/// https://stripe.com/docs/keys
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyKind {
+    Secret,
+    Restricted,
+    Publishable,
+    Unknown
+}
+
+/// A Stripe API key. Its `Debug` and `Display` output never include the key,
+/// and its memory is zeroed when dropped.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretKey(String);
+
+impl SecretKey {
+    pub fn new(key: &str) -> SecretKey {
+        SecretKey(String::from(key))
+    }
+
+    /// Detected from the key prefix: `sk_`, `rk_` or `pk_`.
+    pub fn kind(&self) -> KeyKind {
+        match self.0.split('_').next() {
+            Some("sk") => KeyKind::Secret,
+            Some("rk") => KeyKind::Restricted,
+            Some("pk") => KeyKind::Publishable,
+            _          => KeyKind::Unknown
+        }
+    }
+
+    /// Detected from the key prefix, e.g. `sk_test_` or `rk_live_`.
+    pub fn mode(&self) -> KeyMode {
+        match self.0.split('_').nth(1) {
+            Some("test") => KeyMode::Test,
+            Some("live") => KeyMode::Live,
+            _            => KeyMode::Unknown
+        }
+    }
+
+    /// Identifies the key without revealing it, as the Stripe dashboard
+    /// does: the prefix and the last four characters, e.g. `sk_test_...p7dc`.
+    /// Keys too short to spare four characters show only the prefix.
+    pub fn fingerprint(&self) -> String {
+        let (prefix, secret) = match self.0.rfind('_') {
+            Some(i) => self.0.split_at(i + 1),
+            None    => ("", &self.0[..])
+        };
+        let chars: Vec<char> = secret.chars().collect();
+        if chars.len() < 12 {
+            return format!("{}...", prefix);
+        }
+        let last4: String = chars[chars.len() - 4..].iter().cloned().collect();
+        format!("{}...{}", prefix, last4)
+    }
+
+    /// The raw key, for sending to Stripe. Avoid logging or storing it.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'a> From<&'a str> for SecretKey {
+    fn from(key: &'a str) -> SecretKey {
+        SecretKey::new(key)
+    }
+}
+
+impl From<String> for SecretKey {
+    fn from(key: String) -> SecretKey {
+        SecretKey(key)
+    }
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        unsafe {
+            for byte in self.0.as_mut_vec().iter_mut() {
+                ptr::write_volatile(byte, 0);
+            }
+        }
+    }
+}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SecretKey({})", REDACTED)
+    }
+}
+
+impl fmt::Display for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", REDACTED)
+    }
+}
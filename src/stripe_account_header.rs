@@ -0,0 +1,37 @@
+use hyper::header::{Header, HeaderFormat};
+use hyper;
+use std::fmt;
+
+#[derive(Clone, Debug)]
+pub struct StripeAccount {
+    pub account_id: String
+}
+
+impl StripeAccount {
+    pub fn new(account_id: &str) -> StripeAccount {
+        StripeAccount {
+            account_id: String::from(account_id)
+        }
+    }
+}
+
+impl Header for StripeAccount {
+    fn header_name() -> &'static str {
+        "Stripe-Account"
+    }
+
+    fn parse_header(raw: &[Vec<u8>]) -> hyper::error::Result<Self> {
+        if raw.len() != 1 {
+            Err(hyper::error::Error::Header)
+        } else {
+            let account_id = try!(String::from_utf8(raw[0].clone()));
+            Ok(StripeAccount::new(&account_id))
+        }
+    }
+}
+
+impl HeaderFormat for StripeAccount {
+    fn fmt_header(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", &self.account_id)
+    }
+}
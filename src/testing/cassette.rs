@@ -0,0 +1,202 @@
+use errors::error::Error;
+use hyper::status::StatusCode;
+use middleware::Request;
+use redact;
+use serde_json;
+use std::env;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use transport::{HyperTransport, Transport, TransportResponse};
+use Result;
+
+/// Setting this environment variable makes `CassetteTransport::from_env`
+/// record instead of replay.
+pub const RECORD_VAR: &'static str = "STRIPE_RECORD";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: String,
+    path: String,
+    params: Vec<(String, String)>
+}
+
+impl RecordedRequest {
+    /// Params are redacted and sorted by key, so requests match regardless
+    /// of the order params were added in, and card numbers never reach the
+    /// cassette.
+    fn new(request: &Request) -> RecordedRequest {
+        let mut params = redact::redact_params(&request.params);
+        params.sort_by(|a, b| a.0.cmp(&b.0));
+        RecordedRequest {
+            method: request.method.to_string(),
+            path: request.path.clone(),
+            params: params
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    body: String
+}
+
+impl RecordedResponse {
+    fn new(response: &TransportResponse) -> RecordedResponse {
+        let body = match serde_json::from_str(&response.body) {
+            Ok(json) => serde_json::to_string(&redact::redact_json("", &json))
+                .unwrap_or(response.body.clone()),
+            Err(_) => response.body.clone()
+        };
+        RecordedResponse {
+            status: response.status.to_u16(),
+            body: body
+        }
+    }
+
+    fn to_response(&self) -> TransportResponse {
+        TransportResponse::new(StatusCode::from_u16(self.status), self.body.clone())
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Cassette {
+    interactions: Vec<Interaction>
+}
+
+enum Mode {
+    Record(Box<Transport>),
+    Replay
+}
+
+/// A `Transport` that records requests and Stripe's responses to a JSON
+/// cassette file, or replays a cassette without touching the network.
+///
+/// Recorded params and response bodies are redacted: card and account
+/// numbers keep only their last four digits, and secret keys and CVCs are
+/// removed. The `Authorization` header is never recorded.
+///
+/// When replaying, each request must match an unplayed interaction exactly
+/// on method, path and params, otherwise it fails with an `IoError`.
+pub struct CassetteTransport {
+    path: PathBuf,
+    mode: Mode,
+    interactions: Mutex<Vec<Interaction>>
+}
+
+impl CassetteTransport {
+    /// Sends requests to Stripe, overwriting the cassette at `path` with
+    /// every interaction so far after each one.
+    pub fn record<P: AsRef<Path>>(path: P) -> CassetteTransport {
+        CassetteTransport::record_through(path, HyperTransport::new())
+    }
+
+    /// Like `record`, but sends requests through `transport`.
+    pub fn record_through<P, T>(path: P, transport: T) -> CassetteTransport
+        where P: AsRef<Path>, T: Transport + 'static
+    {
+        CassetteTransport {
+            path: path.as_ref().to_path_buf(),
+            mode: Mode::Record(Box::new(transport)),
+            interactions: Mutex::new(Vec::new())
+        }
+    }
+
+    /// Serves the interactions recorded in the cassette at `path`.
+    pub fn replay<P: AsRef<Path>>(path: P) -> Result<CassetteTransport> {
+        let mut contents = String::new();
+        File::open(path.as_ref())?.read_to_string(&mut contents)?;
+        let cassette: Cassette = serde_json::from_str(&contents)?;
+        Ok(CassetteTransport {
+            path: path.as_ref().to_path_buf(),
+            mode: Mode::Replay,
+            interactions: Mutex::new(cassette.interactions)
+        })
+    }
+
+    /// Records if the `STRIPE_RECORD` environment variable is set, and
+    /// replays otherwise, so tests run offline unless asked to re-record.
+    pub fn from_env<P: AsRef<Path>>(path: P) -> Result<CassetteTransport> {
+        match env::var(RECORD_VAR) {
+            Ok(_)  => Ok(CassetteTransport::record(path)),
+            Err(_) => CassetteTransport::replay(path)
+        }
+    }
+
+    pub fn is_recording(&self) -> bool {
+        match self.mode {
+            Mode::Record(_) => true,
+            Mode::Replay    => false
+        }
+    }
+
+    /// How many recorded interactions haven't been replayed yet.
+    pub fn remaining(&self) -> usize {
+        match self.mode {
+            Mode::Record(_) => 0,
+            Mode::Replay    => self.interactions.lock().unwrap().len()
+        }
+    }
+
+    fn save(&self, interactions: &[Interaction]) -> Result<()> {
+        let cassette = Cassette {
+            interactions: interactions.to_vec()
+        };
+        let json = serde_json::to_string_pretty(&cassette)?;
+        File::create(&self.path)?.write_all(json.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl Transport for CassetteTransport {
+    fn send(&self, request: &Request) -> Result<TransportResponse> {
+        let recorded = RecordedRequest::new(request);
+        let mut interactions = self.interactions.lock().unwrap();
+        match self.mode {
+            Mode::Record(ref transport) => {
+                let response = transport.send(request)?;
+                interactions.push(Interaction {
+                    request: recorded,
+                    response: RecordedResponse::new(&response)
+                });
+                self.save(&interactions)?;
+                Ok(response)
+            },
+            Mode::Replay => {
+                match interactions.iter().position(|interaction| interaction.request == recorded) {
+                    Some(i) => Ok(interactions.remove(i).response.to_response()),
+                    None    => Err(Error::IoError(io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!(
+                            "{}: no recorded interaction for {} {} {:?}",
+                            self.path.display(),
+                            recorded.method,
+                            recorded.path,
+                            recorded.params
+                        )
+                    )))
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Debug for CassetteTransport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CassetteTransport")
+            .field("path", &self.path)
+            .field("recording", &self.is_recording())
+            .field("interactions", &self.interactions.lock().unwrap().len())
+            .finish()
+    }
+}
@@ -0,0 +1,1531 @@
+use hyper::method::Method;
+use hyper::status::StatusCode;
+use middleware::Request;
+use model::{NewCard, Timestamp};
+use serde_json;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+use transport::{Transport, TransportResponse};
+use {Result, StripeClient, API_VERSION};
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// A request the fake refused. Everything it changed while handling the
+/// request is rolled back, unless `keep` is set: Stripe records declined
+/// charges, so those are kept.
+struct Rejection {
+    response: TransportResponse,
+    keep: bool
+}
+
+type Reply = ::std::result::Result<Value, Rejection>;
+
+#[derive(Clone, Debug, Default)]
+struct FakeState {
+    next_id: u64,
+    tokens: Vec<Value>,
+    customers: Vec<Value>,
+    cards: Vec<Value>,
+    card_numbers: BTreeMap<String, String>,
+    charges: Vec<Value>,
+    refunds: Vec<Value>,
+    plans: Vec<Value>,
+    coupons: Vec<Value>,
+    subscriptions: Vec<Value>,
+    invoices: Vec<Value>,
+    events: Vec<Value>
+}
+
+#[derive(Clone, Debug)]
+struct FakeTransport {
+    state: Arc<Mutex<FakeState>>
+}
+
+impl Transport for FakeTransport {
+    fn send(&self, request: &Request) -> Result<TransportResponse> {
+        Ok(self.state.lock().unwrap().handle(request))
+    }
+}
+
+/// A `StripeClient` backed by an in-memory simulation of Stripe instead of the
+/// network. It keeps customers, cards, charges, refunds, plans, coupons,
+/// subscriptions, invoices and events, assigns them IDs, validates requests
+/// the way Stripe does for the common cases, and records an `Event` for each
+/// change.
+///
+/// Stripe's test card numbers behave as they do in test mode, e.g.
+/// `4000000000000002` is declined and `4000000000000341` can be attached to
+/// a customer but declines when charged.
+/// https://stripe.com/docs/testing#cards
+#[derive(Debug)]
+pub struct FakeStripe {
+    client: StripeClient,
+    state: Arc<Mutex<FakeState>>
+}
+
+impl FakeStripe {
+    pub fn new() -> FakeStripe {
+        let state = Arc::new(Mutex::new(FakeState::default()));
+        let transport = FakeTransport {
+            state: state.clone()
+        };
+        FakeStripe {
+            client: StripeClient::new("sk_test_fake").with_transport(transport),
+            state: state
+        }
+    }
+
+    /// The type of every event emitted so far, oldest first, e.g.
+    /// `["customer.created", "charge.succeeded"]`.
+    pub fn event_types(&self) -> Vec<String> {
+        self.state.lock().unwrap().events.iter()
+            .filter_map(|event| get_str(event, "type"))
+            .map(String::from)
+            .collect()
+    }
+
+    /// Forgets every object and event.
+    pub fn reset(&self) {
+        *self.state.lock().unwrap() = FakeState::default();
+    }
+}
+
+impl Deref for FakeStripe {
+    type Target = StripeClient;
+
+    fn deref(&self) -> &StripeClient {
+        &self.client
+    }
+}
+
+impl FakeState {
+    fn handle(&mut self, request: &Request) -> TransportResponse {
+        let snapshot = self.clone();
+        match self.route(request) {
+            Ok(value) => TransportResponse::new(
+                StatusCode::Ok,
+                serde_json::to_string(&value).unwrap()
+            ),
+            Err(rejection) => {
+                if !rejection.keep {
+                    *self = snapshot;
+                }
+                rejection.response
+            }
+        }
+    }
+
+    fn route(&mut self, request: &Request) -> Reply {
+        let params = Params(&request.params);
+        let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+        let segment = |i: usize| segments.get(i).cloned();
+
+        match (&request.method, segment(0), segment(1), segment(2), segment(3), segment(4)) {
+            (&Method::Post, Some("tokens"), None, None, None, None) =>
+                self.create_token(&params),
+            (&Method::Get, Some("tokens"), Some(id), None, None, None) =>
+                retrieve(&self.tokens, "token", id),
+
+            (&Method::Post, Some("customers"), None, None, None, None) =>
+                self.create_customer(&params),
+            (&Method::Get, Some("customers"), None, None, None, None) =>
+                list("/v1/customers", self.customers.iter().map(|c| self.render_customer(c)).collect(), &params),
+            (&Method::Get, Some("customers"), Some(id), None, None, None) =>
+                self.customer(id).map(|customer| self.render_customer(&customer)),
+            (&Method::Post, Some("customers"), Some(id), None, None, None) =>
+                self.update_customer(id, &params),
+            (&Method::Delete, Some("customers"), Some(id), None, None, None) =>
+                self.delete_customer(id),
+
+            (&Method::Post, Some("customers"), Some(id), Some("sources"), None, None) =>
+                self.create_customer_card(id, &params),
+            (&Method::Get, Some("customers"), Some(id), Some("sources"), None, None) => {
+                self.customer(id)?;
+                list(&format!("/v1/customers/{}/sources", id), self.customer_cards(id), &params)
+            },
+            (&Method::Get, Some("customers"), Some(id), Some("sources"), Some(card_id), None) =>
+                self.customer_card(id, card_id).map(|i| self.cards[i].clone()),
+            (&Method::Post, Some("customers"), Some(id), Some("sources"), Some(card_id), None) =>
+                self.update_customer_card(id, card_id, &params),
+            (&Method::Delete, Some("customers"), Some(id), Some("sources"), Some(card_id), None) =>
+                self.delete_customer_card(id, card_id),
+
+            (&Method::Post, Some("customers"), Some(id), Some("subscriptions"), None, None) =>
+                self.create_subscription(id, &params),
+            (&Method::Get, Some("customers"), Some(id), Some("subscriptions"), None, None) => {
+                self.customer(id)?;
+                list(&format!("/v1/customers/{}/subscriptions", id), self.active_subscriptions(id), &params)
+            },
+            (&Method::Get, Some("customers"), Some(id), Some("subscriptions"), Some(sub_id), None) =>
+                self.subscription(id, sub_id).map(|i| self.subscriptions[i].clone()),
+            (&Method::Post, Some("customers"), Some(id), Some("subscriptions"), Some(sub_id), None) =>
+                self.update_subscription(id, sub_id, &params),
+            (&Method::Delete, Some("customers"), Some(id), Some("subscriptions"), Some(sub_id), None) =>
+                self.cancel_subscription(id, sub_id, &params),
+
+            (&Method::Post, Some("charges"), None, None, None, None) =>
+                self.create_charge(&params),
+            (&Method::Get, Some("charges"), None, None, None, None) => {
+                let charges = self.charges.iter()
+                    .filter(|charge| matches_filter(charge, "customer", params.get("customer")))
+                    .map(|charge| self.render_charge(charge))
+                    .collect();
+                list("/v1/charges", charges, &params)
+            },
+            (&Method::Get, Some("charges"), Some(id), None, None, None) =>
+                self.charge_index(id).map(|i| self.render_charge(&self.charges[i])),
+            (&Method::Post, Some("charges"), Some(id), None, None, None) =>
+                self.update_charge(id, &params),
+            (&Method::Post, Some("charges"), Some(id), Some("capture"), None, None) =>
+                self.capture_charge(id, &params),
+
+            (&Method::Post, Some("refunds"), None, None, None, None) =>
+                self.create_refund(&params),
+            (&Method::Get, Some("refunds"), None, None, None, None) => {
+                let refunds = self.refunds.iter()
+                    .filter(|refund| matches_filter(refund, "charge", params.get("charge")))
+                    .cloned()
+                    .collect();
+                list("/v1/refunds", refunds, &params)
+            },
+            (&Method::Get, Some("refunds"), Some(id), None, None, None) =>
+                retrieve(&self.refunds, "refund", id),
+            (&Method::Post, Some("refunds"), Some(id), None, None, None) => {
+                let i = position(&self.refunds, id).ok_or_else(|| no_such("refund", id))?;
+                merge_metadata(&mut self.refunds[i], &params);
+                Ok(self.refunds[i].clone())
+            },
+
+            (&Method::Post, Some("plans"), None, None, None, None) =>
+                self.create_plan(&params),
+            (&Method::Get, Some("plans"), None, None, None, None) =>
+                list("/v1/plans", self.plans.clone(), &params),
+            (&Method::Get, Some("plans"), Some(id), None, None, None) =>
+                retrieve(&self.plans, "plan", id),
+            (&Method::Post, Some("plans"), Some(id), None, None, None) =>
+                self.update_plan(id, &params),
+            (&Method::Delete, Some("plans"), Some(id), None, None, None) =>
+                self.delete(Resource::Plan, id),
+
+            (&Method::Post, Some("coupons"), None, None, None, None) =>
+                self.create_coupon(&params),
+            (&Method::Get, Some("coupons"), None, None, None, None) =>
+                list("/v1/coupons", self.coupons.clone(), &params),
+            (&Method::Get, Some("coupons"), Some(id), None, None, None) =>
+                retrieve(&self.coupons, "coupon", id),
+            (&Method::Post, Some("coupons"), Some(id), None, None, None) => {
+                let i = position(&self.coupons, id).ok_or_else(|| no_such("coupon", id))?;
+                merge_metadata(&mut self.coupons[i], &params);
+                let coupon = self.coupons[i].clone();
+                self.emit("coupon.updated", coupon.clone());
+                Ok(coupon)
+            },
+            (&Method::Delete, Some("coupons"), Some(id), None, None, None) =>
+                self.delete(Resource::Coupon, id),
+
+            (&Method::Post, Some("invoices"), None, None, None, None) => {
+                let customer = params.require("customer")?;
+                self.customer(customer)?;
+                Err(invalid_request(&format!("Nothing to invoice for customer {}", customer), None))
+            },
+            (&Method::Get, Some("invoices"), None, None, None, None) => {
+                let invoices = self.invoices.iter()
+                    .filter(|invoice| matches_filter(invoice, "customer", params.get("customer")))
+                    .cloned()
+                    .collect();
+                list("/v1/invoices", invoices, &params)
+            },
+            (&Method::Get, Some("invoices"), Some(id), None, None, None) =>
+                retrieve(&self.invoices, "invoice", id),
+            (&Method::Post, Some("invoices"), Some(id), None, None, None) =>
+                self.update_invoice(id, &params),
+            (&Method::Get, Some("invoices"), Some(id), Some("lines"), None, None) => {
+                let invoice = retrieve(&self.invoices, "invoice", id)?;
+                let lines = match invoice.find("lines").and_then(|lines| lines.find("data")) {
+                    Some(&Value::Array(ref lines)) => lines.clone(),
+                    _                              => Vec::new()
+                };
+                list(&format!("/v1/invoices/{}/lines", id), lines, &params)
+            },
+            (&Method::Post, Some("invoices"), Some(id), Some("pay"), None, None) => {
+                let i = position(&self.invoices, id).ok_or_else(|| no_such("invoice", id))?;
+                if get_bool(&self.invoices[i], "paid") {
+                    return Err(invalid_request("Invoice is already paid", None));
+                }
+                self.pay_invoice(i)
+            },
+
+            (&Method::Get, Some("events"), None, None, None, None) => {
+                let events = self.events.iter()
+                    .filter(|event| matches_filter(event, "type", params.get("type")))
+                    .cloned()
+                    .collect();
+                list("/v1/events", events, &params)
+            },
+            (&Method::Get, Some("events"), Some(id), None, None, None) =>
+                retrieve(&self.events, "event", id),
+
+            _ => Err(error_response(StatusCode::NotFound, vec![
+                ("type", string("invalid_request_error")),
+                ("message", string(&format!(
+                    "Unrecognized request URL ({}: {}). FakeStripe does not simulate this endpoint.",
+                    request.method,
+                    request.path
+                )))
+            ]))
+        }
+    }
+
+    fn id(&mut self, prefix: &str) -> String {
+        self.next_id += 1;
+        format!("{}_{:014}", prefix, self.next_id)
+    }
+
+    fn emit(&mut self, event_type: &str, object: Value) {
+        let id = self.id("evt");
+        self.events.push(json_object(vec![
+            ("id", string(&id)),
+            ("object", string("event")),
+            ("api_version", string(API_VERSION)),
+            ("created", int(now())),
+            ("data", json_object(vec![("object", object)])),
+            ("livemode", Value::Bool(false)),
+            ("pending_webhooks", int(0)),
+            ("request", Value::Null),
+            ("type", string(event_type))
+        ]));
+    }
+
+    fn delete(&mut self, resource: Resource, id: &str) -> Reply {
+        let (name, event_type) = match resource {
+            Resource::Plan   => ("plan", "plan.deleted"),
+            Resource::Coupon => ("coupon", "coupon.deleted")
+        };
+        let removed = {
+            let list = match resource {
+                Resource::Plan   => &mut self.plans,
+                Resource::Coupon => &mut self.coupons
+            };
+            let i = position(list, id).ok_or_else(|| no_such(name, id))?;
+            list.remove(i)
+        };
+        self.emit(event_type, removed);
+        Ok(deleted(id))
+    }
+
+    // Tokens and cards
+
+    fn create_token(&mut self, params: &Params) -> Reply {
+        let fields = params.nested("card");
+        if fields.is_empty() {
+            return Err(invalid_request(
+                "FakeStripe only simulates tokens created from card details",
+                Some("card")
+            ));
+        }
+        let card = self.new_card(&fields, None)?;
+        let id = self.id("tok");
+        let token = json_object(vec![
+            ("id", string(&id)),
+            ("object", string("token")),
+            ("card", card),
+            ("client_ip", Value::Null),
+            ("created", int(now())),
+            ("livemode", Value::Bool(false)),
+            ("type", string("card")),
+            ("used", Value::Bool(false))
+        ]);
+        self.tokens.push(token.clone());
+        Ok(token)
+    }
+
+    /// Builds a card from `number`, `exp_month` etc., validating it the way
+    /// Stripe does when the card is tokenized.
+    fn new_card(&mut self, fields: &[(String, String)], customer: Option<&str>) -> Reply {
+        let fields = Params(fields);
+        let number: String = fields.require("number")?.chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        let exp_month = fields.int("exp_month")?.ok_or_else(|| missing_param("exp_month"))?;
+        let mut exp_year = fields.int("exp_year")?.ok_or_else(|| missing_param("exp_year"))?;
+        if exp_year < 100 {
+            exp_year += 2000;
+        }
+        let mut card = NewCard::new(number.clone(), exp_month, exp_year);
+        if let Some(cvc) = fields.get("cvc") {
+            card = card.cvc(cvc.to_string());
+        }
+        let brand = card.validate_at(Timestamp::from_secs(now()))
+            .map_err(|err| card_error(&err.code().to_string(), err.message(), err.param()))?;
+        let cvc_check = match fields.get("cvc") {
+            Some(_) => string("pass"),
+            None    => Value::Null
+        };
+
+        let id = self.id("card");
+        self.card_numbers.insert(id.clone(), number.clone());
+        Ok(json_object(vec![
+            ("id", string(&id)),
+            ("object", string("card")),
+            ("address_city", opt_string(fields.get("address_city"))),
+            ("address_country", opt_string(fields.get("address_country"))),
+            ("address_line1", opt_string(fields.get("address_line1"))),
+            ("address_line1_check", Value::Null),
+            ("address_line2", opt_string(fields.get("address_line2"))),
+            ("address_state", opt_string(fields.get("address_state"))),
+            ("address_zip", opt_string(fields.get("address_zip"))),
+            ("address_zip_check", Value::Null),
+            ("brand", string(&brand.to_string())),
+            ("country", string("US")),
+            ("customer", opt_string(customer)),
+            ("cvc_check", cvc_check),
+            ("dynamic_last4", Value::Null),
+            ("exp_month", int(exp_month)),
+            ("exp_year", int(exp_year)),
+            ("fingerprint", string(&fingerprint(&number))),
+            ("funding", string("credit")),
+            ("last4", string(&number[number.len() - 4..])),
+            ("metadata", fields.metadata()),
+            ("name", opt_string(fields.get("name"))),
+            ("tokenization_method", Value::Null)
+        ]))
+    }
+
+    /// The card behind the `key` param, which is either a token or card
+    /// details. The token is marked used.
+    fn card_param(&mut self, params: &Params, key: &str) -> Reply {
+        if let Some(token_id) = params.get(key) {
+            let i = position(&self.tokens, token_id).ok_or_else(|| no_such("token", token_id))?;
+            if get_bool(&self.tokens[i], "used") {
+                return Err(invalid_request(
+                    &format!("You cannot use a Stripe token more than once: {}.", token_id),
+                    Some(key)
+                ));
+            }
+            set(&mut self.tokens[i], "used", Value::Bool(true));
+            return self.tokens[i].find("card")
+                .cloned()
+                .ok_or_else(|| invalid_request(&format!("Token {} is not a card token", token_id), Some(key)));
+        }
+        let fields = params.nested(key);
+        if fields.is_empty() {
+            return Err(missing_param(key));
+        }
+        self.new_card(&fields, None)
+    }
+
+    /// Attaches the card given in the `source` param to a customer. Cards
+    /// Stripe declines when verifying them can't be attached.
+    fn attach_card(&mut self, customer_id: &str, params: &Params) -> Reply {
+        let mut card = self.card_param(params, "source")?;
+        let card_id = get_str(&card, "id").unwrap_or("").to_string();
+        let number = self.card_numbers.get(&card_id).cloned().unwrap_or(String::new());
+        if let Some(decline) = test_card_decline(&number, true) {
+            return Err(decline.rejection(None));
+        }
+
+        set(&mut card, "customer", string(customer_id));
+        merge_metadata(&mut card, params);
+        self.cards.push(card.clone());
+
+        let i = position(&self.customers, customer_id).ok_or_else(|| no_such("customer", customer_id))?;
+        if self.customers[i].find("default_source").map_or(true, Value::is_null) {
+            set(&mut self.customers[i], "default_source", string(&card_id));
+        }
+        self.emit("customer.source.created", card.clone());
+        Ok(card)
+    }
+
+    fn customer_cards(&self, customer_id: &str) -> Vec<Value> {
+        self.cards.iter()
+            .filter(|card| get_str(card, "customer") == Some(customer_id))
+            .cloned()
+            .collect()
+    }
+
+    fn customer_card(&self, customer_id: &str, card_id: &str) -> ::std::result::Result<usize, Rejection> {
+        self.customer(customer_id)?;
+        self.cards.iter()
+            .position(|card| get_str(card, "id") == Some(card_id) && get_str(card, "customer") == Some(customer_id))
+            .ok_or_else(|| no_such("source", card_id))
+    }
+
+    fn create_customer_card(&mut self, customer_id: &str, params: &Params) -> Reply {
+        self.customer(customer_id)?;
+        self.attach_card(customer_id, params)
+    }
+
+    fn update_customer_card(&mut self, customer_id: &str, card_id: &str, params: &Params) -> Reply {
+        let i = self.customer_card(customer_id, card_id)?;
+        for field in &["address_city", "address_country", "address_line1", "address_line2",
+                       "address_state", "address_zip", "name"] {
+            if let Some(value) = params.get(field) {
+                set(&mut self.cards[i], field, string(value));
+            }
+        }
+        for field in &["exp_month", "exp_year"] {
+            if let Some(value) = params.int(field)? {
+                set(&mut self.cards[i], field, int(value));
+            }
+        }
+        merge_metadata(&mut self.cards[i], params);
+        let card = self.cards[i].clone();
+        self.emit("customer.source.updated", card.clone());
+        Ok(card)
+    }
+
+    fn delete_customer_card(&mut self, customer_id: &str, card_id: &str) -> Reply {
+        let i = self.customer_card(customer_id, card_id)?;
+        let card = self.cards.remove(i);
+        self.card_numbers.remove(card_id);
+
+        let next_default = self.customer_cards(customer_id).last()
+            .and_then(|card| get_str(card, "id"))
+            .map(String::from);
+        let c = position(&self.customers, customer_id).unwrap();
+        if get_str(&self.customers[c], "default_source") == Some(card_id) {
+            set(&mut self.customers[c], "default_source", opt_string(next_default));
+        }
+        self.emit("customer.source.deleted", card);
+        Ok(deleted(card_id))
+    }
+
+    // Customers
+
+    fn customer(&self, id: &str) -> Reply {
+        retrieve(&self.customers, "customer", id)
+    }
+
+    fn render_customer(&self, customer: &Value) -> Value {
+        let id = get_str(customer, "id").unwrap_or("");
+        let mut customer = customer.clone();
+        set(&mut customer, "sources", list_object(
+            &format!("/v1/customers/{}/sources", id),
+            self.customer_cards(id)
+        ));
+        set(&mut customer, "subscriptions", list_object(
+            &format!("/v1/customers/{}/subscriptions", id),
+            self.active_subscriptions(id)
+        ));
+        customer
+    }
+
+    fn create_customer(&mut self, params: &Params) -> Reply {
+        let id = self.id("cus");
+        self.customers.push(json_object(vec![
+            ("id", string(&id)),
+            ("object", string("customer")),
+            ("account_balance", int(params.int("account_balance")?.unwrap_or(0))),
+            ("created", int(now())),
+            ("currency", Value::Null),
+            ("default_source", Value::Null),
+            ("delinquent", Value::Bool(false)),
+            ("description", opt_string(params.get("description"))),
+            ("discount", Value::Null),
+            ("email", opt_string(params.get("email"))),
+            ("livemode", Value::Bool(false)),
+            ("metadata", params.metadata()),
+            ("shipping", Value::Null)
+        ]));
+        if let Some(coupon) = params.get("coupon") {
+            let discount = self.redeem(coupon, &id, None)?;
+            let i = self.customers.len() - 1;
+            set(&mut self.customers[i], "discount", discount);
+        }
+        let customer = self.customer(&id)?;
+        self.emit("customer.created", customer);
+
+        if params.get("source").is_some() || !params.nested("source").is_empty() {
+            self.attach_card(&id, params)?;
+        }
+        if params.get("plan").is_some() {
+            let subscription_params: Vec<(String, String)> = params.0.iter()
+                .filter(|&&(ref k, _)| k == "plan" || k == "quantity" || k == "trial_end")
+                .cloned()
+                .collect();
+            self.create_subscription(&id, &Params(&subscription_params))?;
+        }
+        self.customer(&id).map(|customer| self.render_customer(&customer))
+    }
+
+    fn update_customer(&mut self, id: &str, params: &Params) -> Reply {
+        let i = position(&self.customers, id).ok_or_else(|| no_such("customer", id))?;
+        for field in &["description", "email"] {
+            if let Some(value) = params.get(field) {
+                set(&mut self.customers[i], field, string(value));
+            }
+        }
+        if let Some(account_balance) = params.int("account_balance")? {
+            set(&mut self.customers[i], "account_balance", int(account_balance));
+        }
+        merge_metadata(&mut self.customers[i], params);
+
+        if let Some(coupon) = params.get("coupon") {
+            let discount = self.redeem(coupon, id, None)?;
+            set(&mut self.customers[i], "discount", discount);
+        }
+        if params.get("source").is_some() || !params.nested("source").is_empty() {
+            // Passing a source replaces the customer's default source.
+            let previous = get_str(&self.customers[i], "default_source").map(String::from);
+            if let Some(previous) = previous {
+                self.delete_customer_card(id, &previous)?;
+            }
+            let card = self.attach_card(id, params)?;
+            set(&mut self.customers[i], "default_source", card.find("id").cloned().unwrap_or(Value::Null));
+        }
+        if let Some(default_source) = params.get("default_source") {
+            self.customer_card(id, default_source)?;
+            set(&mut self.customers[i], "default_source", string(default_source));
+        }
+
+        let customer = self.render_customer(&self.customers[i]);
+        self.emit("customer.updated", customer.clone());
+        Ok(customer)
+    }
+
+    fn delete_customer(&mut self, id: &str) -> Reply {
+        let i = position(&self.customers, id).ok_or_else(|| no_such("customer", id))?;
+        let active: Vec<String> = self.active_subscriptions(id).iter()
+            .filter_map(|subscription| get_str(subscription, "id"))
+            .map(String::from)
+            .collect();
+        for subscription in active {
+            self.cancel_subscription(id, &subscription, &Params(&[]))?;
+        }
+        let customer = self.customers.remove(i);
+        self.cards.retain(|card| get_str(card, "customer") != Some(id));
+        self.emit("customer.deleted", customer);
+        Ok(deleted(id))
+    }
+
+    // Charges and refunds
+
+    fn charge_index(&self, id: &str) -> ::std::result::Result<usize, Rejection> {
+        position(&self.charges, id).ok_or_else(|| no_such("charge", id))
+    }
+
+    fn render_charge(&self, charge: &Value) -> Value {
+        let id = get_str(charge, "id").unwrap_or("");
+        let refunds = self.refunds.iter()
+            .filter(|refund| get_str(refund, "charge") == Some(id))
+            .cloned()
+            .collect();
+        let mut charge = charge.clone();
+        set(&mut charge, "refunds", list_object(&format!("/v1/charges/{}/refunds", id), refunds));
+        charge
+    }
+
+    fn create_charge(&mut self, params: &Params) -> Reply {
+        let amount = params.int("amount")?.ok_or_else(|| missing_param("amount"))?;
+        if amount < 50 {
+            return Err(invalid_request("Amount must be at least 50 cents", Some("amount")));
+        }
+        let currency = params.require("currency")?;
+        let customer = params.get("customer");
+
+        let card = match (customer, params.get("source")) {
+            (Some(customer), Some(source)) if !source.starts_with("tok_") => {
+                let i = self.customer_card(customer, source)?;
+                self.cards[i].clone()
+            },
+            (Some(customer), None) if params.nested("source").is_empty() => {
+                let customer = self.customer(customer)?;
+                match get_str(&customer, "default_source") {
+                    Some(source) => retrieve(&self.cards, "source", source)?,
+                    None => return Err(invalid_request(
+                        "Cannot charge a customer that has no active card",
+                        Some("customer")
+                    ))
+                }
+            },
+            (None, None) if params.nested("source").is_empty() =>
+                return Err(invalid_request("Must provide source or customer.", None)),
+            _ => {
+                if let Some(customer) = customer {
+                    self.customer(customer)?;
+                }
+                self.card_param(params, "source")?
+            }
+        };
+
+        let capture = params.get("capture").map_or(true, |capture| capture == "true");
+        self.charge(amount, currency, customer, card, capture, None, params)
+    }
+
+    /// Records a charge of `card`, declining it if `card` is a test card
+    /// Stripe declines.
+    fn charge(
+        &mut self,
+        amount: i64,
+        currency: &str,
+        customer: Option<&str>,
+        card: Value,
+        capture: bool,
+        invoice: Option<&str>,
+        params: &Params
+    ) -> Reply {
+        let id = self.id("ch");
+        let number = get_str(&card, "id")
+            .and_then(|card_id| self.card_numbers.get(card_id))
+            .cloned()
+            .unwrap_or(String::new());
+        let mut charge = json_object(vec![
+            ("id", string(&id)),
+            ("object", string("charge")),
+            ("amount", int(amount)),
+            ("amount_refunded", int(0)),
+            ("application_fee", Value::Null),
+            ("balance_transaction", Value::Null),
+            ("captured", Value::Bool(false)),
+            ("created", int(now())),
+            ("currency", string(&currency.to_lowercase())),
+            ("customer", opt_string(customer)),
+            ("description", opt_string(params.get("description"))),
+            ("destination", Value::Null),
+            ("dispute", Value::Null),
+            ("failure_code", Value::Null),
+            ("failure_message", Value::Null),
+            ("fraud_details", json_object(vec![])),
+            ("invoice", opt_string(invoice)),
+            ("livemode", Value::Bool(false)),
+            ("metadata", params.metadata()),
+            ("order", Value::Null),
+            ("paid", Value::Bool(false)),
+            ("receipt_email", opt_string(params.get("receipt_email"))),
+            ("receipt_number", Value::Null),
+            ("refunded", Value::Bool(false)),
+            ("shipping", Value::Null),
+            ("source", card),
+            ("source_transfer", Value::Null),
+            ("statement_descriptor", opt_string(params.get("statement_descriptor"))),
+            ("status", string("succeeded")),
+            ("transfer", Value::Null)
+        ]);
+
+        if let Some(decline) = test_card_decline(&number, false) {
+            set(&mut charge, "status", string("failed"));
+            set(&mut charge, "failure_code", string(decline.code));
+            set(&mut charge, "failure_message", string(decline.message));
+            self.charges.push(charge.clone());
+            let charge = self.render_charge(&charge);
+            self.emit("charge.failed", charge);
+            let mut rejection = decline.rejection(Some(id.as_str()));
+            rejection.keep = true;
+            return Err(rejection);
+        }
+
+        set(&mut charge, "paid", Value::Bool(true));
+        if capture {
+            let balance_transaction = self.id("txn");
+            set(&mut charge, "captured", Value::Bool(true));
+            set(&mut charge, "balance_transaction", string(&balance_transaction));
+        }
+        self.charges.push(charge.clone());
+        let charge = self.render_charge(&charge);
+        self.emit("charge.succeeded", charge.clone());
+        Ok(charge)
+    }
+
+    fn update_charge(&mut self, id: &str, params: &Params) -> Reply {
+        let i = self.charge_index(id)?;
+        for field in &["description", "receipt_email"] {
+            if let Some(value) = params.get(field) {
+                set(&mut self.charges[i], field, string(value));
+            }
+        }
+        merge_metadata(&mut self.charges[i], params);
+        let charge = self.render_charge(&self.charges[i]);
+        self.emit("charge.updated", charge.clone());
+        Ok(charge)
+    }
+
+    fn capture_charge(&mut self, id: &str, params: &Params) -> Reply {
+        let i = self.charge_index(id)?;
+        if get_str(&self.charges[i], "status") != Some("succeeded") {
+            return Err(invalid_request(&format!("Charge {} has failed and cannot be captured.", id), None));
+        }
+        if get_bool(&self.charges[i], "captured") {
+            return Err(invalid_request(&format!("Charge {} has already been captured.", id), None));
+        }
+        let amount = get_i64(&self.charges[i], "amount").unwrap_or(0);
+        let captured = params.int("amount")?.unwrap_or(amount);
+        if captured > amount {
+            return Err(invalid_request(
+                "You cannot capture more than the amount of the charge.",
+                Some("amount")
+            ));
+        }
+
+        let balance_transaction = self.id("txn");
+        set(&mut self.charges[i], "captured", Value::Bool(true));
+        set(&mut self.charges[i], "balance_transaction", string(&balance_transaction));
+        // Capturing less than the full amount refunds the rest.
+        set(&mut self.charges[i], "amount_refunded", int(amount - captured));
+        let charge = self.render_charge(&self.charges[i]);
+        self.emit("charge.captured", charge.clone());
+        Ok(charge)
+    }
+
+    fn create_refund(&mut self, params: &Params) -> Reply {
+        let charge_id = params.require("charge")?;
+        let i = self.charge_index(charge_id)?;
+        let amount = get_i64(&self.charges[i], "amount").unwrap_or(0);
+        let refunded = get_i64(&self.charges[i], "amount_refunded").unwrap_or(0);
+        let remaining = amount - refunded;
+        if get_str(&self.charges[i], "status") != Some("succeeded") {
+            return Err(invalid_request(&format!("Charge {} has failed and cannot be refunded.", charge_id), None));
+        }
+        if remaining == 0 {
+            return Err(invalid_request(&format!("Charge {} has already been refunded.", charge_id), None));
+        }
+        let refund_amount = params.int("amount")?.unwrap_or(remaining);
+        if refund_amount <= 0 || refund_amount > remaining {
+            return Err(invalid_request(
+                &format!(
+                    "Refund amount ({}) is greater than unrefunded amount on charge ({})",
+                    refund_amount,
+                    remaining
+                ),
+                Some("amount")
+            ));
+        }
+        let reason = params.get("reason");
+        match reason {
+            None | Some("duplicate") | Some("fraudulent") | Some("requested_by_customer") => {},
+            Some(reason) => return Err(invalid_request(
+                &format!("Invalid reason: {}. Must be one of duplicate, fraudulent, or requested_by_customer", reason),
+                Some("reason")
+            ))
+        }
+
+        let id = self.id("re");
+        let balance_transaction = self.id("txn");
+        let currency = self.charges[i].find("currency").cloned().unwrap_or(Value::Null);
+        let refund = json_object(vec![
+            ("id", string(&id)),
+            ("object", string("refund")),
+            ("amount", int(refund_amount)),
+            ("balance_transaction", string(&balance_transaction)),
+            ("charge", string(charge_id)),
+            ("created", int(now())),
+            ("currency", currency),
+            ("description", Value::Null),
+            ("metadata", params.metadata()),
+            ("reason", opt_string(reason)),
+            ("receipt_number", Value::Null)
+        ]);
+        self.refunds.push(refund.clone());
+
+        set(&mut self.charges[i], "amount_refunded", int(refunded + refund_amount));
+        set(&mut self.charges[i], "refunded", Value::Bool(refunded + refund_amount == amount));
+        let charge = self.render_charge(&self.charges[i]);
+        self.emit("charge.refunded", charge);
+        Ok(refund)
+    }
+
+    // Plans and coupons
+
+    fn create_plan(&mut self, params: &Params) -> Reply {
+        let id = params.require("id")?;
+        if position(&self.plans, id).is_some() {
+            return Err(invalid_request("Plan already exists.", Some("id")));
+        }
+        let amount = params.int("amount")?.ok_or_else(|| missing_param("amount"))?;
+        if amount < 0 {
+            return Err(invalid_request("Invalid positive integer", Some("amount")));
+        }
+        let currency = params.require("currency")?;
+        let interval = params.require("interval")?;
+        if !["day", "week", "month", "year"].contains(&interval) {
+            return Err(invalid_request(
+                "Invalid interval: must be one of day, month, week, or year",
+                Some("interval")
+            ));
+        }
+        let name = params.require("name")?;
+        let interval_count = params.int("interval_count")?.unwrap_or(1);
+        let trial_period_days = params.int("trial_period_days")?;
+
+        let plan = json_object(vec![
+            ("id", string(id)),
+            ("object", string("plan")),
+            ("amount", int(amount)),
+            ("created", int(now())),
+            ("currency", string(&currency.to_lowercase())),
+            ("interval", string(interval)),
+            ("interval_count", int(interval_count)),
+            ("livemode", Value::Bool(false)),
+            ("metadata", params.metadata()),
+            ("name", string(name)),
+            ("statement_descriptor", opt_string(params.get("statement_descriptor"))),
+            ("trial_period_days", trial_period_days.map_or(Value::Null, int))
+        ]);
+        self.plans.push(plan.clone());
+        self.emit("plan.created", plan.clone());
+        Ok(plan)
+    }
+
+    fn update_plan(&mut self, id: &str, params: &Params) -> Reply {
+        let i = position(&self.plans, id).ok_or_else(|| no_such("plan", id))?;
+        for field in &["name", "statement_descriptor"] {
+            if let Some(value) = params.get(field) {
+                set(&mut self.plans[i], field, string(value));
+            }
+        }
+        merge_metadata(&mut self.plans[i], params);
+        let plan = self.plans[i].clone();
+        self.emit("plan.updated", plan.clone());
+        Ok(plan)
+    }
+
+    fn create_coupon(&mut self, params: &Params) -> Reply {
+        let duration = params.require("duration")?;
+        let duration_in_months = params.int("duration_in_months")?;
+        match duration {
+            "forever" | "once" => {},
+            "repeating" => if duration_in_months.is_none() {
+                return Err(missing_param("duration_in_months"));
+            },
+            _ => return Err(invalid_request(
+                "Invalid duration: must be one of forever, once, or repeating",
+                Some("duration")
+            ))
+        }
+        let percent_off = params.int("percent_off")?;
+        let amount_off = params.int("amount_off")?;
+        let currency = params.get("currency");
+        match (percent_off, amount_off) {
+            (Some(percent_off), None) => if percent_off < 1 || percent_off > 100 {
+                return Err(invalid_request("Invalid percent_off: must be between 1 and 100", Some("percent_off")));
+            },
+            (None, Some(_)) => if currency.is_none() {
+                return Err(missing_param("currency"));
+            },
+            _ => return Err(invalid_request(
+                "Must provide exactly one of these parameters: percent_off, amount_off.",
+                None
+            ))
+        }
+
+        let id = match params.get("id") {
+            Some(id) => id.to_string(),
+            None     => self.id("coupon")
+        };
+        if position(&self.coupons, &id).is_some() {
+            return Err(invalid_request("Coupon already exists.", Some("id")));
+        }
+        let coupon = json_object(vec![
+            ("id", string(&id)),
+            ("object", string("coupon")),
+            ("amount_off", amount_off.map_or(Value::Null, int)),
+            ("created", int(now())),
+            ("currency", opt_string(currency.map(|currency| currency.to_lowercase()))),
+            ("duration", string(duration)),
+            ("duration_in_months", duration_in_months.map_or(Value::Null, int)),
+            ("livemode", Value::Bool(false)),
+            ("max_redemptions", params.int("max_redemptions")?.map_or(Value::Null, int)),
+            ("metadata", params.metadata()),
+            ("percent_off", percent_off.map_or(Value::Null, int)),
+            ("redeem_by", params.int("redeem_by")?.map_or(Value::Null, int)),
+            ("times_redeemed", int(0)),
+            ("valid", Value::Bool(true))
+        ]);
+        self.coupons.push(coupon.clone());
+        self.emit("coupon.created", coupon.clone());
+        Ok(coupon)
+    }
+
+    /// Redeems a coupon, returning the resulting discount.
+    fn redeem(&mut self, coupon_id: &str, customer_id: &str, subscription_id: Option<&str>) -> Reply {
+        let i = position(&self.coupons, coupon_id).ok_or_else(|| no_such("coupon", coupon_id))?;
+        let times_redeemed = get_i64(&self.coupons[i], "times_redeemed").unwrap_or(0) + 1;
+        let exhausted = get_i64(&self.coupons[i], "max_redemptions")
+            .map_or(false, |max_redemptions| times_redeemed > max_redemptions);
+        let expired = get_i64(&self.coupons[i], "redeem_by")
+            .map_or(false, |redeem_by| redeem_by < now());
+        if exhausted || expired {
+            set(&mut self.coupons[i], "valid", Value::Bool(false));
+            return Err(invalid_request(&format!("Coupon expired: {}", coupon_id), Some("coupon")));
+        }
+        set(&mut self.coupons[i], "times_redeemed", int(times_redeemed));
+
+        let start = now();
+        let end = match (get_str(&self.coupons[i], "duration"), get_i64(&self.coupons[i], "duration_in_months")) {
+            (Some("repeating"), Some(months)) => int(start + months * 30 * SECONDS_PER_DAY),
+            _                                 => Value::Null
+        };
+        Ok(json_object(vec![
+            ("object", string("discount")),
+            ("coupon", self.coupons[i].clone()),
+            ("customer", string(customer_id)),
+            ("end", end),
+            ("start", int(start)),
+            ("subscription", opt_string(subscription_id))
+        ]))
+    }
+
+    // Subscriptions and invoices
+
+    fn subscription(&self, customer_id: &str, id: &str) -> ::std::result::Result<usize, Rejection> {
+        self.customer(customer_id)?;
+        self.subscriptions.iter()
+            .position(|sub| get_str(sub, "id") == Some(id) && get_str(sub, "customer") == Some(customer_id))
+            .ok_or_else(|| no_such("subscription", id))
+    }
+
+    fn active_subscriptions(&self, customer_id: &str) -> Vec<Value> {
+        self.subscriptions.iter()
+            .filter(|sub| get_str(sub, "customer") == Some(customer_id))
+            .filter(|sub| get_str(sub, "status") != Some("canceled"))
+            .cloned()
+            .collect()
+    }
+
+    fn create_subscription(&mut self, customer_id: &str, params: &Params) -> Reply {
+        self.customer(customer_id)?;
+        let plan_id = params.require("plan")?;
+        let plan = retrieve(&self.plans, "plan", plan_id)?;
+        let quantity = params.int("quantity")?.unwrap_or(1);
+        if params.get("source").is_some() || !params.nested("source").is_empty() {
+            self.attach_card(customer_id, params)?;
+        }
+
+        let id = self.id("sub");
+        let start = now();
+        let trial_end = match params.get("trial_end") {
+            Some("now") => None,
+            Some(_)     => params.int("trial_end")?,
+            None        => get_i64(&plan, "trial_period_days").map(|days| start + days * SECONDS_PER_DAY)
+        };
+        let discount = match params.get("coupon") {
+            Some(coupon) => self.redeem(coupon, customer_id, Some(id.as_str()))?,
+            None         => Value::Null
+        };
+        let period_end = trial_end.unwrap_or(start + interval_seconds(&plan));
+
+        let subscription = json_object(vec![
+            ("id", string(&id)),
+            ("object", string("subscription")),
+            ("application_fee_percent", Value::Null),
+            ("cancel_at_period_end", Value::Bool(false)),
+            ("canceled_at", Value::Null),
+            ("current_period_end", int(period_end)),
+            ("current_period_start", int(start)),
+            ("customer", string(customer_id)),
+            ("discount", discount),
+            ("ended_at", Value::Null),
+            ("metadata", params.metadata()),
+            ("plan", plan),
+            ("quantity", int(quantity)),
+            ("start", int(start)),
+            ("status", string(if trial_end.is_some() { "trialing" } else { "active" })),
+            ("tax_percent", Value::Null),
+            ("trial_end", trial_end.map_or(Value::Null, int)),
+            ("trial_start", trial_end.map_or(Value::Null, |_| int(start)))
+        ]);
+        self.subscriptions.push(subscription.clone());
+        self.emit("customer.subscription.created", subscription.clone());
+
+        self.invoice_subscription(&subscription).map_err(|mut rejection| {
+            // A subscription whose first invoice can't be paid isn't created.
+            rejection.keep = false;
+            rejection
+        })?;
+        Ok(subscription)
+    }
+
+    fn update_subscription(&mut self, customer_id: &str, id: &str, params: &Params) -> Reply {
+        let i = self.subscription(customer_id, id)?;
+        if let Some(plan_id) = params.get("plan") {
+            let plan = retrieve(&self.plans, "plan", plan_id)?;
+            set(&mut self.subscriptions[i], "plan", plan);
+        }
+        if let Some(quantity) = params.int("quantity")? {
+            set(&mut self.subscriptions[i], "quantity", int(quantity));
+        }
+        if let Some(coupon) = params.get("coupon") {
+            let discount = self.redeem(coupon, customer_id, Some(id))?;
+            set(&mut self.subscriptions[i], "discount", discount);
+        }
+        if params.get("source").is_some() || !params.nested("source").is_empty() {
+            self.attach_card(customer_id, params)?;
+        }
+        match params.get("trial_end") {
+            Some("now") => {
+                set(&mut self.subscriptions[i], "trial_end", int(now()));
+                set(&mut self.subscriptions[i], "status", string("active"));
+            },
+            Some(_) => {
+                let trial_end = params.int("trial_end")?.unwrap_or(0);
+                set(&mut self.subscriptions[i], "trial_end", int(trial_end));
+                set(&mut self.subscriptions[i], "current_period_end", int(trial_end));
+                set(&mut self.subscriptions[i], "status", string("trialing"));
+            },
+            None => {}
+        }
+        merge_metadata(&mut self.subscriptions[i], params);
+        let subscription = self.subscriptions[i].clone();
+        self.emit("customer.subscription.updated", subscription.clone());
+        Ok(subscription)
+    }
+
+    fn cancel_subscription(&mut self, customer_id: &str, id: &str, params: &Params) -> Reply {
+        let i = self.subscription(customer_id, id)?;
+        if get_str(&self.subscriptions[i], "status") == Some("canceled") {
+            return Err(no_such("subscription", id));
+        }
+        let canceled_at = now();
+        set(&mut self.subscriptions[i], "canceled_at", int(canceled_at));
+        if params.get("at_period_end") == Some("true") {
+            set(&mut self.subscriptions[i], "cancel_at_period_end", Value::Bool(true));
+            let subscription = self.subscriptions[i].clone();
+            self.emit("customer.subscription.updated", subscription.clone());
+            return Ok(subscription);
+        }
+        set(&mut self.subscriptions[i], "ended_at", int(canceled_at));
+        set(&mut self.subscriptions[i], "status", string("canceled"));
+        let subscription = self.subscriptions[i].clone();
+        self.emit("customer.subscription.deleted", subscription.clone());
+        Ok(subscription)
+    }
+
+    /// Invoices the first period of a subscription and pays the invoice
+    /// with the customer's default source.
+    fn invoice_subscription(&mut self, subscription: &Value) -> Reply {
+        let customer_id = get_str(subscription, "customer").unwrap_or("");
+        let customer = self.customer(customer_id)?;
+        let plan = subscription.find("plan").cloned().unwrap_or(Value::Null);
+        let quantity = get_i64(subscription, "quantity").unwrap_or(1);
+        let currency = plan.find("currency").cloned().unwrap_or(Value::Null);
+        let period = json_object(vec![
+            ("start", subscription.find("current_period_start").cloned().unwrap_or(Value::Null)),
+            ("end", subscription.find("current_period_end").cloned().unwrap_or(Value::Null))
+        ]);
+        let subtotal = if get_str(subscription, "status") == Some("trialing") {
+            0
+        } else {
+            get_i64(&plan, "amount").unwrap_or(0) * quantity
+        };
+        let discount = match subscription.find("discount") {
+            Some(discount) if !discount.is_null() => discount.clone(),
+            _ => customer.find("discount").cloned().unwrap_or(Value::Null)
+        };
+        let total = discounted(subtotal, &discount);
+
+        let id = self.id("in");
+        let line = json_object(vec![
+            ("id", subscription.find("id").cloned().unwrap_or(Value::Null)),
+            ("object", string("line_item")),
+            ("amount", int(subtotal)),
+            ("currency", currency.clone()),
+            ("description", Value::Null),
+            ("discountable", Value::Bool(true)),
+            ("livemode", Value::Bool(false)),
+            ("metadata", json_object(vec![])),
+            ("period", period),
+            ("plan", plan),
+            ("proration", Value::Bool(false)),
+            ("quantity", int(quantity)),
+            ("subscription", Value::Null),
+            ("type", string("subscription"))
+        ]);
+        let date = now();
+        self.invoices.push(json_object(vec![
+            ("id", string(&id)),
+            ("object", string("invoice")),
+            ("amount_due", int(total)),
+            ("application_fee", Value::Null),
+            ("attempt_count", int(0)),
+            ("attempted", Value::Bool(false)),
+            ("charge", Value::Null),
+            ("closed", Value::Bool(false)),
+            ("currency", currency),
+            ("customer", string(customer_id)),
+            ("date", int(date)),
+            ("description", Value::Null),
+            ("discount", discount),
+            ("ending_balance", Value::Null),
+            ("forgiven", Value::Bool(false)),
+            ("lines", list_object(&format!("/v1/invoices/{}/lines", id), vec![line])),
+            ("livemode", Value::Bool(false)),
+            ("metadata", json_object(vec![])),
+            ("next_payment_attempt", Value::Null),
+            ("paid", Value::Bool(false)),
+            ("period_end", int(date)),
+            ("period_start", int(date)),
+            ("receipt_number", Value::Null),
+            ("starting_balance", int(0)),
+            ("statement_descriptor", Value::Null),
+            ("subscription", subscription.find("id").cloned().unwrap_or(Value::Null)),
+            ("subscription_proration_date", Value::Null),
+            ("subtotal", int(subtotal)),
+            ("tax", Value::Null),
+            ("tax_percent", Value::Null),
+            ("total", int(total)),
+            ("webhooks_delivered_at", Value::Null)
+        ]));
+        let i = self.invoices.len() - 1;
+        let invoice = self.invoices[i].clone();
+        self.emit("invoice.created", invoice);
+        self.pay_invoice(i)
+    }
+
+    fn pay_invoice(&mut self, i: usize) -> Reply {
+        let amount_due = get_i64(&self.invoices[i], "amount_due").unwrap_or(0);
+        if amount_due > 0 {
+            let id = get_str(&self.invoices[i], "id").unwrap_or("").to_string();
+            let customer_id = get_str(&self.invoices[i], "customer").unwrap_or("").to_string();
+            let currency = get_str(&self.invoices[i], "currency").unwrap_or("").to_string();
+            let attempt_count = get_i64(&self.invoices[i], "attempt_count").unwrap_or(0) + 1;
+            set(&mut self.invoices[i], "attempt_count", int(attempt_count));
+            set(&mut self.invoices[i], "attempted", Value::Bool(true));
+
+            let customer = self.customer(&customer_id)?;
+            let card = match get_str(&customer, "default_source") {
+                Some(source) => retrieve(&self.cards, "source", source)?,
+                None => return Err(invalid_request(
+                    "This customer has no attached payment source",
+                    None
+                ))
+            };
+            let charge = match self.charge(amount_due, &currency, Some(customer_id.as_str()), card, true, Some(id.as_str()), &Params(&[])) {
+                Ok(charge) => charge,
+                Err(rejection) => {
+                    let invoice = self.invoices[i].clone();
+                    self.emit("invoice.payment_failed", invoice);
+                    return Err(rejection);
+                }
+            };
+            set(&mut self.invoices[i], "charge", charge.find("id").cloned().unwrap_or(Value::Null));
+        }
+        set(&mut self.invoices[i], "paid", Value::Bool(true));
+        set(&mut self.invoices[i], "closed", Value::Bool(true));
+        let invoice = self.invoices[i].clone();
+        self.emit("invoice.payment_succeeded", invoice.clone());
+        Ok(invoice)
+    }
+
+    fn update_invoice(&mut self, id: &str, params: &Params) -> Reply {
+        let i = position(&self.invoices, id).ok_or_else(|| no_such("invoice", id))?;
+        for field in &["description", "statement_descriptor"] {
+            if let Some(value) = params.get(field) {
+                set(&mut self.invoices[i], field, string(value));
+            }
+        }
+        for field in &["closed", "forgiven"] {
+            if let Some(value) = params.get(field) {
+                set(&mut self.invoices[i], field, Value::Bool(value == "true"));
+            }
+        }
+        merge_metadata(&mut self.invoices[i], params);
+        let invoice = self.invoices[i].clone();
+        self.emit("invoice.updated", invoice.clone());
+        Ok(invoice)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Resource {
+    Plan,
+    Coupon
+}
+
+/// How Stripe responds to one of its test card numbers.
+struct Decline {
+    code: &'static str,
+    decline_code: Option<&'static str>,
+    message: &'static str
+}
+
+impl Decline {
+    fn rejection(&self, charge_id: Option<&str>) -> Rejection {
+        let mut fields = vec![
+            ("type", string("card_error")),
+            ("message", string(self.message)),
+            ("code", string(self.code))
+        ];
+        if let Some(decline_code) = self.decline_code {
+            fields.push(("decline_code", string(decline_code)));
+        }
+        if let Some(charge_id) = charge_id {
+            fields.push(("charge", string(charge_id)));
+        }
+        error_response(StatusCode::PaymentRequired, fields)
+    }
+}
+
+/// https://stripe.com/docs/testing#cards
+fn test_card_decline(number: &str, attaching: bool) -> Option<Decline> {
+    let (code, decline_code, message) = match number {
+        "4000000000000002" =>
+            ("card_declined", Some("generic_decline"), "Your card was declined."),
+        "4000000000009995" =>
+            ("card_declined", Some("insufficient_funds"), "Your card has insufficient funds."),
+        "4000000000000069" =>
+            ("expired_card", None, "Your card has expired."),
+        "4000000000000127" =>
+            ("incorrect_cvc", None, "Your card's security code is incorrect."),
+        "4000000000000119" =>
+            ("processing_error", None, "An error occurred while processing your card. Try again in a little bit."),
+        "4000000000000341" if !attaching =>
+            ("card_declined", Some("generic_decline"), "Your card was declined."),
+        _ => return None
+    };
+    Some(Decline {
+        code: code,
+        decline_code: decline_code,
+        message: message
+    })
+}
+
+/// Stable per card number, like Stripe's fingerprints (FNV-1a).
+fn fingerprint(number: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in number.bytes() {
+        hash = (hash ^ byte as u64).wrapping_mul(0x100000001b3);
+    }
+    format!("{:016x}", hash)
+}
+
+fn interval_seconds(plan: &Value) -> i64 {
+    let days = match get_str(plan, "interval") {
+        Some("day")  => 1,
+        Some("week") => 7,
+        Some("year") => 365,
+        _            => 30
+    };
+    days * SECONDS_PER_DAY * get_i64(plan, "interval_count").unwrap_or(1)
+}
+
+fn discounted(amount: i64, discount: &Value) -> i64 {
+    let coupon = match discount.find("coupon") {
+        Some(coupon) => coupon,
+        None         => return amount
+    };
+    match (get_i64(coupon, "percent_off"), get_i64(coupon, "amount_off")) {
+        (Some(percent_off), _) => amount - amount * percent_off / 100,
+        (_, Some(amount_off))  => if amount_off > amount { 0 } else { amount - amount_off },
+        _                      => amount
+    }
+}
+
+fn now() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// Form params of a request. Nested params such as `card[number]` are
+/// flattened, as they are sent.
+struct Params<'a>(&'a [(String, String)]);
+
+impl<'a> Params<'a> {
+    fn get(&self, key: &str) -> Option<&'a str> {
+        self.0.iter()
+            .rev()
+            .find(|&&(ref k, _)| k == key)
+            .map(|&(_, ref v)| v.as_str())
+    }
+
+    fn require(&self, key: &str) -> ::std::result::Result<&'a str, Rejection> {
+        self.get(key).ok_or_else(|| missing_param(key))
+    }
+
+    fn int(&self, key: &str) -> ::std::result::Result<Option<i64>, Rejection> {
+        match self.get(key) {
+            None        => Ok(None),
+            Some(value) => value.parse()
+                .map(Some)
+                .map_err(|_| invalid_request(&format!("Invalid integer: {}", value), Some(key)))
+        }
+    }
+
+    /// The fields of `prefix[...]`, e.g. `number` for `card[number]`.
+    fn nested(&self, prefix: &str) -> Vec<(String, String)> {
+        let start = format!("{}[", prefix);
+        self.0.iter()
+            .filter(|&&(ref k, _)| k.starts_with(start.as_str()) && k.ends_with(']'))
+            .map(|&(ref k, ref v)| (k[start.len()..k.len() - 1].to_string(), v.clone()))
+            .collect()
+    }
+
+    fn metadata(&self) -> Value {
+        Value::Object(self.nested("metadata").into_iter()
+            .map(|(k, v)| (k, Value::String(v)))
+            .collect())
+    }
+}
+
+fn merge_metadata(object: &mut Value, params: &Params) {
+    let updates = params.nested("metadata");
+    if updates.is_empty() {
+        return;
+    }
+    let mut metadata = match object.find("metadata") {
+        Some(&Value::Object(ref metadata)) => metadata.clone(),
+        _                                  => BTreeMap::new()
+    };
+    for (k, v) in updates {
+        // Setting a key to an empty value removes it.
+        if v.is_empty() {
+            metadata.remove(&k);
+        } else {
+            metadata.insert(k, Value::String(v));
+        }
+    }
+    set(object, "metadata", Value::Object(metadata));
+}
+
+fn list(url: &str, mut items: Vec<Value>, params: &Params) -> Reply {
+    // Lists are newest first.
+    items.reverse();
+    let total_count = items.len();
+    let limit = params.int("limit")?.unwrap_or(10);
+    if limit < 1 || limit > 100 {
+        return Err(invalid_request("Limit must be between 1 and 100", Some("limit")));
+    }
+    let limit = limit as usize;
+
+    if let Some(starting_after) = params.get("starting_after") {
+        let i = position(&items, starting_after).ok_or_else(|| no_such("object", starting_after))?;
+        items = items.split_off(i + 1);
+    }
+    if let Some(ending_before) = params.get("ending_before") {
+        let i = position(&items, ending_before).ok_or_else(|| no_such("object", ending_before))?;
+        items.truncate(i);
+        let skip = if items.len() > limit { items.len() - limit } else { 0 };
+        let has_more = skip > 0;
+        items = items.split_off(skip);
+        let mut list = list_object(url, items);
+        set(&mut list, "has_more", Value::Bool(has_more));
+        set(&mut list, "total_count", int(total_count as i64));
+        return Ok(list);
+    }
+
+    let has_more = items.len() > limit;
+    items.truncate(limit);
+    let mut list = list_object(url, items);
+    set(&mut list, "has_more", Value::Bool(has_more));
+    set(&mut list, "total_count", int(total_count as i64));
+    Ok(list)
+}
+
+fn list_object(url: &str, items: Vec<Value>) -> Value {
+    let total_count = items.len() as i64;
+    json_object(vec![
+        ("object", string("list")),
+        ("data", Value::Array(items)),
+        ("has_more", Value::Bool(false)),
+        ("total_count", int(total_count)),
+        ("url", string(url))
+    ])
+}
+
+fn deleted(id: &str) -> Value {
+    json_object(vec![
+        ("deleted", Value::Bool(true)),
+        ("id", string(id))
+    ])
+}
+
+fn retrieve(list: &[Value], resource: &str, id: &str) -> Reply {
+    list.iter()
+        .find(|value| get_str(value, "id") == Some(id))
+        .cloned()
+        .ok_or_else(|| no_such(resource, id))
+}
+
+fn position(list: &[Value], id: &str) -> Option<usize> {
+    list.iter().position(|value| get_str(value, "id") == Some(id))
+}
+
+fn matches_filter(value: &Value, key: &str, filter: Option<&str>) -> bool {
+    filter.map_or(true, |filter| get_str(value, key) == Some(filter))
+}
+
+fn error_response(status: StatusCode, fields: Vec<(&str, Value)>) -> Rejection {
+    let body = json_object(vec![("error", json_object(fields))]);
+    Rejection {
+        response: TransportResponse::new(status, serde_json::to_string(&body).unwrap()),
+        keep: false
+    }
+}
+
+fn invalid_request(message: &str, param: Option<&str>) -> Rejection {
+    let mut fields = vec![
+        ("type", string("invalid_request_error")),
+        ("message", string(message))
+    ];
+    if let Some(param) = param {
+        fields.push(("param", string(param)));
+    }
+    error_response(StatusCode::BadRequest, fields)
+}
+
+fn missing_param(param: &str) -> Rejection {
+    invalid_request(&format!("Missing required param: {}.", param), Some(param))
+}
+
+fn no_such(resource: &str, id: &str) -> Rejection {
+    error_response(StatusCode::NotFound, vec![
+        ("type", string("invalid_request_error")),
+        ("message", string(&format!("No such {}: {}", resource, id))),
+        ("param", string("id"))
+    ])
+}
+
+fn card_error(code: &str, message: &str, param: &str) -> Rejection {
+    error_response(StatusCode::PaymentRequired, vec![
+        ("type", string("card_error")),
+        ("message", string(message)),
+        ("code", string(code)),
+        ("param", string(param))
+    ])
+}
+
+fn json_object(fields: Vec<(&str, Value)>) -> Value {
+    Value::Object(fields.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+}
+
+fn string(s: &str) -> Value {
+    Value::String(s.to_string())
+}
+
+fn opt_string<S: AsRef<str>>(s: Option<S>) -> Value {
+    s.map_or(Value::Null, |s| string(s.as_ref()))
+}
+
+fn int(i: i64) -> Value {
+    Value::I64(i)
+}
+
+fn set(object: &mut Value, key: &str, value: Value) {
+    if let Value::Object(ref mut map) = *object {
+        map.insert(key.to_string(), value);
+    }
+}
+
+fn get_str<'v>(value: &'v Value, key: &str) -> Option<&'v str> {
+    match value.find(key) {
+        Some(&Value::String(ref s)) => Some(s),
+        _                           => None
+    }
+}
+
+fn get_i64(value: &Value, key: &str) -> Option<i64> {
+    match value.find(key) {
+        Some(&Value::I64(i)) => Some(i),
+        Some(&Value::U64(u)) => Some(u as i64),
+        _                    => None
+    }
+}
+
+fn get_bool(value: &Value, key: &str) -> bool {
+    match value.find(key) {
+        Some(&Value::Bool(b)) => b,
+        _                     => false
+    }
+}
@@ -0,0 +1,161 @@
+use hyper::header::{Authorization, Basic};
+use hyper::method::Method;
+use hyper::status::StatusCode;
+use middleware::Request;
+use prepared_request::PreparedRequest;
+use serde;
+use serde_json;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex};
+use transport::{Transport, TransportResponse};
+use {Result, StripeClient};
+
+#[derive(Clone, Debug)]
+struct CannedResponse {
+    method: Method,
+    path: String,
+    status: StatusCode,
+    body: String
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    responses: Vec<CannedResponse>,
+    calls: Vec<PreparedRequest>,
+    required_key: Option<String>
+}
+
+/// The `Transport` behind a `MockStripe`, for a `StripeClient` configured
+/// some other way, e.g. with middleware. Requests it sends share the mock's
+/// responses and calls.
+#[derive(Clone, Debug)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>
+}
+
+impl Transport for MockTransport {
+    fn send(&self, request: &Request) -> Result<TransportResponse> {
+        let mut state = self.state.lock().unwrap();
+        state.calls.push(PreparedRequest::new(request));
+
+        let key = request.headers.get::<Authorization<Basic>>().map(|auth| auth.username.clone());
+        let authenticated = state.required_key.is_none() || key == state.required_key;
+        let canned = state.responses.iter()
+            .rev()
+            .find(|canned| canned.method == request.method && canned.path == request.path)
+            .cloned();
+        let mut response = match canned {
+            _ if !authenticated => TransportResponse::new(
+                StatusCode::Unauthorized,
+                error_body("authentication_error", "Invalid API Key provided")
+            ),
+            Some(canned) => TransportResponse::new(canned.status, canned.body),
+            None => TransportResponse::new(
+                StatusCode::NotFound,
+                error_body(
+                    "invalid_request_error",
+                    &format!("No mock response for {} {}", request.method, request.path)
+                )
+            )
+        };
+        let request_id = format!("req_mock_{}", state.calls.len());
+        response.headers.set_raw("Request-Id", vec![request_id.into_bytes()]);
+        Ok(response)
+    }
+}
+
+/// A `StripeClient` that never touches the network. It answers each request
+/// with the response most recently registered for its method and path, and
+/// records every request it receives. The `n`th response has the
+/// `Request-Id` `req_mock_n`, counting from 1.
+#[derive(Debug)]
+pub struct MockStripe {
+    client: StripeClient,
+    state: Arc<Mutex<MockState>>
+}
+
+impl MockStripe {
+    pub fn new() -> MockStripe {
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let transport = MockTransport {
+            state: state.clone()
+        };
+        MockStripe {
+            client: StripeClient::new("sk_test_mock").with_transport(transport),
+            state: state
+        }
+    }
+
+    /// Answers `method path` with a 200 and `model` serialized the way
+    /// Stripe would send it.
+    pub fn respond_with<T: serde::Serialize>(&self, method: Method, path: &str, model: &T) {
+        let json = serde_json::to_string(model).unwrap();
+        self.respond(method, path, StatusCode::Ok, json);
+    }
+
+    /// Answers `method path` with a 200 and `json`, e.g. a canned `Charge`.
+    pub fn respond_with_json(&self, method: Method, path: &str, json: &str) {
+        self.respond(method, path, StatusCode::Ok, json.to_string());
+    }
+
+    /// Answers `method path` with a Stripe error of the given type, e.g.
+    /// `card_error`.
+    pub fn respond_with_error(
+        &self,
+        method: Method,
+        path: &str,
+        status: StatusCode,
+        kind: &str,
+        message: &str
+    ) {
+        self.respond(method, path, status, error_body(kind, message));
+    }
+
+    /// Answers requests sent with any other API key the way Stripe does, with
+    /// a 401 `authentication_error`.
+    pub fn require_key(&self, key: &str) {
+        self.state.lock().unwrap().required_key = Some(key.to_string());
+    }
+
+    /// Sends requests to this mock, for a client configured like the one
+    /// the code under test uses.
+    pub fn transport(&self) -> MockTransport {
+        MockTransport {
+            state: self.state.clone()
+        }
+    }
+
+    /// Every request received so far, oldest first.
+    pub fn calls(&self) -> Vec<PreparedRequest> {
+        self.state.lock().unwrap().calls.clone()
+    }
+
+    pub fn clear_calls(&self) {
+        self.state.lock().unwrap().calls.clear();
+    }
+
+    fn respond(&self, method: Method, path: &str, status: StatusCode, body: String) {
+        self.state.lock().unwrap().responses.push(CannedResponse {
+            method: method,
+            path: path.to_string(),
+            status: status,
+            body: body
+        });
+    }
+}
+
+impl Deref for MockStripe {
+    type Target = StripeClient;
+
+    fn deref(&self) -> &StripeClient {
+        &self.client
+    }
+}
+
+fn error_body(kind: &str, message: &str) -> String {
+    format!(
+        "{{\"error\":{{\"type\":\"{}\",\"message\":\"{}\"}}}}",
+        kind,
+        message.replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
@@ -0,0 +1,7 @@
+mod cassette;
+mod fake;
+mod mock;
+
+pub use testing::cassette::*;
+pub use testing::fake::*;
+pub use testing::mock::*;
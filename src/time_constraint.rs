@@ -1,3 +1,7 @@
+#[cfg(feature = "timestamps")]
+use chrono::{NaiveDate, TimeZone};
+use model::Timestamp;
+use std::time::{Duration, SystemTime};
 use url_encodable::UrlEncodable;
 
 #[derive(Clone, Debug)]
@@ -18,6 +22,32 @@ impl TimeConstraint {
         }
     }
 
+    /// From `start` up to but not including `end`, e.g. between two
+    /// `SystemTime`s or chrono `DateTime`s.
+    pub fn between<S: Into<Timestamp>, E: Into<Timestamp>>(start: S, end: E) -> TimeConstraint {
+        TimeConstraint::new()
+            .greater_than_or_equal(start.into().as_secs())
+            .less_than(end.into().as_secs())
+    }
+
+    /// From `duration_ago` before now on, e.g. the last 24 hours.
+    pub fn since(duration_ago: Duration) -> TimeConstraint {
+        let start = Timestamp::from(SystemTime::now() - duration_ago);
+        TimeConstraint::new().greater_than_or_equal(start.as_secs())
+    }
+
+    /// The whole of `date` in `tz`, from its midnight up to but not including
+    /// the next day's, so a day with a daylight saving change is 23 or 25
+    /// hours long. `None` if the zone skipped `date` or the day after it
+    /// altogether.
+    #[cfg(feature = "timestamps")]
+    pub fn on_day<Tz: TimeZone>(date: NaiveDate, tz: &Tz) -> Option<TimeConstraint> {
+        match (start_of_day(date, tz), start_of_day(date.succ(), tz)) {
+            (Some(start), Some(end)) => Some(TimeConstraint::between(start, end)),
+            _                        => None
+        }
+    }
+
     pub fn greater_than(mut self, timestamp: i64) -> TimeConstraint {
         self.gt = Some(timestamp);
         self
@@ -39,6 +69,17 @@ impl TimeConstraint {
     }
 }
 
+/// The first moment of `date` in `tz`. That's midnight, unless a daylight
+/// saving change skips midnight, as in some time zones, which makes it the
+/// first minute after the gap. `None` if the zone skipped the whole day.
+#[cfg(feature = "timestamps")]
+fn start_of_day<Tz: TimeZone>(date: NaiveDate, tz: &Tz) -> Option<Timestamp> {
+    (0..24 * 60)
+        .filter_map(|minute| tz.from_local_datetime(&date.and_hms(minute / 60, minute % 60, 0)).earliest())
+        .next()
+        .map(Timestamp::from)
+}
+
 impl UrlEncodable for TimeConstraint {
     fn key_value_pairs(&self) -> Vec<(String, String)> {
         let mut vec = Vec::new();
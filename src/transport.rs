@@ -0,0 +1,76 @@
+use hyper::Client;
+use hyper::header::Headers;
+use hyper::method::Method;
+use hyper::status::StatusCode;
+use middleware::Request;
+use std::io::Read;
+use url_encodable::UrlEncodable;
+use {Result, StripeClient};
+
+/// A raw HTTP response from Stripe, or from whatever stands in for it.
+#[derive(Clone, Debug)]
+pub struct TransportResponse {
+    pub status: StatusCode,
+    pub headers: Headers,
+    pub body: String
+}
+
+impl TransportResponse {
+    pub fn new(status: StatusCode, body: String) -> TransportResponse {
+        TransportResponse {
+            status: status,
+            headers: Headers::new(),
+            body: body
+        }
+    }
+}
+
+/// Sends fully built requests. `StripeClient` uses `HyperTransport` unless
+/// given another with `StripeClient::with_transport`. This is the seam for
+/// testing code that calls Stripe: write it against `StripeClient`, and give
+/// it a client whose transport is a `testing::MockStripe` or
+/// `testing::FakeStripe` in tests.
+pub trait Transport: Send + Sync {
+    fn send(&self, request: &Request) -> Result<TransportResponse>;
+}
+
+/// Sends requests to the Stripe API over HTTPS.
+#[derive(Debug)]
+pub struct HyperTransport {
+    client: Client
+}
+
+impl HyperTransport {
+    pub fn new() -> HyperTransport {
+        HyperTransport {
+            client: Client::new()
+        }
+    }
+}
+
+impl Transport for HyperTransport {
+    fn send(&self, request: &Request) -> Result<TransportResponse> {
+        let url = StripeClient::url(request);
+        let mut res = if request.method == Method::Get {
+            self.client.get(&url)
+                .headers(request.headers.clone())
+                .send()?
+        } else {
+            let params = request.params.encoded_string();
+            let mut req_builder = self.client.request(request.method.clone(), &url)
+                .headers(request.headers.clone());
+
+            if !params.is_empty() {
+                req_builder = req_builder.body(params.as_bytes());
+            }
+            req_builder.send()?
+        };
+        let mut body = String::new();
+        res.read_to_string(&mut body)?;
+        Ok(TransportResponse {
+            status: res.status,
+            headers: res.headers.clone(),
+            body: body
+        })
+    }
+}
@@ -0,0 +1,53 @@
+use schema_drift;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering, ATOMIC_BOOL_INIT};
+
+static LOG_UNKNOWN_FIELDS: AtomicBool = ATOMIC_BOOL_INIT;
+
+/// While enabled, each model deserialized with fields it doesn't declare logs
+/// their names at `info` level, e.g.
+/// `stripe: Charge has unknown fields: outcome, review`. The fields are kept
+/// in the model's `extra` map either way.
+pub fn log_unknown_fields(enabled: bool) {
+    LOG_UNKNOWN_FIELDS.store(enabled, Ordering::Relaxed);
+}
+
+/// The fields of `json` that aren't in `known`, a model's declared fields
+/// serialized back out. `object` is left out, since the model's type already
+/// says what it is.
+pub fn extract(type_name: &str, json: Value, known: &Value) -> BTreeMap<String, Value> {
+    let mut extra = BTreeMap::new();
+    if let Value::Object(json) = json {
+        if let Value::Object(ref known) = *known {
+            schema_drift::record_fields(type_name, &json, known);
+            for (key, value) in json {
+                if key != "object" && !known.contains_key(&key) {
+                    extra.insert(key, value);
+                }
+            }
+        }
+    }
+
+    if !extra.is_empty() && LOG_UNKNOWN_FIELDS.load(Ordering::Relaxed) {
+        let names: Vec<&str> = extra.keys().map(|key| key.as_str()).collect();
+        info!("stripe: {} has unknown fields: {}", type_name, names.join(", "));
+    }
+    extra
+}
+
+/// `known` with the fields of `extra` added, keeping `known`'s value for any
+/// field in both.
+pub fn merge(known: Value, extra: &BTreeMap<String, Value>) -> Value {
+    match known {
+        Value::Object(mut map) => {
+            for (key, value) in extra {
+                if !map.contains_key(key) {
+                    map.insert(key.clone(), value.clone());
+                }
+            }
+            Value::Object(map)
+        },
+        other => other
+    }
+}
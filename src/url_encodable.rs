@@ -36,7 +36,7 @@ impl UrlEncodable {
     {
         let mut vec = vec![];
         for (k, v) in value.key_value_pairs() {
-            vec.push((format!("{}[{}]", structure_key, k), v));
+            vec.push((nested_key(&structure_key, &k), v));
         }
         vec
     }
@@ -56,9 +56,10 @@ impl UrlEncodable {
     {
         let mut vec = vec![];
         if !list.is_empty() {
+            let list_name = format!("{}[]", list_name);
             for t in list {
                 for (k, v) in t.key_value_pairs() {
-                    vec.push((format!("{}[][{}]", list_name, k), v));
+                    vec.push((nested_key(&list_name, &k), v));
                 }
             }
         } else {
@@ -69,6 +70,16 @@ impl UrlEncodable {
     }
 }
 
+/// `outer[key]`, keeping any brackets already in `key` outside, so nested
+/// objects encode as `shipping[address][line1]` rather than
+/// `shipping[address[line1]]`.
+fn nested_key<S: Display>(outer: S, key: &str) -> String {
+    match key.find('[') {
+        Some(i) => format!("{}[{}]{}", outer, &key[..i], &key[i..]),
+        None    => format!("{}[{}]", outer, key)
+    }
+}
+
 impl<'a> UrlEncodable for &'a UrlEncodable {
     fn key_value_pairs(&self) -> Vec<(String, String)> {
         (*self).key_value_pairs()
@@ -0,0 +1,127 @@
+extern crate stripe;
+
+use stripe::StripeClient;
+use stripe::api::ApiCall;
+use stripe::errors::amount_error::AmountError;
+use stripe::model::{Amount, CouponDuration, Currency, Interval, Inventory, InventoryType, OrderItem};
+
+fn usd(amount: i64) -> Amount {
+    Amount::new(amount, Currency::UnitedStatesDollar)
+}
+
+#[test]
+fn zero_decimal_currencies_have_no_minor_units() {
+    assert_eq!(Currency::JapanYen.exponent(), 0);
+    assert!(Currency::SouthKoreaWon.is_zero_decimal());
+    assert!(Currency::UgandaShilling.is_zero_decimal());
+    assert_eq!(Currency::UnitedStatesDollar.exponent(), 2);
+    assert!(!Currency::Unknown("xyz".to_string()).is_zero_decimal());
+}
+
+#[test]
+fn three_decimal_currencies_have_three_minor_digits() {
+    for currency in &[Currency::BahrainDinar, Currency::JordanDinar, Currency::KuwaitDinar,
+                      Currency::OmanRial, Currency::TunisiaDinar] {
+        assert_eq!(currency.exponent(), 3, "{}", currency);
+        assert!(!currency.is_zero_decimal());
+    }
+
+    let kwd = |amount| Amount::new(amount, Currency::KuwaitDinar);
+    assert_eq!(Amount::parse("1.234", Currency::KuwaitDinar), Ok(kwd(1234)));
+    assert_eq!(Amount::parse("1.5", Currency::KuwaitDinar), Ok(kwd(1500)));
+    assert_eq!(Amount::parse("-0.005", Currency::KuwaitDinar), Ok(kwd(-5)));
+    assert!(Amount::parse("1.2345", Currency::KuwaitDinar).is_err());
+    assert_eq!(kwd(1234).to_decimal(), "1.234");
+    assert_eq!(kwd(-5).to_decimal(), "-0.005");
+    assert_eq!(kwd(1500).to_string(), "1.500 kwd");
+}
+
+#[test]
+fn amounts_are_parsed_and_formatted_in_major_units() {
+    assert_eq!(Amount::parse("12.50", Currency::UnitedStatesDollar), Ok(usd(1250)));
+    assert_eq!(Amount::parse("12.5", Currency::UnitedStatesDollar), Ok(usd(1250)));
+    assert_eq!(Amount::parse("12", Currency::UnitedStatesDollar), Ok(usd(1200)));
+    assert_eq!(Amount::parse("-0.05", Currency::UnitedStatesDollar), Ok(usd(-5)));
+    assert_eq!(Amount::parse("1250", Currency::JapanYen), Ok(Amount::new(1250, Currency::JapanYen)));
+    assert_eq!(Amount::parse("1250.00", Currency::JapanYen), Ok(Amount::new(1250, Currency::JapanYen)));
+
+    for invalid in &["12.505", "12.", ".50", "", "-", "1,250", "12.5x"] {
+        assert!(Amount::parse(invalid, Currency::UnitedStatesDollar).is_err(), "{:?} parsed", invalid);
+    }
+    assert!(Amount::parse("12.5", Currency::JapanYen).is_err());
+    assert_eq!(Amount::parse("99999999999999999999", Currency::JapanYen), Err(AmountError::Overflow));
+
+    assert_eq!(usd(1250).to_decimal(), "12.50");
+    assert_eq!(usd(-5).to_decimal(), "-0.05");
+    assert_eq!(Amount::new(1250, Currency::JapanYen).to_string(), "1250 jpy");
+    assert_eq!(usd(1250).to_string(), "12.50 usd");
+}
+
+#[test]
+fn arithmetic_is_checked_and_stays_in_one_currency() {
+    assert_eq!(usd(1250).checked_add(&usd(50)), Ok(usd(1300)));
+    assert_eq!(usd(1250).checked_sub(&usd(1300)), Ok(usd(-50)));
+    assert_eq!(usd(1250).checked_mul(3), Ok(usd(3750)));
+    assert_eq!(usd(std::i64::MAX).checked_add(&usd(1)), Err(AmountError::Overflow));
+    assert_eq!(
+        usd(1250).checked_add(&Amount::new(1250, Currency::JapanYen)),
+        Err(AmountError::CurrencyMismatch {
+            expected: Currency::UnitedStatesDollar,
+            actual: Currency::JapanYen
+        })
+    );
+}
+
+#[test]
+fn builders_send_amounts_in_minor_units() {
+    let client = StripeClient::new("sk_test_amount");
+    let yen = Amount::parse("1250", Currency::JapanYen).unwrap();
+    let has = |params: &[(String, String)], key: &str, value: &str| {
+        params.contains(&(key.to_string(), value.to_string()))
+    };
+
+    let charge = client.create_charge_for(yen.clone()).prepare().unwrap();
+    assert!(has(&charge.params, "amount", "1250") && has(&charge.params, "currency", "jpy"));
+
+    let plan = client.create_plan_for("gold".parse().unwrap(), usd(999), Interval::Month, "Gold".to_string())
+        .prepare()
+        .unwrap();
+    assert!(has(&plan.params, "amount", "999") && has(&plan.params, "currency", "usd"));
+
+    let inventory = Inventory { quantity: None, inventory_type: InventoryType::Infinite, value: None };
+    let sku = client.create_sku_for(yen.clone(), inventory, "prod_1".parse().unwrap())
+        .prepare()
+        .unwrap();
+    assert!(has(&sku.params, "price", "1250") && has(&sku.params, "currency", "jpy"));
+    let sku = client.update_sku("sku_1".parse().unwrap()).price_for(usd(500)).prepare().unwrap();
+    assert!(has(&sku.params, "price", "500") && has(&sku.params, "currency", "usd"));
+
+    let coupon = client.create_coupon(CouponDuration::Once).amount_off_for(usd(500)).prepare().unwrap();
+    assert!(has(&coupon.params, "amount_off", "500") && has(&coupon.params, "currency", "usd"));
+
+    let order = client.create_order(Currency::JapanYen)
+        .items(vec![OrderItem::default().amount_for(yen)])
+        .prepare()
+        .unwrap();
+    assert!(has(&order.params, "items[][amount]", "1250") && has(&order.params, "items[][currency]", "jpy"));
+
+    let refund = client.create_refund("ch_1".parse().unwrap())
+        .amount_for(usd(500), &Currency::UnitedStatesDollar)
+        .unwrap()
+        .prepare()
+        .unwrap();
+    assert!(has(&refund.params, "amount", "500"));
+    assert!(!refund.params.iter().any(|&(ref key, _)| key == "currency"));
+}
+
+#[test]
+fn refunds_in_another_currency_than_the_charge_are_refused() {
+    let client = StripeClient::new("sk_test_amount");
+    let err = client.create_refund("ch_1".parse().unwrap())
+        .amount_for(Amount::new(500, Currency::JapanYen), &Currency::UnitedStatesDollar)
+        .unwrap_err();
+    assert_eq!(err, AmountError::CurrencyMismatch {
+        expected: Currency::UnitedStatesDollar,
+        actual: Currency::JapanYen
+    });
+}
@@ -0,0 +1,86 @@
+extern crate serde_json;
+extern crate stripe;
+
+use stripe::errors::bank_account_validation_error::BankAccountValidationError;
+use stripe::model::{Country, CountrySpec, Currency, NewBankAccount};
+
+fn account(account_number: &str, country: &str, currency: Currency) -> NewBankAccount {
+    NewBankAccount::new(account_number.to_string(), country.parse().unwrap(), currency)
+}
+
+fn us(routing_number: &str) -> NewBankAccount {
+    account("000123456789", "US", Currency::UnitedStatesDollar).routing_number(routing_number.to_string())
+}
+
+#[test]
+fn us_routing_numbers_pass_the_aba_checksum() {
+    assert_eq!(us("110000000").validate(), Ok(()));
+    assert_eq!(us("021000021").validate(), Ok(()));
+    assert_eq!(us("110000001").validate(), Err(BankAccountValidationError::IncorrectRoutingNumber));
+    assert_eq!(us("11000000").validate(), Err(BankAccountValidationError::InvalidRoutingNumber));
+    assert_eq!(
+        account("000123456789", "US", Currency::UnitedStatesDollar).validate(),
+        Err(BankAccountValidationError::MissingRoutingNumber)
+    );
+    assert_eq!(
+        account("123", "US", Currency::UnitedStatesDollar).routing_number("110000000".to_string()).validate(),
+        Err(BankAccountValidationError::InvalidAccountNumber)
+    );
+}
+
+#[test]
+fn gb_au_and_ca_numbers_have_their_country_formats() {
+    let gb = account("00012345", "GB", Currency::UnitedKingdomPound);
+    assert_eq!(gb.clone().routing_number("10-88-00".to_string()).validate(), Ok(()));
+    assert_eq!(gb.routing_number("10880".to_string()).validate(), Err(BankAccountValidationError::InvalidRoutingNumber));
+    assert_eq!(account("GB82WEST12345698765432", "GB", Currency::UnitedKingdomPound).validate(), Ok(()));
+
+    let au = account("000123456", "AU", Currency::AustraliaDollar);
+    assert_eq!(au.routing_number("110-000".to_string()).validate(), Ok(()));
+
+    let ca = account("000123456789", "CA", Currency::CanadaDollar);
+    assert_eq!(ca.clone().routing_number("11000-000".to_string()).validate(), Ok(()));
+    assert_eq!(ca.validate(), Err(BankAccountValidationError::MissingRoutingNumber));
+}
+
+#[test]
+fn sepa_accounts_are_ibans() {
+    assert_eq!(account("DE89 3704 0044 0532 0130 00", "DE", Currency::EuroMemberCountries).validate(), Ok(()));
+    assert_eq!(account("FR1420041010050500013M02606", "fr", Currency::EuroMemberCountries).validate(), Ok(()));
+    assert_eq!(
+        account("DE89370400440532013001", "DE", Currency::EuroMemberCountries).validate(),
+        Err(BankAccountValidationError::IncorrectAccountNumber)
+    );
+    assert_eq!(
+        account("DE8937040044053201300", "DE", Currency::EuroMemberCountries).validate(),
+        Err(BankAccountValidationError::InvalidAccountNumber)
+    );
+    assert_eq!(
+        account("0532013000", "DE", Currency::EuroMemberCountries).validate(),
+        Err(BankAccountValidationError::InvalidAccountNumber)
+    );
+}
+
+#[test]
+fn ibans_must_be_from_the_accounts_country() {
+    assert_eq!(
+        account("DE89370400440532013000", "FR", Currency::EuroMemberCountries).validate(),
+        Err(BankAccountValidationError::IbanCountryMismatch)
+    );
+    assert_eq!(
+        account("GB82WEST12345698765432", "IE", Currency::EuroMemberCountries).validate(),
+        Err(BankAccountValidationError::IbanCountryMismatch)
+    );
+}
+
+#[test]
+fn currencies_are_checked_against_the_country_spec() {
+    let spec: CountrySpec = serde_json::from_str(include_str!("fixtures/country_spec.json")).unwrap();
+    assert_eq!(us("110000000").validate_for(&spec), Ok(()));
+
+    let cad = account("000123456789", "US", Currency::CanadaDollar).routing_number("110000000".to_string());
+    assert_eq!(cad.validate_for(&spec), Err(BankAccountValidationError::UnsupportedCurrency {
+        country: Country::UnitedStates,
+        currency: Currency::CanadaDollar
+    }));
+}
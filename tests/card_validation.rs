@@ -0,0 +1,82 @@
+extern crate stripe;
+
+use stripe::errors::card_validation_error::CardValidationError;
+use stripe::errors::stripe_error::StripeErrorCode;
+use stripe::model::{CardBrand, NewCard, Timestamp};
+
+/// 2016-05-01T00:00:00Z
+fn may_2016() -> Timestamp {
+    Timestamp::from_secs(1462060800)
+}
+
+fn validate(card: NewCard) -> Result<CardBrand, CardValidationError> {
+    card.validate_at(may_2016())
+}
+
+#[test]
+fn brands_are_detected_from_test_card_numbers() {
+    let cards = [
+        ("4242424242424242", CardBrand::Visa),
+        ("4242 4242 4242 4242", CardBrand::Visa),
+        ("378282246310005", CardBrand::AmericanExpress),
+        ("5555555555554444", CardBrand::MasterCard),
+        ("2223003122003222", CardBrand::MasterCard),
+        ("6011111111111117", CardBrand::Discover),
+        ("3566002020360505", CardBrand::Jcb),
+        ("30569309025904", CardBrand::DinersClub),
+        ("6200000000000005", CardBrand::Unknown("Unknown".to_string()))
+    ];
+    for &(number, ref brand) in &cards {
+        assert_eq!(validate(NewCard::new(number.to_string(), 12, 2020)).as_ref(), Ok(brand), "{}", number);
+    }
+}
+
+#[test]
+fn bad_numbers_are_rejected() {
+    let invalid = |number: &str| validate(NewCard::new(number.to_string(), 12, 2020));
+    assert_eq!(invalid("4242424242424241"), Err(CardValidationError::IncorrectNumber));
+    assert_eq!(invalid("4242-4242-4242-4242"), Err(CardValidationError::InvalidNumber));
+    assert_eq!(invalid("42424242424242"), Err(CardValidationError::InvalidNumber));
+    assert_eq!(invalid("3782822463100050"), Err(CardValidationError::InvalidNumber));
+    assert_eq!(invalid(""), Err(CardValidationError::InvalidNumber));
+}
+
+#[test]
+fn expiry_is_checked_against_the_current_month() {
+    let card = |exp_month, exp_year| validate(NewCard::new("4242424242424242".to_string(), exp_month, exp_year));
+    assert!(card(5, 2016).is_ok());
+    assert!(card(5, 16).is_ok());
+    assert_eq!(card(4, 2016), Err(CardValidationError::Expired));
+    assert_eq!(card(12, 15), Err(CardValidationError::Expired));
+    assert_eq!(card(13, 2020), Err(CardValidationError::InvalidExpiryMonth));
+    assert_eq!(card(0, 2020), Err(CardValidationError::InvalidExpiryMonth));
+    assert_eq!(card(12, -1), Err(CardValidationError::InvalidExpiryYear));
+}
+
+#[test]
+fn expiry_is_checked_against_the_utc_month() {
+    assert_eq!(Timestamp::from_secs(1462060800).year_month(), (2016, 5));
+    assert_eq!(Timestamp::from_secs(1462060799).year_month(), (2016, 4));
+    assert_eq!(Timestamp::from_secs(-1).year_month(), (1969, 12));
+}
+
+#[test]
+fn cvc_length_depends_on_the_brand() {
+    let visa = NewCard::new("4242424242424242".to_string(), 12, 2020);
+    let amex = NewCard::new("378282246310005".to_string(), 12, 2020);
+    assert!(validate(visa.clone().cvc("123".to_string())).is_ok());
+    assert!(validate(visa.clone().cvc("012".to_string())).is_ok());
+    assert_eq!(validate(visa.clone().cvc("12".to_string())), Err(CardValidationError::InvalidCvc));
+    assert_eq!(validate(visa.cvc("1234".to_string())), Err(CardValidationError::InvalidCvc));
+    assert!(validate(amex.clone().cvc("1234".to_string())).is_ok());
+    assert_eq!(validate(amex.clone().cvc("123".to_string())), Err(CardValidationError::InvalidCvc));
+    assert_eq!(validate(amex.cvc("12a4".to_string())), Err(CardValidationError::InvalidCvc));
+}
+
+#[test]
+fn errors_map_to_stripe_error_codes() {
+    assert_eq!(CardValidationError::IncorrectNumber.code(), StripeErrorCode::IncorrectNumber);
+    assert_eq!(CardValidationError::Expired.code(), StripeErrorCode::InvalidExpiryYear);
+    assert_eq!(CardValidationError::Expired.param(), "exp_year");
+    assert_eq!(CardValidationError::InvalidCvc.code(), StripeErrorCode::InvalidCvC);
+}
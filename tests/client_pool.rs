@@ -0,0 +1,116 @@
+#![cfg(feature = "testing")]
+
+extern crate hyper;
+extern crate stripe;
+
+use hyper::method::Method;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use stripe::api::ApiCall;
+use stripe::errors::error::Error;
+use stripe::errors::rate_limit_error::RateLimitError;
+use stripe::key_provider::StaticKey;
+use stripe::middleware::{Middleware, Request};
+use stripe::testing::MockStripe;
+use stripe::{SecretKey, StripeClientPool, TenantConfig};
+
+type Labels = Arc<Mutex<Vec<Vec<(String, String)>>>>;
+
+/// Records the labels of every request a tenant sends.
+struct RecordLabels(Labels);
+
+impl Middleware for RecordLabels {
+    fn before_send(&self, request: &mut Request) {
+        self.0.lock().unwrap().push(request.labels.clone());
+    }
+}
+
+fn config() -> TenantConfig {
+    TenantConfig::new(StaticKey::new(SecretKey::new("sk_test_pool")))
+}
+
+fn pool(created: Arc<AtomicUsize>) -> StripeClientPool {
+    labelled_pool(created, Arc::new(Mutex::new(Vec::new())))
+}
+
+fn labelled_pool(created: Arc<AtomicUsize>, labels: Labels) -> StripeClientPool {
+    let stripe = MockStripe::new();
+    stripe.respond_with_json(Method::Get, "/balance", include_str!("fixtures/balance.json"));
+    StripeClientPool::new(move |tenant| {
+        created.fetch_add(1, Ordering::SeqCst);
+        match tenant {
+            "missing" => Err(Error::IoError(std::io::Error::new(std::io::ErrorKind::NotFound, "no key"))),
+            "panics"  => panic!("configure panicked"),
+            "no_rate" => Ok(config().rate_limit(0, Duration::from_secs(1))),
+            _         => Ok(config().label("plan", "gold").middleware(RecordLabels(labels.clone())))
+        }
+    }).transport(stripe.transport())
+}
+
+#[test]
+fn clients_are_created_once_per_tenant() {
+    let created = Arc::new(AtomicUsize::new(0));
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let pool = labelled_pool(created.clone(), requests.clone());
+
+    pool.get("acme").unwrap().retrieve_balance().call().unwrap();
+    pool.get("acme").unwrap();
+    pool.get("globex").unwrap();
+    assert_eq!(created.load(Ordering::SeqCst), 2);
+    assert_eq!(pool.len(), 2);
+    assert_eq!(requests.lock().unwrap()[0], vec![
+        ("tenant".to_string(), "acme".to_string()),
+        ("plan".to_string(), "gold".to_string())
+    ]);
+
+    pool.remove("acme");
+    pool.get("acme").unwrap();
+    assert_eq!(created.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn idle_tenants_are_evicted_and_recreated() {
+    let created = Arc::new(AtomicUsize::new(0));
+    let pool = pool(created.clone()).idle_timeout(Duration::from_millis(50));
+    pool.get("acme").unwrap();
+    assert_eq!(pool.evict_idle(), 0);
+
+    thread::sleep(Duration::from_millis(100));
+    assert_eq!(pool.evict_idle(), 1);
+    assert_eq!(pool.len(), 0);
+
+    pool.get("acme").unwrap();
+    assert_eq!(created.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn failed_configuration_is_not_cached() {
+    let created = Arc::new(AtomicUsize::new(0));
+    let pool = pool(created.clone());
+    assert!(pool.get("missing").is_err());
+    assert!(pool.get("missing").is_err());
+    assert_eq!(created.load(Ordering::SeqCst), 2);
+    assert_eq!(pool.len(), 0);
+}
+
+#[test]
+fn a_panicking_tenant_leaves_the_pool_usable() {
+    let pool = pool(Arc::new(AtomicUsize::new(0)));
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| pool.get("panics")));
+    assert!(result.is_err());
+    assert!(pool.get("acme").is_ok());
+    assert_eq!(pool.len(), 1);
+}
+
+#[test]
+fn a_zero_rate_limit_is_an_error_not_a_panic() {
+    let pool = pool(Arc::new(AtomicUsize::new(0)));
+    match pool.get("no_rate") {
+        Err(Error::RateLimitError(err)) => assert_eq!(err, RateLimitError::NoRequests),
+        other                           => panic!("expected a rate limit error, got {:?}", other)
+    }
+    assert_eq!(pool.len(), 0);
+}
@@ -0,0 +1,110 @@
+extern crate serde_json;
+extern crate stripe;
+
+use stripe::errors::address_validation_error::AddressValidationError;
+use stripe::model::{Account, Address, Country};
+
+fn address(country: Country, state: Option<&str>, postal_code: Option<&str>) -> Address {
+    Address {
+        line1: Some("1234 Main Street".to_string()),
+        line2: None,
+        city: Some("Springfield".to_string()),
+        country: Some(country),
+        postal_code: postal_code.map(|s| s.to_string()),
+        state: state.map(|s| s.to_string())
+    }
+}
+
+#[test]
+fn countries_parse_and_serialize_as_alpha_2_codes() {
+    assert_eq!("us".parse::<Country>(), Ok(Country::UnitedStates));
+    assert_eq!("GB".parse::<Country>(), Ok(Country::UnitedKingdom));
+    assert_eq!("XX".parse::<Country>().unwrap_err().code, "XX");
+    assert_eq!(Country::CoteDIvoire.name(), Some("Côte d'Ivoire"));
+    assert_eq!(Country::BosniaAndHerzegovina.name(), Some("Bosnia and Herzegovina"));
+
+    assert_eq!(serde_json::to_string(&Country::Japan).unwrap(), "\"JP\"");
+    match serde_json::from_str::<Country>("\"XK\"").unwrap() {
+        Country::Unknown(ref code) => assert_eq!(code, "XK"),
+        country                    => panic!("expected an unknown country, got {:?}", country)
+    }
+
+    let account: Account = serde_json::from_str(include_str!("fixtures/account_managed_new.json")).unwrap();
+    assert_eq!(account.country, Country::UnitedStates);
+}
+
+#[test]
+fn us_addresses_need_a_known_state_and_zip() {
+    assert_eq!(address(Country::UnitedStates, Some("CA"), Some("94111")).validate(), Ok(()));
+    assert_eq!(address(Country::UnitedStates, Some("ny"), Some("10001-1234")).validate(), Ok(()));
+    assert_eq!(address(Country::UnitedStates, Some("California"), Some("94111")).validate(), Ok(()));
+    assert_eq!(address(Country::UnitedStates, Some("new york"), None).validate(), Ok(()));
+    assert_eq!(
+        address(Country::UnitedStates, Some("Calif"), Some("94111")).validate(),
+        Err(AddressValidationError::Invalid { field: "state" })
+    );
+    assert_eq!(
+        address(Country::UnitedStates, Some("CA"), Some("9411")).validate(),
+        Err(AddressValidationError::Invalid { field: "postal_code" })
+    );
+
+    let us = Country::UnitedStates;
+    assert_eq!(
+        address(Country::UnitedStates, None, Some("94111")).validate_complete_in(&us),
+        Err(AddressValidationError::Missing { field: "state" })
+    );
+    assert_eq!(
+        address(Country::UnitedStates, Some("CA"), Some(" ")).validate_complete_in(&us),
+        Err(AddressValidationError::Missing { field: "postal_code" })
+    );
+}
+
+#[test]
+fn states_are_found_by_code_or_name() {
+    assert_eq!(Country::UnitedStates.state_code("California"), Some("CA"));
+    assert_eq!(Country::Canada.state_code("qc"), Some("QC"));
+    assert_eq!(Country::Australia.state_code("new south wales"), Some("NSW"));
+    assert_eq!(Country::UnitedStates.state_code("Ontario"), None);
+    assert_eq!(Country::Japan.state_code("Tokyo"), None);
+}
+
+#[test]
+fn postal_codes_have_their_country_shape() {
+    assert_eq!(address(Country::UnitedKingdom, None, Some("SW1A 1AA")).validate(), Ok(()));
+    assert_eq!(address(Country::UnitedKingdom, None, Some("ec1a1bb")).validate(), Ok(()));
+    assert_eq!(address(Country::Canada, Some("ON"), Some("K1A 0B1")).validate(), Ok(()));
+    assert_eq!(address(Country::Netherlands, None, Some("1012 AB")).validate(), Ok(()));
+    assert_eq!(
+        address(Country::Germany, None, Some("1011")).validate(),
+        Err(AddressValidationError::Invalid { field: "postal_code" })
+    );
+    assert_eq!(address(Country::HongKong, None, None).validate(), Ok(()));
+    assert_eq!(address(Country::Japan, Some("Tokyo"), Some("100-0001")).validate(), Ok(()));
+}
+
+#[test]
+fn addresses_default_to_the_callers_country() {
+    let mut unknown = address(Country::Unknown("XK".to_string()), None, None);
+    assert_eq!(unknown.validate(), Err(AddressValidationError::Invalid { field: "country" }));
+    unknown.country = None;
+    assert_eq!(unknown.validate(), Ok(()));
+    assert_eq!(unknown.validate_complete_in(&Country::HongKong), Ok(()));
+
+    let mut partial = address(Country::UnitedStates, None, Some("9411"));
+    partial.country = None;
+    partial.line1 = None;
+    assert_eq!(partial.validate(), Ok(()));
+    assert_eq!(
+        partial.validate_in(&Country::UnitedStates),
+        Err(AddressValidationError::Invalid { field: "postal_code" })
+    );
+    assert_eq!(partial.validate_in(&Country::HongKong), Ok(()));
+
+    let mut no_street = address(Country::HongKong, None, None);
+    no_street.line1 = None;
+    assert_eq!(no_street.validate(), Ok(()));
+    assert_eq!(
+        no_street.validate_complete_in(&Country::UnitedStates),
+        Err(AddressValidationError::Missing { field: "line1" })
+    );
+}
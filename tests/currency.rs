@@ -0,0 +1,60 @@
+extern crate serde_json;
+extern crate stripe;
+
+use std::collections::HashSet;
+use stripe::model::Currency;
+
+#[test]
+fn codes_parse_in_any_case() {
+    assert_eq!("usd".parse::<Currency>(), Ok(Currency::UnitedStatesDollar));
+    assert_eq!("JPY".parse::<Currency>(), Ok(Currency::JapanYen));
+    assert_eq!("xyz".parse::<Currency>().unwrap_err().code, "xyz");
+    assert_eq!(Currency::from_code("Eur"), Some(Currency::EuroMemberCountries));
+}
+
+#[test]
+fn currencies_compare_and_hash_by_code() {
+    assert_eq!(Currency::Unknown("usd".to_string()), Currency::UnitedStatesDollar);
+    assert!(Currency::AustraliaDollar < Currency::UnitedStatesDollar);
+    assert!(Currency::CfpFranc > Currency::EastCaribbeanDollar);
+
+    let mut currencies = HashSet::new();
+    currencies.insert(Currency::UnitedStatesDollar);
+    currencies.insert(Currency::UnitedStatesDollar);
+    currencies.insert(Currency::CanadaDollar);
+    assert_eq!(currencies.len(), 2);
+}
+
+#[test]
+fn currencies_know_their_iso_metadata() {
+    let gbp = Currency::UnitedKingdomPound;
+    assert_eq!(gbp.numeric_code(), Some(826));
+    assert_eq!(gbp.name(), Some("Pound Sterling"));
+    assert_eq!(gbp.symbol(), Some("£"));
+    assert_eq!(Currency::AlbaniaLek.numeric_code(), Some(8));
+    assert_eq!(Currency::GuernseyPound.numeric_code(), None);
+    assert_eq!(Currency::Unknown("xyz".to_string()).name(), None);
+}
+
+#[test]
+fn obsolete_currencies_are_flagged_and_not_chargeable() {
+    for currency in &[Currency::SeborgaLuigino, Currency::ZimbabweDollar] {
+        assert!(currency.is_obsolete());
+        assert!(!currency.is_chargeable());
+    }
+    assert!(!Currency::UnitedStatesDollar.is_obsolete());
+    assert!(Currency::JapanYen.is_chargeable());
+    assert!(Currency::JapanYen.is_payout_currency());
+    assert!(Currency::KenyaShilling.is_chargeable());
+    assert!(!Currency::KenyaShilling.is_payout_currency());
+}
+
+#[test]
+fn currencies_serialize_as_their_code() {
+    assert_eq!(serde_json::to_string(&Currency::JapanYen).unwrap(), "\"jpy\"");
+    assert_eq!(serde_json::from_str::<Currency>("\"jpy\"").unwrap(), Currency::JapanYen);
+    match serde_json::from_str::<Currency>("\"xyz\"").unwrap() {
+        Currency::Unknown(ref code) => assert_eq!(code, "xyz"),
+        currency                    => panic!("expected an unknown currency, got {:?}", currency)
+    }
+}
@@ -0,0 +1,2094 @@
+//! Builds every `*Call` through `StripeClient` with every setter, and checks
+//! the method, path and params it sends against the Stripe API reference for
+//! the pinned API version.
+//!
+//! Each table row lists every param the call can send. When moving to a new
+//! Stripe API version, run `cargo test --test endpoints -- --ignored` to print
+//! the tables as `METHOD path params`, and diff that against the new
+//! version's reference to find added or renamed params.
+
+#![cfg(feature = "testing")]
+
+extern crate stripe;
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::str::FromStr;
+use stripe::api::ApiCall;
+use stripe::model::*;
+use stripe::testing::MockStripe;
+use stripe::{StripeClient, TimeConstraint};
+
+const BASE_URL: &'static str = "https://api.stripe.com/v1";
+
+struct Endpoint {
+    call_type: &'static str,
+    method: &'static str,
+    path: &'static str,
+    params: Vec<(String, String)>,
+    send: Box<Fn(&StripeClient)>
+}
+
+fn endpoint<F>(
+    call_type: &'static str,
+    method: &'static str,
+    path: &'static str,
+    params: &[(&str, &str)],
+    send: F
+) -> Endpoint
+    where F: Fn(&StripeClient) + 'static
+{
+    Endpoint {
+        call_type: call_type,
+        method: method,
+        path: path,
+        params: params.iter().map(|&(k, v)| (k.to_string(), v.to_string())).collect(),
+        send: Box::new(send)
+    }
+}
+
+/// Sends every endpoint in `table` through a `MockStripe`, and fails with
+/// every mismatch found rather than just the first.
+fn check(table: Vec<Endpoint>) {
+    let stripe = MockStripe::new();
+    let mut mismatches = Vec::new();
+
+    for endpoint in &table {
+        stripe.clear_calls();
+        (endpoint.send)(&stripe);
+        let calls = stripe.calls();
+        if calls.len() != 1 {
+            mismatches.push(format!("{}: sent {} requests", endpoint.call_type, calls.len()));
+            continue;
+        }
+        let call = &calls[0];
+
+        let path = call.url.trim_left_matches(BASE_URL);
+        let mut params = call.params.clone();
+        params.sort();
+        let mut expected = endpoint.params.clone();
+        expected.sort();
+
+        if call.call_type != endpoint.call_type {
+            mismatches.push(format!("{}: call type was {}", endpoint.call_type, call.call_type));
+        }
+        if call.method.to_string() != endpoint.method || path != endpoint.path {
+            mismatches.push(format!(
+                "{}: expected {} {}, sent {} {}",
+                endpoint.call_type, endpoint.method, endpoint.path, call.method, path
+            ));
+        }
+        if params != expected {
+            mismatches.push(format!(
+                "{} {} {}:\n    expected {:?}\n    sent     {:?}",
+                endpoint.call_type, endpoint.method, endpoint.path, expected, params
+            ));
+        }
+    }
+
+    if !mismatches.is_empty() {
+        panic!("{} mismatches:\n{}", mismatches.len(), mismatches.join("\n"));
+    }
+}
+
+fn s(s: &str) -> String {
+    s.to_string()
+}
+
+fn id<T: FromStr>(id: &str) -> T where T::Err: Debug {
+    id.parse().unwrap()
+}
+
+fn metadata() -> BTreeMap<String, String> {
+    let mut metadata = BTreeMap::new();
+    metadata.insert(s("order_id"), s("6735"));
+    metadata
+}
+
+fn between() -> TimeConstraint {
+    TimeConstraint::new().greater_than(1462000000).less_than_or_equal(1462086400)
+}
+
+fn card() -> NewCard {
+    NewCard::new(s("4242424242424242"), 8, 2020).cvc(s("123"))
+}
+
+fn bank_account() -> NewBankAccount {
+    NewBankAccount::new(s("000123456789"), Country::UnitedStates, Currency::UnitedStatesDollar)
+        .routing_number(s("110000000"))
+        .account_holder_name(s("Jane Austen"))
+        .account_holder_type(AccountHolderType::Individual)
+}
+
+fn shipping() -> Shipping {
+    Shipping {
+        address: Address {
+            line1: Some(s("1234 Main Street")),
+            line2: None,
+            city: Some(s("San Francisco")),
+            country: Some(Country::UnitedStates),
+            postal_code: Some(s("94111")),
+            state: None
+        },
+        carrier: None,
+        name: s("Jenny Rosen"),
+        phone: None,
+        tracking_number: None
+    }
+}
+
+fn dimensions() -> Dimensions {
+    Dimensions {
+        height: 1.5,
+        length: 2.0,
+        weight: 3.25,
+        width: 4.0
+    }
+}
+
+fn tos_acceptance() -> TosAcceptance {
+    TosAcceptance::new(1462000000i64, "8.8.8.8".parse().unwrap())
+}
+
+fn transfer_schedule() -> TransferSchedule {
+    TransferSchedule::weekly(Weekday::Friday).delay_days(7)
+}
+
+fn address() -> Address {
+    Address {
+        line1: Some(s("1234 Main Street")),
+        line2: None,
+        city: Some(s("San Francisco")),
+        country: Some(Country::UnitedStates),
+        postal_code: Some(s("94111")),
+        state: Some(s("CA"))
+    }
+}
+
+fn individual() -> NewLegalEntity {
+    NewLegalEntity::individual()
+        .address(address())
+        .dob(DateOfBirth::new(1, 2, 1980))
+        .first_name(s("Jenny"))
+        .gender(Gender::Female)
+        .last_name(s("Rosen"))
+        .maiden_name(s("Smith"))
+        .personal_id_number(s("000000000"))
+        .phone_number(s("5555550100"))
+        .ssn_last_4(s("0000"))
+        .verification(s("file_1"))
+}
+
+fn company() -> NewLegalEntity {
+    NewLegalEntity::company()
+        .additional_owners(vec![
+            NewOwner::new()
+                .address(address())
+                .dob(DateOfBirth::new(1, 2, 1980))
+                .first_name(s("Jenny"))
+                .last_name(s("Rosen"))
+                .verification(s("file_2"))
+        ])
+        .address(address())
+        .business_name(s("Widgets Inc"))
+        .business_tax_id(s("000000000"))
+        .business_vat_id(s("GB000000000"))
+        .personal_address(address())
+}
+
+fn balance() -> Vec<Endpoint> {
+    vec![
+        endpoint("RetrieveBalanceCall", "GET", "/balance", &[], |c| {
+            let _ = c.retrieve_balance().call();
+        }),
+        endpoint("RetrieveBalanceTransactionCall", "GET", "/balance/history/txn_1", &[], |c| {
+            let _ = c.retrieve_balance_transaction(id("txn_1")).call();
+        }),
+        endpoint("ListBalanceHistoryCall", "GET", "/balance/history", &[
+            ("include[]", "total_count"),
+            ("available_on[gt]", "1462000000"),
+            ("available_on[lte]", "1462086400"),
+            ("created[gt]", "1462000000"),
+            ("created[lte]", "1462086400"),
+            ("currency", "usd"),
+            ("ending_before", "txn_2"),
+            ("limit", "10"),
+            ("source", "ch_1"),
+            ("starting_after", "txn_1"),
+            ("transfer", "tr_1"),
+            ("type", "charge")
+        ], |c| {
+            let _ = c.list_balance_history()
+                .available_on(between())
+                .created(between())
+                .currency(Currency::UnitedStatesDollar)
+                .ending_before(s("txn_2"))
+                .limit(10)
+                .source(s("ch_1"))
+                .starting_after(s("txn_1"))
+                .transfer(id("tr_1"))
+                .transaction_type(TransactionType::Charge)
+                .call();
+        })
+    ]
+}
+
+fn charges() -> Vec<Endpoint> {
+    vec![
+        endpoint("CreateChargeCall", "POST", "/charges", &[
+            ("amount", "1000"),
+            ("currency", "usd"),
+            ("application_fee", "100"),
+            ("capture", "false"),
+            ("customer", "cus_1"),
+            ("description", "Widgets"),
+            ("destination", "acct_1"),
+            ("expand[]", "customer"),
+            ("metadata[order_id]", "6735"),
+            ("receipt_email", "jenny@example.com"),
+            ("shipping[address][line1]", "1234 Main Street"),
+            ("shipping[address][city]", "San Francisco"),
+            ("shipping[address][country]", "US"),
+            ("shipping[address][postal_code]", "94111"),
+            ("shipping[name]", "Jenny Rosen"),
+            ("source", "card_1"),
+            ("statement_descriptor", "WIDGETS")
+        ], |c| {
+            let _ = c.create_charge(1000, Currency::UnitedStatesDollar)
+                .application_fee(100)
+                .capture(false)
+                .customer(id("cus_1"))
+                .description(s("Widgets"))
+                .destination(id("acct_1"))
+                .expand(s("customer"))
+                .idempotency_key(s("order-6735"))
+                .metadata(metadata())
+                .receipt_email(s("jenny@example.com"))
+                .shipping(shipping())
+                .source(s("card_1"))
+                .statement_descriptor(s("WIDGETS"))
+                .call();
+        }),
+        endpoint("RetrieveChargeCall", "GET", "/charges/ch_1", &[
+            ("expand[]", "customer")
+        ], |c| {
+            let _ = c.retrieve_charge(id("ch_1")).expand(s("customer")).call();
+        }),
+        endpoint("UpdateChargeCall", "POST", "/charges/ch_1", &[
+            ("description", "Widgets"),
+            ("expand[]", "customer"),
+            ("fraud_details[user_report]", "safe"),
+            ("metadata[order_id]", "6735"),
+            ("receipt_email", "jenny@example.com"),
+            ("shipping[address][line1]", "1234 Main Street"),
+            ("shipping[address][city]", "San Francisco"),
+            ("shipping[address][country]", "US"),
+            ("shipping[address][postal_code]", "94111"),
+            ("shipping[name]", "Jenny Rosen")
+        ], |c| {
+            let mut fraud_details = BTreeMap::new();
+            fraud_details.insert(s("user_report"), s("safe"));
+            let _ = c.update_charge(id("ch_1"))
+                .description(s("Widgets"))
+                .expand(s("customer"))
+                .fraud_details(fraud_details)
+                .metadata(metadata())
+                .receipt_email(s("jenny@example.com"))
+                .shipping(shipping())
+                .call();
+        }),
+        endpoint("CaptureChargeCall", "POST", "/charges/ch_1/capture", &[
+            ("amount", "500"),
+            ("application_fee", "50"),
+            ("expand[]", "customer"),
+            ("receipt_email", "jenny@example.com"),
+            ("statement_descriptor", "WIDGETS")
+        ], |c| {
+            let _ = c.capture_charge(id("ch_1"))
+                .amount(500)
+                .application_fee(50)
+                .expand(s("customer"))
+                .receipt_email(s("jenny@example.com"))
+                .statement_descriptor(s("WIDGETS"))
+                .call();
+        }),
+        endpoint("ListChargesCall", "GET", "/charges", &[
+            ("include[]", "total_count"),
+            ("created[gt]", "1462000000"),
+            ("created[lte]", "1462086400"),
+            ("customer", "cus_1"),
+            ("ending_before", "ch_2"),
+            ("expand[]", "data.customer"),
+            ("limit", "10"),
+            ("source[object]", "card"),
+            ("starting_after", "ch_1")
+        ], |c| {
+            let _ = c.list_charges()
+                .created(between())
+                .customer(id("cus_1"))
+                .ending_before(s("ch_2"))
+                .expand(s("data.customer"))
+                .limit(10)
+                .source(SourceType::Card)
+                .starting_after(s("ch_1"))
+                .call();
+        })
+    ]
+}
+
+fn customers() -> Vec<Endpoint> {
+    vec![
+        endpoint("CreateCustomerCall", "POST", "/customers", &[
+            ("account_balance", "-500"),
+            ("business_vat_id", "DE123456789"),
+            ("coupon", "25OFF"),
+            ("description", "Gold member"),
+            ("email", "jenny@example.com"),
+            ("metadata[order_id]", "6735"),
+            ("plan", "gold"),
+            ("quantity", "2"),
+            ("shipping[address][line1]", "1234 Main Street"),
+            ("shipping[address][city]", "San Francisco"),
+            ("shipping[address][country]", "US"),
+            ("shipping[address][postal_code]", "94111"),
+            ("shipping[name]", "Jenny Rosen"),
+            ("source", "tok_1"),
+            ("tax_percent", "8.25"),
+            ("trial_end", "1462086400")
+        ], |c| {
+            let _ = c.create_customer()
+                .account_balance(-500)
+                .business_vat_id(s("DE123456789"))
+                .coupon(id("25OFF"))
+                .description(s("Gold member"))
+                .email(s("jenny@example.com"))
+                .metadata(metadata())
+                .plan(id("gold"))
+                .quantity(2)
+                .shipping(shipping())
+                .source_token(id("tok_1"))
+                .tax_percent(8.25)
+                .trial_end(1462086400)
+                .call();
+        }),
+        endpoint("CreateCustomerCall", "POST", "/customers", &[
+            ("source[object]", "card"),
+            ("source[exp_month]", "08"),
+            ("source[exp_year]", "2020"),
+            ("source[number]", "4242424242424242"),
+            ("source[cvc]", "123")
+        ], |c| {
+            let _ = c.create_customer().source_card(card()).call();
+        }),
+        endpoint("RetrieveCustomerCall", "GET", "/customers/cus_1", &[], |c| {
+            let _ = c.retrieve_customer(id("cus_1")).call();
+        }),
+        endpoint("UpdateCustomerCall", "POST", "/customers/cus_1", &[
+            ("account_balance", "-500"),
+            ("business_vat_id", "DE123456789"),
+            ("coupon", "25OFF"),
+            ("default_source", "card_1"),
+            ("description", "Gold member"),
+            ("email", "jenny@example.com"),
+            ("metadata[order_id]", "6735"),
+            ("shipping[address][line1]", "1234 Main Street"),
+            ("shipping[address][city]", "San Francisco"),
+            ("shipping[address][country]", "US"),
+            ("shipping[address][postal_code]", "94111"),
+            ("shipping[name]", "Jenny Rosen"),
+            ("source", "tok_1")
+        ], |c| {
+            let _ = c.update_customer(id("cus_1"))
+                .account_balance(s("-500"))
+                .business_vat_id(s("DE123456789"))
+                .coupon(id("25OFF"))
+                .default_source(id("card_1"))
+                .description(s("Gold member"))
+                .email(s("jenny@example.com"))
+                .metadata(metadata())
+                .shipping(shipping())
+                .source_token(id("tok_1"))
+                .call();
+        }),
+        endpoint("UpdateCustomerCall", "POST", "/customers/cus_1", &[
+            ("source[object]", "card"),
+            ("source[exp_month]", "08"),
+            ("source[exp_year]", "2020"),
+            ("source[number]", "4242424242424242"),
+            ("source[cvc]", "123")
+        ], |c| {
+            let _ = c.update_customer(id("cus_1")).source_card(card()).call();
+        }),
+        endpoint("DeleteCustomerCall", "DELETE", "/customers/cus_1", &[], |c| {
+            let _ = c.delete_customer(id("cus_1")).call();
+        }),
+        endpoint("ListCustomersCall", "GET", "/customers", &[
+            ("include[]", "total_count"),
+            ("created[gt]", "1462000000"),
+            ("created[lte]", "1462086400"),
+            ("ending_before", "cus_2"),
+            ("limit", "10"),
+            ("starting_after", "cus_1")
+        ], |c| {
+            let _ = c.list_customers()
+                .created(between())
+                .ending_before(s("cus_2"))
+                .limit(10)
+                .starting_after(s("cus_1"))
+                .call();
+        }),
+        endpoint("DeleteCustomerDiscountCall", "DELETE", "/customers/cus_1/discount", &[], |c| {
+            let _ = c.delete_discount(id("cus_1")).call();
+        }),
+        endpoint(
+            "DeleteSubscriptionDiscountCall",
+            "DELETE",
+            "/customers/cus_1/subscriptions/sub_1/discount",
+            &[],
+            |c| {
+                let _ = c.delete_subscription_discount(id("cus_1"), id("sub_1")).call();
+            }
+        )
+    ]
+}
+
+fn customer_sources() -> Vec<Endpoint> {
+    vec![
+        endpoint("CustomerCreateBankAccountCall", "POST", "/customers/cus_1/sources", &[
+            ("source", "btok_1"),
+            ("default_for_currency", "true"),
+            ("metadata[order_id]", "6735")
+        ], |c| {
+            let _ = c.customer_create_bank_account(id("cus_1"))
+                .bank_account_token(id("btok_1"))
+                .default_for_currency(true)
+                .metadata(metadata())
+                .call();
+        }),
+        endpoint("CustomerCreateBankAccountCall", "POST", "/customers/cus_1/sources", &[
+            ("source[object]", "bank_account"),
+            ("source[account_number]", "000123456789"),
+            ("source[country]", "US"),
+            ("source[currency]", "usd"),
+            ("source[routing_number]", "110000000"),
+            ("source[account_holder_name]", "Jane Austen"),
+            ("source[account_holder_type]", "individual")
+        ], |c| {
+            let _ = c.customer_create_bank_account(id("cus_1")).bank_account(bank_account()).call();
+        }),
+        endpoint("CustomerRetrieveBankAccountCall", "GET", "/customers/cus_1/sources/ba_1", &[], |c| {
+            let _ = c.customer_retrieve_bank_account(id("cus_1"), id("ba_1")).call();
+        }),
+        endpoint("CustomerUpdateBankAccountCall", "POST", "/customers/cus_1/sources/ba_1", &[
+            ("account_holder_name", "Jane Austen"),
+            ("account_holder_type", "company"),
+            ("metadata[order_id]", "6735")
+        ], |c| {
+            let _ = c.customer_update_bank_account(id("cus_1"), id("ba_1"))
+                .account_holder_name(s("Jane Austen"))
+                .account_holder_type(AccountHolderType::Company)
+                .metadata(metadata())
+                .call();
+        }),
+        endpoint("CustomerDeleteBankAccountCall", "DELETE", "/customers/cus_1/sources/ba_1", &[], |c| {
+            let _ = c.customer_delete_bank_account(id("cus_1"), id("ba_1")).call();
+        }),
+        endpoint("CustomerListBankAccountsCall", "GET", "/customers/cus_1/sources", &[
+            ("include[]", "total_count"),
+            ("object", "bank_account"),
+            ("ending_before", "ba_2"),
+            ("limit", "10"),
+            ("starting_after", "ba_1")
+        ], |c| {
+            let _ = c.customer_list_bank_accounts(id("cus_1"))
+                .ending_before(s("ba_2"))
+                .limit(10)
+                .starting_after(s("ba_1"))
+                .call();
+        }),
+        endpoint("CustomerCreateCardCall", "POST", "/customers/cus_1/sources", &[
+            ("source", "tok_1"),
+            ("default_for_currency", "true"),
+            ("metadata[order_id]", "6735")
+        ], |c| {
+            let _ = c.create_card(id("cus_1"))
+                .card_token(id("tok_1"))
+                .default_for_currency(true)
+                .metadata(metadata())
+                .call();
+        }),
+        endpoint("CustomerCreateCardCall", "POST", "/customers/cus_1/sources", &[
+            ("source[object]", "card"),
+            ("source[exp_month]", "08"),
+            ("source[exp_year]", "2020"),
+            ("source[number]", "4242424242424242"),
+            ("source[cvc]", "123")
+        ], |c| {
+            let _ = c.create_card(id("cus_1")).card(card()).call();
+        }),
+        endpoint("CustomerRetrieveCardCall", "GET", "/customers/cus_1/sources/card_1", &[], |c| {
+            let _ = c.retrieve_card(id("cus_1"), id("card_1")).call();
+        }),
+        endpoint("CustomerUpdateCardCall", "POST", "/customers/cus_1/sources/card_1", &[
+            ("address_city", "San Francisco"),
+            ("address_country", "US"),
+            ("address_line1", "1234 Main Street"),
+            ("address_line2", "Apt 2"),
+            ("address_state", "CA"),
+            ("address_zip", "94111"),
+            ("default_for_currency", "true"),
+            ("exp_month", "09"),
+            ("exp_year", "2021"),
+            ("metadata[order_id]", "6735"),
+            ("name", "Jenny Rosen")
+        ], |c| {
+            let _ = c.update_card(id("cus_1"), id("card_1"))
+                .address_city(s("San Francisco"))
+                .address_country(s("US"))
+                .address_line1(s("1234 Main Street"))
+                .address_line2(s("Apt 2"))
+                .address_state(s("CA"))
+                .address_zip(s("94111"))
+                .default_for_currency(true)
+                .exp_month(s("09"))
+                .exp_year(s("2021"))
+                .metadata(metadata())
+                .name(s("Jenny Rosen"))
+                .call();
+        }),
+        endpoint("CustomerDeleteCardCall", "DELETE", "/customers/cus_1/sources/card_1", &[], |c| {
+            let _ = c.delete_card(id("cus_1"), id("card_1")).call();
+        }),
+        endpoint("CustomerListCardsCall", "GET", "/customers/cus_1/sources", &[
+            ("include[]", "total_count"),
+            ("object", "card"),
+            ("ending_before", "card_2"),
+            ("limit", "10"),
+            ("starting_after", "card_1")
+        ], |c| {
+            let _ = c.list_cards(id("cus_1"))
+                .ending_before(s("card_2"))
+                .limit(10)
+                .starting_after(s("card_1"))
+                .call();
+        })
+    ]
+}
+
+fn disputes() -> Vec<Endpoint> {
+    vec![
+        endpoint("RetrieveDisputeCall", "GET", "/disputes/dp_1", &[], |c| {
+            let _ = c.retrieve_dispute(id("dp_1")).call();
+        }),
+        endpoint("UpdateDisputeCall", "POST", "/disputes/dp_1", &[
+            ("evidence[customer_name]", "Jenny Rosen"),
+            ("metadata[order_id]", "6735")
+        ], |c| {
+            let mut evidence = BTreeMap::new();
+            evidence.insert(s("customer_name"), s("Jenny Rosen"));
+            let _ = c.update_dispute(id("dp_1")).evidence(evidence).metadata(metadata()).call();
+        }),
+        endpoint("CloseDisputeCall", "POST", "/disputes/dp_1/close", &[], |c| {
+            let _ = c.close_dispute(id("dp_1")).call();
+        }),
+        endpoint("ListDisputesCall", "GET", "/disputes", &[
+            ("include[]", "total_count"),
+            ("created[gt]", "1462000000"),
+            ("created[lte]", "1462086400"),
+            ("ending_before", "dp_2"),
+            ("limit", "10"),
+            ("starting_after", "dp_1")
+        ], |c| {
+            let _ = c.list_disputes()
+                .created(between())
+                .ending_before(s("dp_2"))
+                .limit(10)
+                .starting_after(s("dp_1"))
+                .call();
+        })
+    ]
+}
+
+fn events() -> Vec<Endpoint> {
+    vec![
+        endpoint("RetrieveEventCall", "GET", "/events/evt_1", &[], |c| {
+            let _ = c.retrieve_event(id("evt_1")).call();
+        }),
+        endpoint("ListEventCall", "GET", "/events", &[
+            ("include[]", "total_count"),
+            ("created[gt]", "1462000000"),
+            ("created[lte]", "1462086400"),
+            ("ending_before", "evt_2"),
+            ("limit", "10"),
+            ("starting_after", "evt_1"),
+            ("type", "charge.succeeded")
+        ], |c| {
+            let _ = c.list_events()
+                .created(between())
+                .ending_before(s("evt_2"))
+                .limit(10)
+                .starting_after(s("evt_1"))
+                .event_type(s("charge.succeeded"))
+                .call();
+        })
+    ]
+}
+
+fn refunds() -> Vec<Endpoint> {
+    vec![
+        endpoint("CreateRefundCall", "POST", "/refunds", &[
+            ("charge", "ch_1"),
+            ("amount", "500"),
+            ("metadata[order_id]", "6735"),
+            ("reason", "requested_by_customer"),
+            ("refund_application_fee", "true"),
+            ("reverse_transfer", "true")
+        ], |c| {
+            let _ = c.create_refund(id("ch_1"))
+                .amount(500)
+                .metadata(metadata())
+                .reason(RefundReason::RequestedByCustomer)
+                .refund_application_fee(true)
+                .reverse_transfer(true)
+                .call();
+        }),
+        endpoint("RetrieveRefundCall", "GET", "/refunds/re_1", &[], |c| {
+            let _ = c.retrieve_refund(id("re_1")).call();
+        }),
+        endpoint("UpdateRefundCall", "POST", "/refunds/re_1", &[
+            ("metadata[order_id]", "6735")
+        ], |c| {
+            let _ = c.update_refund(id("re_1")).metadata(metadata()).call();
+        }),
+        endpoint("ListRefundCall", "GET", "/refunds", &[
+            ("include[]", "total_count"),
+            ("charge", "ch_1"),
+            ("ending_before", "re_2"),
+            ("limit", "10"),
+            ("starting_after", "re_1")
+        ], |c| {
+            let _ = c.list_refunds()
+                .charge(id("ch_1"))
+                .ending_before(s("re_2"))
+                .limit(10)
+                .starting_after(s("re_1"))
+                .call();
+        })
+    ]
+}
+
+fn tokens() -> Vec<Endpoint> {
+    vec![
+        endpoint("CreateCardTokenCall", "POST", "/tokens", &[
+            ("card[object]", "card"),
+            ("card[exp_month]", "08"),
+            ("card[exp_year]", "2020"),
+            ("card[number]", "4242424242424242"),
+            ("card[cvc]", "123")
+        ], |c| {
+            let _ = c.create_card_token().card(card()).call();
+        }),
+        endpoint("CreateCardTokenCall", "POST", "/tokens", &[
+            ("customer", "cus_1"),
+            ("card", "card_1")
+        ], |c| {
+            let _ = c.create_card_token().customer(id("cus_1"), id("card_1")).call();
+        }),
+        endpoint("CreateBankAccountTokenCall", "POST", "/tokens", &[
+            ("bank_account[object]", "bank_account"),
+            ("bank_account[account_number]", "000123456789"),
+            ("bank_account[country]", "US"),
+            ("bank_account[currency]", "usd"),
+            ("bank_account[routing_number]", "110000000"),
+            ("bank_account[account_holder_name]", "Jane Austen"),
+            ("bank_account[account_holder_type]", "individual"),
+            ("customer", "cus_1")
+        ], |c| {
+            let _ = c.create_bank_account_token()
+                .bank_account(bank_account())
+                .customer(id("cus_1"))
+                .call();
+        }),
+        endpoint("CreatePiiTokenCall", "POST", "/tokens", &[
+            ("pii[personal_id_number]", "000000000")
+        ], |c| {
+            let _ = c.create_pii_token(s("000000000")).call();
+        }),
+        endpoint("RetrieveTokenCall", "GET", "/tokens/tok_1", &[], |c| {
+            let _ = c.retrieve_token(id("tok_1")).call();
+        })
+    ]
+}
+
+fn transfers() -> Vec<Endpoint> {
+    vec![
+        endpoint("CreateTransferCall", "POST", "/transfers", &[
+            ("amount", "1000"),
+            ("currency", "usd"),
+            ("destination", "acct_1"),
+            ("description", "Payout"),
+            ("metadata[order_id]", "6735"),
+            ("source_transaction", "ch_1"),
+            ("source_type", "bank_account"),
+            ("statement_descriptor", "PAYOUT")
+        ], |c| {
+            let _ = c.create_transfer(1000, Currency::UnitedStatesDollar, id("acct_1"))
+                .description(s("Payout"))
+                .metadata(metadata())
+                .source_transaction(s("ch_1"))
+                .source_type(SourceType::BankAccount)
+                .statement_descriptor(s("PAYOUT"))
+                .call();
+        }),
+        endpoint("RetrieveTransferCall", "GET", "/transfers/tr_1", &[], |c| {
+            let _ = c.retrieve_transfer(id("tr_1")).call();
+        }),
+        endpoint("UpdateTransferCall", "POST", "/transfers/tr_1", &[
+            ("description", "Payout"),
+            ("metadata[order_id]", "6735")
+        ], |c| {
+            let _ = c.update_transfer(id("tr_1"))
+                .description(s("Payout"))
+                .metadata(metadata())
+                .call();
+        }),
+        endpoint("ListTransfersCall", "GET", "/transfers", &[
+            ("created[gt]", "1462000000"),
+            ("created[lte]", "1462086400"),
+            ("date[gt]", "1462000000"),
+            ("date[lte]", "1462086400"),
+            ("destination", "acct_1"),
+            ("ending_before", "tr_2"),
+            ("limit", "10"),
+            ("recipient", "rp_1"),
+            ("starting_after", "tr_1"),
+            ("status", "paid")
+        ], |c| {
+            let _ = c.list_transfers()
+                .created(between())
+                .date(between())
+                .destination(s("acct_1"))
+                .ending_before(s("tr_2"))
+                .limit(10)
+                .recipient(id("rp_1"))
+                .starting_after(s("tr_1"))
+                .status(TransferStatus::Paid)
+                .call();
+        }),
+        endpoint("ListTransfersCall", "GET", "/transfers", &[
+            ("created", "1462000000"),
+            ("date", "1462086400")
+        ], |c| {
+            let _ = c.list_transfers()
+                .created_exact(1462000000)
+                .date_exact(1462086400)
+                .call();
+        }),
+        endpoint("CreateTransferReversalCall", "POST", "/transfers/tr_1/reversals", &[
+            ("amount", "100"),
+            ("description", "Overpaid"),
+            ("metadata[order_id]", "6735"),
+            ("refund_application_fee", "true")
+        ], |c| {
+            let _ = c.create_transfer_reversal(id("tr_1"))
+                .amount(100)
+                .description(s("Overpaid"))
+                .metadata(metadata())
+                .refund_application_fee(true)
+                .call();
+        }),
+        endpoint("RetrieveTransferReversalCall", "GET", "/transfers/tr_1/reversals/trr_1", &[], |c| {
+            let _ = c.retrieve_transfer_reversal(id("tr_1"), id("trr_1")).call();
+        }),
+        endpoint("UpdateTransferReversalCall", "POST", "/transfers/tr_1/reversals/trr_1", &[
+            ("description", "Overpaid"),
+            ("metadata[order_id]", "6735")
+        ], |c| {
+            let _ = c.update_transfer_reversal(id("tr_1"), id("trr_1"))
+                .description(s("Overpaid"))
+                .metadata(metadata())
+                .call();
+        }),
+        endpoint("ListTransferReversalsCall", "GET", "/transfers/tr_1/reversals", &[
+            ("ending_before", "trr_2"),
+            ("limit", "10"),
+            ("starting_after", "trr_1")
+        ], |c| {
+            let _ = c.list_transfer_reversals(id("tr_1"))
+                .ending_before(s("trr_2"))
+                .limit(10)
+                .starting_after(s("trr_1"))
+                .call();
+        })
+    ]
+}
+
+fn accounts() -> Vec<Endpoint> {
+    vec![
+        endpoint("RetrieveAccountCall", "GET", "/account", &[], |c| {
+            let _ = c.retrieve_current_account().call();
+        }),
+        endpoint("RetrieveAccountCall", "GET", "/accounts/acct_1", &[], |c| {
+            let _ = c.retrieve_account(id("acct_1")).call();
+        }),
+        endpoint("CreateAccountCall", "POST", "/accounts", &[
+            ("business_logo", "file_1"),
+            ("business_primary_color", "#ff0000"),
+            ("business_url", "https://example.com"),
+            ("country", "US"),
+            ("debit_negative_balances", "true"),
+            ("decline_charge_on[avs_failure]", "true"),
+            ("decline_charge_on[cvc_failure]", "false"),
+            ("default_currency", "usd"),
+            ("email", "site@example.com"),
+            ("external_account", "btok_1"),
+            ("managed", "true"),
+            ("metadata[order_id]", "6735"),
+            ("product_description", "Widgets"),
+            ("statement_descriptor", "WIDGETS"),
+            ("support_email", "support@example.com"),
+            ("support_phone", "5555550100"),
+            ("support_url", "https://example.com/support"),
+            ("tos_acceptance[date]", "1462000000"),
+            ("tos_acceptance[ip]", "8.8.8.8"),
+            ("transfer_schedule[delay_days]", "7"),
+            ("transfer_schedule[interval]", "weekly"),
+            ("transfer_schedule[weekly_anchor]", "friday")
+        ], |c| {
+            let _ = c.create_account()
+                .business_logo(s("file_1"))
+                .business_primary_color(s("#ff0000"))
+                .business_url(s("https://example.com"))
+                .country(Country::UnitedStates)
+                .debit_negative_balances(true)
+                .decline_charge_on(true, false)
+                .default_currency(Currency::UnitedStatesDollar)
+                .email(s("site@example.com"))
+                .external_account_id(s("btok_1"))
+                .managed(true)
+                .metadata(metadata())
+                .product_description(s("Widgets"))
+                .statement_descriptor(s("WIDGETS"))
+                .support_email(s("support@example.com"))
+                .support_phone(s("5555550100"))
+                .support_url(s("https://example.com/support"))
+                .tos_acceptance(tos_acceptance())
+                .transfer_schedule(transfer_schedule())
+                .unwrap()
+                .call();
+        }),
+        endpoint("CreateAccountCall", "POST", "/accounts", &[
+            ("external_account[object]", "card"),
+            ("external_account[exp_month]", "08"),
+            ("external_account[exp_year]", "2020"),
+            ("external_account[number]", "4242424242424242"),
+            ("external_account[cvc]", "123")
+        ], |c| {
+            let _ = c.create_account().external_card(card()).call();
+        }),
+        endpoint("CreateAccountCall", "POST", "/accounts", &[
+            ("external_account[object]", "bank_account"),
+            ("external_account[account_number]", "000123456789"),
+            ("external_account[country]", "US"),
+            ("external_account[currency]", "usd"),
+            ("external_account[routing_number]", "110000000"),
+            ("external_account[account_holder_name]", "Jane Austen"),
+            ("external_account[account_holder_type]", "individual")
+        ], |c| {
+            let _ = c.create_account().external_bank_account(bank_account()).call();
+        }),
+        endpoint("CreateAccountCall", "POST", "/accounts", &[
+            ("legal_entity[address][line1]", "1234 Main Street"),
+            ("legal_entity[address][city]", "San Francisco"),
+            ("legal_entity[address][country]", "US"),
+            ("legal_entity[address][postal_code]", "94111"),
+            ("legal_entity[address][state]", "CA"),
+            ("legal_entity[dob][day]", "01"),
+            ("legal_entity[dob][month]", "02"),
+            ("legal_entity[dob][year]", "1980"),
+            ("legal_entity[first_name]", "Jenny"),
+            ("legal_entity[gender]", "female"),
+            ("legal_entity[last_name]", "Rosen"),
+            ("legal_entity[maiden_name]", "Smith"),
+            ("legal_entity[personal_id_number]", "000000000"),
+            ("legal_entity[phone_number]", "5555550100"),
+            ("legal_entity[ssn_last_4]", "0000"),
+            ("legal_entity[type]", "individual"),
+            ("legal_entity[verification][document]", "file_1")
+        ], |c| {
+            let _ = c.create_account().legal_entity(individual()).call();
+        }),
+        endpoint("CreateAccountCall", "POST", "/accounts", &[
+            ("legal_entity[additional_owners][][address][line1]", "1234 Main Street"),
+            ("legal_entity[additional_owners][][address][city]", "San Francisco"),
+            ("legal_entity[additional_owners][][address][country]", "US"),
+            ("legal_entity[additional_owners][][address][postal_code]", "94111"),
+            ("legal_entity[additional_owners][][address][state]", "CA"),
+            ("legal_entity[additional_owners][][dob][day]", "01"),
+            ("legal_entity[additional_owners][][dob][month]", "02"),
+            ("legal_entity[additional_owners][][dob][year]", "1980"),
+            ("legal_entity[additional_owners][][first_name]", "Jenny"),
+            ("legal_entity[additional_owners][][last_name]", "Rosen"),
+            ("legal_entity[additional_owners][][verification][document]", "file_2"),
+            ("legal_entity[address][line1]", "1234 Main Street"),
+            ("legal_entity[address][city]", "San Francisco"),
+            ("legal_entity[address][country]", "US"),
+            ("legal_entity[address][postal_code]", "94111"),
+            ("legal_entity[address][state]", "CA"),
+            ("legal_entity[business_name]", "Widgets Inc"),
+            ("legal_entity[business_tax_id]", "000000000"),
+            ("legal_entity[business_vat_id]", "GB000000000"),
+            ("legal_entity[personal_address][line1]", "1234 Main Street"),
+            ("legal_entity[personal_address][city]", "San Francisco"),
+            ("legal_entity[personal_address][country]", "US"),
+            ("legal_entity[personal_address][postal_code]", "94111"),
+            ("legal_entity[personal_address][state]", "CA"),
+            ("legal_entity[type]", "company")
+        ], |c| {
+            let _ = c.create_account().legal_entity(company()).call();
+        }),
+        endpoint("UpdateAccountCall", "POST", "/accounts/acct_1", &[
+            ("business_logo", "file_1"),
+            ("business_primary_color", "#ff0000"),
+            ("business_url", "https://example.com"),
+            ("debit_negative_balances", "true"),
+            ("decline_charge_on[avs_failure]", "true"),
+            ("decline_charge_on[cvc_failure]", "false"),
+            ("default_currency", "usd"),
+            ("email", "site@example.com"),
+            ("external_account", "btok_1"),
+            ("metadata[order_id]", "6735"),
+            ("product_description", "Widgets"),
+            ("statement_descriptor", "WIDGETS"),
+            ("support_email", "support@example.com"),
+            ("support_phone", "5555550100"),
+            ("support_url", "https://example.com/support"),
+            ("tos_acceptance[date]", "1462000000"),
+            ("tos_acceptance[ip]", "8.8.8.8"),
+            ("transfer_schedule[delay_days]", "7"),
+            ("transfer_schedule[interval]", "weekly"),
+            ("transfer_schedule[weekly_anchor]", "friday")
+        ], |c| {
+            let _ = c.update_account(id("acct_1"))
+                .business_logo(s("file_1"))
+                .business_primary_color(s("#ff0000"))
+                .business_url(s("https://example.com"))
+                .debit_negative_balances(true)
+                .decline_charge_on(true, false)
+                .default_currency(Currency::UnitedStatesDollar)
+                .email(s("site@example.com"))
+                .external_account_id(s("btok_1"))
+                .metadata(metadata())
+                .product_description(s("Widgets"))
+                .statement_descriptor(s("WIDGETS"))
+                .support_email(s("support@example.com"))
+                .support_phone(s("5555550100"))
+                .support_url(s("https://example.com/support"))
+                .tos_acceptance(tos_acceptance())
+                .transfer_schedule(transfer_schedule())
+                .unwrap()
+                .call();
+        }),
+        endpoint("UpdateAccountCall", "POST", "/accounts/acct_1", &[
+            ("external_account[object]", "card"),
+            ("external_account[exp_month]", "08"),
+            ("external_account[exp_year]", "2020"),
+            ("external_account[number]", "4242424242424242"),
+            ("external_account[cvc]", "123")
+        ], |c| {
+            let _ = c.update_account(id("acct_1")).external_card(card()).call();
+        }),
+        endpoint("UpdateAccountCall", "POST", "/accounts/acct_1", &[
+            ("legal_entity[address][line1]", "1234 Main Street"),
+            ("legal_entity[address][city]", "San Francisco"),
+            ("legal_entity[address][country]", "US"),
+            ("legal_entity[address][postal_code]", "94111"),
+            ("legal_entity[address][state]", "CA"),
+            ("legal_entity[dob][day]", "01"),
+            ("legal_entity[dob][month]", "02"),
+            ("legal_entity[dob][year]", "1980"),
+            ("legal_entity[first_name]", "Jenny"),
+            ("legal_entity[gender]", "female"),
+            ("legal_entity[last_name]", "Rosen"),
+            ("legal_entity[maiden_name]", "Smith"),
+            ("legal_entity[personal_id_number]", "000000000"),
+            ("legal_entity[phone_number]", "5555550100"),
+            ("legal_entity[ssn_last_4]", "0000"),
+            ("legal_entity[type]", "individual"),
+            ("legal_entity[verification][document]", "file_1")
+        ], |c| {
+            let _ = c.update_account(id("acct_1")).legal_entity(individual()).call();
+        }),
+        endpoint("UpdateAccountCall", "POST", "/accounts/acct_1", &[
+            ("legal_entity[additional_owners][][address][line1]", "1234 Main Street"),
+            ("legal_entity[additional_owners][][address][city]", "San Francisco"),
+            ("legal_entity[additional_owners][][address][country]", "US"),
+            ("legal_entity[additional_owners][][address][postal_code]", "94111"),
+            ("legal_entity[additional_owners][][address][state]", "CA"),
+            ("legal_entity[additional_owners][][dob][day]", "01"),
+            ("legal_entity[additional_owners][][dob][month]", "02"),
+            ("legal_entity[additional_owners][][dob][year]", "1980"),
+            ("legal_entity[additional_owners][][first_name]", "Jenny"),
+            ("legal_entity[additional_owners][][last_name]", "Rosen"),
+            ("legal_entity[additional_owners][][verification][document]", "file_2"),
+            ("legal_entity[address][line1]", "1234 Main Street"),
+            ("legal_entity[address][city]", "San Francisco"),
+            ("legal_entity[address][country]", "US"),
+            ("legal_entity[address][postal_code]", "94111"),
+            ("legal_entity[address][state]", "CA"),
+            ("legal_entity[business_name]", "Widgets Inc"),
+            ("legal_entity[business_tax_id]", "000000000"),
+            ("legal_entity[business_vat_id]", "GB000000000"),
+            ("legal_entity[personal_address][line1]", "1234 Main Street"),
+            ("legal_entity[personal_address][city]", "San Francisco"),
+            ("legal_entity[personal_address][country]", "US"),
+            ("legal_entity[personal_address][postal_code]", "94111"),
+            ("legal_entity[personal_address][state]", "CA"),
+            ("legal_entity[type]", "company")
+        ], |c| {
+            let _ = c.update_account(id("acct_1")).legal_entity(company()).call();
+        }),
+        endpoint("UpdateAccountCall", "POST", "/accounts/acct_1", &[
+            ("external_account[object]", "bank_account"),
+            ("external_account[account_number]", "000123456789"),
+            ("external_account[country]", "US"),
+            ("external_account[currency]", "usd"),
+            ("external_account[routing_number]", "110000000"),
+            ("external_account[account_holder_name]", "Jane Austen"),
+            ("external_account[account_holder_type]", "individual")
+        ], |c| {
+            let _ = c.update_account(id("acct_1")).external_bank_account(bank_account()).call();
+        }),
+        endpoint("DeleteAccountCall", "DELETE", "/accounts/acct_1", &[], |c| {
+            let _ = c.delete_account(id("acct_1")).call();
+        }),
+        endpoint("RejectAccountCall", "POST", "/accounts/acct_1/reject", &[
+            ("reason", "fraud")
+        ], |c| {
+            let _ = c.reject_account(id("acct_1"), AccountRejectReason::Fraud).call();
+        }),
+        endpoint("ListAccountsCall", "GET", "/accounts", &[
+            ("ending_before", "acct_2"),
+            ("limit", "10"),
+            ("starting_after", "acct_1")
+        ], |c| {
+            let _ = c.list_accounts()
+                .ending_before(s("acct_2"))
+                .limit(10)
+                .starting_after(s("acct_1"))
+                .call();
+        })
+    ]
+}
+
+fn external_accounts() -> Vec<Endpoint> {
+    vec![
+        endpoint("AccountCreateBankAccountCall", "POST", "/accounts/acct_1/external_accounts", &[
+            ("external_account", "btok_1"),
+            ("default_for_currency", "true"),
+            ("metadata[order_id]", "6735")
+        ], |c| {
+            let _ = c.account_create_bank_account(id("acct_1"))
+                .bank_account_token(id("btok_1"))
+                .default_for_currency(true)
+                .metadata(metadata())
+                .call();
+        }),
+        endpoint("AccountCreateBankAccountCall", "POST", "/accounts/acct_1/external_accounts", &[
+            ("external_account[object]", "bank_account"),
+            ("external_account[account_number]", "000123456789"),
+            ("external_account[country]", "US"),
+            ("external_account[currency]", "usd"),
+            ("external_account[routing_number]", "110000000"),
+            ("external_account[account_holder_name]", "Jane Austen"),
+            ("external_account[account_holder_type]", "individual")
+        ], |c| {
+            let _ = c.account_create_bank_account(id("acct_1")).bank_account(bank_account()).call();
+        }),
+        endpoint(
+            "AccountRetrieveBankAccountCall",
+            "GET",
+            "/accounts/acct_1/external_accounts/ba_1",
+            &[],
+            |c| {
+                let _ = c.account_retrieve_bank_account(id("acct_1"), id("ba_1")).call();
+            }
+        ),
+        endpoint("AccountUpdateBankAccountCall", "POST", "/accounts/acct_1/external_accounts/ba_1", &[
+            ("default_for_currency", "true"),
+            ("metadata[order_id]", "6735")
+        ], |c| {
+            let _ = c.account_update_bank_account(id("acct_1"), id("ba_1"))
+                .default_for_currency(true)
+                .metadata(metadata())
+                .call();
+        }),
+        endpoint(
+            "AccountDeleteBankAccountCall",
+            "DELETE",
+            "/accounts/acct_1/external_accounts/ba_1",
+            &[],
+            |c| {
+                let _ = c.account_delete_bank_account(id("acct_1"), id("ba_1")).call();
+            }
+        ),
+        endpoint("AccountListBankAccountsCall", "GET", "/accounts/acct_1/external_accounts", &[
+            ("include[]", "total_count"),
+            ("object", "bank_account"),
+            ("ending_before", "ba_2"),
+            ("limit", "10"),
+            ("starting_after", "ba_1")
+        ], |c| {
+            let _ = c.account_list_bank_accounts(id("acct_1"))
+                .ending_before(s("ba_2"))
+                .limit(10)
+                .starting_after(s("ba_1"))
+                .call();
+        }),
+        endpoint("AccountCreateCardCall", "POST", "/accounts/acct_1/external_accounts", &[
+            ("external_account", "tok_1"),
+            ("default_for_currency", "true"),
+            ("metadata[order_id]", "6735")
+        ], |c| {
+            let _ = c.account_create_card(id("acct_1"))
+                .card_token(id("tok_1"))
+                .default_for_currency(true)
+                .metadata(metadata())
+                .call();
+        }),
+        endpoint("AccountCreateCardCall", "POST", "/accounts/acct_1/external_accounts", &[
+            ("external_account[object]", "card"),
+            ("external_account[exp_month]", "08"),
+            ("external_account[exp_year]", "2020"),
+            ("external_account[number]", "4242424242424242"),
+            ("external_account[cvc]", "123")
+        ], |c| {
+            let _ = c.account_create_card(id("acct_1")).card(card()).call();
+        }),
+        endpoint(
+            "AccountRetrieveCardCall",
+            "GET",
+            "/accounts/acct_1/external_accounts/card_1",
+            &[],
+            |c| {
+                let _ = c.account_retrieve_card(id("acct_1"), id("card_1")).call();
+            }
+        ),
+        endpoint("AccountUpdateCardCall", "POST", "/accounts/acct_1/external_accounts/card_1", &[
+            ("address_city", "San Francisco"),
+            ("address_country", "US"),
+            ("address_line1", "1234 Main Street"),
+            ("address_line2", "Apt 2"),
+            ("address_state", "CA"),
+            ("address_zip", "94111"),
+            ("default_for_currency", "true"),
+            ("exp_month", "09"),
+            ("exp_year", "2021"),
+            ("metadata[order_id]", "6735"),
+            ("name", "Jenny Rosen")
+        ], |c| {
+            let _ = c.account_update_card(id("acct_1"), id("card_1"))
+                .address_city(s("San Francisco"))
+                .address_country(s("US"))
+                .address_line1(s("1234 Main Street"))
+                .address_line2(s("Apt 2"))
+                .address_state(s("CA"))
+                .address_zip(s("94111"))
+                .default_for_currency(true)
+                .exp_month(s("09"))
+                .exp_year(s("2021"))
+                .metadata(metadata())
+                .name(s("Jenny Rosen"))
+                .call();
+        }),
+        endpoint(
+            "AccountDeleteCardCall",
+            "DELETE",
+            "/accounts/acct_1/external_accounts/card_1",
+            &[],
+            |c| {
+                let _ = c.account_delete_card(id("acct_1"), id("card_1")).call();
+            }
+        ),
+        endpoint("AccountListCardsCall", "GET", "/accounts/acct_1/external_accounts", &[
+            ("include[]", "total_count"),
+            ("object", "card"),
+            ("ending_before", "card_2"),
+            ("limit", "10"),
+            ("starting_after", "card_1")
+        ], |c| {
+            let _ = c.account_list_cards(id("acct_1"))
+                .ending_before(s("card_2"))
+                .limit(10)
+                .starting_after(s("card_1"))
+                .call();
+        })
+    ]
+}
+
+fn application_fees() -> Vec<Endpoint> {
+    vec![
+        endpoint("RetrieveApplicationFeeCall", "GET", "/application_fees/fee_1", &[], |c| {
+            let _ = c.retrieve_application_fee(id("fee_1")).call();
+        }),
+        endpoint("ListApplicationFeesCall", "GET", "/application_fees", &[
+            ("charge", "ch_1"),
+            ("created[gt]", "1462000000"),
+            ("created[lte]", "1462086400"),
+            ("ending_before", "fee_2"),
+            ("limit", "10"),
+            ("starting_after", "fee_1")
+        ], |c| {
+            let _ = c.list_application_fees()
+                .charge(id("ch_1"))
+                .created(between())
+                .ending_before(s("fee_2"))
+                .limit(10)
+                .starting_after(s("fee_1"))
+                .call();
+        }),
+        endpoint("ListApplicationFeesCall", "GET", "/application_fees", &[
+            ("created", "1462000000")
+        ], |c| {
+            let _ = c.list_application_fees().created_exact(1462000000).call();
+        }),
+        endpoint("CreateFeeRefundCall", "POST", "/application_fees/fee_1/refunds", &[
+            ("amount", "50"),
+            ("metadata[order_id]", "6735")
+        ], |c| {
+            let _ = c.create_fee_refund(id("fee_1")).amount(50).metadata(metadata()).call();
+        }),
+        endpoint("RetrieveFeeRefundCall", "GET", "/application_fees/fee_1/refunds/fr_1", &[], |c| {
+            let _ = c.retrieve_fee_refund(id("fee_1"), id("fr_1")).call();
+        }),
+        endpoint("UpdateFeeRefundCall", "POST", "/application_fees/fee_1/refunds/fr_1", &[
+            ("metadata[order_id]", "6735")
+        ], |c| {
+            let _ = c.update_fee_refund(id("fee_1"), id("fr_1")).metadata(metadata()).call();
+        }),
+        endpoint("ListFeeRefundsCall", "GET", "/application_fees/fee_1/refunds", &[
+            ("include[]", "total_count"),
+            ("ending_before", "fr_2"),
+            ("limit", "10"),
+            ("starting_after", "fr_1")
+        ], |c| {
+            let _ = c.list_fee_refunds(id("fee_1"))
+                .ending_before(s("fr_2"))
+                .limit(10)
+                .starting_after(s("fr_1"))
+                .call();
+        })
+    ]
+}
+
+fn country_specs() -> Vec<Endpoint> {
+    vec![
+        endpoint("ListCountrySpecCall", "GET", "/country_specs", &[
+            ("ending_before", "US"),
+            ("limit", "10"),
+            ("starting_after", "CA")
+        ], |c| {
+            let _ = c.list_country_specs()
+                .ending_before(s("US"))
+                .limit(10)
+                .starting_after(s("CA"))
+                .call();
+        }),
+        endpoint("RetrieveCountrySpecCall", "GET", "/country_specs/US", &[], |c| {
+            let _ = c.retrieve_country_spec(s("US")).call();
+        })
+    ]
+}
+
+fn bitcoin_receivers() -> Vec<Endpoint> {
+    vec![
+        endpoint("CreateBitcoinReceiverCall", "POST", "/bitcoin/receivers", &[
+            ("amount", "1000"),
+            ("currency", "usd"),
+            ("email", "jenny@example.com"),
+            ("description", "Widgets"),
+            ("metadata[order_id]", "6735"),
+            ("refund_mispayments", "true")
+        ], |c| {
+            let _ = c.create_bitcoin_receiver(1000, Currency::UnitedStatesDollar, s("jenny@example.com"))
+                .description(s("Widgets"))
+                .metadata(metadata())
+                .refund_mispayments(true)
+                .call();
+        }),
+        endpoint("RetrieveBitcoinReceiverCall", "GET", "/bitcoin/receivers/btcrcv_1", &[], |c| {
+            let _ = c.retrieve_bitcoin_receiver(id("btcrcv_1")).call();
+        }),
+        endpoint("ListBitcoinReceiversCall", "GET", "/bitcoin/receivers", &[
+            ("include[]", "total_count"),
+            ("active", "true"),
+            ("ending_before", "btcrcv_2"),
+            ("filled", "false"),
+            ("limit", "10"),
+            ("starting_after", "btcrcv_1"),
+            ("uncaptured_funds", "false")
+        ], |c| {
+            let _ = c.list_bitcoin_receivers()
+                .active(true)
+                .ending_before(s("btcrcv_2"))
+                .filled(false)
+                .limit(10)
+                .starting_after(s("btcrcv_1"))
+                .uncaptured_funds(false)
+                .call();
+        })
+    ]
+}
+
+fn orders() -> Vec<Endpoint> {
+    vec![
+        endpoint("CreateOrderCall", "POST", "/orders", &[
+            ("currency", "usd"),
+            ("coupon", "25OFF"),
+            ("customer", "cus_1"),
+            ("email", "jenny@example.com"),
+            ("items[][parent]", "sku_1"),
+            ("items[][quantity]", "2"),
+            ("items[][type]", "sku"),
+            ("metadata[order_id]", "6735"),
+            ("shipping[address][line1]", "1234 Main Street"),
+            ("shipping[address][city]", "San Francisco"),
+            ("shipping[address][country]", "US"),
+            ("shipping[address][postal_code]", "94111"),
+            ("shipping[name]", "Jenny Rosen")
+        ], |c| {
+            let item = OrderItem {
+                amount: None,
+                currency: None,
+                description: None,
+                parent: Some(s("sku_1")),
+                quantity: Some(2),
+                item_type: Some(ItemType::Sku)
+            };
+            let _ = c.create_order(Currency::UnitedStatesDollar)
+                .coupon(id("25OFF"))
+                .customer(id("cus_1"))
+                .email(s("jenny@example.com"))
+                .items(vec![item])
+                .metadata(metadata())
+                .shipping(shipping())
+                .call();
+        }),
+        endpoint("RetrieveOrderCall", "GET", "/orders/or_1", &[], |c| {
+            let _ = c.retrieve_order(id("or_1")).call();
+        }),
+        endpoint("UpdateOrderCall", "POST", "/orders/or_1", &[
+            ("coupon", "25OFF"),
+            ("metadata[order_id]", "6735"),
+            ("selected_shipping_method", "ship_express"),
+            ("status", "fulfilled")
+        ], |c| {
+            let _ = c.update_order(id("or_1"))
+                .coupon(id("25OFF"))
+                .metadata(metadata())
+                .selected_shipping_method(s("ship_express"))
+                .status(OrderStatus::Fulfilled)
+                .call();
+        }),
+        endpoint("PayOrderCall", "POST", "/orders/or_1/pay", &[
+            ("application_fee", "100"),
+            ("customer", "cus_1"),
+            ("email", "jenny@example.com"),
+            ("metadata[order_id]", "6735"),
+            ("source", "tok_1")
+        ], |c| {
+            let _ = c.pay_order(id("or_1"))
+                .application_fee(100)
+                .customer(id("cus_1"))
+                .email(s("jenny@example.com"))
+                .metadata(metadata())
+                .source_id(s("tok_1"))
+                .call();
+        }),
+        endpoint("PayOrderCall", "POST", "/orders/or_1/pay", &[
+            ("source[object]", "card"),
+            ("source[exp_month]", "08"),
+            ("source[exp_year]", "2020"),
+            ("source[number]", "4242424242424242"),
+            ("source[cvc]", "123")
+        ], |c| {
+            let _ = c.pay_order(id("or_1")).source(card()).call();
+        }),
+        endpoint("ListOrdersCall", "GET", "/orders", &[
+            ("include[]", "total_count"),
+            ("created[gt]", "1462000000"),
+            ("created[lte]", "1462086400"),
+            ("customer", "cus_1"),
+            ("ending_before", "or_3"),
+            ("ids[0]", "or_1"),
+            ("ids[1]", "or_2"),
+            ("limit", "10"),
+            ("starting_after", "or_1"),
+            ("status", "paid"),
+            ("status_transitions[canceled][gt]", "1462000000"),
+            ("status_transitions[canceled][lte]", "1462086400"),
+            ("status_transitions[fulfilled][gt]", "1462000000"),
+            ("status_transitions[fulfilled][lte]", "1462086400"),
+            ("status_transitions[paid][gt]", "1462000000"),
+            ("status_transitions[paid][lte]", "1462086400"),
+            ("status_transitions[returned][gt]", "1462000000"),
+            ("status_transitions[returned][lte]", "1462086400"),
+            ("upstream_ids[0]", "6735")
+        ], |c| {
+            let _ = c.list_orders()
+                .created(between())
+                .customer(id("cus_1"))
+                .ending_before(s("or_3"))
+                .ids(vec![s("or_1"), s("or_2")])
+                .limit(10)
+                .starting_after(s("or_1"))
+                .status(OrderStatus::Paid)
+                .canceled(between())
+                .fulfilled(between())
+                .paid(between())
+                .returned(between())
+                .upstream_ids(vec![s("6735")])
+                .call();
+        }),
+        endpoint("ListOrdersCall", "GET", "/orders", &[
+            ("include[]", "total_count"),
+            ("created", "1462000000"),
+            ("status_transitions[canceled]", "1462000001"),
+            ("status_transitions[fulfilled]", "1462000002"),
+            ("status_transitions[paid]", "1462000003"),
+            ("status_transitions[returned]", "1462000004")
+        ], |c| {
+            let _ = c.list_orders()
+                .created_exact(1462000000)
+                .canceled_exact(1462000001)
+                .fulfilled_exact(1462000002)
+                .paid_exact(1462000003)
+                .returned_exact(1462000004)
+                .call();
+        })
+    ]
+}
+
+fn products() -> Vec<Endpoint> {
+    vec![
+        endpoint("CreateProductCall", "POST", "/products", &[
+            ("name", "T-shirt"),
+            ("active", "true"),
+            ("attributes[0]", "size"),
+            ("attributes[1]", "gender"),
+            ("caption", "Comfortable"),
+            ("deactivate_on[0]", "fulfilled"),
+            ("description", "Gray cotton t-shirt"),
+            ("id", "prod_1"),
+            ("images[0]", "https://example.com/shirt.png"),
+            ("metadata[order_id]", "6735"),
+            ("package_dimensions[height]", "1.5"),
+            ("package_dimensions[length]", "2"),
+            ("package_dimensions[weight]", "3.25"),
+            ("package_dimensions[width]", "4"),
+            ("shippable", "true"),
+            ("url", "https://example.com/shirt")
+        ], |c| {
+            let _ = c.create_product(s("T-shirt"))
+                .active(true)
+                .attributes(vec![s("size"), s("gender")])
+                .caption(s("Comfortable"))
+                .deactivate_on(vec![s("fulfilled")])
+                .description(s("Gray cotton t-shirt"))
+                .id(id("prod_1"))
+                .images(vec![s("https://example.com/shirt.png")])
+                .metadata(metadata())
+                .package_dimensions(dimensions())
+                .shippable(true)
+                .url(s("https://example.com/shirt"))
+                .call();
+        }),
+        endpoint("RetrieveProductCall", "GET", "/products/prod_1", &[], |c| {
+            let _ = c.retrieve_product(id("prod_1")).call();
+        }),
+        endpoint("UpdateProductCall", "POST", "/products/prod_1", &[
+            ("active", "false"),
+            ("caption", "Comfortable"),
+            ("deactivate_on[]", ""),
+            ("description", "Gray cotton t-shirt"),
+            ("images[0]", "https://example.com/shirt.png"),
+            ("metadata[order_id]", "6735"),
+            ("name", "T-shirt"),
+            ("package_dimensions[height]", "1.5"),
+            ("package_dimensions[length]", "2"),
+            ("package_dimensions[weight]", "3.25"),
+            ("package_dimensions[width]", "4"),
+            ("shippable", "false"),
+            ("url", "https://example.com/shirt")
+        ], |c| {
+            let _ = c.update_product(id("prod_1"))
+                .active(false)
+                .caption(s("Comfortable"))
+                .deactivate_on(Vec::new())
+                .description(s("Gray cotton t-shirt"))
+                .images(vec![s("https://example.com/shirt.png")])
+                .metadata(metadata())
+                .name(s("T-shirt"))
+                .package_dimensions(dimensions())
+                .shippable(false)
+                .url(s("https://example.com/shirt"))
+                .call();
+        }),
+        endpoint("DeleteProductCall", "DELETE", "/products/prod_1", &[], |c| {
+            let _ = c.delete_product(id("prod_1")).call();
+        }),
+        endpoint("ListProductsCall", "GET", "/products", &[
+            ("include[]", "total_count"),
+            ("active", "true"),
+            ("ending_before", "prod_3"),
+            ("ids[0]", "prod_1"),
+            ("ids[1]", "prod_2"),
+            ("limit", "10"),
+            ("shippable", "true"),
+            ("starting_after", "prod_1"),
+            ("url", "https://example.com/shirt")
+        ], |c| {
+            let _ = c.list_products()
+                .active(true)
+                .ending_before(s("prod_3"))
+                .ids(vec![id("prod_1"), id("prod_2")])
+                .limit(10)
+                .shippable(true)
+                .starting_after(s("prod_1"))
+                .url(s("https://example.com/shirt"))
+                .call();
+        })
+    ]
+}
+
+fn skus() -> Vec<Endpoint> {
+    vec![
+        endpoint("CreateSkuCall", "POST", "/skus", &[
+            ("currency", "usd"),
+            ("inventory[quantity]", "50"),
+            ("inventory[type]", "finite"),
+            ("price", "1500"),
+            ("product", "prod_1"),
+            ("active", "true"),
+            ("attributes[size]", "Medium"),
+            ("id", "sku_1"),
+            ("image", "https://example.com/shirt.png"),
+            ("metadata[order_id]", "6735"),
+            ("package_dimensions[height]", "1.5"),
+            ("package_dimensions[length]", "2"),
+            ("package_dimensions[weight]", "3.25"),
+            ("package_dimensions[width]", "4")
+        ], |c| {
+            let inventory = Inventory {
+                quantity: Some(50),
+                inventory_type: InventoryType::Finite,
+                value: None
+            };
+            let mut attributes = BTreeMap::new();
+            attributes.insert(s("size"), s("Medium"));
+            let _ = c.create_sku(Currency::UnitedStatesDollar, inventory, 1500, id("prod_1"))
+                .active(true)
+                .attributes(attributes)
+                .id(id("sku_1"))
+                .image(s("https://example.com/shirt.png"))
+                .metadata(metadata())
+                .package_dimensions(dimensions())
+                .call();
+        }),
+        endpoint("RetrieveSkuCall", "GET", "/skus/sku_1", &[], |c| {
+            let _ = c.retrieve_sku(id("sku_1")).call();
+        }),
+        endpoint("UpdateSkuCall", "POST", "/skus/sku_1", &[
+            ("active", "false"),
+            ("currency", "usd"),
+            ("image", "https://example.com/shirt.png"),
+            ("inventory[type]", "bucket"),
+            ("inventory[value]", "in_stock"),
+            ("metadata[order_id]", "6735"),
+            ("package_dimensions[height]", "1.5"),
+            ("package_dimensions[length]", "2"),
+            ("package_dimensions[weight]", "3.25"),
+            ("package_dimensions[width]", "4"),
+            ("price", "1500"),
+            ("product", "prod_1")
+        ], |c| {
+            let inventory = Inventory {
+                quantity: None,
+                inventory_type: InventoryType::Bucket,
+                value: Some(InventoryState::InStock)
+            };
+            let _ = c.update_sku(id("sku_1"))
+                .active(false)
+                .currency(Currency::UnitedStatesDollar)
+                .image(s("https://example.com/shirt.png"))
+                .inventory(inventory)
+                .metadata(metadata())
+                .package_dimensions(dimensions())
+                .price(1500)
+                .product(id("prod_1"))
+                .call();
+        }),
+        endpoint("ListSkusCall", "GET", "/skus", &[
+            ("include[]", "total_count"),
+            ("active", "true"),
+            ("attributes[size]", "Medium"),
+            ("ending_before", "sku_3"),
+            ("ids[0]", "sku_1"),
+            ("ids[1]", "sku_2"),
+            ("in_stock", "true"),
+            ("limit", "10"),
+            ("product", "prod_1"),
+            ("starting_after", "sku_1")
+        ], |c| {
+            let mut attributes = BTreeMap::new();
+            attributes.insert(s("size"), s("Medium"));
+            let _ = c.list_skus()
+                .active(true)
+                .attributes(attributes)
+                .ending_before(s("sku_3"))
+                .ids(vec![id("sku_1"), id("sku_2")])
+                .in_stock(true)
+                .limit(10)
+                .product(id("prod_1"))
+                .starting_after(s("sku_1"))
+                .call();
+        }),
+        endpoint("DeleteSkuCall", "DELETE", "/skus/sku_1", &[], |c| {
+            let _ = c.delete_sku(id("sku_1")).call();
+        })
+    ]
+}
+
+fn coupons() -> Vec<Endpoint> {
+    vec![
+        endpoint("CreateCouponCall", "POST", "/coupons", &[
+            ("duration", "repeating"),
+            ("amount_off", "500"),
+            ("currency", "usd"),
+            ("duration_in_months", "3"),
+            ("id", "25OFF"),
+            ("max_redemptions", "100"),
+            ("metadata[order_id]", "6735"),
+            ("percent_off", "25"),
+            ("redeem_by", "1462086400")
+        ], |c| {
+            let _ = c.create_coupon(CouponDuration::Repeating)
+                .amount_off(500)
+                .currency(Currency::UnitedStatesDollar)
+                .duration_in_months(3)
+                .id(id("25OFF"))
+                .max_redemptions(100)
+                .metadata(metadata())
+                .percent_off(25)
+                .redeem_by(1462086400)
+                .call();
+        }),
+        endpoint("RetrieveCouponCall", "GET", "/coupons/25OFF", &[], |c| {
+            let _ = c.retrieve_coupon(id("25OFF")).call();
+        }),
+        endpoint("UpdateCouponCall", "POST", "/coupons/25OFF", &[
+            ("metadata[order_id]", "6735")
+        ], |c| {
+            let _ = c.update_coupon(id("25OFF")).metadata(metadata()).call();
+        }),
+        endpoint("DeleteCouponCall", "DELETE", "/coupons/25OFF", &[], |c| {
+            let _ = c.delete_coupon(id("25OFF")).call();
+        }),
+        endpoint("ListCouponsCall", "GET", "/coupons", &[
+            ("include[]", "total_count"),
+            ("created[gt]", "1462000000"),
+            ("created[lte]", "1462086400"),
+            ("ending_before", "50OFF"),
+            ("limit", "10"),
+            ("starting_after", "25OFF")
+        ], |c| {
+            let _ = c.list_coupons()
+                .created(between())
+                .ending_before(s("50OFF"))
+                .limit(10)
+                .starting_after(s("25OFF"))
+                .call();
+        }),
+        endpoint("ListCouponsCall", "GET", "/coupons", &[
+            ("include[]", "total_count"),
+            ("created", "1462000000")
+        ], |c| {
+            let _ = c.list_coupons().created_exact(1462000000).call();
+        })
+    ]
+}
+
+fn invoices() -> Vec<Endpoint> {
+    vec![
+        endpoint("CreateInvoiceCall", "POST", "/invoices", &[
+            ("customer", "cus_1"),
+            ("application_fee", "100"),
+            ("description", "May"),
+            ("metadata[order_id]", "6735"),
+            ("statement_descriptor", "WIDGETS"),
+            ("subscription", "sub_1"),
+            ("tax_percent", "8.25")
+        ], |c| {
+            let _ = c.create_invoice(id("cus_1"))
+                .application_fee(100)
+                .description(s("May"))
+                .metadata(metadata())
+                .statement_descriptor(s("WIDGETS"))
+                .subscription(id("sub_1"))
+                .tax_percent(8.25)
+                .call();
+        }),
+        endpoint("RetrieveInvoiceCall", "GET", "/invoices/in_1", &[], |c| {
+            let _ = c.retrieve_invoice(id("in_1")).call();
+        }),
+        endpoint("RetrieveInvoiceLinesCall", "GET", "/invoices/in_1/lines", &[
+            ("coupon", "25OFF"),
+            ("customer", "cus_1"),
+            ("ending_before", "ii_2"),
+            ("limit", "10"),
+            ("starting_after", "ii_1"),
+            ("subscription", "sub_1"),
+            ("subscription_plan", "gold"),
+            ("subscription_prorate", "true"),
+            ("subscription_proration_date", "1462000000"),
+            ("subscription_quantity", "2"),
+            ("subscription_trial_end", "1462086400")
+        ], |c| {
+            let _ = c.retrieve_invoice_lines(id("in_1"))
+                .coupon(id("25OFF"))
+                .customer(id("cus_1"))
+                .ending_before(s("ii_2"))
+                .limit(10)
+                .starting_after(s("ii_1"))
+                .subscription(id("sub_1"))
+                .subscription_plan(id("gold"))
+                .subscription_prorate(true)
+                .subscription_proration_date(1462000000)
+                .subscription_quantity(s("2"))
+                .subscription_trial_end(1462086400)
+                .call();
+        }),
+        endpoint("RetrieveUpcomingInvoiceCall", "GET", "/invoices/upcoming", &[
+            ("customer", "cus_1"),
+            ("coupon", "25OFF"),
+            ("subscription", "sub_1"),
+            ("subscription_plan", "gold"),
+            ("subscription_prorate", "true"),
+            ("subscription_proration_date", "1462000000"),
+            ("subscription_quantity", "2"),
+            ("subscription_trial_end", "1462086400")
+        ], |c| {
+            let _ = c.retrieve_upcoming_invoice(id("cus_1"))
+                .coupon(id("25OFF"))
+                .subscription(id("sub_1"))
+                .subscription_plan(id("gold"))
+                .subscription_prorate(true)
+                .subscription_proration_date(1462000000)
+                .subscription_quantity(2)
+                .subscription_trial_end(1462086400)
+                .call();
+        }),
+        endpoint("UpdateInvoiceCall", "POST", "/invoices/in_1", &[
+            ("application_fee", "100"),
+            ("closed", "true"),
+            ("description", "May"),
+            ("forgiven", "false"),
+            ("metadata[order_id]", "6735"),
+            ("statement_descriptor", "WIDGETS"),
+            ("tax_percent", "8.25")
+        ], |c| {
+            let _ = c.update_invoice(id("in_1"))
+                .application_fee(100)
+                .closed(true)
+                .description(s("May"))
+                .forgiven(false)
+                .metadata(metadata())
+                .statement_descriptor(s("WIDGETS"))
+                .tax_percent(8.25)
+                .call();
+        }),
+        endpoint("PayInvoiceCall", "POST", "/invoices/in_1/pay", &[], |c| {
+            let _ = c.pay_invoice(id("in_1")).call();
+        }),
+        endpoint("ListInvoicesCall", "GET", "/invoices", &[
+            ("include[]", "total_count"),
+            ("customer", "cus_1"),
+            ("date[gt]", "1462000000"),
+            ("date[lte]", "1462086400"),
+            ("ending_before", "in_2"),
+            ("limit", "10"),
+            ("starting_after", "in_1")
+        ], |c| {
+            let _ = c.list_invoices()
+                .customer(id("cus_1"))
+                .date(between())
+                .ending_before(s("in_2"))
+                .limit(10)
+                .starting_after(s("in_1"))
+                .call();
+        }),
+        endpoint("ListInvoicesCall", "GET", "/invoices", &[
+            ("include[]", "total_count"),
+            ("date", "1462000000")
+        ], |c| {
+            let _ = c.list_invoices().date_exact(1462000000).call();
+        }),
+        endpoint("CreateInvoiceitemCall", "POST", "/invoiceitems", &[
+            ("amount", "1000"),
+            ("currency", "usd"),
+            ("customer", "cus_1"),
+            ("description", "Setup fee"),
+            ("discountable", "false"),
+            ("invoice", "in_1"),
+            ("metadata[order_id]", "6735"),
+            ("subscription", "sub_1")
+        ], |c| {
+            let _ = c.create_invoiceitem(1000, Currency::UnitedStatesDollar, id("cus_1"))
+                .description(s("Setup fee"))
+                .discountable(false)
+                .invoice(id("in_1"))
+                .metadata(metadata())
+                .subscription(id("sub_1"))
+                .call();
+        }),
+        endpoint("RetrieveInvoiceitemCall", "GET", "/invoiceitems/ii_1", &[], |c| {
+            let _ = c.retrieve_invoiceitem(id("ii_1")).call();
+        }),
+        endpoint("UpdateInvoiceitemCall", "POST", "/invoiceitems/ii_1", &[
+            ("amount", "1500"),
+            ("description", "Setup fee"),
+            ("discountable", "true"),
+            ("metadata[order_id]", "6735")
+        ], |c| {
+            let _ = c.update_invoiceitem(id("ii_1"))
+                .amount(1500)
+                .description(s("Setup fee"))
+                .discountable(true)
+                .metadata(metadata())
+                .call();
+        }),
+        endpoint("DeleteInvoiceitemCall", "DELETE", "/invoiceitems/ii_1", &[], |c| {
+            let _ = c.delete_invoiceitem(id("ii_1")).call();
+        }),
+        endpoint("ListInvoiceitemsCall", "GET", "/invoiceitems", &[
+            ("include[]", "total_count"),
+            ("created[gt]", "1462000000"),
+            ("created[lte]", "1462086400"),
+            ("customer", "cus_1"),
+            ("ending_before", "ii_2"),
+            ("limit", "10"),
+            ("starting_after", "ii_1")
+        ], |c| {
+            let _ = c.list_invoiceitems()
+                .created(between())
+                .customer(id("cus_1"))
+                .ending_before(s("ii_2"))
+                .limit(10)
+                .starting_after(s("ii_1"))
+                .call();
+        }),
+        endpoint("ListInvoiceitemsCall", "GET", "/invoiceitems", &[
+            ("include[]", "total_count"),
+            ("created", "1462000000")
+        ], |c| {
+            let _ = c.list_invoiceitems().created_exact(1462000000).call();
+        })
+    ]
+}
+
+fn plans() -> Vec<Endpoint> {
+    vec![
+        endpoint("CreatePlanCall", "POST", "/plans", &[
+            ("id", "gold"),
+            ("amount", "2000"),
+            ("currency", "usd"),
+            ("interval", "month"),
+            ("name", "Gold"),
+            ("interval_count", "3"),
+            ("metadata[order_id]", "6735"),
+            ("statement_descriptor", "GOLD"),
+            ("trial_period_days", "14")
+        ], |c| {
+            let _ = c.create_plan(id("gold"), 2000, Currency::UnitedStatesDollar, Interval::Month, s("Gold"))
+                .interval_count(3)
+                .metadata(metadata())
+                .statement_descriptor(s("GOLD"))
+                .trial_period_days(14)
+                .call();
+        }),
+        endpoint("RetrievePlanCall", "GET", "/plans/gold", &[], |c| {
+            let _ = c.retrieve_plan(id("gold")).call();
+        }),
+        endpoint("UpdatePlanCall", "POST", "/plans/gold", &[
+            ("metadata[order_id]", "6735"),
+            ("name", "Gold"),
+            ("statement_descriptor", "GOLD")
+        ], |c| {
+            let _ = c.update_plan(id("gold"))
+                .metadata(metadata())
+                .name(s("Gold"))
+                .statement_descriptor(s("GOLD"))
+                .call();
+        }),
+        endpoint("DeletePlanCall", "DELETE", "/plans/gold", &[], |c| {
+            let _ = c.delete_plan(id("gold")).call();
+        }),
+        endpoint("ListPlansCall", "GET", "/plans", &[
+            ("include[]", "total_count"),
+            ("created[gt]", "1462000000"),
+            ("created[lte]", "1462086400"),
+            ("ending_before", "silver"),
+            ("limit", "10"),
+            ("starting_after", "gold")
+        ], |c| {
+            let _ = c.list_plans()
+                .created(between())
+                .ending_before(s("silver"))
+                .limit(10)
+                .starting_after(s("gold"))
+                .call();
+        }),
+        endpoint("ListPlansCall", "GET", "/plans", &[
+            ("include[]", "total_count"),
+            ("created", "1462000000")
+        ], |c| {
+            let _ = c.list_plans().created_exact(1462000000).call();
+        })
+    ]
+}
+
+fn subscriptions() -> Vec<Endpoint> {
+    vec![
+        endpoint("CreateSubscriptionCall", "POST", "/customers/cus_1/subscriptions", &[
+            ("plan", "gold"),
+            ("application_fee_percent", "10.00"),
+            ("coupon", "25OFF"),
+            ("metadata[order_id]", "6735"),
+            ("quantity", "2"),
+            ("source", "tok_1"),
+            ("tax_percent", "8.25"),
+            ("trial_end", "1462086400")
+        ], |c| {
+            let _ = c.create_subscription(id("cus_1"), id("gold"))
+                .application_fee_percent(10.0)
+                .coupon(id("25OFF"))
+                .metadata(metadata())
+                .quantity(2)
+                .source(s("tok_1"))
+                .tax_percent(8.25)
+                .trial_end(1462086400)
+                .call();
+        }),
+        endpoint("CreateSubscriptionCall", "POST", "/customers/cus_1/subscriptions", &[
+            ("plan", "gold"),
+            ("source[object]", "card"),
+            ("source[exp_month]", "08"),
+            ("source[exp_year]", "2020"),
+            ("source[number]", "4242424242424242"),
+            ("source[cvc]", "123")
+        ], |c| {
+            let _ = c.create_subscription(id("cus_1"), id("gold")).card(card()).call();
+        }),
+        endpoint("RetrieveSubscriptionCall", "GET", "/customers/cus_1/subscriptions/sub_1", &[], |c| {
+            let _ = c.retrieve_subscription(id("cus_1"), id("sub_1")).call();
+        }),
+        endpoint("UpdateSubscriptionCall", "POST", "/customers/cus_1/subscriptions/sub_1", &[
+            ("application_fee_percent", "10.00"),
+            ("coupon", "25OFF"),
+            ("metadata[order_id]", "6735"),
+            ("plan", "silver"),
+            ("prorate", "false"),
+            ("proration_date", "1462000000"),
+            ("quantity", "2"),
+            ("source", "tok_1"),
+            ("tax_percent", "8.25"),
+            ("trial_end", "1462086400")
+        ], |c| {
+            let _ = c.update_subscription(id("cus_1"), id("sub_1"))
+                .application_fee_percent(10.0)
+                .coupon(id("25OFF"))
+                .metadata(metadata())
+                .plan(id("silver"))
+                .prorate(false)
+                .proration_date(1462000000)
+                .quantity(2)
+                .source(s("tok_1"))
+                .tax_percent(8.25)
+                .trial_end(1462086400)
+                .call();
+        }),
+        endpoint("UpdateSubscriptionCall", "POST", "/customers/cus_1/subscriptions/sub_1", &[
+            ("source[object]", "card"),
+            ("source[exp_month]", "08"),
+            ("source[exp_year]", "2020"),
+            ("source[number]", "4242424242424242"),
+            ("source[cvc]", "123"),
+            ("trial_end", "now")
+        ], |c| {
+            let _ = c.update_subscription(id("cus_1"), id("sub_1"))
+                .new_source(card())
+                .trial_end_now()
+                .call();
+        }),
+        endpoint("CancelSubscriptionCall", "DELETE", "/customers/cus_1/subscriptions/sub_1", &[
+            ("at_period_end", "true")
+        ], |c| {
+            let _ = c.cancel_subscription(id("cus_1"), id("sub_1")).at_period_end(true).call();
+        }),
+        endpoint("ListActiveSubscriptionsCall", "GET", "/customers/cus_1/subscriptions", &[
+            ("include[]", "total_count"),
+            ("ending_before", "sub_2"),
+            ("limit", "10"),
+            ("starting_after", "sub_1")
+        ], |c| {
+            let _ = c.list_subscriptions(id("cus_1"))
+                .ending_before(s("sub_2"))
+                .limit(10)
+                .starting_after(s("sub_1"))
+                .call();
+        })
+    ]
+}
+
+#[test] fn balance_endpoints() { check(balance()) }
+#[test] fn charge_endpoints() { check(charges()) }
+#[test] fn customer_endpoints() { check(customers()) }
+#[test] fn customer_source_endpoints() { check(customer_sources()) }
+#[test] fn dispute_endpoints() { check(disputes()) }
+#[test] fn event_endpoints() { check(events()) }
+#[test] fn refund_endpoints() { check(refunds()) }
+#[test] fn token_endpoints() { check(tokens()) }
+#[test] fn transfer_endpoints() { check(transfers()) }
+#[test] fn account_endpoints() { check(accounts()) }
+#[test] fn external_account_endpoints() { check(external_accounts()) }
+#[test] fn application_fee_endpoints() { check(application_fees()) }
+#[test] fn country_spec_endpoints() { check(country_specs()) }
+#[test] fn bitcoin_receiver_endpoints() { check(bitcoin_receivers()) }
+#[test] fn order_endpoints() { check(orders()) }
+#[test] fn product_endpoints() { check(products()) }
+#[test] fn sku_endpoints() { check(skus()) }
+#[test] fn coupon_endpoints() { check(coupons()) }
+#[test] fn invoice_endpoints() { check(invoices()) }
+#[test] fn plan_endpoints() { check(plans()) }
+#[test] fn subscription_endpoints() { check(subscriptions()) }
+
+/// Prints every table as `METHOD path params`, for diffing against the API
+/// reference of a new Stripe version.
+#[test]
+#[ignore]
+fn print_endpoint_tables() {
+    let tables = vec![
+        balance(), charges(), customers(), customer_sources(), disputes(), events(), refunds(),
+        tokens(), transfers(), accounts(), external_accounts(), application_fees(),
+        country_specs(), bitcoin_receivers(), orders(), products(), skus(), coupons(),
+        invoices(), plans(), subscriptions()
+    ];
+    for table in tables {
+        for endpoint in table {
+            let mut params: Vec<String> = endpoint.params.into_iter().map(|(k, _)| k).collect();
+            params.sort();
+            params.dedup();
+            println!("{} {} {}", endpoint.method, endpoint.path, params.join(" "));
+        }
+    }
+}
@@ -0,0 +1,126 @@
+#![cfg(feature = "testing")]
+
+extern crate stripe;
+
+use stripe::api::ApiCall;
+use stripe::errors::error::Error;
+use stripe::errors::stripe_error::{StripeError, StripeErrorCode, StripeErrorKind};
+use stripe::model::{Currency, NewCard};
+use stripe::testing::FakeStripe;
+
+fn card(number: &str) -> NewCard {
+    NewCard::new(number.to_string(), 8, 2030).cvc("123".to_string())
+}
+
+fn stripe_error<T: ::std::fmt::Debug>(result: stripe::Result<T>) -> StripeError {
+    match result {
+        Err(Error::StripeError(err)) => err,
+        other                        => panic!("expected a Stripe error, got {:?}", other)
+    }
+}
+
+#[test]
+fn charges_move_from_authorized_to_captured_to_refunded() {
+    let stripe = FakeStripe::new();
+    let customer = stripe.create_customer().source_card(card("4242424242424242")).call().unwrap();
+
+    let charge = stripe.create_charge(1000, Currency::UnitedStatesDollar)
+        .customer(customer.id.clone())
+        .capture(false)
+        .call()
+        .unwrap();
+    assert!(charge.paid);
+    assert!(!charge.captured);
+
+    let charge = stripe.capture_charge(charge.id.clone()).amount(600).call().unwrap();
+    assert!(charge.captured);
+    assert_eq!(charge.amount_refunded, 400);
+    let err = stripe_error(stripe.capture_charge(charge.id.clone()).call());
+    assert_eq!(err.kind, StripeErrorKind::InvalidRequestError);
+
+    let refund = stripe.create_refund(charge.id.clone()).call().unwrap();
+    assert_eq!(refund.amount, 600);
+    let charge = stripe.retrieve_charge(charge.id.clone()).call().unwrap();
+    assert!(charge.refunded);
+    assert_eq!(charge.amount_refunded, 1000);
+    let err = stripe_error(stripe.create_refund(charge.id.clone()).call());
+    assert_eq!(err.kind, StripeErrorKind::InvalidRequestError);
+
+    assert_eq!(stripe.event_types(), vec![
+        "customer.created",
+        "customer.source.created",
+        "charge.succeeded",
+        "charge.captured",
+        "charge.refunded"
+    ]);
+}
+
+#[test]
+fn declined_charges_are_recorded_as_failed() {
+    let stripe = FakeStripe::new();
+    let token = stripe.create_card_token().card(card("4000000000000002")).call().unwrap();
+
+    let err = stripe_error(
+        stripe.create_charge(1000, Currency::UnitedStatesDollar).source(token.id.to_string()).call()
+    );
+    assert_eq!(err.kind, StripeErrorKind::CardError);
+    assert_eq!(err.code, Some(StripeErrorCode::CardDeclined));
+
+    let charges = stripe.list_charges().call().unwrap();
+    assert_eq!(charges.data.len(), 1);
+    assert_eq!(charges.data[0].status.to_string(), "failed");
+    assert_eq!(charges.data[0].failure_code, Some(StripeErrorCode::CardDeclined));
+    assert_eq!(stripe.event_types(), vec!["charge.failed"]);
+}
+
+#[test]
+fn cvcs_must_match_the_brand_length() {
+    let stripe = FakeStripe::new();
+    let visa = card("4242424242424242").cvc("1234".to_string());
+
+    let err = stripe_error(stripe.create_card_token().card(visa).call());
+    assert_eq!(err.kind, StripeErrorKind::CardError);
+    assert_eq!(err.code, Some(StripeErrorCode::InvalidCvC));
+    assert_eq!(err.param, Some("cvc".to_string()));
+
+    let amex = card("378282246310005").cvc("1234".to_string());
+    assert!(stripe.create_card_token().card(amex).call().is_ok());
+}
+
+#[test]
+fn rejected_requests_change_nothing() {
+    let stripe = FakeStripe::new();
+
+    // The card is declined while attaching it, so the customer isn't kept.
+    let err = stripe_error(stripe.create_customer().source_card(card("4000000000000002")).call());
+    assert_eq!(err.kind, StripeErrorKind::CardError);
+    assert!(stripe.list_customers().call().unwrap().data.is_empty());
+    assert!(stripe.event_types().is_empty());
+
+    let err = stripe_error(stripe.create_charge(10, Currency::UnitedStatesDollar).call());
+    assert_eq!(err.param, Some("amount".to_string()));
+    let err = stripe_error(stripe.create_charge(1000, Currency::UnitedStatesDollar).call());
+    assert_eq!(err.kind, StripeErrorKind::InvalidRequestError);
+    assert!(stripe.list_charges().call().unwrap().data.is_empty());
+}
+
+#[test]
+fn tokens_can_only_be_used_once() {
+    let stripe = FakeStripe::new();
+    let token = stripe.create_card_token().card(card("4242424242424242")).call().unwrap();
+    stripe.create_charge(1000, Currency::UnitedStatesDollar).source(token.id.to_string()).call().unwrap();
+
+    let err = stripe_error(
+        stripe.create_charge(1000, Currency::UnitedStatesDollar).source(token.id.to_string()).call()
+    );
+    assert_eq!(err.param, Some("source".to_string()));
+}
+
+#[test]
+fn reset_forgets_everything() {
+    let stripe = FakeStripe::new();
+    stripe.create_customer().call().unwrap();
+    stripe.reset();
+    assert!(stripe.list_customers().call().unwrap().data.is_empty());
+    assert!(stripe.event_types().is_empty());
+}
@@ -0,0 +1,30 @@
+extern crate serde_json;
+extern crate stripe;
+
+use stripe::model::{Charge, ChargeId, PlanId, SourceId, TransferId};
+
+#[test]
+fn ids_are_checked_against_their_prefix() {
+    let charge_id: ChargeId = "ch_18W4Ch2eZvKYlo2C8ZnEzJDg".parse().unwrap();
+    assert_eq!(charge_id.to_string(), "ch_18W4Ch2eZvKYlo2C8ZnEzJDg");
+    assert!("py_18W4Ch2eZvKYlo2C8ZnEzJDg".parse::<ChargeId>().is_ok());
+
+    let err = "cus_8UauOtdvTJV1Dd".parse::<ChargeId>().unwrap_err();
+    assert_eq!(err.id_type, "ChargeId");
+    assert_eq!(err.prefixes, &["ch_", "py_"]);
+    assert!("ch_".parse::<ChargeId>().is_err());
+
+    assert!("tr_18W4Ch2eZvKYlo2C8ZnEzJDg".parse::<TransferId>().is_ok());
+    assert!("po_18W4Ch2eZvKYlo2C8ZnEzJDg".parse::<TransferId>().is_err());
+    assert!("ba_18W4Ch2eZvKYlo2C8ZnEzJDg".parse::<SourceId>().is_ok());
+    assert!("tok_18W4Ch2eZvKYlo2C8ZnEzJDg".parse::<SourceId>().is_err());
+
+    assert!("gold".parse::<PlanId>().is_ok());
+    assert!("".parse::<PlanId>().is_err());
+
+    // Responses aren't held to the prefixes, only to being non-empty.
+    let json = include_str!("fixtures/charge.json").replace("\"id\": \"ch_", "\"id\": \"pi_");
+    let charge = serde_json::from_str::<Charge>(&json).unwrap();
+    assert!(charge.id.as_str().starts_with("pi_"));
+    assert!(serde_json::from_str::<ChargeId>("\"\"").is_err());
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "testing")]
+
+extern crate hyper;
+extern crate stripe;
+
+use hyper::header::Headers;
+use hyper::method::Method;
+use hyper::status::StatusCode;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use stripe::api::ApiCall;
+use stripe::errors::error::Error;
+use stripe::errors::stripe_error::{StripeError, StripeErrorCode, StripeErrorKind};
+use stripe::instrumentation::{Instrumentation, RequestRecord};
+use stripe::middleware::{Request, Response};
+use stripe::model::Currency;
+use stripe::testing::MockStripe;
+use stripe::StripeClient;
+
+fn instrumented() -> (StripeClient, Arc<Mutex<Vec<RequestRecord>>>) {
+    let stripe = MockStripe::new();
+    stripe.respond_with_json(Method::Get, "/balance", include_str!("fixtures/balance.json"));
+    stripe.respond_with_error(Method::Post, "/charges", StatusCode::PaymentRequired, "card_error", "Declined");
+
+    let records = Arc::new(Mutex::new(Vec::new()));
+    let recorded = records.clone();
+    let client = StripeClient::new("sk_test_instrumented")
+        .with_transport(stripe.transport())
+        .with_label("tenant", "acme")
+        .with_middleware(Instrumentation::new(move |record: &RequestRecord| {
+            recorded.lock().unwrap().push(record.clone());
+        }));
+    (client, records)
+}
+
+#[test]
+fn each_call_is_recorded_with_its_call_type_and_outcome() {
+    let (client, records) = instrumented();
+    client.retrieve_balance().call().unwrap();
+    assert!(client.create_charge(100, Currency::UnitedStatesDollar).call().is_err());
+    assert!(client.retrieve_charge("ch_1".parse().unwrap()).call().is_err());
+
+    let records = records.lock().unwrap();
+    assert_eq!(records.len(), 3);
+
+    assert_eq!(records[0].call_type, "RetrieveBalanceCall");
+    assert_eq!(records[0].resource, "balance");
+    assert_eq!(records[0].method, Method::Get);
+    assert_eq!(records[0].status, Some(200));
+    assert_eq!(records[0].request_id, Some("req_mock_1".to_string()));
+    assert_eq!(records[0].retries, 0);
+    assert_eq!(records[0].labels, vec![("tenant".to_string(), "acme".to_string())]);
+    assert_eq!(records[0].error_kind, None);
+
+    assert_eq!(records[1].call_type, "CreateChargeCall");
+    assert_eq!(records[1].resource, "charges");
+    assert_eq!(records[1].status, Some(402));
+    assert_eq!(records[1].error_kind, Some("card_error".to_string()));
+
+    assert_eq!(records[2].status, Some(404));
+    assert_eq!(records[2].error_kind, Some("invalid_request_error".to_string()));
+    assert_eq!(records[2].error_code, None);
+}
+
+#[test]
+fn errors_are_labelled_with_stripes_type_and_code() {
+    let request = Request {
+        call_type: "CreateChargeCall",
+        retries: 0,
+        labels: Vec::new(),
+        method: Method::Post,
+        path: "/charges".to_string(),
+        params: Vec::new(),
+        headers: Headers::new()
+    };
+    let err = Error::StripeError(StripeError {
+        kind: StripeErrorKind::CardError,
+        message: None,
+        code: Some(StripeErrorCode::CardDeclined),
+        param: None
+    });
+    let record = RequestRecord::new(&request, &Response {
+        status: Some(StatusCode::PaymentRequired),
+        headers: None,
+        latency: Duration::from_millis(5),
+        key_fingerprint: String::new(),
+        result: Err(&err)
+    });
+    assert_eq!(record.error_kind, Some("card_error".to_string()));
+    assert_eq!(record.error_code, Some("card_declined".to_string()));
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "testing")]
+
+extern crate hyper;
+extern crate stripe;
+
+use hyper::method::Method;
+use stripe::api::ApiCall;
+use stripe::errors::error::Error;
+use stripe::errors::mode_error::ModeError;
+use stripe::testing::MockStripe;
+use stripe::{KeyKind, KeyMode, SecretKey, StripeClient};
+
+fn client(key: &str, livemode: bool) -> StripeClient {
+    let stripe = MockStripe::new();
+    let balance = include_str!("fixtures/balance.json")
+        .replace("\"livemode\": false", &format!("\"livemode\": {}", livemode));
+    stripe.respond_with_json(Method::Get, "/balance", &balance);
+    StripeClient::new(key).with_transport(stripe.transport())
+}
+
+#[test]
+fn mode_and_kind_come_from_the_key_prefix() {
+    assert_eq!(SecretKey::new("sk_test_123").mode(), KeyMode::Test);
+    assert_eq!(SecretKey::new("rk_live_123").mode(), KeyMode::Live);
+    assert_eq!(SecretKey::new("sk_123").mode(), KeyMode::Unknown);
+
+    assert_eq!(SecretKey::new("sk_live_123").kind(), KeyKind::Secret);
+    assert_eq!(SecretKey::new("rk_test_123").kind(), KeyKind::Restricted);
+    assert_eq!(SecretKey::new("pk_test_123").kind(), KeyKind::Publishable);
+    assert_eq!(SecretKey::new("123").kind(), KeyKind::Unknown);
+}
+
+#[test]
+fn clients_refuse_keys_for_the_wrong_mode() {
+    match client("sk_live_123", true).require_test_mode().retrieve_balance().call() {
+        Err(Error::ModeError(ModeError::KeyModeNotAllowed { required, actual })) => {
+            assert_eq!(required, KeyMode::Test);
+            assert_eq!(actual, KeyMode::Live);
+        },
+        other => panic!("expected KeyModeNotAllowed, got {:?}", other)
+    }
+    assert!(client("sk_live_123", true).require_live_mode().retrieve_balance().call().is_ok());
+}
+
+#[test]
+fn responses_from_the_other_mode_are_rejected() {
+    assert!(client("sk_test_123", false).retrieve_balance().call().is_ok());
+    match client("sk_test_123", true).retrieve_balance().call() {
+        Err(Error::ModeError(ModeError::LivemodeMismatch { key_mode, object, id })) => {
+            assert_eq!(key_mode, KeyMode::Test);
+            assert_eq!(object, "balance");
+            assert_eq!(id, None);
+        },
+        other => panic!("expected LivemodeMismatch, got {:?}", other)
+    }
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "testing")]
+
+extern crate hyper;
+extern crate stripe;
+
+use hyper::method::Method;
+use std::env;
+use std::fs::File;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use stripe::api::ApiCall;
+use stripe::key_provider::{EnvKey, FileKey, KeyProvider, KeySlot, RotatingKey, StaticKey};
+use stripe::middleware::{Middleware, Request, Response};
+use stripe::testing::{MockStripe, MockTransport};
+use stripe::{SecretKey, StripeClient};
+
+const PRIMARY: &'static str = "sk_test_primary000000000001";
+const SECONDARY: &'static str = "sk_test_secondary0000000002";
+
+/// Only accepts the secondary key.
+fn secondary_only() -> MockTransport {
+    let stripe = MockStripe::new();
+    stripe.respond_with_json(Method::Get, "/balance", include_str!("fixtures/balance.json"));
+    stripe.require_key(SECONDARY);
+    stripe.transport()
+}
+
+struct Attempts(Arc<Mutex<Vec<(u32, String, Option<u16>)>>>);
+
+impl Middleware for Attempts {
+    fn after_receive(&self, request: &Request, response: &Response) {
+        let status = response.status.map(|status| status.to_u16());
+        self.0.lock().unwrap().push((request.retries, response.key_fingerprint.clone(), status));
+    }
+}
+
+struct Unreachable;
+
+impl KeyProvider for Unreachable {
+    fn current_key(&self) -> stripe::Result<SecretKey> {
+        panic!("the key was looked up")
+    }
+}
+
+#[test]
+fn static_env_and_file_keys_are_read() {
+    assert_eq!(StaticKey::new(SecretKey::new(PRIMARY)).current_key().unwrap().expose(), PRIMARY);
+
+    env::set_var("STRIPE_KEY_PROVIDER_TEST", format!(" {}\n", PRIMARY));
+    assert_eq!(EnvKey::new("STRIPE_KEY_PROVIDER_TEST").current_key().unwrap().expose(), PRIMARY);
+    assert!(EnvKey::new("STRIPE_KEY_PROVIDER_TEST_MISSING").current_key().is_err());
+
+    let path = env::temp_dir().join("stripe_key_provider_test");
+    File::create(&path).unwrap().write_all(format!("{}\n", SECONDARY).as_bytes()).unwrap();
+    assert_eq!(FileKey::new(&path).current_key().unwrap().expose(), SECONDARY);
+    assert!(FileKey::new(path.with_extension("missing")).current_key().is_err());
+}
+
+#[test]
+fn rejected_keys_fall_back_through_the_middleware() {
+    let attempts = Arc::new(Mutex::new(Vec::new()));
+    let rotating = RotatingKey::new(StaticKey::new(SecretKey::new(PRIMARY)), StaticKey::new(SecretKey::new(SECONDARY)));
+    let client = StripeClient::with_key_provider(rotating)
+        .with_transport(secondary_only())
+        .with_middleware(Attempts(attempts.clone()));
+
+    assert!(client.retrieve_balance().call().is_ok());
+    assert_eq!(*attempts.lock().unwrap(), vec![
+        (0, "sk_test_...0001".to_string(), Some(401)),
+        (1, "sk_test_...0002".to_string(), Some(200))
+    ]);
+    assert_eq!(client.key().unwrap().expose(), SECONDARY);
+
+    attempts.lock().unwrap().clear();
+    assert!(client.retrieve_balance().call().is_ok());
+    assert_eq!(attempts.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn fallback_is_tried_once() {
+    let rotating = RotatingKey::new(StaticKey::new(SecretKey::new(PRIMARY)), StaticKey::new(SecretKey::new(PRIMARY)));
+    assert_eq!(rotating.active(), KeySlot::Primary);
+    let client = StripeClient::with_key_provider(rotating).with_transport(secondary_only());
+    assert!(client.retrieve_balance().call().is_err());
+}
+
+#[test]
+fn debug_output_does_not_read_keys() {
+    let rotating = RotatingKey::new(Unreachable, Unreachable);
+    assert_eq!(format!("{:?}", rotating), "RotatingKey { active: Primary }");
+    let client = StripeClient::with_key_provider(RotatingKey::new(Unreachable, Unreachable));
+    let _ = format!("{:?}", client);
+}
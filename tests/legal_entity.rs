@@ -0,0 +1,108 @@
+extern crate stripe;
+
+use stripe::StripeClient;
+use stripe::errors::account_validation_error::AccountValidationError;
+use stripe::errors::address_validation_error::AddressValidationError;
+use stripe::model::{Address, Country, DateOfBirth, NewLegalEntity, NewOwner, TransferSchedule, Weekday};
+
+fn address(postal_code: &str) -> Address {
+    Address {
+        line1: Some("1234 Main Street".to_string()),
+        line2: None,
+        city: Some("San Francisco".to_string()),
+        country: Some(Country::UnitedStates),
+        postal_code: Some(postal_code.to_string()),
+        state: Some("CA".to_string())
+    }
+}
+
+fn individual() -> NewLegalEntity {
+    NewLegalEntity::individual()
+        .address(address("94111"))
+        .dob(DateOfBirth::new(29, 2, 1980))
+        .first_name("Jenny".to_string())
+        .last_name("Rosen".to_string())
+        .ssn_last_4("0000".to_string())
+}
+
+#[test]
+fn dates_of_birth_must_be_complete_real_dates() {
+    assert!(DateOfBirth::new(29, 2, 2000).is_valid());
+    assert!(!DateOfBirth::new(29, 2, 1900).is_valid());
+    assert!(!DateOfBirth::new(31, 4, 1980).is_valid());
+    assert!(!DateOfBirth::new(1, 13, 1980).is_valid());
+    assert!(!DateOfBirth { day: Some(1), month: Some(2), year: None }.is_valid());
+}
+
+#[test]
+fn legal_entities_are_checked_down_to_each_owner() {
+    assert_eq!(individual().validate(&Country::UnitedStates), Ok(()));
+    assert_eq!(
+        individual().ssn_last_4("00000".to_string()).validate(&Country::UnitedStates),
+        Err(AccountValidationError::InvalidSsnLast4 { param: "legal_entity[ssn_last_4]".to_string() })
+    );
+    assert_eq!(
+        individual().personal_address(address("9411")).validate(&Country::UnitedStates),
+        Err(AccountValidationError::InvalidAddress {
+            param: "legal_entity[personal_address]".to_string(),
+            error: AddressValidationError::Invalid { field: "postal_code" }
+        })
+    );
+
+    let company = NewLegalEntity::company()
+        .business_name("Widgets Inc".to_string())
+        .additional_owners(vec![
+            NewOwner::new().dob(DateOfBirth::new(1, 2, 1980)),
+            NewOwner::new().dob(DateOfBirth::new(30, 2, 1980))
+        ]);
+    let error = company.validate(&Country::UnitedStates).unwrap_err();
+    assert_eq!(error.param(), "legal_entity[additional_owners][1][dob]");
+}
+
+#[test]
+fn partial_addresses_are_checked_in_the_accounts_country() {
+    let update = NewLegalEntity::new().address(Address {
+        line1: None,
+        line2: None,
+        city: None,
+        country: None,
+        postal_code: Some("K1A 0B1".to_string()),
+        state: Some("Ontario".to_string())
+    });
+    assert_eq!(update.validate(&Country::Canada), Ok(()));
+    assert_eq!(
+        update.validate(&Country::UnitedStates),
+        Err(AccountValidationError::InvalidAddress {
+            param: "legal_entity[address]".to_string(),
+            error: AddressValidationError::Invalid { field: "state" }
+        })
+    );
+}
+
+#[test]
+fn id_numbers_are_redacted_from_debug_output() {
+    let debug = format!("{:?}", individual().personal_id_number("123456789".to_string()));
+    assert!(!debug.contains("123456789"), "{}", debug);
+    assert!(!debug.contains("\"0000\""), "{}", debug);
+    assert!(debug.contains("Jenny"), "{}", debug);
+}
+
+#[test]
+fn monthly_anchors_are_days_of_the_month() {
+    assert_eq!(TransferSchedule::monthly(31).validate(), Ok(()));
+    assert_eq!(TransferSchedule::monthly(0).validate(), Err(AccountValidationError::InvalidMonthlyAnchor));
+    assert_eq!(TransferSchedule::weekly(Weekday::Monday).validate(), Ok(()));
+    assert_eq!(TransferSchedule::daily().delay_days(2).delay_days, Some(2));
+}
+
+#[test]
+fn account_calls_refuse_invalid_transfer_schedules() {
+    let client = StripeClient::new("sk_test_schedule");
+    let err = client.create_account().transfer_schedule(TransferSchedule::monthly(0)).unwrap_err();
+    assert_eq!(err, AccountValidationError::InvalidMonthlyAnchor);
+    let err = client.update_account("acct_1".parse().unwrap())
+        .transfer_schedule(TransferSchedule::monthly(32))
+        .unwrap_err();
+    assert_eq!(err, AccountValidationError::InvalidMonthlyAnchor);
+    assert!(client.create_account().transfer_schedule(TransferSchedule::monthly(31)).is_ok());
+}
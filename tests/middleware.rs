@@ -0,0 +1,114 @@
+#![cfg(feature = "testing")]
+
+extern crate hyper;
+#[macro_use]
+extern crate log;
+extern crate stripe;
+
+use hyper::method::Method;
+use hyper::status::StatusCode;
+use log::{Log, LogLevelFilter, LogMetadata, LogRecord};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use stripe::api::ApiCall;
+use stripe::errors::rate_limit_error::RateLimitError;
+use stripe::middleware::{LoggingMiddleware, Middleware, RateLimiter, Request, Response};
+use stripe::model::NewCard;
+use stripe::testing::MockStripe;
+use stripe::StripeClient;
+
+fn client() -> StripeClient {
+    let stripe = MockStripe::new();
+    stripe.respond_with_json(Method::Get, "/balance", include_str!("fixtures/balance.json"));
+    StripeClient::new("sk_test_middleware").with_transport(stripe.transport())
+}
+
+struct Recorder {
+    name: &'static str,
+    events: Arc<Mutex<Vec<String>>>
+}
+
+impl Middleware for Recorder {
+    fn before_send(&self, request: &mut Request) {
+        self.events.lock().unwrap().push(format!("{} before {}", self.name, request.call_type));
+        request.labels.push(("seen_by".to_string(), self.name.to_string()));
+    }
+
+    fn after_receive(&self, request: &Request, response: &Response) {
+        assert_eq!(response.status, Some(StatusCode::Ok));
+        self.events.lock().unwrap().push(format!("{} after {:?}", self.name, request.labels));
+    }
+}
+
+#[test]
+fn middleware_runs_in_the_order_it_was_added() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let client = client()
+        .with_middleware(Recorder { name: "first", events: events.clone() })
+        .with_middleware(Recorder { name: "second", events: events.clone() });
+    client.retrieve_balance().call().unwrap();
+
+    let labels = r#"[("seen_by", "first"), ("seen_by", "second")]"#;
+    assert_eq!(*events.lock().unwrap(), vec![
+        "first before RetrieveBalanceCall".to_string(),
+        "second before RetrieveBalanceCall".to_string(),
+        format!("first after {}", labels),
+        format!("second after {}", labels)
+    ]);
+}
+
+struct Capture(Arc<Mutex<Vec<String>>>);
+
+impl Log for Capture {
+    fn enabled(&self, _metadata: &LogMetadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &LogRecord) {
+        self.0.lock().unwrap().push(record.args().to_string());
+    }
+}
+
+#[test]
+fn logging_middleware_redacts_cards_and_keys() {
+    let lines = Arc::new(Mutex::new(Vec::new()));
+    let capture = lines.clone();
+    log::set_logger(|max_level| {
+        max_level.set(LogLevelFilter::Debug);
+        Box::new(Capture(capture))
+    }).unwrap();
+    debug!("logger installed");
+
+    let client = client().with_middleware(LoggingMiddleware);
+    let card = NewCard::new("4242424242424242".to_string(), 8, 2030).cvc("123".to_string());
+    let _ = client.create_card_token().card(card).call();
+
+    let lines = lines.lock().unwrap();
+    let logged: Vec<&String> = lines.iter().filter(|line| line.contains("CreateCardTokenCall")).collect();
+    assert_eq!(logged.len(), 2, "{:?}", *lines);
+    assert!(logged[0].contains("************4242"), "{}", logged[0]);
+    assert!(logged[0].contains("[REDACTED]"), "{}", logged[0]);
+    for line in lines.iter() {
+        assert!(!line.contains("4242424242424242"), "{}", line);
+        assert!(!line.contains("\"123\""), "{}", line);
+        assert!(!line.contains("sk_test_middleware"), "{}", line);
+    }
+}
+
+#[test]
+fn rate_limiter_blocks_once_the_window_is_used_up() {
+    let client = client().with_middleware(RateLimiter::new(2, Duration::from_millis(400)).unwrap());
+    let start = Instant::now();
+    client.retrieve_balance().call().unwrap();
+    client.retrieve_balance().call().unwrap();
+    assert!(start.elapsed() < Duration::from_millis(150));
+
+    client.retrieve_balance().call().unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(150));
+}
+
+#[test]
+fn rate_limiter_rejects_zero_requests_or_a_zero_window() {
+    assert_eq!(RateLimiter::new(0, Duration::from_secs(1)).unwrap_err(), RateLimitError::NoRequests);
+    assert_eq!(RateLimiter::new(10, Duration::from_secs(0)).unwrap_err(), RateLimitError::ZeroWindow);
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "testing")]
+
+extern crate hyper;
+extern crate serde_json;
+extern crate stripe;
+
+use hyper::method::Method;
+use hyper::status::StatusCode;
+use std::str::FromStr;
+use stripe::api::ApiCall;
+use stripe::errors::error::Error;
+use stripe::errors::stripe_error::StripeErrorKind;
+use stripe::model::{Balance, ChargeId, Currency};
+use stripe::testing::MockStripe;
+
+fn charge_id() -> ChargeId {
+    ChargeId::from_str("ch_18CbaH2eZvKYlo2CZ0YQWVZ6").unwrap()
+}
+
+#[test]
+fn canned_json_is_returned_for_its_method_and_path() {
+    let stripe = MockStripe::new();
+    stripe.respond_with_json(Method::Post, "/charges", include_str!("fixtures/charge.json"));
+
+    let charge = stripe.create_charge(1000, Currency::UnitedStatesDollar).call().unwrap();
+    assert_eq!(charge.id, charge_id());
+    assert_eq!(charge.amount, 1000);
+
+    // Same path, different method: nothing registered.
+    assert!(stripe.retrieve_charge(charge_id()).call().is_err());
+}
+
+#[test]
+fn models_are_serialized_as_canned_responses() {
+    let stripe = MockStripe::new();
+    let balance: Balance = serde_json::from_str(include_str!("fixtures/balance.json")).unwrap();
+    stripe.respond_with(Method::Get, "/balance", &balance);
+
+    let returned = stripe.retrieve_balance().call().unwrap();
+    assert_eq!(returned.livemode, balance.livemode);
+    assert_eq!(returned.available.len(), balance.available.len());
+}
+
+#[test]
+fn the_latest_response_for_a_path_wins() {
+    let stripe = MockStripe::new();
+    stripe.respond_with_json(Method::Post, "/charges", include_str!("fixtures/charge.json"));
+    stripe.respond_with_error(
+        Method::Post,
+        "/charges",
+        StatusCode::PaymentRequired,
+        "card_error",
+        "Your card was \"declined\"."
+    );
+
+    match stripe.create_charge(1000, Currency::UnitedStatesDollar).call() {
+        Err(Error::StripeError(err)) => {
+            assert_eq!(err.kind, StripeErrorKind::CardError);
+            assert_eq!(err.message, Some("Your card was \"declined\".".to_string()));
+        },
+        other => panic!("expected a card error, got {:?}", other)
+    }
+}
+
+#[test]
+fn unregistered_requests_get_a_404() {
+    let stripe = MockStripe::new();
+    match stripe.retrieve_balance().call() {
+        Err(Error::StripeError(err)) => {
+            assert_eq!(err.kind, StripeErrorKind::InvalidRequestError);
+            assert_eq!(err.message, Some("No mock response for GET /balance".to_string()));
+        },
+        other => panic!("expected a 404, got {:?}", other)
+    }
+}
+
+#[test]
+fn every_request_is_recorded_until_cleared() {
+    let stripe = MockStripe::new();
+    let _ = stripe.retrieve_balance().call();
+    let _ = stripe.create_charge(1000, Currency::UnitedStatesDollar).call();
+
+    let calls = stripe.calls();
+    assert_eq!(calls.len(), 2);
+    assert_eq!(calls[0].call_type, "RetrieveBalanceCall");
+    assert_eq!(calls[0].method, Method::Get);
+    assert_eq!(calls[1].call_type, "CreateChargeCall");
+    assert!(calls[1].params.contains(&("amount".to_string(), "1000".to_string())));
+
+    stripe.clear_calls();
+    assert!(stripe.calls().is_empty());
+}
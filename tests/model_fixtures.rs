@@ -0,0 +1,238 @@
+//! Deserializes every fixture in `tests/fixtures` into its model, and checks
+//! that serializing it gives back Stripe's JSON. The fixtures are shaped like
+//! real Stripe responses, including nulls, expanded and unexpanded relations,
+//! and enum values this crate doesn't know about.
+
+extern crate serde;
+extern crate serde_json;
+extern crate stripe;
+
+use serde_json::Value;
+use stripe::Either;
+use stripe::model::*;
+
+fn fixture<T: serde::Deserialize>(name: &str, json: &str) -> T {
+    match serde_json::from_str(json) {
+        Ok(model) => model,
+        Err(err)  => panic!("fixtures/{}.json: {}", name, err)
+    }
+}
+
+/// Serializes the model parsed from `json`, and checks that it parses back
+/// to the same thing and that every value it emits matches the fixture.
+fn round_trip<T: serde::Deserialize + serde::Serialize>(name: &str, json: &str) {
+    let model: T = fixture(name, json);
+    let serialized = serde_json::to_value(&model);
+    let reparsed: T = match serde_json::from_value(serialized.clone()) {
+        Ok(model) => model,
+        Err(err)  => panic!("fixtures/{}.json: serialized form doesn't parse: {}", name, err)
+    };
+    if serde_json::to_value(&reparsed) != serialized {
+        panic!("fixtures/{}.json: serializing isn't stable across a round trip", name);
+    }
+
+    let mut mismatches = Vec::new();
+    compare("", &serialized, &serde_json::from_str(json).unwrap(), &mut mismatches);
+    if !mismatches.is_empty() {
+        panic!("fixtures/{}.json: serialized differently at {}", name, mismatches.join(", "));
+    }
+}
+
+/// Every field the model serializes must be in the fixture and match it, and
+/// every field of the fixture must be serialized back. A serialized `null`
+/// matches anything.
+fn compare(path: &str, serialized: &Value, original: &Value, mismatches: &mut Vec<String>) {
+    match (serialized, original) {
+        (&Value::Null, _) => {},
+        (&Value::Object(ref serialized), &Value::Object(ref original)) => {
+            for (key, value) in serialized {
+                let path = format!("{}.{}", path, key);
+                match original.get(key) {
+                    Some(original) => compare(&path, value, original, mismatches),
+                    None           => mismatches.push(path)
+                }
+            }
+            for key in original.keys() {
+                if !serialized.contains_key(key) {
+                    mismatches.push(format!("{}.{} (dropped)", path, key));
+                }
+            }
+        },
+        (&Value::Array(ref serialized), &Value::Array(ref original)) => {
+            if serialized.len() != original.len() {
+                mismatches.push(path.to_string());
+            }
+            for (i, (value, original)) in serialized.iter().zip(original).enumerate() {
+                compare(&format!("{}[{}]", path, i), value, original, mismatches);
+            }
+        },
+        (serialized, original) => {
+            let same = match (serialized.as_f64(), original.as_f64()) {
+                (Some(serialized), Some(original)) => serialized == original,
+                _                                  => serialized == original
+            };
+            if !same {
+                mismatches.push(path.to_string());
+            }
+        }
+    }
+}
+
+macro_rules! fixtures {
+    ($($name:ident: $model:ty),* $(,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                round_trip::<$model>(
+                    stringify!($name),
+                    include_str!(concat!("fixtures/", stringify!($name), ".json"))
+                );
+            }
+        )*
+    }
+}
+
+fixtures! {
+    account_managed_new: Account,
+    account_managed_verified: Account,
+    account_standalone: Account,
+    alipay_account: AlipayAccount,
+    application_fee: ApplicationFee,
+    balance: Balance,
+    balance_transaction: BalanceTransaction,
+    balance_transaction_unknown_enums: BalanceTransaction,
+    bank_account: BankAccount,
+    bank_account_nulls: BankAccount,
+    bitcoin_receiver: BitcoinReceiver,
+    card: Card,
+    card_unknown_enums: Card,
+    charge: Charge,
+    charge_disputed: Charge,
+    charge_expanded: Charge,
+    charge_failed: Charge,
+    charge_unknown_fields: Charge,
+    country_spec: CountrySpec,
+    coupon: Coupon,
+    coupon_amount_off: Coupon,
+    coupon_unknown_duration: Coupon,
+    customer: Customer,
+    customer_empty: Customer,
+    customer_list: ApiList<Customer>,
+    delete: Delete,
+    discount: Discount,
+    dispute: Dispute,
+    dispute_unknown_enums: Dispute,
+    event: Event,
+    event_previous_attributes: Event,
+    fee_refund: FeeRefund,
+    invoice: Invoice,
+    invoice_expanded_charge: Invoice,
+    invoice_upcoming: Invoice,
+    invoiceitem: Invoiceitem,
+    order: Order,
+    order_unknown_status: Order,
+    plan: Plan,
+    plan_unknown_interval: Plan,
+    product: Product,
+    recipient: Recipient,
+    refund: Refund,
+    refund_unknown_reason: Refund,
+    sku: Sku,
+    sku_expanded_product: Sku,
+    sku_unknown_enums: Sku,
+    subscription: Subscription,
+    subscription_discounted: Subscription,
+    subscription_unknown_status: Subscription,
+    token: Token,
+    token_bank_account: Token,
+    token_pii: Token,
+    transfer: Transfer,
+    transfer_reversal: TransferReversal,
+    transfer_unknown_enums: Transfer,
+}
+
+#[test]
+fn unknown_enum_values_are_kept() {
+    let card: Card = fixture("card_unknown_enums", include_str!("fixtures/card_unknown_enums.json"));
+    match card.brand {
+        CardBrand::Unknown(ref brand) => assert_eq!(brand, "UnionPay"),
+        ref brand                     => panic!("expected an unknown brand, got {:?}", brand)
+    }
+    assert!(card.country.is_none());
+
+    let transfer: Transfer = fixture("transfer_unknown_enums", include_str!("fixtures/transfer_unknown_enums.json"));
+    match transfer.transfer_type {
+        TransferType::Other(ref other) => assert_eq!(other, "instant"),
+        ref other                      => panic!("expected an unknown type, got {:?}", other)
+    }
+}
+
+#[test]
+fn expanded_relations_are_parsed() {
+    let charge: Charge = fixture("charge_expanded", include_str!("fixtures/charge_expanded.json"));
+    match charge.customer {
+        Some(Either::Right(ref customer)) => assert_eq!(customer.id(), "cus_8UauOtdvTJV1Dd"),
+        ref customer                      => panic!("expected an expanded customer, got {:?}", customer)
+    }
+    match charge.balance_transaction {
+        Some(Either::Right(ref txn)) => assert_eq!(txn.fee, 59),
+        ref txn                      => panic!("expected an expanded balance transaction, got {:?}", txn)
+    }
+    match charge.source {
+        Source::BitcoinReceiver(ref receiver) => assert_eq!(receiver.amount, 100),
+        ref source                            => panic!("expected a bitcoin receiver, got {:?}", source)
+    }
+
+    let charge: Charge = fixture("charge", include_str!("fixtures/charge.json"));
+    match charge.customer {
+        Some(Either::Left(ref id)) => assert_eq!(id.as_str(), "cus_8UauOtdvTJV1Dd"),
+        ref customer               => panic!("expected a customer id, got {:?}", customer)
+    }
+}
+
+#[test]
+fn serialized_sources_keep_their_object_type() {
+    let charge: Charge = fixture("charge_expanded", include_str!("fixtures/charge_expanded.json"));
+    let reparsed: Charge = serde_json::from_str(&serde_json::to_string(&charge).unwrap()).unwrap();
+    match reparsed.source {
+        Source::BitcoinReceiver(ref receiver) => assert_eq!(receiver.amount, 100),
+        ref source                            => panic!("expected a bitcoin receiver, got {:?}", source)
+    }
+    match reparsed.customer {
+        Some(Either::Right(ref customer)) => assert_eq!(customer.id(), "cus_8UauOtdvTJV1Dd"),
+        ref customer                      => panic!("expected an expanded customer, got {:?}", customer)
+    }
+}
+
+#[test]
+fn unknown_fields_are_kept_and_serialized() {
+    let charge: Charge = fixture("charge_unknown_fields", include_str!("fixtures/charge_unknown_fields.json"));
+    assert_eq!(charge.extra.keys().collect::<Vec<_>>(), vec!["outcome", "review"]);
+    match charge.source {
+        Source::Card(ref card) => {
+            assert_eq!(card.extra.get("three_d_secure"), Some(&Value::String("optional".to_string())))
+        },
+        ref source => panic!("expected a card, got {:?}", source)
+    }
+
+    let json = serde_json::to_value(&charge);
+    assert_eq!(json.lookup("outcome.risk_level"), Some(&Value::String("normal".to_string())));
+    assert_eq!(json.find("review"), Some(&Value::Null));
+    assert_eq!(json.lookup("source.three_d_secure"), Some(&Value::String("optional".to_string())));
+}
+
+#[test]
+fn serialized_models_name_their_object() {
+    let object = |json: &Value| json.find("object").and_then(Value::as_str).map(String::from);
+
+    let charge: Charge = fixture("charge", include_str!("fixtures/charge.json"));
+    assert_eq!(charge.object, Some("charge".to_string()));
+    let json = serde_json::to_value(&charge);
+    assert_eq!(object(&json), Some("charge".to_string()));
+    assert_eq!(json.find("refunds").and_then(|refunds| object(refunds)), Some("list".to_string()));
+
+    let balance: Balance = fixture("balance", include_str!("fixtures/balance.json"));
+    assert_eq!(object(&serde_json::to_value(&balance)), Some("balance".to_string()));
+    let discount: Discount = fixture("discount", include_str!("fixtures/discount.json"));
+    assert_eq!(object(&serde_json::to_value(&discount)), Some("discount".to_string()));
+}
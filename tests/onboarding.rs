@@ -0,0 +1,65 @@
+extern crate serde_json;
+extern crate stripe;
+
+use stripe::model::{Account, CountrySpec, LegalEntityType, NewLegalEntity};
+use stripe::{LegalEntitySetter, OnboardingChecklist, RequirementDue, RequirementKind};
+
+fn account(name: &str, json: &str) -> Account {
+    serde_json::from_str(json).unwrap_or_else(|err| panic!("fixtures/{}.json: {}", name, err))
+}
+
+fn spec() -> CountrySpec {
+    serde_json::from_str(include_str!("fixtures/country_spec.json")).unwrap()
+}
+
+#[test]
+fn new_accounts_list_fields_needed_then_additional_fields() {
+    let account = account("account_managed_new", include_str!("fixtures/account_managed_new.json"));
+    let checklist = OnboardingChecklist::new(&account, &spec());
+
+    let fields: Vec<&str> = checklist.requirements.iter().map(|requirement| requirement.field.as_str()).collect();
+    assert_eq!(fields, vec![
+        "legal_entity.type",
+        "tos_acceptance.date",
+        "tos_acceptance.ip",
+        "legal_entity.personal_id_number",
+        "legal_entity.verification.document"
+    ]);
+    assert_eq!(checklist.requirements[0].due, RequirementDue::Now { due_by: None });
+    assert_eq!(checklist.requirements[3].due, RequirementDue::Eventually);
+    assert_eq!(checklist.disabled_reason, Some("fields_needed".to_string()));
+    assert!(!checklist.is_complete());
+    assert_eq!(checklist.due_now().len(), 3);
+    assert_eq!(checklist.of_kind(RequirementKind::TosAcceptance).len(), 2);
+}
+
+#[test]
+fn legal_entity_requirements_map_to_builder_methods() {
+    let account = account("account_managed_new", include_str!("fixtures/account_managed_new.json"));
+    let checklist = OnboardingChecklist::new(&account, &spec());
+    let setters: Vec<Option<LegalEntitySetter>> = checklist.requirements.iter()
+        .map(|requirement| requirement.legal_entity_setter())
+        .collect();
+    assert!(setters[1].is_none() && setters[2].is_none());
+
+    let mut entity = NewLegalEntity::new();
+    for setter in setters.into_iter().filter_map(|setter| setter) {
+        entity = match setter {
+            LegalEntitySetter::EntityType(set)       => set(entity, LegalEntityType::Individual),
+            LegalEntitySetter::PersonalIdNumber(set) => set(entity, "123456789".to_string()),
+            LegalEntitySetter::Verification(set)     => set(entity, "file_12345".to_string()),
+            _                                        => panic!("unexpected setter")
+        };
+    }
+    assert!(entity.entity_type.is_some());
+    assert_eq!(entity.personal_id_number, Some("123456789".to_string()));
+    assert_eq!(entity.verification, Some("file_12345".to_string()));
+}
+
+#[test]
+fn verified_accounts_need_nothing_now() {
+    let account = account("account_managed_verified", include_str!("fixtures/account_managed_verified.json"));
+    let checklist = OnboardingChecklist::from_account(&account);
+    assert!(checklist.is_complete());
+    assert!(checklist.requirements.is_empty());
+}
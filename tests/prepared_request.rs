@@ -0,0 +1,135 @@
+#![cfg(feature = "testing")]
+
+extern crate hyper;
+extern crate stripe;
+
+use hyper::method::Method;
+use std::panic;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use stripe::api::ApiCall;
+use stripe::errors::error::Error;
+use stripe::middleware::{Middleware, Request};
+use stripe::model::{Currency, NewCard};
+use stripe::testing::MockStripe;
+use stripe::StripeClient;
+
+struct AddMetadata;
+
+impl Middleware for AddMetadata {
+    fn before_send(&self, request: &mut Request) {
+        request.params.push(("metadata[source]".to_string(), "middleware".to_string()));
+    }
+}
+
+/// Panics while the flag is set.
+struct PanicWhile(Arc<AtomicBool>);
+
+impl Middleware for PanicWhile {
+    fn before_send(&self, _request: &mut Request) {
+        if self.0.load(Ordering::SeqCst) {
+            panic!("middleware panicked");
+        }
+    }
+}
+
+fn client(stripe: &MockStripe) -> StripeClient {
+    StripeClient::new("sk_test_prepared")
+        .with_transport(stripe.transport())
+        .with_middleware(AddMetadata)
+}
+
+fn card() -> NewCard {
+    NewCard::new("4242424242424242".to_string(), 8, 2030).cvc("123".to_string())
+}
+
+#[test]
+fn prepare_captures_the_request_after_middleware() {
+    let stripe = MockStripe::new();
+    let prepared = client(&stripe).create_charge(100, Currency::UnitedStatesDollar).prepare().unwrap();
+
+    assert_eq!(prepared.call_type, "CreateChargeCall");
+    assert_eq!(prepared.method, Method::Post);
+    assert!(prepared.url.ends_with("/charges"), "{}", prepared.url);
+    assert!(prepared.params.contains(&("amount".to_string(), "100".to_string())));
+    assert!(prepared.params.contains(&("metadata[source]".to_string(), "middleware".to_string())));
+    for &(_, ref value) in &prepared.headers {
+        assert!(!value.contains("sk_test_prepared"), "{}", value);
+    }
+    assert!(stripe.calls().is_empty());
+}
+
+#[test]
+fn card_numbers_are_redacted_from_debug_and_curl() {
+    let stripe = MockStripe::new();
+    let prepared = client(&stripe).create_card_token().card(card()).prepare().unwrap();
+    assert!(prepared.params.contains(&("card[number]".to_string(), "4242424242424242".to_string())));
+
+    let debug = format!("{:?}", prepared);
+    assert!(debug.contains("************4242"), "{}", debug);
+    assert!(!debug.contains("4242424242424242"), "{}", debug);
+    assert!(!debug.contains("\"123\""), "{}", debug);
+
+    let curl = prepared.to_curl();
+    assert!(curl.starts_with("curl -X POST "), "{}", curl);
+    assert!(curl.contains(" -u \"$STRIPE_API_KEY:\""), "{}", curl);
+    assert!(curl.contains("card[number]=************4242"), "{}", curl);
+    assert!(!curl.contains("4242424242424242"), "{}", curl);
+    assert!(!curl.contains("sk_test_prepared"), "{}", curl);
+
+    let display = prepared.to_string();
+    assert!(!display.contains("4242424242424242"), "{}", display);
+}
+
+struct AddAccountNumber;
+
+impl Middleware for AddAccountNumber {
+    fn before_send(&self, request: &mut Request) {
+        request.params.push(("bank_account[account_number]".to_string(), "000123456789".to_string()));
+    }
+}
+
+#[test]
+fn get_urls_leave_the_params_out() {
+    let stripe = MockStripe::new();
+    let client = client(&stripe).with_middleware(AddAccountNumber);
+    let prepared = client.list_charges().limit(3).prepare().unwrap();
+
+    assert!(prepared.url.ends_with("/charges"), "{}", prepared.url);
+    assert!(prepared.params.contains(&("limit".to_string(), "3".to_string())));
+
+    let curl = prepared.to_curl();
+    assert!(curl.contains("/charges?"), "{}", curl);
+    assert!(curl.contains("limit=3"), "{}", curl);
+    assert!(!curl.contains("000123456789"), "{}", curl);
+    assert!(!format!("{:?}", prepared).contains("000123456789"));
+}
+
+#[test]
+fn dry_run_clients_return_the_request_without_sending_it() {
+    let stripe = MockStripe::new();
+    let client = client(&stripe).dry_run();
+    match client.create_charge(100, Currency::UnitedStatesDollar).call() {
+        Err(Error::DryRun(prepared)) => {
+            assert_eq!(prepared.call_type, "CreateChargeCall");
+            assert!(prepared.params.contains(&("metadata[source]".to_string(), "middleware".to_string())));
+        },
+        other => panic!("expected a dry run, got {:?}", other)
+    }
+    assert!(stripe.calls().is_empty());
+}
+
+#[test]
+fn a_panic_while_preparing_does_not_leave_the_thread_in_dry_run() {
+    let stripe = MockStripe::new();
+    stripe.respond_with_json(Method::Get, "/balance", include_str!("fixtures/balance.json"));
+    let panicking = Arc::new(AtomicBool::new(true));
+    let client = client(&stripe).with_middleware(PanicWhile(panicking.clone()));
+
+    let prepared = panic::catch_unwind(panic::AssertUnwindSafe(|| client.retrieve_balance().prepare()));
+    assert!(prepared.is_err());
+
+    panicking.store(false, Ordering::SeqCst);
+    assert!(client.retrieve_balance().call().is_ok());
+    assert_eq!(stripe.calls().len(), 1);
+}
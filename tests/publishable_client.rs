@@ -0,0 +1,30 @@
+extern crate stripe;
+
+use stripe::api::ApiCall;
+use stripe::model::NewCard;
+use stripe::{KeyMode, PublishableClient, PublishableKey};
+
+#[test]
+fn only_publishable_keys_are_accepted() {
+    assert!(PublishableKey::new("sk_test_123").is_none());
+    assert!(PublishableKey::new("rk_live_123").is_none());
+
+    let key = PublishableKey::new("pk_test_123").unwrap();
+    assert_eq!(key.mode(), KeyMode::Test);
+    assert_eq!(key.as_str(), "pk_test_123");
+}
+
+#[test]
+fn the_key_is_sent_as_a_param_instead_of_basic_auth() {
+    let client = PublishableClient::new(PublishableKey::new("pk_test_123").unwrap());
+    let card = NewCard::new("4242424242424242".to_string(), 8, 2030);
+    let prepared = client.create_card_token().card(card).prepare().unwrap();
+
+    assert_eq!(prepared.call_type, "CreateCardTokenCall");
+    assert!(prepared.url.ends_with("/tokens"), "{}", prepared.url);
+    assert!(prepared.params.contains(&("key".to_string(), "pk_test_123".to_string())));
+    assert!(prepared.headers.iter().all(|&(ref name, _)| name.to_lowercase() != "authorization"));
+
+    let prepared = client.create_pii_token("000000000".to_string()).prepare().unwrap();
+    assert!(prepared.params.contains(&("key".to_string(), "pk_test_123".to_string())));
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "testing")]
+
+extern crate hyper;
+extern crate serde_json;
+extern crate stripe;
+
+use hyper::method::Method;
+use serde_json::Value;
+use stripe::api::ApiCall;
+use stripe::testing::MockStripe;
+use stripe::{SchemaDrift, StripeClient};
+
+/// A charge with two fields `Charge` doesn't declare, one it does left out,
+/// a status it doesn't know, and a card source with a field `Card` doesn't
+/// declare.
+fn drifted_charge() -> String {
+    let mut charge: Value = serde_json::from_str(include_str!("fixtures/charge_unknown_fields.json")).unwrap();
+    if let Value::Object(ref mut charge) = charge {
+        charge.remove("order");
+        charge.insert("status".to_string(), Value::String("blocked".to_string()));
+    }
+    serde_json::to_string(&charge).unwrap()
+}
+
+fn client() -> StripeClient {
+    let stripe = MockStripe::new();
+    stripe.respond_with_json(Method::Get, "/charges/ch_1", &drifted_charge());
+    StripeClient::new("sk_test_drift").with_transport(stripe.transport())
+}
+
+#[test]
+fn drift_is_aggregated_per_type() {
+    let drift = SchemaDrift::new();
+    let client = client().detect_schema_drift(&drift);
+    client.retrieve_charge("ch_1".parse().unwrap()).call().unwrap();
+    client.retrieve_charge("ch_1".parse().unwrap()).call().unwrap();
+
+    let report = drift.report();
+    assert_eq!(report["Charge"].ignored_fields.get("outcome"), Some(&2));
+    assert_eq!(report["Charge"].ignored_fields.get("review"), Some(&2));
+    assert_eq!(report["Charge"].missing_fields.get("order"), Some(&2));
+    assert_eq!(report["ChargeStatus"].unknown_values.get("blocked"), Some(&2));
+    assert_eq!(report["Card"].ignored_fields.get("three_d_secure"), Some(&2));
+    assert!(drift.to_string().contains("Charge: ignored outcome (2), review (2); missing order (2)\n"));
+
+    drift.clear();
+    assert!(drift.report().is_empty());
+}
+
+#[test]
+fn clients_without_a_detector_record_nothing() {
+    let drift = SchemaDrift::new();
+    let _detecting = client().detect_schema_drift(&drift);
+    client().retrieve_charge("ch_1".parse().unwrap()).call().unwrap();
+    assert!(drift.report().is_empty());
+}
@@ -0,0 +1,30 @@
+extern crate stripe;
+
+use stripe::model::{Country, Currency, NewBankAccount, NewCard};
+use stripe::{SecretKey, StripeClient};
+
+const KEY: &'static str = "sk_test_4eC39HqLyjWDarjtT1zdp7dc";
+
+#[test]
+fn keys_never_show_up_in_debug_or_display() {
+    let key = SecretKey::new(KEY);
+    assert_eq!(format!("{:?}", key), "SecretKey([REDACTED])");
+    assert_eq!(key.to_string(), "[REDACTED]");
+    assert_eq!(key.expose(), KEY);
+
+    let client = format!("{:?}", StripeClient::new(KEY));
+    assert!(!client.contains(KEY), "{}", client);
+}
+
+#[test]
+fn card_and_account_numbers_are_masked_in_debug() {
+    let card = format!("{:?}", NewCard::new("4242424242424242".to_string(), 8, 2030).cvc("123".to_string()));
+    assert!(!card.contains("4242424242424242"), "{}", card);
+    assert!(card.contains("************4242"), "{}", card);
+    assert!(!card.contains("123"), "{}", card);
+
+    let account = NewBankAccount::new("000123456789".to_string(), Country::UnitedStates, Currency::UnitedStatesDollar);
+    let account = format!("{:?}", account);
+    assert!(!account.contains("000123456789"), "{}", account);
+    assert!(account.contains("********6789"), "{}", account);
+}
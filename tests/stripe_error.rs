@@ -0,0 +1,17 @@
+extern crate serde_json;
+extern crate stripe;
+
+use stripe::errors::stripe_error::StripeErrorCode;
+use stripe::model::Charge;
+
+#[test]
+fn error_codes_parse_as_stripe_sends_them() {
+    let code: StripeErrorCode = serde_json::from_str("\"invalid_expiry_year\"").unwrap();
+    assert_eq!(code, StripeErrorCode::InvalidExpiryYear);
+    assert_eq!(serde_json::to_string(&code).unwrap(), "\"invalid_expiry_year\"");
+
+    let json = include_str!("fixtures/charge_failed.json")
+        .replace("\"card_declined\"", "\"invalid_expiry_year\"");
+    let charge: Charge = serde_json::from_str(&json).unwrap();
+    assert_eq!(charge.failure_code, Some(StripeErrorCode::InvalidExpiryYear));
+}
@@ -0,0 +1,51 @@
+#[cfg(feature = "timestamps")]
+extern crate chrono;
+extern crate stripe;
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use stripe::TimeConstraint;
+use stripe::model::Timestamp;
+
+#[test]
+fn timestamps_convert_to_and_from_system_time() {
+    let time = UNIX_EPOCH + Duration::from_secs(1462000000);
+    assert_eq!(Timestamp::from(time).as_secs(), 1462000000);
+    assert_eq!(Timestamp::from_secs(1462000000).to_system_time(), time);
+
+    let before_epoch = UNIX_EPOCH - Duration::from_millis(1500);
+    assert_eq!(Timestamp::from(before_epoch).as_secs(), -2);
+    assert_eq!(Timestamp::from_secs(-2).to_system_time(), UNIX_EPOCH - Duration::from_secs(2));
+}
+
+#[test]
+fn between_includes_the_start_but_not_the_end() {
+    let start = UNIX_EPOCH + Duration::from_secs(1462000000);
+    let constraint = TimeConstraint::between(start, start + Duration::from_secs(86400));
+    assert_eq!(constraint.gte, Some(1462000000));
+    assert_eq!(constraint.lt, Some(1462086400));
+    assert_eq!(constraint.gt, None);
+    assert_eq!(constraint.lte, None);
+}
+
+#[test]
+fn since_counts_back_from_now() {
+    let before = Timestamp::now().as_secs();
+    let constraint = TimeConstraint::since(Duration::from_secs(3600));
+    let after = Timestamp::now().as_secs();
+    let start = constraint.gte.unwrap();
+    assert!(start >= before - 3600 && start <= after - 3600);
+    assert_eq!(constraint.lt, None);
+}
+
+#[cfg(feature = "timestamps")]
+#[test]
+fn on_day_covers_the_day_in_the_time_zone() {
+    use chrono::{FixedOffset, NaiveDate, UTC};
+
+    let date = NaiveDate::from_ymd(2016, 5, 1);
+    let utc = TimeConstraint::on_day(date, &UTC).unwrap();
+    assert_eq!((utc.gte, utc.lt), (Some(1462060800), Some(1462147200)));
+
+    let pacific = TimeConstraint::on_day(date, &FixedOffset::west(7 * 3600)).unwrap();
+    assert_eq!((pacific.gte, pacific.lt), (Some(1462086000), Some(1462172400)));
+}
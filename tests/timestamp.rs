@@ -0,0 +1,20 @@
+extern crate serde_json;
+extern crate stripe;
+
+use serde_json::Value;
+use stripe::model::{Charge, Timestamp};
+
+#[test]
+fn timestamps_parse_as_timestamps() {
+    let charge: Charge = serde_json::from_str(include_str!("fixtures/charge.json")).unwrap();
+    assert_eq!(charge.created, Timestamp::from_secs(1462000000));
+    assert_eq!(serde_json::to_value(&charge).find("created").and_then(Value::as_i64), Some(1462000000));
+}
+
+#[test]
+fn timestamps_convert_to_and_from_system_time() {
+    let timestamp = Timestamp::from_secs(1462000000);
+    assert_eq!(Timestamp::from(timestamp.to_system_time()), timestamp);
+    assert_eq!(Timestamp::from_secs(-1).year_month(), (1969, 12));
+    assert_eq!(timestamp.year_month(), (2016, 4));
+}